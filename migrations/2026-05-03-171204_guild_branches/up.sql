@@ -0,0 +1,35 @@
+CREATE TABLE guild_branches (
+    id BIGSERIAL PRIMARY KEY,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    guild_id NUMERIC NOT NULL,
+    tag VARCHAR(100) NOT NULL,
+    display_name VARCHAR(100) NOT NULL,
+    ansi_color SMALLINT NOT NULL,
+    emoji VARCHAR(100),
+    sort_order INT NOT NULL DEFAULT 0
+);
+
+CREATE UNIQUE INDEX unique_guild_branch_per_guild_per_tag ON guild_branches (guild_id, tag);
+
+-- Carry over the branches that used to be hard-coded for every guild that has goals.
+INSERT INTO guild_branches (
+    guild_id,
+    tag,
+    display_name,
+    ansi_color,
+    sort_order
+)
+SELECT DISTINCT
+    monthly_goals.guild_id,
+    branches.tag,
+    branches.display_name,
+    branches.ansi_color,
+    branches.sort_order
+FROM monthly_goals
+CROSS JOIN (
+    VALUES
+        ('navy', 'Navy', 34, 1),
+        ('legion', 'Legion', 32, 2),
+        ('industry', 'Industry', 35, 3)
+) AS branches (tag, display_name, ansi_color, sort_order);
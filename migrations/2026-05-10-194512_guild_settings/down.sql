@@ -0,0 +1,2 @@
+DROP INDEX tracker_count_changes_by_stat_guild_created;
+DROP TABLE guild_settings;
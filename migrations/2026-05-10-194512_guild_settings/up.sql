@@ -0,0 +1,8 @@
+CREATE TABLE guild_settings (
+    guild_id NUMERIC PRIMARY KEY,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    timezone VARCHAR(64) NOT NULL DEFAULT 'UTC'
+);
+
+CREATE INDEX tracker_count_changes_by_stat_guild_created ON tracker_count_changes (stat, guild_id, created);
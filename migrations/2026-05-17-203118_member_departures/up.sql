@@ -0,0 +1,6 @@
+CREATE TABLE guild_member_departures (
+    guild_id NUMERIC NOT NULL,
+    user_id NUMERIC NOT NULL,
+    departed TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (guild_id, user_id)
+);
@@ -0,0 +1 @@
+ALTER TABLE tracker_counts DROP COLUMN archived;
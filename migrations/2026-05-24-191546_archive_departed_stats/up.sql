@@ -0,0 +1,7 @@
+ALTER TABLE tracker_counts ADD COLUMN archived TIMESTAMP WITH TIME ZONE;
+
+UPDATE tracker_counts
+SET archived = guild_member_departures.departed
+FROM guild_member_departures
+WHERE tracker_counts.guild_id = guild_member_departures.guild_id
+    AND tracker_counts.user_id = guild_member_departures.user_id;
@@ -0,0 +1,2 @@
+DROP TABLE season_results;
+DROP TABLE seasons;
@@ -0,0 +1,24 @@
+CREATE TABLE seasons (
+    id BIGSERIAL PRIMARY KEY,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    guild_id NUMERIC NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    -- Both inclusive, as calendar days in the guild's timezone.
+    starts DATE NOT NULL,
+    ends DATE NOT NULL,
+    finalized TIMESTAMP WITH TIME ZONE,
+    CHECK (starts <= ends)
+);
+
+CREATE UNIQUE INDEX unique_season_per_guild_per_name ON seasons (guild_id, name);
+
+CREATE TABLE season_results (
+    id BIGSERIAL PRIMARY KEY,
+    season_id BIGINT NOT NULL REFERENCES seasons (id) ON DELETE CASCADE,
+    stat VARCHAR(100) NOT NULL,
+    rank INT NOT NULL,
+    user_id NUMERIC NOT NULL,
+    total NUMERIC NOT NULL
+);
+
+CREATE UNIQUE INDEX unique_season_result_per_stat_per_user ON season_results (season_id, stat, user_id);
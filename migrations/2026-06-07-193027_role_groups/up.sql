@@ -0,0 +1,9 @@
+CREATE TABLE guild_role_groups (
+    id BIGSERIAL PRIMARY KEY,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    guild_id NUMERIC NOT NULL,
+    role_id NUMERIC NOT NULL,
+    name VARCHAR(100) NOT NULL
+);
+
+CREATE UNIQUE INDEX unique_guild_role_group_per_guild_per_role ON guild_role_groups (guild_id, role_id);
@@ -0,0 +1,2 @@
+DROP TABLE profit_run_shares;
+DROP TABLE profit_runs;
@@ -0,0 +1,26 @@
+CREATE TABLE profit_runs (
+    id BIGSERIAL PRIMARY KEY,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    guild_id NUMERIC NOT NULL,
+    -- Whoever funded the run and collected the revenue, and so owes the crew their shares.
+    owner NUMERIC NOT NULL,
+    recorded_by NUMERIC NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    gross NUMERIC NOT NULL,
+    costs NUMERIC NOT NULL,
+    CHECK (gross >= costs)
+);
+
+CREATE INDEX profit_runs_by_guild_and_owner ON profit_runs (guild_id, owner);
+
+CREATE TABLE profit_run_shares (
+    id BIGSERIAL PRIMARY KEY,
+    run_id BIGINT NOT NULL REFERENCES profit_runs (id) ON DELETE CASCADE,
+    user_id NUMERIC NOT NULL,
+    percent NUMERIC NOT NULL,
+    amount NUMERIC NOT NULL,
+    -- NULL while the owner still owes the payout.
+    paid TIMESTAMP WITH TIME ZONE
+);
+
+CREATE UNIQUE INDEX unique_profit_run_share_per_run_per_user ON profit_run_shares (run_id, user_id);
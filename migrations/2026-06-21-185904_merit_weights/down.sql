@@ -0,0 +1 @@
+DROP TABLE guild_merit_weights;
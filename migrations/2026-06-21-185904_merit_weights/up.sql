@@ -0,0 +1,11 @@
+CREATE TABLE guild_merit_weights (
+    guild_id NUMERIC NOT NULL,
+    stat VARCHAR(100) NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    -- Merit earned for every `per` of the stat, counted in the same units members see, e.g. 1 point per 100000 aUEC.
+    points NUMERIC NOT NULL,
+    per NUMERIC NOT NULL DEFAULT 1,
+    PRIMARY KEY (guild_id, stat),
+    CHECK (per > 0)
+);
@@ -0,0 +1,3 @@
+DROP TABLE guild_stat_limits;
+
+ALTER TABLE guild_settings DROP COLUMN officer_channel;
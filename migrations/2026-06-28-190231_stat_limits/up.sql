@@ -0,0 +1,19 @@
+ALTER TABLE guild_settings ADD COLUMN officer_channel NUMERIC;
+
+-- Limits are stored in the same units as tracker counts. A missing limit means there isn't one.
+CREATE TABLE guild_stat_limits (
+    guild_id NUMERIC NOT NULL,
+    stat VARCHAR(100) NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    -- Largest single record.
+    max_change_soft NUMERIC,
+    max_change_hard NUMERIC,
+    -- Most that can be recorded for one member in a day, by anyone.
+    daily_cap_soft NUMERIC,
+    daily_cap_hard NUMERIC,
+    -- Most members can record for themselves in a day.
+    self_daily_soft NUMERIC,
+    self_daily_hard NUMERIC,
+    PRIMARY KEY (guild_id, stat)
+);
@@ -0,0 +1,3 @@
+DROP TABLE event_attendees;
+DROP TABLE event_rsvps;
+DROP TABLE events;
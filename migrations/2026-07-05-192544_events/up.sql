@@ -0,0 +1,32 @@
+CREATE TABLE events (
+    id BIGSERIAL PRIMARY KEY,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    guild_id NUMERIC NOT NULL,
+    host NUMERIC NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    description VARCHAR(1000),
+    starts TIMESTAMP WITH TIME ZONE NOT NULL,
+    -- Where the event card with the RSVP buttons was posted.
+    channel_id NUMERIC NOT NULL,
+    message_id NUMERIC,
+    -- Set once attendees have been credited, which can only happen once.
+    roll_called TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX events_by_guild_starts ON events (guild_id, starts);
+
+CREATE TABLE event_rsvps (
+    event_id BIGINT NOT NULL REFERENCES events (id) ON DELETE CASCADE,
+    user_id NUMERIC NOT NULL,
+    updated TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    response VARCHAR(16) NOT NULL,
+    PRIMARY KEY (event_id, user_id)
+);
+
+-- Links each confirmed attendee to the participation credit they were given.
+CREATE TABLE event_attendees (
+    event_id BIGINT NOT NULL REFERENCES events (id) ON DELETE CASCADE,
+    user_id NUMERIC NOT NULL,
+    change_id BIGINT NOT NULL REFERENCES tracker_count_changes (id) ON DELETE CASCADE,
+    PRIMARY KEY (event_id, user_id)
+);
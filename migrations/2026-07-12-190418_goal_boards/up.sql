@@ -0,0 +1,11 @@
+-- Goal boards kept up to date whenever the guild's monthly goals change, one per branch.
+CREATE TABLE goal_boards (
+    guild_id NUMERIC NOT NULL,
+    branch VARCHAR(100) NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    channel_id NUMERIC NOT NULL,
+    message_id NUMERIC NOT NULL,
+    show_details BOOLEAN NOT NULL,
+    show_branches BOOLEAN NOT NULL,
+    PRIMARY KEY (guild_id, branch)
+);
@@ -0,0 +1,7 @@
+ALTER TABLE guild_branches DROP COLUMN channel_id;
+
+DROP TABLE goal_milestones;
+
+ALTER TABLE monthly_goals
+    DROP COLUMN deadline,
+    DROP COLUMN reminders_sent;
@@ -0,0 +1,14 @@
+ALTER TABLE monthly_goals
+    ADD COLUMN deadline TIMESTAMP WITH TIME ZONE,
+    -- How many of the reminders leading up to the deadline have gone out, reset whenever the deadline moves.
+    ADD COLUMN reminders_sent SMALLINT NOT NULL DEFAULT 0;
+
+-- Progress percentages announced when a goal crosses them, on top of the usual quarters.
+CREATE TABLE goal_milestones (
+    goal_id BIGINT NOT NULL REFERENCES monthly_goals (id) ON DELETE CASCADE,
+    percent SMALLINT NOT NULL,
+    PRIMARY KEY (goal_id, percent)
+);
+
+-- Where reminders and announcements for the branch's goals are posted.
+ALTER TABLE guild_branches ADD COLUMN channel_id NUMERIC;
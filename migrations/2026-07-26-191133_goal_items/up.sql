@@ -0,0 +1,12 @@
+-- Checklist items of a goal. Once a goal has any, its progress is the share of them that are completed.
+CREATE TABLE goal_items (
+    id BIGSERIAL PRIMARY KEY,
+    goal_id BIGINT NOT NULL REFERENCES monthly_goals (id) ON DELETE CASCADE,
+    -- 1-based, in the order the items were added.
+    position INTEGER NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    description VARCHAR(256) NOT NULL,
+    completed TIMESTAMP WITH TIME ZONE,
+    completed_by NUMERIC,
+    UNIQUE (goal_id, position)
+);
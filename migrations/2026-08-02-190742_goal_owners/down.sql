@@ -0,0 +1,3 @@
+DROP TABLE goal_pledges;
+
+ALTER TABLE monthly_goals DROP COLUMN owner_id;
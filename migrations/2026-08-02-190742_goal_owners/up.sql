@@ -0,0 +1,10 @@
+-- The officer responsible for a goal, who hears about pledges and progress.
+ALTER TABLE monthly_goals ADD COLUMN owner_id NUMERIC;
+
+-- Members who've offered to help with a goal.
+CREATE TABLE goal_pledges (
+    goal_id BIGINT NOT NULL REFERENCES monthly_goals (id) ON DELETE CASCADE,
+    user_id NUMERIC NOT NULL,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (goal_id, user_id)
+);
@@ -0,0 +1,16 @@
+-- Every change to a goal's header, body or progress, with what it was before. The `old_` columns are empty for the
+-- revision that created the goal.
+CREATE TABLE goal_revisions (
+    id BIGSERIAL PRIMARY KEY,
+    goal_id BIGINT NOT NULL REFERENCES monthly_goals (id) ON DELETE CASCADE,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updater NUMERIC NOT NULL,
+    old_header VARCHAR(256),
+    old_body VARCHAR(4096),
+    old_progress SMALLINT,
+    header VARCHAR(256) NOT NULL,
+    body VARCHAR(4096) NOT NULL,
+    progress SMALLINT NOT NULL
+);
+
+CREATE INDEX goal_revisions_goal_id ON goal_revisions (goal_id, created);
@@ -0,0 +1,2 @@
+-- How progress bars are drawn by default: `ansi`, `unicode`, `emoji` or `embed`.
+ALTER TABLE guild_settings ADD COLUMN bar_style VARCHAR(20) NOT NULL DEFAULT 'ansi';
@@ -0,0 +1,7 @@
+-- Tokens for the read-only HTTP API, one per guild. Only a SHA-256 hash of the token is kept.
+CREATE TABLE guild_api_tokens (
+    guild_id NUMERIC PRIMARY KEY,
+    created TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    created_by NUMERIC NOT NULL,
+    token_hash VARCHAR(64) NOT NULL UNIQUE
+);
@@ -0,0 +1 @@
+ALTER TABLE tracker_count_changes DROP COLUMN transfer;
@@ -0,0 +1,3 @@
+-- Ledger entries written by a stat transfer. They move lifetime totals between accounts, so they're left out of anything
+-- counting activity over time.
+ALTER TABLE tracker_count_changes ADD COLUMN transfer BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,351 @@
+//! Read-only JSON over HTTP, for things like the org website. Off unless the `[api]` section of the configuration
+//! enables it, and every request needs the bearer token of the guild it asks about, see `/settings api_token`.
+//!
+//! - `GET /v1/guilds/{guild_id}/scoreboards/{stat}?start=0&limit=25`
+//! - `GET /v1/guilds/{guild_id}/members/{user_id}`
+//! - `GET /v1/guilds/{guild_id}/goals?status=active` or `status=archived`
+
+use std::{borrow::Cow, convert::Infallible, net::SocketAddr, sync::Arc};
+
+use http_body_util::Full;
+use hyper::{Method, Request, Response, StatusCode, body::{Bytes, Incoming}, header, server::conn::http1, service::service_fn};
+use hyper_util::rt::TokioIo;
+use serde::Serialize;
+use serenity::all::{GuildId, UserId};
+use sha2::{Digest, Sha256};
+use tokio::net::TcpListener;
+use tracing as trc;
+
+use azel::{DatabaseConfiguration, db::{Connector, DbError}};
+
+use crate::db::{self, DiscordGuildId, DiscordUserId, MonthlyGoal, TrackerStat};
+
+const DEFAULT_LIMIT: i64 = 25;
+const MAX_LIMIT: i64 = 100;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ApiConfiguration {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_listen")]
+    pub listen: SocketAddr,
+}
+
+impl Default for ApiConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            listen: default_listen(),
+        }
+    }
+}
+
+fn default_listen() -> SocketAddr {
+    SocketAddr::from(([127, 0, 0, 1], 8080))
+}
+
+/// Tokens are only ever stored hashed.
+pub fn hash_token(token: &str) -> String {
+    Sha256::digest(token.as_bytes()).iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Runs forever, so spawn it.
+pub async fn serve(cfg: ApiConfiguration, db_cfg: Arc<DatabaseConfiguration>) {
+    let listener = match TcpListener::bind(cfg.listen).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            trc::error!("Failed to start the HTTP API on {} due to {e:?}.", cfg.listen);
+            return;
+        },
+    };
+    trc::info!("HTTP API listening on {}.", cfg.listen);
+
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                trc::warn!("Failed to accept HTTP API connection due to {e:?}.");
+                continue;
+            },
+        };
+        let db_cfg = Arc::clone(&db_cfg);
+        tokio::spawn(async move {
+            let service = service_fn(move |req| {
+                let db_cfg = Arc::clone(&db_cfg);
+                async move { Ok::<_, Infallible>(handle(&db_cfg.as_ref(), req).await) }
+            });
+            if let Err(e) = http1::Builder::new().serve_connection(TokioIo::new(stream), service).await {
+                trc::info!("HTTP API connection ended due to {e:?}.");
+            }
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Route {
+    Scoreboard { guild_id: GuildId, stat: TrackerStat, start: i64, limit: i64 },
+    Member { guild_id: GuildId, user_id: UserId },
+    Goals { guild_id: GuildId, archived: bool, limit: i64 },
+}
+
+impl Route {
+    fn guild_id(&self) -> GuildId {
+        match self {
+            Self::Scoreboard { guild_id, .. } | Self::Member { guild_id, .. } | Self::Goals { guild_id, .. } => *guild_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ApiError(StatusCode, Cow<'static, str>);
+
+fn route(path: &str, query: Option<&str>) -> Result<Route, ApiError> {
+    const NOT_FOUND: ApiError = ApiError(StatusCode::NOT_FOUND, Cow::Borrowed("Not found."));
+
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let ["v1", "guilds", guild_id, rest @ ..] = segments.as_slice() else {
+        return Err(NOT_FOUND);
+    };
+    let guild_id = parse_id(guild_id).map(GuildId::new).ok_or(NOT_FOUND)?;
+    let param = |name: &str| query.unwrap_or_default()
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value);
+    let number = |name: &str, default: i64, max: i64| match param(name) {
+        None => Ok(default),
+        Some(value) => value.parse::<i64>().ok()
+            .filter(|value| (0..=max).contains(value))
+            .ok_or_else(|| ApiError(StatusCode::BAD_REQUEST, match max {
+                i64::MAX => format!("`{name}` must be a whole number, 0 or more."),
+                max => format!("`{name}` must be a whole number from 0 to {max}."),
+            }.into())),
+    };
+
+    match rest {
+        ["scoreboards", stat] => Ok(Route::Scoreboard {
+            guild_id,
+            stat: stat.parse().map_err(|_| NOT_FOUND)?,
+            start: number("start", 0, i64::MAX)?,
+            limit: number("limit", DEFAULT_LIMIT, MAX_LIMIT)?,
+        }),
+        ["members", user_id] => Ok(Route::Member {
+            guild_id,
+            user_id: parse_id(user_id).map(UserId::new).ok_or(NOT_FOUND)?,
+        }),
+        ["goals"] => Ok(Route::Goals {
+            guild_id,
+            archived: match param("status") {
+                None | Some("active") => false,
+                Some("archived") => true,
+                Some(_) => return Err(ApiError(StatusCode::BAD_REQUEST, "`status` must be `active` or `archived`.".into())),
+            },
+            limit: number("limit", DEFAULT_LIMIT, MAX_LIMIT)?,
+        }),
+        _ => Err(NOT_FOUND),
+    }
+}
+
+/// Discord ids are never zero, and serenity panics on one.
+fn parse_id(s: &str) -> Option<u64> {
+    s.parse().ok().filter(|id| *id != 0)
+}
+
+fn bearer_token(req: &Request<Incoming>) -> Option<&str> {
+    req.headers().get(header::AUTHORIZATION)?.to_str().ok()?.strip_prefix("Bearer ")
+}
+
+async fn handle(db_cfg: &impl Connector, req: Request<Incoming>) -> Response<Full<Bytes>> {
+    match respond(db_cfg, &req).await {
+        Ok(body) => json(StatusCode::OK, body),
+        Err(ApiError(status, error)) => json(status, serde_json::json!({ "error": error }).to_string()),
+    }
+}
+
+async fn respond(db_cfg: &impl Connector, req: &Request<Incoming>) -> Result<String, ApiError> {
+    if req.method() != Method::GET {
+        return Err(ApiError(StatusCode::METHOD_NOT_ALLOWED, "Only GET is supported.".into()));
+    }
+    let route = route(req.uri().path(), req.uri().query())?;
+
+    const UNAUTHORIZED: ApiError = ApiError(StatusCode::UNAUTHORIZED, Cow::Borrowed("Missing or invalid token."));
+    let token = bearer_token(req).ok_or(UNAUTHORIZED)?;
+    let token_guild_id = match db::GuildApiToken::load_guild_for(db_cfg, hash_token(token).as_str()).await {
+        Ok(guild_id) => guild_id.ok_or(UNAUTHORIZED)?,
+        Err(e) => {
+            trc::error!("Failed to look up API token due to {e:?}.");
+            return Err(ApiError(StatusCode::INTERNAL_SERVER_ERROR, "Failed to check the token.".into()));
+        },
+    };
+    if token_guild_id != DiscordGuildId::from(route.guild_id()) {
+        return Err(ApiError(StatusCode::FORBIDDEN, "The token belongs to another server.".into()));
+    }
+
+    let body = match route {
+        Route::Scoreboard { guild_id, stat, start, limit } => serde_json::to_string(&scoreboard(db_cfg, guild_id.into(), stat, start, limit).await?),
+        Route::Member { guild_id, user_id } => serde_json::to_string(&member(db_cfg, guild_id.into(), user_id.into()).await?),
+        Route::Goals { guild_id, archived, limit } => serde_json::to_string(&goals(db_cfg, guild_id, archived, limit).await?),
+    };
+    body.map_err(|e| {
+        trc::error!("Failed to serialize API response for {route:?} due to {e:?}.");
+        ApiError(StatusCode::INTERNAL_SERVER_ERROR, "Failed to build the response.".into())
+    })
+}
+
+fn json(status: StatusCode, body: String) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::from(body)));
+    *response.status_mut() = status;
+    response.headers_mut().insert(header::CONTENT_TYPE, header::HeaderValue::from_static("application/json"));
+    response
+}
+
+fn load_failed(what: &'static str) -> impl FnOnce(DbError) -> ApiError {
+    move |e| {
+        trc::error!("Failed to load {what} for the HTTP API due to {e:?}.");
+        ApiError(StatusCode::INTERNAL_SERVER_ERROR, "Failed to load data.".into())
+    }
+}
+
+/// Numbers are sent as strings, so ids and large totals survive JavaScript.
+#[derive(Debug, Serialize)]
+struct ScoreboardRow {
+    rank: i64,
+    user_id: String,
+    total: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Scoreboard {
+    stat: &'static str,
+    name: &'static str,
+    rows: Vec<ScoreboardRow>,
+}
+
+/// Same order and ranks as `/... scoreboard at:rank`.
+async fn scoreboard(db_cfg: &impl Connector, guild_id: DiscordGuildId, stat: TrackerStat, start: i64, limit: i64) -> Result<Scoreboard, ApiError> {
+    let counts = db::TrackerCount::load_asc(db_cfg, stat, guild_id, start, limit).await.map_err(load_failed("scoreboard"))?;
+    Ok(Scoreboard {
+        stat: stat.as_str(),
+        name: stat.as_command_opt_display_name(),
+        rows: counts.into_iter()
+            .enumerate()
+            .map(|(offset, count)| ScoreboardRow {
+                rank: start + offset as i64 + 1,
+                user_id: count.user_id.inner().to_string(),
+                total: stat.display_value(count.total).to_string(),
+            })
+            .collect(),
+    })
+}
+
+#[derive(Debug, Serialize)]
+struct MemberStat {
+    stat: &'static str,
+    name: &'static str,
+    total: String,
+    /// Left out while the member is away from the server, as they're kept off scoreboards.
+    rank: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+struct Member {
+    user_id: String,
+    stats: Vec<MemberStat>,
+}
+
+async fn member(db_cfg: &impl Connector, guild_id: DiscordGuildId, user_id: DiscordUserId) -> Result<Member, ApiError> {
+    let counts = db::TrackerCount::load_all_for_user(db_cfg, guild_id, user_id).await.map_err(load_failed("member stats"))?;
+    let mut stats = Vec::with_capacity(counts.len());
+    for count in counts {
+        let rank = match count.archived {
+            Some(_) => None,
+            None => Some(db::TrackerCount::get_rank_of(db_cfg, count.stat, guild_id, user_id).await.map_err(load_failed("member rank"))? + 1),
+        };
+        stats.push(MemberStat {
+            stat: count.stat.as_str(),
+            name: count.stat.as_command_opt_display_name(),
+            total: count.stat.display_value(count.total).to_string(),
+            rank,
+        });
+    }
+    Ok(Member {
+        user_id: user_id.inner().to_string(),
+        stats,
+    })
+}
+
+#[derive(Debug, Serialize)]
+struct Goal {
+    id: i64,
+    tag: String,
+    shortname: String,
+    header: String,
+    body: String,
+    progress: i16,
+    owner_id: Option<String>,
+    created: String,
+    deadline: Option<String>,
+    archived: Option<String>,
+}
+
+impl From<MonthlyGoal> for Goal {
+    fn from(goal: MonthlyGoal) -> Self {
+        Self {
+            id: goal.id,
+            tag: goal.tag,
+            shortname: goal.shortname,
+            header: goal.header,
+            body: goal.body,
+            progress: goal.progress,
+            owner_id: goal.owner_id.map(|owner_id| owner_id.inner().to_string()),
+            created: goal.created.to_rfc3339(),
+            deadline: goal.deadline.map(|deadline| deadline.to_rfc3339()),
+            archived: goal.disabled.map(|disabled| disabled.to_rfc3339()),
+        }
+    }
+}
+
+/// Active goals are all sent, `limit` only applies to the archive.
+async fn goals(db_cfg: &impl Connector, guild_id: GuildId, archived: bool, limit: i64) -> Result<Vec<Goal>, ApiError> {
+    let goals = match archived {
+        true => MonthlyGoal::load_archived(db_cfg, guild_id, limit).await,
+        false => MonthlyGoal::load_all_active(db_cfg, guild_id).await,
+    };
+    Ok(goals.map_err(load_failed("goals"))?.into_iter().map(Goal::from).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use hyper::StatusCode;
+    use serenity::all::{GuildId, UserId};
+
+    use crate::db::TrackerStat;
+
+    use super::{ApiError, Route, hash_token, route};
+
+    #[test]
+    fn test_route() {
+        let guild_id = GuildId::new(12);
+        assert_eq!(
+            route("/v1/guilds/12/scoreboards/naval_victory", Some("limit=10")),
+            Ok(Route::Scoreboard { guild_id, stat: TrackerStat::NavyVictory, start: 0, limit: 10 }),
+        );
+        assert_eq!(route("/v1/guilds/12/members/34", None), Ok(Route::Member { guild_id, user_id: UserId::new(34) }));
+        assert_eq!(route("/v1/guilds/12/goals", Some("status=archived")), Ok(Route::Goals { guild_id, archived: true, limit: 25 }));
+        assert_eq!(route("/v1/guilds/0/goals", None).map_err(|ApiError(status, _)| status), Err(StatusCode::NOT_FOUND));
+        assert_eq!(route("/v1/guilds/12/scoreboards/karma", None).map_err(|ApiError(status, _)| status), Err(StatusCode::NOT_FOUND));
+        assert_eq!(
+            route("/v1/guilds/12/goals", Some("limit=500")),
+            Err(ApiError(StatusCode::BAD_REQUEST, "`limit` must be a whole number from 0 to 100.".into())),
+        );
+        assert_eq!(
+            route("/v1/guilds/12/scoreboards/naval_victory", Some("start=-1")),
+            Err(ApiError(StatusCode::BAD_REQUEST, "`start` must be a whole number, 0 or more.".into())),
+        );
+    }
+
+    #[test]
+    fn test_hash_token() {
+        assert_eq!(hash_token("abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+}
@@ -0,0 +1,289 @@
+//! Turns calendar periods ("this month", explicit dates, ...) as seen from a guild's timezone into UTC ranges, which
+//! is what everything in the database is stored as.
+
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Datelike, Duration, LocalResult, Months, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::locale::Locale;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+const RANGE_SEPARATOR: &str = "..";
+
+/// Period option value asking for lifetime totals, overriding the running season.
+pub const ALL_TIME: &str = "all_time";
+
+pub fn is_all_time(s: &str) -> bool {
+    s.trim().to_lowercase().replace([' ', '-'], "_") == ALL_TIME
+}
+
+/// A span of local calendar days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    ThisWeek,
+    LastWeek,
+    ThisMonth,
+    LastMonth,
+    /// Both dates are inclusive.
+    Dates(NaiveDate, NaiveDate),
+}
+
+/// Half-open range, `start <= t < end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UtcRange {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl UtcRange {
+    pub fn contains(&self, t: DateTime<Utc>) -> bool {
+        self.start <= t && t < self.end
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeriodParseError {
+    Unrecognized(String),
+    Backwards(NaiveDate, NaiveDate),
+}
+
+impl fmt::Display for PeriodParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unrecognized(s) => write!(
+                f,
+                "`{s}` isn't a period I understand. Try `this_week`, `last_week`, `this_month`, `last_month`, a month like `2026-03`, a date like `2026-03-01`, or a range like `2026-03-01..2026-03-15`.",
+            ),
+            Self::Backwards(start, end) => write!(f, "The period starts ({start}) after it ends ({end})."),
+        }
+    }
+}
+
+impl FromStr for Period {
+    type Err = PeriodParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim().to_lowercase().replace([' ', '-'], "_");
+        match normalized.as_str() {
+            "this_week" | "week" => return Ok(Self::ThisWeek),
+            "last_week" => return Ok(Self::LastWeek),
+            "this_month" | "month" => return Ok(Self::ThisMonth),
+            "last_month" => return Ok(Self::LastMonth),
+            _ => {},
+        }
+
+        let s = s.trim();
+        let unrecognized = || PeriodParseError::Unrecognized(s.to_owned());
+        let (start, end) = if let Some((start, end)) = s.split_once(RANGE_SEPARATOR) {
+            let start = NaiveDate::parse_from_str(start.trim(), DATE_FORMAT).map_err(|_| unrecognized())?;
+            let end = NaiveDate::parse_from_str(end.trim(), DATE_FORMAT).map_err(|_| unrecognized())?;
+            (start, end)
+        } else if let Ok(date) = NaiveDate::parse_from_str(s, DATE_FORMAT) {
+            (date, date)
+        } else {
+            // Whole month, e.g. `2026-03`.
+            let start = NaiveDate::parse_from_str(format!("{s}-01").as_str(), DATE_FORMAT).map_err(|_| unrecognized())?;
+            let end = start.checked_add_months(Months::new(1)).ok_or_else(unrecognized)?.pred_opt().ok_or_else(unrecognized)?;
+            (start, end)
+        };
+
+        if start > end {
+            return Err(PeriodParseError::Backwards(start, end));
+        }
+        Ok(Self::Dates(start, end))
+    }
+}
+
+impl Period {
+    /// First local day of the period and the local day after the period ends.
+    pub fn local_dates(self, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        match self {
+            Self::ThisWeek => {
+                let start = start_of_week(today);
+                (start, start + Duration::days(7))
+            },
+            Self::LastWeek => {
+                let start = start_of_week(today) - Duration::days(7);
+                (start, start + Duration::days(7))
+            },
+            Self::ThisMonth => {
+                let start = start_of_month(today);
+                (start, start + Months::new(1))
+            },
+            Self::LastMonth => {
+                let start = start_of_month(today) - Months::new(1);
+                (start, start + Months::new(1))
+            },
+            Self::Dates(start, end) => (start, end + Duration::days(1)),
+        }
+    }
+
+    pub fn to_utc_range(self, tz: Tz, now: DateTime<Utc>) -> UtcRange {
+        let today = now.with_timezone(&tz).date_naive();
+        let (start, end) = self.local_dates(today);
+        UtcRange {
+            start: start_of_day(tz, start),
+            end: start_of_day(tz, end),
+        }
+    }
+
+    /// Phrase that reads naturally at the end of a sentence, e.g. "We have 3 events recorded for X this month."
+    pub fn describe(self) -> String {
+        self.describe_in(Locale::English)
+    }
+
+    pub fn describe_in(self, locale: Locale) -> String {
+        match self {
+            Self::ThisWeek => locale.translate("this week").to_owned(),
+            Self::LastWeek => locale.translate("last week").to_owned(),
+            Self::ThisMonth => locale.translate("this month").to_owned(),
+            Self::LastMonth => locale.translate("last month").to_owned(),
+            Self::Dates(start, end) if start == end => locale.format("on {}", &[&start.format(DATE_FORMAT)]),
+            Self::Dates(start, end) => locale.format("from {} to {}", &[&start.format(DATE_FORMAT), &end.format(DATE_FORMAT)]),
+        }
+    }
+}
+
+/// Weeks start on Monday.
+pub fn start_of_week(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
+
+pub fn start_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).expect("every month has a first day")
+}
+
+/// The first instant of `date` in `tz`.
+pub fn start_of_day(tz: Tz, date: NaiveDate) -> DateTime<Utc> {
+    let midnight = date.and_time(NaiveTime::MIN);
+    match tz.from_local_datetime(&midnight) {
+        LocalResult::Single(t) => t.with_timezone(&Utc),
+        LocalResult::Ambiguous(earliest, _) => earliest.with_timezone(&Utc),
+        LocalResult::None => {
+            // Midnight was skipped by a DST transition, so the day starts at the transition itself, which is
+            // midnight under the offset in effect just before it.
+            let before = tz.offset_from_utc_datetime(&(midnight - Duration::days(1)));
+            let offset = Duration::seconds(i64::from(before.fix().local_minus_utc()));
+            Utc.from_utc_datetime(&(midnight - offset))
+        },
+    }
+}
+
+pub fn parse_timezone(s: &str) -> Option<Tz> {
+    Tz::from_str(s.trim()).ok()
+}
+
+/// Parses `YYYY-MM-DD HH:MM` as a time in `tz`. Times skipped by a DST transition don't exist, times repeated by one
+/// are taken as the earlier.
+pub fn parse_local_datetime(s: &str, tz: Tz) -> Option<DateTime<Utc>> {
+    let local = NaiveDateTime::parse_from_str(s.trim(), DATETIME_FORMAT).ok()?;
+    tz.from_local_datetime(&local).earliest().map(|t| t.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+    use chrono_tz::Tz;
+
+    use super::{Period, PeriodParseError, UtcRange, parse_local_datetime, start_of_day};
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).expect("valid test timestamp").with_timezone(&Utc)
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).expect("valid test date")
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("this_month".parse(), Ok(Period::ThisMonth));
+        assert_eq!("Last Week".parse(), Ok(Period::LastWeek));
+        assert_eq!("2026-03-01".parse(), Ok(Period::Dates(date(2026, 3, 1), date(2026, 3, 1))));
+        assert_eq!("2026-03-01..2026-03-15".parse(), Ok(Period::Dates(date(2026, 3, 1), date(2026, 3, 15))));
+        assert_eq!("2026-02".parse(), Ok(Period::Dates(date(2026, 2, 1), date(2026, 2, 28))));
+        assert_eq!("2026-03-15..2026-03-01".parse::<Period>(), Err(PeriodParseError::Backwards(date(2026, 3, 15), date(2026, 3, 1))));
+        assert!(matches!("fortnight".parse::<Period>(), Err(PeriodParseError::Unrecognized(_))));
+    }
+
+    #[test]
+    fn test_month_rolls_over_year() {
+        assert_eq!(Period::ThisMonth.local_dates(date(2026, 12, 31)), (date(2026, 12, 1), date(2027, 1, 1)));
+        assert_eq!(Period::LastMonth.local_dates(date(2026, 1, 15)), (date(2025, 12, 1), date(2026, 1, 1)));
+        assert_eq!(Period::LastWeek.local_dates(date(2026, 1, 1)), (date(2025, 12, 22), date(2025, 12, 29)));
+    }
+
+    #[test]
+    fn test_local_evening_is_still_this_month() {
+        // 21:00 on the 31st in Sydney is already the 1st in UTC.
+        let now = utc("2026-08-31T11:00:00Z");
+        let range = Period::ThisMonth.to_utc_range(chrono_tz::Australia::Sydney, now);
+        assert_eq!(range, UtcRange {
+            start: utc("2026-07-31T14:00:00Z"),
+            end: utc("2026-08-31T14:00:00Z"),
+        });
+        assert!(range.contains(now));
+
+        // 20:00 on the 31st in Los Angeles is already the 1st in UTC.
+        let now = utc("2026-11-01T03:00:00Z");
+        let range = Period::ThisMonth.to_utc_range(chrono_tz::America::Los_Angeles, now);
+        assert_eq!(range.start, utc("2026-10-01T07:00:00Z"));
+        assert!(range.contains(now));
+    }
+
+    #[test]
+    fn test_dst_start_in_month() {
+        // Sydney moves from +10 to +11 on 2026-10-04.
+        let range = Period::ThisMonth.to_utc_range(chrono_tz::Australia::Sydney, utc("2026-10-15T00:00:00Z"));
+        assert_eq!(range.start, utc("2026-09-30T14:00:00Z"));
+        assert_eq!(range.end, utc("2026-10-31T13:00:00Z"));
+
+        // New York moves from -5 to -4 on 2026-03-08.
+        let range = Period::ThisMonth.to_utc_range(chrono_tz::America::New_York, utc("2026-03-15T00:00:00Z"));
+        assert_eq!(range.start, utc("2026-03-01T05:00:00Z"));
+        assert_eq!(range.end, utc("2026-04-01T04:00:00Z"));
+    }
+
+    #[test]
+    fn test_dst_week_is_short() {
+        let range = Period::ThisWeek.to_utc_range(chrono_tz::America::New_York, utc("2026-03-05T12:00:00Z"));
+        assert_eq!(range.start, utc("2026-03-02T05:00:00Z"));
+        assert_eq!(range.end, utc("2026-03-09T04:00:00Z"));
+        assert_eq!((range.end - range.start).num_hours(), 7 * 24 - 1);
+    }
+
+    #[test]
+    fn test_dst_end_in_range() {
+        // Berlin moves from +2 to +1 on 2026-10-25, so that day has 25 hours.
+        let range = Period::Dates(date(2026, 10, 25), date(2026, 10, 25)).to_utc_range(chrono_tz::Europe::Berlin, utc("2026-11-01T00:00:00Z"));
+        assert_eq!(range.start, utc("2026-10-24T22:00:00Z"));
+        assert_eq!(range.end, utc("2026-10-25T23:00:00Z"));
+        assert_eq!((range.end - range.start).num_hours(), 25);
+    }
+
+    #[test]
+    fn test_skipped_midnight() {
+        // Havana skips from 00:00 straight to 01:00 on 2026-03-08.
+        assert_eq!(start_of_day(chrono_tz::America::Havana, date(2026, 3, 8)), utc("2026-03-08T05:00:00Z"));
+        assert_eq!(start_of_day(chrono_tz::America::Havana, date(2026, 3, 9)), utc("2026-03-09T04:00:00Z"));
+    }
+
+    #[test]
+    fn test_utc_default() {
+        let range = Period::Dates(date(2026, 3, 1), date(2026, 3, 2)).to_utc_range(Tz::UTC, Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap());
+        assert_eq!(range.start, utc("2026-03-01T00:00:00Z"));
+        assert_eq!(range.end, utc("2026-03-03T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_parse_local_datetime() {
+        let tz = chrono_tz::Europe::Berlin;
+        assert_eq!(parse_local_datetime("2026-07-10 20:00", tz), Some(utc("2026-07-10T18:00:00Z")));
+        assert_eq!(parse_local_datetime(" 2026-01-10 20:00 ", tz), Some(utc("2026-01-10T19:00:00Z")));
+        // Clocks jump from 02:00 to 03:00.
+        assert_eq!(parse_local_datetime("2026-03-29 02:30", tz), None);
+        assert_eq!(parse_local_datetime("2026-07-10", tz), None);
+    }
+}
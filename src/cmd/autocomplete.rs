@@ -0,0 +1,100 @@
+use serenity::all::{CommandDataOption, CommandDataOptionValue, CommandInteraction, Context, CreateAutocompleteResponse, CreateInteractionResponse};
+use tracing as trc;
+
+use azel::db::Connector;
+
+use crate::{calendar::{self, Period}, cmd::{RequestError, event, lib::branch::Branches, monthly_goal, season, settings}, db};
+
+/// Responds to an autocomplete interaction for whichever option the user is currently typing in.
+pub async fn respond(ctx: &Context, db_cfg: &impl Connector, cmd: &CommandInteraction) -> Result<(), RequestError> {
+    let Some(focused) = cmd.data.autocomplete() else {
+        trc::warn!("Autocomplete interaction without a focused option {:?}", cmd.data);
+        return Ok(());
+    };
+    let Some(guild_id) = cmd.guild_id else {
+        return Ok(());
+    };
+
+    let choices = match (cmd.data.name.as_str(), focused.name) {
+        ("monthly_goal", "branch") => {
+            match Branches::load(db_cfg, guild_id.into()).await {
+                Ok(branches) => branches.autocomplete_choices(focused.value),
+                Err(e) => {
+                    trc::error!("Failed to load branches for autocomplete due to {e:?}.");
+                    return Err(RequestError::Internal("Failed to load branches.".into()));
+                },
+            }
+        },
+        ("event", "event") => {
+            match db::Event::load_open(db_cfg, guild_id.into()).await {
+                Ok(events) => event::autocomplete_choices(&events, focused.value),
+                Err(e) => {
+                    trc::error!("Failed to load events for autocomplete due to {e:?}.");
+                    return Err(RequestError::Internal("Failed to load events.".into()));
+                },
+            }
+        },
+        ("monthly_goal", "shortname") => {
+            match db::MonthlyGoal::load_all_active(db_cfg, guild_id).await {
+                Ok(goals) => monthly_goal::shortname_choices(&goals, string_option(&cmd.data.options, "branch"), focused.value),
+                Err(e) => {
+                    trc::error!("Failed to load monthly goals for autocomplete due to {e:?}.");
+                    return Err(RequestError::Internal("Failed to load monthly goals.".into()));
+                },
+            }
+        },
+        ("season", "name") => {
+            match db::Season::load_all(db_cfg, guild_id.into()).await {
+                Ok(seasons) => season::autocomplete_choices(&seasons, focused.value),
+                Err(e) => {
+                    trc::error!("Failed to load seasons for autocomplete due to {e:?}.");
+                    return Err(RequestError::Internal("Failed to load seasons.".into()));
+                },
+            }
+        },
+        ("settings", "timezone") => settings::timezone::autocomplete_choices(focused.value),
+        (_, "period") => period_choices(focused.value),
+        _ => {
+            trc::warn!("Unknown autocomplete option `{}` for `{}`", focused.name, cmd.data.name);
+            vec![]
+        },
+    };
+
+    let response = choices.into_iter()
+        .fold(CreateAutocompleteResponse::new(), |response, (name, value)| response.add_string_choice(name, value));
+    if let Err(e) = cmd.create_response(ctx, CreateInteractionResponse::Autocomplete(response)).await {
+        trc::error!("Failed to send autocomplete response due to {e:?}.");
+        return Err(RequestError::Internal("Failed to send autocomplete response.".into()));
+    }
+
+    Ok(())
+}
+
+/// A string option the user has already filled in elsewhere in the same command, looking inside subcommands.
+fn string_option<'a>(options: &'a [CommandDataOption], name: &str) -> Option<&'a str> {
+    options.iter().find_map(|opt| match &opt.value {
+        CommandDataOptionValue::SubCommand(options) | CommandDataOptionValue::SubCommandGroup(options) => string_option(options, name),
+        CommandDataOptionValue::String(value) if opt.name == name => Some(value.as_str()),
+        _ => None,
+    })
+}
+
+fn period_choices(partial: &str) -> Vec<(String, String)> {
+    const KEYWORDS: [(&str, &str); 5] = [
+        ("This week", "this_week"),
+        ("Last week", "last_week"),
+        ("This month", "this_month"),
+        ("Last month", "last_month"),
+        ("All time", calendar::ALL_TIME),
+    ];
+    let normalized = partial.trim().to_lowercase().replace([' ', '-'], "_");
+    let typed = match partial.parse::<Period>() {
+        Ok(Period::Dates(..)) => Some((partial.trim().to_owned(), partial.trim().to_owned())),
+        _ => None,
+    };
+    typed.into_iter()
+        .chain(KEYWORDS.into_iter()
+            .filter(|(_, value)| value.contains(normalized.as_str()))
+            .map(|(name, value)| (name.to_owned(), value.to_owned())))
+        .collect()
+}
@@ -0,0 +1,54 @@
+use std::borrow::Cow;
+
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::branch::Branches}, db};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, _options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+
+        Ok(Self {
+            guild_id,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let branches = match Branches::load(&ctx.db_cfg, self.guild_id).await {
+            Ok(branches) => branches,
+            Err(e) => {
+                trc::error!("Failed to load branches for {:?} due to {e:?}.", self.guild_id);
+                return Err(RequestError::Internal("Failed to load branches.".into()));
+            },
+        };
+
+        if branches.iter().next().is_none() {
+            ctx.reply_restricted("No branches have been set up! Use `/branch set` to add one.".to_owned()).await?;
+            return Ok(());
+        }
+
+        let msg: String = std::iter::once(Cow::Borrowed("- "))
+            .chain(branches.iter()
+                .map(|branch| Cow::Owned(format!(
+                    "`{}` {} (color {}, order {}{})",
+                    branch.tag,
+                    branches.label(&branch.tag),
+                    branch.ansi_color,
+                    branch.sort_order,
+                    branch.channel_id.map(|channel_id| format!(", posts in {}", channel_id.inner().mention())).unwrap_or_default(),
+                )))
+                .intersperse(Cow::Borrowed("\n- ")))
+            .collect();
+        ctx.reply_restricted(msg).await?;
+
+        Ok(())
+    }
+}
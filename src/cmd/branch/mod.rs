@@ -0,0 +1,3 @@
+pub mod set;
+pub mod remove;
+pub mod list;
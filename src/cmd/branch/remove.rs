@@ -0,0 +1,64 @@
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::permissions}, db, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    tag: &'a str,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can change branches.".into()));
+        }
+        let mut tag = None;
+        for opt in options {
+            match opt.name {
+                "tag" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `tag` in `branch remove` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `tag` in `branch remove`.".into()));
+                    };
+                    tag = Some(u);
+                }
+                _ => {
+                    trc::error!("Unknown option `{}` for `branch remove`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `branch remove`".into()));
+                }
+            }
+        }
+
+        let Some(tag) = tag else {
+            trc::error!("Missing value for `tag` in `branch remove`");
+            return Err(RequestError::Internal("Missing value for `tag` in `branch remove`.".into()));
+        };
+
+        Ok(Self {
+            tag,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let guild_id = ctx.cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
+        let removed = match db::GuildBranch::remove(&ctx.db_cfg, guild_id.into(), self.tag).await {
+            Ok(removed) => removed,
+            Err(e) => {
+                trc::error!("Failed to remove branch {:?} due to {e:?}.", self.tag);
+                return Err(RequestError::Internal("Failed to remove branch.".into()));
+            },
+        };
+
+        if removed == 0 {
+            return Err(RequestError::User(locale.format("No branch with tag `{}` exists.", &[&self.tag]).into()));
+        }
+
+        ctx.reply_restricted(format!("Removed branch `{}`. Goals still tagged with it will show up as \"Other\".", self.tag)).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::permissions}, db::{self, DiscordChannelId}, locale::Locale};
+
+// Matches the column widths of `guild_branches`.
+const MAX_TAG_LENGTH: usize = 100;
+const MAX_DISPLAY_NAME_LENGTH: usize = 100;
+const MAX_EMOJI_LENGTH: usize = 100;
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    tag: &'a str,
+    display_name: Option<&'a str>,
+    ansi_color: Option<i16>,
+    emoji: Option<&'a str>,
+    clear_emoji: bool,
+    sort_order: Option<i32>,
+    channel_id: Option<DiscordChannelId>,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can change branches.".into()));
+        }
+        let locale = Locale::of(cmd);
+        let mut tag = None;
+        let mut display_name = None;
+        let mut ansi_color = None;
+        let mut emoji = None;
+        let mut clear_emoji = false;
+        let mut sort_order = None;
+        let mut channel_id = None;
+        for opt in options {
+            match opt.name {
+                "tag" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `tag` in `branch set` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `tag` in `branch set`.".into()));
+                    };
+                    tag = Some(u);
+                }
+                "display_name" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `display_name` in `branch set` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `display_name` in `branch set`.".into()));
+                    };
+                    display_name = Some(u);
+                }
+                "color" => {
+                    let ResolvedValue::Integer(u) = opt.value else {
+                        trc::error!("Bad value for `color` in `branch set` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `color` in `branch set`.".into()));
+                    };
+                    let Ok(u) = u8::try_from(u) else {
+                        return Err(RequestError::User("`color` must be an ANSI color between 0 and 255.".into()));
+                    };
+                    ansi_color = Some(i16::from(u));
+                }
+                "emoji" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `emoji` in `branch set` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `emoji` in `branch set`.".into()));
+                    };
+                    emoji = Some(u);
+                }
+                "clear_emoji" => {
+                    let ResolvedValue::Boolean(u) = opt.value else {
+                        trc::error!("Bad value for `clear_emoji` in `branch set` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `clear_emoji` in `branch set`.".into()));
+                    };
+                    clear_emoji = u;
+                }
+                "sort_order" => {
+                    let ResolvedValue::Integer(u) = opt.value else {
+                        trc::error!("Bad value for `sort_order` in `branch set` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `sort_order` in `branch set`.".into()));
+                    };
+                    let Ok(u) = i32::try_from(u) else {
+                        return Err(RequestError::User("`sort_order` is too large.".into()));
+                    };
+                    sort_order = Some(u);
+                }
+                "channel" => {
+                    let ResolvedValue::Channel(c) = opt.value else {
+                        trc::error!("Bad value for `channel` in `branch set` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `channel` in `branch set`.".into()));
+                    };
+                    channel_id = Some(c.id.into());
+                }
+                _ => {
+                    trc::error!("Unknown option `{}` for `branch set`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `branch set`".into()));
+                }
+            }
+        }
+
+        let Some(tag) = tag else {
+            trc::error!("Missing value for `tag` in `branch set`");
+            return Err(RequestError::Internal("Missing value for `tag` in `branch set`.".into()));
+        };
+        if tag.is_empty() || tag.len() > MAX_TAG_LENGTH || tag.chars().any(|c| c.is_whitespace() || c.is_uppercase()) {
+            return Err(RequestError::User(locale.format(
+                "`tag` must be lowercase, without spaces, and at most {} characters.",
+                &[&MAX_TAG_LENGTH],
+            ).into()));
+        }
+        if display_name.is_some_and(|name| name.is_empty() || name.chars().count() > MAX_DISPLAY_NAME_LENGTH) {
+            return Err(RequestError::User(locale.format("`display_name` must be between 1 and {} characters.", &[&MAX_DISPLAY_NAME_LENGTH]).into()));
+        }
+        if emoji.is_some_and(|emoji| emoji.chars().count() > MAX_EMOJI_LENGTH) {
+            return Err(RequestError::User(locale.format("`emoji` must be at most {} characters.", &[&MAX_EMOJI_LENGTH]).into()));
+        }
+        if emoji.is_some() && clear_emoji {
+            return Err(RequestError::User("Pick an `emoji` or `clear_emoji`, not both.".into()));
+        }
+
+        Ok(Self {
+            tag,
+            display_name,
+            ansi_color,
+            emoji,
+            clear_emoji,
+            sort_order,
+            channel_id,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let guild_id = ctx.cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
+        if let Err(e) = db::GuildBranch::upsert(&ctx.db_cfg, db::NewGuildBranch {
+            guild_id: guild_id.into(),
+            tag: self.tag,
+            display_name: self.display_name,
+            ansi_color: self.ansi_color,
+            emoji: self.emoji,
+            sort_order: self.sort_order,
+            channel_id: self.channel_id,
+        }, self.clear_emoji).await {
+            trc::error!("Failed to write branch {:?} due to {e:?}.", self.tag);
+            return Err(RequestError::Internal("Failed to write branch.".into()));
+        };
+
+        ctx.reply_restricted(format!("Updated branch `{}`.", self.tag)).await?;
+
+        Ok(())
+    }
+}
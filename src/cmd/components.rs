@@ -0,0 +1,61 @@
+use serenity::all::{ComponentInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage, ModalInteraction};
+use tracing as trc;
+
+use azel::db::Connector;
+
+use crate::{cmd::{RequestError, event, lib::pages, monthly_goal}, locale::Locale};
+
+/// Responds to a button press or menu selection on one of our messages.
+pub async fn respond(ctx: &Context, db_cfg: &impl Connector, component: &ComponentInteraction) {
+    let custom_id = component.data.custom_id.as_str();
+    let result = if let Some((event_id, response)) = event::parse_rsvp_custom_id(custom_id) {
+        event::rsvp(ctx, db_cfg, component, event_id, response).await
+    } else if let Some(event_id) = event::parse_roll_call_custom_id(custom_id) {
+        event::confirm_roll_call(ctx, db_cfg, component, event_id).await
+    } else if let Some(goal_id) = monthly_goal::edit::parse_save_custom_id(custom_id) {
+        monthly_goal::edit::save(ctx, db_cfg, component, goal_id).await
+    } else if custom_id == monthly_goal::edit::CANCEL_CUSTOM_ID {
+        monthly_goal::edit::cancel(ctx, component).await
+    } else if let Some((goal_id, pledging)) = monthly_goal::pledges::parse_custom_id(custom_id) {
+        monthly_goal::pledges::respond(ctx, db_cfg, component, goal_id, pledging).await
+    } else if pages::is_custom_id(custom_id) {
+        // Answered by the collector waiting on the reply.
+        return;
+    } else {
+        trc::warn!("Unknown component `{custom_id}`");
+        return;
+    };
+
+    if let Err(e) = result {
+        if let Err(e) = component.create_response(ctx, error_response(e, custom_id, Locale::from_discord(component.locale.as_str()))).await {
+            trc::error!("Failed to report error for component `{custom_id}` due to {e:?}.");
+        }
+    }
+}
+
+/// Responds to a submitted modal.
+pub async fn respond_modal(ctx: &Context, db_cfg: &impl Connector, modal: &ModalInteraction) {
+    let custom_id = modal.data.custom_id.as_str();
+    let result = if let Some(goal_id) = monthly_goal::edit::parse_modal_custom_id(custom_id) {
+        monthly_goal::edit::submit(ctx, db_cfg, modal, goal_id).await
+    } else {
+        trc::warn!("Unknown modal `{custom_id}`");
+        return;
+    };
+
+    if let Err(e) = result {
+        if let Err(e) = modal.create_response(ctx, error_response(e, custom_id, Locale::from_discord(modal.locale.as_str()))).await {
+            trc::error!("Failed to report error for modal `{custom_id}` due to {e:?}.");
+        }
+    }
+}
+
+fn error_response(e: RequestError, custom_id: &str, locale: Locale) -> CreateInteractionResponse {
+    if let RequestError::Internal(msg) = &e {
+        trc::error!("Failed to handle `{custom_id}`: {msg}");
+    }
+    let message = CreateInteractionResponseMessage::new()
+        .content(locale.error_message(&e))
+        .ephemeral(true);
+    CreateInteractionResponse::Message(message)
+}
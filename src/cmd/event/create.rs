@@ -0,0 +1,139 @@
+use chrono::Utc;
+use serenity::all::{CommandInteraction, CreateAllowedMentions, CreateInteractionResponse, CreateInteractionResponseMessage, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{calendar, cmd::{RequestError, event}, db::{self, DiscordGuildId}, locale::Locale};
+
+// Matches the column widths of `events`.
+const MAX_NAME_LEN: usize = 100;
+const MAX_DESCRIPTION_LEN: usize = 1000;
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    guild_id: DiscordGuildId,
+    name: &'a str,
+    starts: &'a str,
+    description: Option<&'a str>,
+}
+
+impl <'a> Request<'a> {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        let locale = Locale::of(cmd);
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut name = None;
+        let mut starts = None;
+        let mut description = None;
+        for opt in options {
+            match opt.name {
+                "name" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `name` in `event create` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `name` in `event create`.".into()));
+                    };
+                    if s.trim().is_empty() || s.chars().count() > MAX_NAME_LEN {
+                        return Err(RequestError::User(locale.format("Event names have to be between 1 and {} characters.", &[&MAX_NAME_LEN]).into()));
+                    }
+                    name = Some(s.trim());
+                },
+                "starts" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `starts` in `event create` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `starts` in `event create`.".into()));
+                    };
+                    starts = Some(s);
+                },
+                "description" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `description` in `event create` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `description` in `event create`.".into()));
+                    };
+                    if s.chars().count() > MAX_DESCRIPTION_LEN {
+                        return Err(RequestError::User(locale.format(
+                            "Event descriptions can be at most {} characters.",
+                            &[&MAX_DESCRIPTION_LEN],
+                        ).into()));
+                    }
+                    description = Some(s);
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `event create`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `event create`".into()));
+                },
+            }
+        }
+
+        let (Some(name), Some(starts)) = (name, starts) else {
+            trc::error!("Missing value for `name` or `starts` in `event create`");
+            return Err(RequestError::Internal("Missing value for `name` or `starts` in `event create`.".into()));
+        };
+
+        Ok(Self {
+            guild_id,
+            name,
+            starts,
+            description,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let Self { guild_id, name, starts, description } = self;
+        let tz = match db::GuildSettings::load_timezone(&ctx.db_cfg, guild_id).await {
+            Ok(tz) => tz,
+            Err(e) => {
+                trc::error!("Failed to load timezone for {guild_id:?} due to {e:?}.");
+                return Err(RequestError::Internal("Failed to load server settings.".into()));
+            },
+        };
+        let Some(starts) = calendar::parse_local_datetime(starts, tz) else {
+            return Err(RequestError::User(locale.format(
+                "`{}` isn't a time I understand. Use `YYYY-MM-DD HH:MM` in the server's timezone (`{}`).",
+                &[&starts, &tz.name()],
+            ).into()));
+        };
+        if starts < Utc::now() {
+            return Err(RequestError::User(locale.format("<t:{}:F> has already passed.", &[&starts.timestamp()]).into()));
+        }
+
+        let event = match db::Event::create(&ctx.db_cfg, db::NewEvent {
+            guild_id,
+            host: ctx.cmd.user.id.into(),
+            name,
+            description,
+            starts,
+            channel_id: ctx.cmd.channel_id.into(),
+        }).await {
+            Ok(event) => event,
+            Err(e) => {
+                trc::error!("Failed to create event {name:?} due to {e:?}.");
+                return Err(RequestError::Internal("Failed to create event.".into()));
+            },
+        };
+
+        let message = CreateInteractionResponseMessage::new()
+            .content(event::render_card(&event, &[]))
+            .components(event::rsvp_buttons(&event))
+            .allowed_mentions(CreateAllowedMentions::new());
+        if let Err(e) = ctx.cmd.create_response(ctx.ctx, CreateInteractionResponse::Message(message)).await {
+            trc::error!("Failed to post card of event {} due to {e:?}.", event.id);
+            return Err(RequestError::Internal("Failed to post the event card.".into()));
+        }
+        // Anything sent after this has to be a follow-up.
+        *ctx.is_first_response.lock().await = false;
+
+        let card = match ctx.cmd.get_response(ctx.ctx).await {
+            Ok(card) => card,
+            Err(e) => {
+                trc::error!("Failed to fetch card of event {} due to {e:?}.", event.id);
+                return Err(RequestError::Internal("Failed to find the event card.".into()));
+            },
+        };
+        if let Err(e) = db::Event::set_message(&ctx.db_cfg, event.id, card.id.into()).await {
+            trc::error!("Failed to save card of event {} due to {e:?}.", event.id);
+            return Err(RequestError::Internal("Failed to save the event card.".into()));
+        }
+        Ok(())
+    }
+}
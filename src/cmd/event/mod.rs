@@ -0,0 +1,272 @@
+//! Scheduled events with RSVP buttons, and the roll call that credits participation once they've started.
+
+pub mod create;
+pub mod roll_call;
+
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use serenity::all::{
+    ButtonStyle, ComponentInteraction, ComponentInteractionDataKind, Context, CreateActionRow, CreateAllowedMentions, CreateButton,
+    CreateInteractionResponse, CreateInteractionResponseMessage, EditMessage, Mentionable, Permissions, UserId,
+};
+use tracing as trc;
+
+use azel::db::Connector;
+
+use crate::{db::{self, DiscordUserId, Event, EventRsvp, RsvpResponse, TrackerStat}, locale::Locale};
+
+use super::{RequestError, lib::{generic_tracker::limits, options}};
+
+const RSVP_PREFIX: &str = "event_rsvp";
+const ROLL_CALL_PREFIX: &str = "event_roll_call";
+
+pub fn rsvp_custom_id(event_id: i64, response: RsvpResponse) -> String {
+    format!("{RSVP_PREFIX}:{event_id}:{}", response.as_str())
+}
+
+pub fn parse_rsvp_custom_id(custom_id: &str) -> Option<(i64, RsvpResponse)> {
+    let mut parts = custom_id.split(':');
+    if parts.next()? != RSVP_PREFIX {
+        return None;
+    }
+    let event_id = parts.next()?.parse().ok()?;
+    let response = RsvpResponse::from_str(parts.next()?).ok()?;
+    parts.next().is_none().then_some((event_id, response))
+}
+
+pub fn roll_call_custom_id(event_id: i64) -> String {
+    format!("{ROLL_CALL_PREFIX}:{event_id}")
+}
+
+pub fn parse_roll_call_custom_id(custom_id: &str) -> Option<i64> {
+    let (prefix, event_id) = custom_id.split_once(':')?;
+    if prefix != ROLL_CALL_PREFIX {
+        return None;
+    }
+    event_id.parse().ok()
+}
+
+/// The host runs their own event, officers can step in for them.
+pub fn can_manage(event: &Event, user_id: UserId, permissions: Option<Permissions>) -> bool {
+    DiscordUserId::from(user_id) == event.host || permissions.is_some_and(|permissions| permissions.manage_guild())
+}
+
+/// Text of the event card, listing who's answered what.
+pub fn render_card(event: &Event, rsvps: &[EventRsvp]) -> String {
+    let starts = event.starts.timestamp();
+    let mut card = format!("**{}** hosted by {}\nStarts <t:{starts}:F> (<t:{starts}:R>)\n", event.name, event.host.inner().mention());
+    if let Some(description) = &event.description {
+        card.push_str(format!("{description}\n").as_str());
+    }
+    card.push('\n');
+
+    for (response, title) in [
+        (RsvpResponse::Attending, "Attending"),
+        (RsvpResponse::Maybe, "Maybe"),
+        (RsvpResponse::Declined, "Declined"),
+    ] {
+        let users: Vec<String> = rsvps.iter()
+            .filter(|rsvp| rsvp.response == response)
+            .map(|rsvp| rsvp.user_id.inner().mention().to_string())
+            .collect();
+        let listed = match users.is_empty() {
+            true => "-".to_owned(),
+            false => users.join(", "),
+        };
+        card.push_str(format!("**{title} ({}):** {listed}\n", users.len()).as_str());
+    }
+
+    if event.roll_called.is_some() {
+        card.push_str("\nRoll call is done, RSVPs are closed.");
+    }
+    card
+}
+
+pub fn rsvp_buttons(event: &Event) -> Vec<CreateActionRow> {
+    if event.roll_called.is_some() {
+        return vec![];
+    }
+    vec![
+        CreateActionRow::Buttons(vec![
+            CreateButton::new(rsvp_custom_id(event.id, RsvpResponse::Attending)).label("Attending").style(ButtonStyle::Success),
+            CreateButton::new(rsvp_custom_id(event.id, RsvpResponse::Maybe)).label("Maybe").style(ButtonStyle::Secondary),
+            CreateButton::new(rsvp_custom_id(event.id, RsvpResponse::Declined)).label("Decline").style(ButtonStyle::Danger),
+        ]),
+    ]
+}
+
+async fn load_event(db_cfg: &impl Connector, component: &ComponentInteraction, event_id: i64) -> Result<Event, RequestError> {
+    let Some(guild_id) = component.guild_id else {
+        return Err(RequestError::User("Events only work within a server.".into()));
+    };
+    match db::Event::load(db_cfg, guild_id.into(), event_id).await {
+        Ok(Some(event)) => Ok(event),
+        Ok(None) => Err(RequestError::User("This event doesn't exist anymore.".into())),
+        Err(e) => {
+            trc::error!("Failed to load event {event_id} due to {e:?}.");
+            Err(RequestError::Internal("Failed to load event.".into()))
+        },
+    }
+}
+
+async fn load_rsvps(db_cfg: &impl Connector, event_id: i64) -> Result<Vec<EventRsvp>, RequestError> {
+    db::Event::load_rsvps(db_cfg, event_id).await.map_err(|e| {
+        trc::error!("Failed to load RSVPs of event {event_id} due to {e:?}.");
+        RequestError::Internal("Failed to load RSVPs.".into())
+    })
+}
+
+/// Someone pressed one of the buttons on an event card.
+pub async fn rsvp(ctx: &Context, db_cfg: &impl Connector, component: &ComponentInteraction, event_id: i64, response: RsvpResponse) -> Result<(), RequestError> {
+    let event = load_event(db_cfg, component, event_id).await?;
+    if event.roll_called.is_some() {
+        return Err(RequestError::User("Roll call for this event is done, RSVPs are closed.".into()));
+    }
+
+    if let Err(e) = db::Event::set_rsvp(db_cfg, event.id, component.user.id.into(), response).await {
+        trc::error!("Failed to save RSVP to event {} due to {e:?}.", event.id);
+        return Err(RequestError::Internal("Failed to save RSVP.".into()));
+    }
+    let rsvps = load_rsvps(db_cfg, event.id).await?;
+
+    let message = CreateInteractionResponseMessage::new()
+        .content(render_card(&event, &rsvps))
+        .allowed_mentions(CreateAllowedMentions::new());
+    if let Err(e) = component.create_response(ctx, CreateInteractionResponse::UpdateMessage(message)).await {
+        trc::error!("Failed to update card of event {} due to {e:?}.", event.id);
+        return Err(RequestError::Internal("Failed to update the event card.".into()));
+    }
+    Ok(())
+}
+
+/// The host confirmed who attended in the roll call menu.
+pub async fn confirm_roll_call(ctx: &Context, db_cfg: &impl Connector, component: &ComponentInteraction, event_id: i64) -> Result<(), RequestError> {
+    let event = load_event(db_cfg, component, event_id).await?;
+    if !can_manage(&event, component.user.id, component.member.as_ref().and_then(|member| member.permissions)) {
+        return Err(RequestError::User("Only the host or an officer can run the roll call.".into()));
+    }
+    let ComponentInteractionDataKind::StringSelect { ref values } = component.data.kind else {
+        trc::error!("Roll call for event {event_id} wasn't a select {:?}", component.data);
+        return Err(RequestError::Internal("Bad roll call selection.".into()));
+    };
+    let Some(attendees) = values.iter()
+        .map(|value| value.parse::<u64>().ok().filter(|id| *id != 0).map(|id| DiscordUserId::from(UserId::new(id))))
+        .collect::<Option<Vec<_>>>() else {
+        trc::error!("Bad roll call selection {values:?} for event {event_id}");
+        return Err(RequestError::Internal("Bad roll call selection.".into()));
+    };
+
+    let stat = TrackerStat::EventParticipation;
+    let credits = attendees.iter()
+        .map(|user_id| db::NewTrackerCountChange {
+            stat,
+            guild_id: event.guild_id,
+            updater: component.user.id.into(),
+            target: *user_id,
+            total: stat.db_value(BigDecimal::from(1)),
+            user_note: Some(event.name.clone()),
+        })
+        .collect::<Vec<_>>();
+    let locale = Locale::from_discord(component.locale.as_str());
+    let flags = limits::enforce_in(db_cfg, locale, event.guild_id, &credits).await?;
+    match db::Event::roll_call(db_cfg, event.id, credits).await {
+        Ok(true) => {},
+        Ok(false) => return Err(RequestError::User("Roll call for this event has already been done.".into())),
+        Err(e) => {
+            trc::error!("Failed to credit attendees of event {} due to {e:?}.", event.id);
+            return Err(RequestError::Internal("Failed to credit attendees.".into()));
+        },
+    }
+
+    let mentions: Vec<String> = attendees.iter().map(|user_id| user_id.inner().mention().to_string()).collect();
+    let message = CreateInteractionResponseMessage::new()
+        .content(format!("Credited {} attendees of **{}**: {}", attendees.len(), event.name, mentions.join(", ")))
+        .components(vec![])
+        .allowed_mentions(CreateAllowedMentions::new());
+    if let Err(e) = component.create_response(ctx, CreateInteractionResponse::UpdateMessage(message)).await {
+        trc::error!("Failed to confirm roll call of event {} due to {e:?}.", event.id);
+    }
+    limits::flag_in(ctx, db_cfg, event.guild_id, component.channel_id, flags).await;
+
+    // Close the RSVPs on the card, it's fine if it's been deleted in the meantime.
+    let Some(message_id) = event.message_id else {
+        return Ok(());
+    };
+    let event = db::Event { roll_called: Some(Utc::now()), ..event };
+    let rsvps = load_rsvps(db_cfg, event.id).await?;
+    let edit = EditMessage::new()
+        .content(render_card(&event, &rsvps))
+        .components(rsvp_buttons(&event))
+        .allowed_mentions(CreateAllowedMentions::new());
+    if let Err(e) = event.channel_id.inner().edit_message(ctx, message_id.inner(), edit).await {
+        trc::warn!("Failed to close card of event {} due to {e:?}.", event.id);
+    }
+    Ok(())
+}
+
+/// Events still waiting for a roll call, as `(name, value)` pairs filtered by what the user has typed so far.
+pub fn autocomplete_choices(events: &[Event], partial: &str) -> Vec<(String, String)> {
+    let partial = partial.trim().to_lowercase();
+    events.iter()
+        .filter(|event| event.name.to_lowercase().contains(partial.as_str()))
+        .take(options::MAX_CHOICES)
+        .map(|event| (
+            format!("{} ({})", event.name, event.starts.format("%Y-%m-%d %H:%M UTC")),
+            event.id.to_string(),
+        ))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+    use serenity::all::{ChannelId, GuildId, UserId};
+
+    use crate::db::{DiscordUserId, Event, EventRsvp, RsvpResponse};
+
+    use super::{parse_roll_call_custom_id, parse_rsvp_custom_id, render_card, roll_call_custom_id, rsvp_custom_id};
+
+    fn user(id: u64) -> DiscordUserId {
+        UserId::new(id).into()
+    }
+
+    #[test]
+    fn test_custom_ids() {
+        assert_eq!(parse_rsvp_custom_id(&rsvp_custom_id(12, RsvpResponse::Maybe)), Some((12, RsvpResponse::Maybe)));
+        assert_eq!(parse_rsvp_custom_id("event_rsvp:12:sometimes"), None);
+        assert_eq!(parse_rsvp_custom_id("event_rsvp:12:maybe:extra"), None);
+        assert_eq!(parse_rsvp_custom_id(&roll_call_custom_id(12)), None);
+        assert_eq!(parse_roll_call_custom_id(&roll_call_custom_id(12)), Some(12));
+        assert_eq!(parse_roll_call_custom_id("event_rsvp:12"), None);
+    }
+
+    #[test]
+    fn test_render_card() {
+        let starts = Utc.with_ymd_and_hms(2026, 7, 10, 18, 0, 0).unwrap();
+        let event = Event {
+            id: 1,
+            created: starts,
+            guild_id: GuildId::new(1).into(),
+            host: user(1),
+            name: "Mining op".to_owned(),
+            description: Some("Bring a Prospector.".to_owned()),
+            starts,
+            channel_id: ChannelId::new(1).into(),
+            message_id: None,
+            roll_called: None,
+        };
+        let rsvp = |id: u64, response: RsvpResponse| EventRsvp { event_id: 1, user_id: user(id), updated: starts, response };
+        let rsvps = vec![rsvp(2, RsvpResponse::Attending), rsvp(3, RsvpResponse::Declined), rsvp(4, RsvpResponse::Attending)];
+        assert_eq!(render_card(&event, &rsvps), concat!(
+            "**Mining op** hosted by <@1>\n",
+            "Starts <t:1783706400:F> (<t:1783706400:R>)\n",
+            "Bring a Prospector.\n",
+            "\n",
+            "**Attending (2):** <@2>, <@4>\n",
+            "**Maybe (0):** -\n",
+            "**Declined (1):** <@3>\n",
+        ));
+    }
+}
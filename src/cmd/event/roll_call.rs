@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use serenity::all::{
+    CommandInteraction, CreateActionRow, CreateAllowedMentions, CreateInteractionResponse, CreateInteractionResponseMessage, CreateSelectMenu,
+    CreateSelectMenuKind, CreateSelectMenuOption, Mentionable, ResolvedOption, ResolvedValue, UserId,
+};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, event, lib::{members, options, pages}}, db::{self, DiscordGuildId, DiscordUserId, RsvpResponse}, locale::Locale};
+
+/// Matches the number of `extraN` options the command is registered with.
+pub const MAX_EXTRAS: usize = 5;
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: DiscordGuildId,
+    event_id: i64,
+    extras: Vec<UserId>,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut event_id = None;
+        let mut extras: [Option<UserId>; MAX_EXTRAS] = [None; MAX_EXTRAS];
+        for opt in options {
+            if let Some(slot) = options::numbered_slot(opt.name, "extra", MAX_EXTRAS) {
+                let ResolvedValue::User(u, _) = opt.value else {
+                    trc::error!("Bad value for `{}` in `event roll_call` {:?}", opt.name, opt);
+                    return Err(RequestError::Internal(format!("Bad value for `{}` in `event roll_call`.", opt.name).into()));
+                };
+                extras[slot] = Some(u.id);
+                continue;
+            }
+            match opt.name {
+                "event" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `event` in `event roll_call` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `event` in `event roll_call`.".into()));
+                    };
+                    event_id = Some(s.trim().parse::<i64>().map_err(|_| RequestError::User("Pick an event from the list.".into()))?);
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `event roll_call`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `event roll_call`".into()));
+                },
+            }
+        }
+
+        let Some(event_id) = event_id else {
+            trc::error!("Missing value for `event` in `event roll_call`");
+            return Err(RequestError::Internal("Missing value for `event` in `event roll_call`.".into()));
+        };
+
+        Ok(Self {
+            guild_id,
+            event_id,
+            extras: extras.into_iter().flatten().collect(),
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let Self { guild_id, event_id, extras } = self;
+        let event = match db::Event::load(&ctx.db_cfg, guild_id, event_id).await {
+            Ok(Some(event)) => event,
+            Ok(None) => return Err(RequestError::User("Pick an event from the list.".into())),
+            Err(e) => {
+                trc::error!("Failed to load event {event_id} due to {e:?}.");
+                return Err(RequestError::Internal("Failed to load event.".into()));
+            },
+        };
+        if !event::can_manage(&event, ctx.cmd.user.id, ctx.cmd.member.as_ref().and_then(|member| member.permissions)) {
+            return Err(RequestError::User("Only the host or an officer can run the roll call.".into()));
+        }
+        if event.roll_called.is_some() {
+            return Err(RequestError::User(locale.format("Roll call for **{}** has already been done.", &[&event.name]).into()));
+        }
+        if event.starts > Utc::now() {
+            return Err(RequestError::User(locale.format(
+                "Roll call opens once **{}** starts <t:{}:R>.",
+                &[&event.name, &event.starts.timestamp()],
+            ).into()));
+        }
+
+        let rsvps = match db::Event::load_rsvps(&ctx.db_cfg, event.id).await {
+            Ok(rsvps) => rsvps,
+            Err(e) => {
+                trc::error!("Failed to load RSVPs of event {} due to {e:?}.", event.id);
+                return Err(RequestError::Internal("Failed to load RSVPs.".into()));
+            },
+        };
+        let mut candidates = candidates(
+            rsvps.iter().map(|rsvp| (rsvp.user_id, rsvp.response)),
+            extras.into_iter().map(DiscordUserId::from),
+        );
+        if candidates.is_empty() {
+            return Err(RequestError::User("Nobody has RSVP'd yet. Add attendees with the `extra` options.".into()));
+        }
+        let left_out = candidates.split_off(candidates.len().min(options::MAX_CHOICES));
+
+        // Only used to label the menu, mentions don't render in select options.
+        let names: HashMap<DiscordUserId, String> = match members::fetch_members(ctx.ctx, *guild_id.inner()).await {
+            Ok(members) => members.into_iter()
+                .map(|member| (member.user.id.into(), member.display_name().to_owned()))
+                .collect(),
+            Err(e) => {
+                trc::warn!("Failed to fetch members of {guild_id:?} for roll call labels due to {e:?}.");
+                HashMap::new()
+            },
+        };
+
+        let select_options: Vec<CreateSelectMenuOption> = candidates.iter()
+            .map(|candidate| {
+                let name = names.get(&candidate.user_id).cloned().unwrap_or_else(|| candidate.user_id.inner().to_string());
+                CreateSelectMenuOption::new(name, candidate.user_id.inner().to_string())
+                    .description(candidate.reason)
+                    .default_selection(candidate.preselected)
+            })
+            .collect();
+        let count = select_options.len() as u8;
+        let menu = CreateSelectMenu::new(event::roll_call_custom_id(event.id), CreateSelectMenuKind::String { options: select_options })
+            .placeholder("Who attended?")
+            .min_values(1)
+            .max_values(count);
+
+        let mut content = format!("Select everyone who attended **{}**, then close the menu to credit them.", event.name);
+        if !left_out.is_empty() {
+            let room = pages::MESSAGE_MAX_LEN - content.chars().count();
+            content.push_str(left_out_note(&left_out, room).as_str());
+        }
+        let message = CreateInteractionResponseMessage::new()
+            .content(content)
+            .components(vec![CreateActionRow::SelectMenu(menu)])
+            .allowed_mentions(CreateAllowedMentions::new())
+            .ephemeral(true);
+        if let Err(e) = ctx.cmd.create_response(ctx.ctx, CreateInteractionResponse::Message(message)).await {
+            trc::error!("Failed to send roll call for event {} due to {e:?}.", event.id);
+            return Err(RequestError::Internal("Failed to send the roll call.".into()));
+        }
+        *ctx.is_first_response.lock().await = false;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Candidate {
+    user_id: DiscordUserId,
+    reason: &'static str,
+    preselected: bool,
+}
+
+/// Who to offer in the roll call: everyone attending or maybe attending, then the extras. Attendees and extras start
+/// out selected. Anyone who declined only shows up if they're added as an extra. There can be more than fit in the menu.
+fn candidates(rsvps: impl Iterator<Item = (DiscordUserId, RsvpResponse)>, extras: impl Iterator<Item = DiscordUserId>) -> Vec<Candidate> {
+    let mut candidates: Vec<Candidate> = vec![];
+    let rsvps: Vec<_> = rsvps.collect();
+    for response in [RsvpResponse::Attending, RsvpResponse::Maybe] {
+        for (user_id, _) in rsvps.iter().filter(|(_, r)| *r == response) {
+            candidates.push(Candidate {
+                user_id: *user_id,
+                reason: match response {
+                    RsvpResponse::Attending => "RSVP'd attending",
+                    _ => "RSVP'd maybe",
+                },
+                preselected: response == RsvpResponse::Attending,
+            });
+        }
+    }
+    for user_id in extras {
+        match candidates.iter_mut().find(|candidate| candidate.user_id == user_id) {
+            Some(candidate) => candidate.preselected = true,
+            None => candidates.push(Candidate { user_id, reason: "Added by the host", preselected: true }),
+        }
+    }
+    candidates
+}
+
+/// Tells the host who didn't fit in the menu, so they can credit them by hand. Mentions as many as fit in `room`.
+fn left_out_note(left_out: &[Candidate], room: usize) -> String {
+    let note = |listed: &str| format!(
+        "\nOnly {} fit in the menu, so {listed} aren't in it. Credit them with `/event participation record` if they attended.",
+        options::MAX_CHOICES,
+    );
+    let more = |count: usize| match count {
+        0 => String::new(),
+        _ => format!(" and {count} more"),
+    };
+    let mut listed = String::new();
+    for (i, candidate) in left_out.iter().enumerate() {
+        let mention = candidate.user_id.inner().mention().to_string();
+        let separator = if i == 0 { "" } else { ", " };
+        // Leaves room to say how many are left after this one.
+        if note(format!("{listed}{separator}{mention}{}", more(left_out.len() - i - 1)).as_str()).chars().count() > room {
+            match i {
+                0 => listed = format!("{} members", left_out.len()),
+                _ => listed.push_str(more(left_out.len() - i).as_str()),
+            }
+            break;
+        }
+        listed.push_str(separator);
+        listed.push_str(mention.as_str());
+    }
+    note(listed.as_str())
+}
+
+#[cfg(test)]
+mod test {
+    use serenity::all::UserId;
+
+    use crate::db::{DiscordUserId, RsvpResponse};
+
+    use super::{Candidate, candidates, left_out_note};
+
+    fn user(id: u64) -> DiscordUserId {
+        UserId::new(id).into()
+    }
+
+    #[test]
+    fn test_candidates() {
+        let rsvps = vec![
+            (user(1), RsvpResponse::Maybe),
+            (user(2), RsvpResponse::Attending),
+            (user(3), RsvpResponse::Declined),
+            (user(4), RsvpResponse::Maybe),
+        ];
+        let picked: Vec<_> = candidates(rsvps.into_iter(), [user(4), user(5)].into_iter())
+            .into_iter()
+            .map(|Candidate { user_id, preselected, .. }| (user_id, preselected))
+            .collect();
+        assert_eq!(picked, vec![(user(2), true), (user(1), false), (user(4), true), (user(5), true)]);
+    }
+
+    #[test]
+    fn test_left_out_note() {
+        let left_out: Vec<_> = (1..=3).map(|id| Candidate { user_id: user(id), reason: "RSVP'd maybe", preselected: false }).collect();
+        let note = left_out_note(&left_out, 2000);
+        assert!(note.contains("so <@1>, <@2>, <@3> aren't in it"), "{note}");
+        let note = left_out_note(&left_out, 123);
+        assert!(note.chars().count() <= 123, "{note}");
+        assert!(note.contains("so <@1> and 2 more aren't in it"), "{note}");
+    }
+}
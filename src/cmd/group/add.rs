@@ -0,0 +1,80 @@
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue, Role};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::permissions}, db, locale::Locale};
+
+// Matches the column width of `guild_role_groups`.
+const MAX_NAME_LENGTH: usize = 100;
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    guild_id: db::DiscordGuildId,
+    role: &'a Role,
+    name: Option<&'a str>,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can change groups.".into()));
+        }
+        let locale = Locale::of(cmd);
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut role = None;
+        let mut name = None;
+        for opt in options {
+            match opt.name {
+                "role" => {
+                    let ResolvedValue::Role(r) = opt.value else {
+                        trc::error!("Bad value for `role` in `group add` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `role` in `group add`.".into()));
+                    };
+                    role = Some(r);
+                },
+                "name" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `name` in `group add` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `name` in `group add`.".into()));
+                    };
+                    name = Some(s.trim());
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `group add`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `group add`".into()));
+                },
+            }
+        }
+
+        let Some(role) = role else {
+            trc::error!("Missing value for `role` in `group add`");
+            return Err(RequestError::Internal("Missing value for `role` in `group add`.".into()));
+        };
+        if name.is_some_and(|name| name.is_empty() || name.chars().count() > MAX_NAME_LENGTH) {
+            return Err(RequestError::User(locale.format("`name` must be between 1 and {} characters.", &[&MAX_NAME_LENGTH]).into()));
+        }
+
+        Ok(Self {
+            guild_id,
+            role,
+            name,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        // Role names can be longer than we store, so the fallback has to be cut down to fit.
+        let role_name: String = self.role.name.chars().take(MAX_NAME_LENGTH).collect();
+        let name = self.name.unwrap_or(role_name.as_str());
+        if let Err(e) = db::GuildRoleGroup::upsert(&ctx.db_cfg, db::NewGuildRoleGroup {
+            guild_id: self.guild_id,
+            role_id: self.role.id.into(),
+            name,
+        }).await {
+            trc::error!("Failed to write role group for {:?} due to {e:?}.", self.role.id);
+            return Err(RequestError::Internal("Failed to write group.".into()));
+        }
+
+        ctx.reply_restricted(format!("Members with the `{}` role are now ranked together as `{name}`.", self.role.name)).await
+    }
+}
@@ -0,0 +1,44 @@
+use std::borrow::Cow;
+
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::RequestError, db};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, _options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+
+        Ok(Self {
+            guild_id,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let groups = match db::GuildRoleGroup::load_all(&ctx.db_cfg, self.guild_id).await {
+            Ok(groups) => groups,
+            Err(e) => {
+                trc::error!("Failed to load role groups for {:?} due to {e:?}.", self.guild_id);
+                return Err(RequestError::Internal("Failed to load groups.".into()));
+            },
+        };
+
+        if groups.is_empty() {
+            return ctx.reply_restricted("No groups have been set up! Use `/group add` to rank a role's members together.".to_owned()).await;
+        }
+
+        let msg: String = std::iter::once(Cow::Borrowed("- "))
+            .chain(groups.iter()
+                .map(|group| Cow::Owned(format!("`{}` ({})", group.name, group.role_id.inner().mention())))
+                .intersperse(Cow::Borrowed("\n- ")))
+            .collect();
+        ctx.reply_restricted(msg).await
+    }
+}
@@ -0,0 +1,131 @@
+pub mod add;
+pub mod list;
+pub mod remove;
+pub mod scoreboard;
+
+use std::collections::HashMap;
+
+use bigdecimal::{BigDecimal, Zero};
+use serenity::all::RoleId;
+
+use crate::db::{DiscordUserId, GuildRoleGroup};
+
+/// Sum of a stat across everyone holding a group's role.
+#[derive(Debug, Clone)]
+pub struct GroupTotal<'a> {
+    pub group: &'a GuildRoleGroup,
+    /// Everyone holding the role, including members without any records.
+    pub members: usize,
+    pub total: BigDecimal,
+}
+
+impl GroupTotal<'_> {
+    /// Total per member, so small and large groups can be compared.
+    pub fn average(&self) -> BigDecimal {
+        if self.members == 0 {
+            return BigDecimal::zero();
+        }
+        &self.total / BigDecimal::from(self.members as u64)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ranking {
+    Total,
+    Average,
+}
+
+/// Sums `totals` per group and ranks the groups, highest first. Members holding several group roles count towards
+/// each of them.
+pub fn aggregate<'a>(
+    groups: &'a [GuildRoleGroup],
+    member_roles: &HashMap<DiscordUserId, Vec<RoleId>>,
+    totals: &[(DiscordUserId, BigDecimal)],
+    ranking: Ranking,
+) -> Vec<GroupTotal<'a>> {
+    let mut group_totals: Vec<_> = groups.iter()
+        .map(|group| {
+            let role_id = *group.role_id.inner();
+            let holds_role = |user_id: &DiscordUserId| member_roles.get(user_id).is_some_and(|roles| roles.contains(&role_id));
+            GroupTotal {
+                group,
+                members: member_roles.keys().filter(|user_id| holds_role(user_id)).count(),
+                total: totals.iter()
+                    .filter(|(user_id, _)| holds_role(user_id))
+                    .map(|(_, total)| total)
+                    .sum(),
+            }
+        })
+        .collect();
+    group_totals.sort_by(|a, b| {
+        let order = match ranking {
+            Ranking::Total => b.total.cmp(&a.total),
+            Ranking::Average => b.average().cmp(&a.average()),
+        };
+        order.then_with(|| a.group.name.cmp(&b.group.name))
+    });
+    group_totals
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use bigdecimal::BigDecimal;
+    use chrono::Utc;
+    use serenity::all::{GuildId, RoleId, UserId};
+
+    use crate::db::{DiscordUserId, GuildRoleGroup};
+
+    use super::{Ranking, aggregate};
+
+    fn user(id: u64) -> DiscordUserId {
+        UserId::new(id).into()
+    }
+
+    fn group(role_id: u64, name: &str) -> GuildRoleGroup {
+        GuildRoleGroup {
+            id: 0,
+            created: Utc::now(),
+            guild_id: GuildId::new(1).into(),
+            role_id: RoleId::new(role_id).into(),
+            name: name.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_aggregate_total_and_average() {
+        let groups = vec![group(100, "Alpha"), group(200, "Bravo"), group(300, "Charlie")];
+        let member_roles = HashMap::from([
+            (user(1), vec![RoleId::new(100)]),
+            (user(2), vec![RoleId::new(100)]),
+            (user(3), vec![RoleId::new(100), RoleId::new(200)]),
+            (user(4), vec![RoleId::new(200)]),
+            (user(5), vec![]),
+        ]);
+        let totals = vec![
+            (user(1), BigDecimal::from(6)),
+            (user(3), BigDecimal::from(3)),
+            (user(4), BigDecimal::from(5)),
+            (user(5), BigDecimal::from(50)),
+        ];
+
+        let by_total: Vec<_> = aggregate(&groups, &member_roles, &totals, Ranking::Total).into_iter()
+            .map(|group_total| (group_total.group.name.clone(), group_total.members, group_total.total))
+            .collect();
+        assert_eq!(by_total, vec![
+            ("Alpha".to_owned(), 3, BigDecimal::from(9)),
+            ("Bravo".to_owned(), 2, BigDecimal::from(8)),
+            ("Charlie".to_owned(), 0, BigDecimal::from(0)),
+        ]);
+
+        let by_average: Vec<_> = aggregate(&groups, &member_roles, &totals, Ranking::Average).into_iter()
+            .map(|group_total| (group_total.group.name.clone(), group_total.average()))
+            .collect();
+        assert_eq!(by_average, vec![
+            ("Bravo".to_owned(), BigDecimal::from(4)),
+            ("Alpha".to_owned(), BigDecimal::from(3)),
+            ("Charlie".to_owned(), BigDecimal::from(0)),
+        ]);
+    }
+}
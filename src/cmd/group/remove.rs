@@ -0,0 +1,64 @@
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue, Role};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::permissions}, db, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    guild_id: db::DiscordGuildId,
+    role: &'a Role,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can change groups.".into()));
+        }
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut role = None;
+        for opt in options {
+            match opt.name {
+                "role" => {
+                    let ResolvedValue::Role(r) = opt.value else {
+                        trc::error!("Bad value for `role` in `group remove` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `role` in `group remove`.".into()));
+                    };
+                    role = Some(r);
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `group remove`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `group remove`".into()));
+                },
+            }
+        }
+
+        let Some(role) = role else {
+            trc::error!("Missing value for `role` in `group remove`");
+            return Err(RequestError::Internal("Missing value for `role` in `group remove`.".into()));
+        };
+
+        Ok(Self {
+            guild_id,
+            role,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let removed = match db::GuildRoleGroup::remove(&ctx.db_cfg, self.guild_id, self.role.id.into()).await {
+            Ok(removed) => removed,
+            Err(e) => {
+                trc::error!("Failed to remove role group for {:?} due to {e:?}.", self.role.id);
+                return Err(RequestError::Internal("Failed to remove group.".into()));
+            },
+        };
+
+        if removed == 0 {
+            return Err(RequestError::User(locale.format("The `{}` role isn't a group.", &[&self.role.name]).into()));
+        }
+
+        ctx.reply_restricted(format!("The `{}` role is no longer a group.", self.role.name)).await
+    }
+}
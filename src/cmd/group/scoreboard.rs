@@ -0,0 +1,153 @@
+use std::{collections::HashMap, str::FromStr};
+
+use chrono::Utc;
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{calendar::{self, Period}, cmd::{RequestError, group::{self, Ranking}, lib::{generic_tracker::scoreboard::resolve_period, members, pages}}, db::{self, DiscordUserId, TrackerStat}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+    stat: TrackerStat,
+    period: Option<Period>,
+    all_time: bool,
+    ranking: Ranking,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut stat = None;
+        let mut period = None;
+        let mut all_time = false;
+        let mut ranking = Ranking::Total;
+        for opt in options {
+            match opt.name {
+                "stat" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `stat` in `group scoreboard` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `stat` in `group scoreboard`.".into()));
+                    };
+                    let Ok(s) = TrackerStat::from_str(s) else {
+                        trc::error!("Unknown stat {s:?} in `group scoreboard`");
+                        return Err(RequestError::Internal("Unknown stat in `group scoreboard`.".into()));
+                    };
+                    stat = Some(s);
+                },
+                "period" => {
+                    let ResolvedValue::String(p) = opt.value else {
+                        trc::error!("Bad value for `period` in `group scoreboard` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `period` in `group scoreboard`.".into()));
+                    };
+                    if calendar::is_all_time(p) {
+                        all_time = true;
+                    } else {
+                        period = Some(p.parse::<Period>().map_err(|e| RequestError::User(e.to_string().into()))?);
+                    }
+                },
+                "rank_by" => {
+                    let ResolvedValue::String(r) = opt.value else {
+                        trc::error!("Bad value for `rank_by` in `group scoreboard` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `rank_by` in `group scoreboard`.".into()));
+                    };
+                    ranking = match r {
+                        "total" => Ranking::Total,
+                        "average" => Ranking::Average,
+                        _ => {
+                            trc::error!("Unknown ranking {r:?} in `group scoreboard`");
+                            return Err(RequestError::Internal("Unknown value for `rank_by` in `group scoreboard`.".into()));
+                        },
+                    };
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `group scoreboard`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `group scoreboard`".into()));
+                },
+            }
+        }
+
+        let Some(stat) = stat else {
+            trc::error!("Missing value for `stat` in `group scoreboard`");
+            return Err(RequestError::Internal("Missing value for `stat` in `group scoreboard`.".into()));
+        };
+
+        Ok(Self {
+            guild_id,
+            stat,
+            period,
+            all_time,
+            ranking,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let Self { guild_id, stat, period, all_time, ranking } = self;
+        let locale = Locale::of(ctx.cmd);
+        // Listing every member can take a while on larger servers.
+        ctx.defer().await?;
+
+        let groups = match db::GuildRoleGroup::load_all(&ctx.db_cfg, guild_id).await {
+            Ok(groups) => groups,
+            Err(e) => {
+                trc::error!("Failed to load role groups for {guild_id:?} due to {e:?}.");
+                return Err(RequestError::Internal("Failed to load groups.".into()));
+            },
+        };
+        if groups.is_empty() {
+            return ctx.reply_restricted(locale.translate("No groups have been set up! Use `/group add` to rank a role's members together.").to_owned()).await;
+        }
+
+        let period = resolve_period(ctx, locale, guild_id, period, all_time).await?;
+        let totals = match &period {
+            Some((period, _)) => {
+                let tz = match db::GuildSettings::load_timezone(&ctx.db_cfg, guild_id).await {
+                    Ok(tz) => tz,
+                    Err(e) => {
+                        trc::error!("Failed to load timezone for {guild_id:?} due to {e:?}.");
+                        return Err(RequestError::Internal("Failed to load server settings.".into()));
+                    },
+                };
+                db::TrackerCount::load_period_totals(&ctx.db_cfg, stat, guild_id, period.to_utc_range(tz, Utc::now())).await
+            },
+            None => db::TrackerCount::load_totals(&ctx.db_cfg, stat, guild_id).await,
+        };
+        let totals = match totals {
+            Ok(totals) => totals,
+            Err(e) => {
+                trc::error!("Failed to load totals of {stat:?} for {guild_id:?} due to {e:?}.");
+                return Err(RequestError::Internal("Failed to load totals.".into()));
+            },
+        };
+
+        let member_roles: HashMap<DiscordUserId, _> = match members::fetch_members(ctx.ctx, *guild_id.inner()).await {
+            Ok(members) => members.into_iter().map(|member| (member.user.id.into(), member.roles)).collect(),
+            Err(e) => {
+                trc::error!("Failed to list members of {guild_id:?} due to {e:?}.");
+                return Err(RequestError::Internal("Failed to list server members.".into()));
+            },
+        };
+
+        let label = period.map(|(_, label)| label).unwrap_or_else(|| locale.translate("all time").to_owned());
+        let msgid = match ranking {
+            Ranking::Total => "**Group scoreboard for {} ({}, by total):**\n",
+            Ranking::Average => "**Group scoreboard for {} ({}, by average per member):**\n",
+        };
+        let header = locale.format(msgid, &[&stat.display_name_in(locale), &label]);
+        let rows = group::aggregate(&groups, &member_roles, &totals, ranking).into_iter().enumerate().map(|(index, group_total)| locale.format(
+            "\t{}) {} {}: {} ({} per member across {} members)\n",
+            &[
+                &(index + 1),
+                &group_total.group.name,
+                &group_total.group.role_id.inner().mention(),
+                &stat.format_count_in(locale, group_total.total.clone()),
+                &stat.format_count_in(locale, group_total.average()),
+                &group_total.members,
+            ],
+        ));
+
+        pages::reply_paged(ctx, pages::text_pages(pages::split_pages(header.as_str(), rows, pages::MESSAGE_MAX_LEN))).await
+    }
+}
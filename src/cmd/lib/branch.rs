@@ -0,0 +1,158 @@
+use std::borrow::Cow;
+
+use crossterm::style::Color;
+
+use azel::db::{Connector, DbResult};
+
+use crate::{cmd::lib::options, db::{self, DiscordGuildId, GuildBranch}};
+
+/// The org-wide branch. Always available, even if the guild hasn't configured it.
+pub const MAIN_BRANCH: &str = "main";
+
+const MAIN_BRANCH_DISPLAY_NAME: &str = "Main";
+const MAIN_BRANCH_ANSI_COLOR: u8 = 33;
+const UNKNOWN_BRANCH_DISPLAY_NAME: &str = "Other";
+
+/// Lookup table for the branches a guild has defined.
+#[derive(Debug, Clone, Default)]
+pub struct Branches {
+    branches: Vec<GuildBranch>,
+}
+
+impl Branches {
+    pub fn new(branches: Vec<GuildBranch>) -> Self {
+        Self {
+            branches,
+        }
+    }
+
+    pub async fn load(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<Self> {
+        Ok(Self::new(db::GuildBranch::load_all(connection_maker, guild_id).await?))
+    }
+
+    pub fn get(&self, tag: &str) -> Option<&GuildBranch> {
+        self.branches.iter().find(|branch| branch.tag == tag)
+    }
+
+    pub fn contains(&self, tag: &str) -> bool {
+        tag == MAIN_BRANCH || self.get(tag).is_some()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &GuildBranch> {
+        self.branches.iter()
+    }
+
+    pub fn display_name<'b>(&'b self, tag: &str) -> &'b str {
+        match self.get(tag) {
+            Some(branch) => branch.display_name.as_str(),
+            None if tag == MAIN_BRANCH => MAIN_BRANCH_DISPLAY_NAME,
+            None => UNKNOWN_BRANCH_DISPLAY_NAME,
+        }
+    }
+
+    /// Display name, prefixed with the branch's emoji if it has one.
+    pub fn label<'b>(&'b self, tag: &str) -> Cow<'b, str> {
+        match self.get(tag).and_then(|branch| branch.emoji.as_deref()) {
+            Some(emoji) => Cow::Owned(format!("{} {}", emoji, self.display_name(tag))),
+            None => Cow::Borrowed(self.display_name(tag)),
+        }
+    }
+
+    pub fn color(&self, tag: &str) -> Color {
+        match self.get(tag) {
+            Some(branch) => Color::AnsiValue(u8::try_from(branch.ansi_color).unwrap_or(u8::MAX)),
+            None if tag == MAIN_BRANCH => Color::AnsiValue(MAIN_BRANCH_ANSI_COLOR),
+            None => Color::White,
+        }
+    }
+
+    /// Configured branches come first in their sort order, then anything unknown by tag.
+    pub fn sort_key<'b>(&self, tag: &'b str) -> (i32, &'b str) {
+        match self.get(tag) {
+            Some(branch) => (branch.sort_order, tag),
+            None if tag == MAIN_BRANCH => (i32::MIN, tag),
+            None => (i32::MAX, tag),
+        }
+    }
+
+    /// Choices for a branch option as `(name, value)` pairs, filtered by what the user has typed so far.
+    pub fn autocomplete_choices(&self, partial: &str) -> Vec<(String, String)> {
+        let partial = partial.trim().to_lowercase();
+        let main = self.get(MAIN_BRANCH).is_none().then(|| (MAIN_BRANCH_DISPLAY_NAME.to_owned(), MAIN_BRANCH.to_owned()));
+        main.into_iter()
+            .chain(self.branches.iter().map(|branch| (self.label(&branch.tag).into_owned(), branch.tag.clone())))
+            .filter(|(name, tag)| {
+                partial.is_empty()
+                    || tag.to_lowercase().starts_with(partial.as_str())
+                    || name.to_lowercase().contains(partial.as_str())
+            })
+            .take(options::MAX_CHOICES)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+    use crossterm::style::Color;
+
+    use crate::db::{DiscordGuildId, GuildBranch};
+
+    use super::{Branches, MAIN_BRANCH};
+
+    fn branch(tag: &str, display_name: &str, ansi_color: i16, emoji: Option<&str>, sort_order: i32) -> GuildBranch {
+        GuildBranch {
+            id: 0,
+            created: Utc::now(),
+            updated: Utc::now(),
+            guild_id: DiscordGuildId::from(serenity::all::GuildId::new(1)),
+            tag: tag.to_owned(),
+            display_name: display_name.to_owned(),
+            ansi_color,
+            emoji: emoji.map(str::to_owned),
+            sort_order,
+            channel_id: None,
+        }
+    }
+
+    fn sample() -> Branches {
+        Branches::new(vec![
+            branch("navy", "Navy", 34, Some("⚓"), 1),
+            branch("legion", "Legion", 32, None, 2),
+        ])
+    }
+
+    #[test]
+    fn test_builtin_and_unknown_branches() {
+        let branches = Branches::default();
+        assert!(branches.contains(MAIN_BRANCH));
+        assert!(!branches.contains("navy"));
+        assert_eq!(branches.display_name(MAIN_BRANCH), "Main");
+        assert_eq!(branches.display_name("navy"), "Other");
+        assert_eq!(branches.color(MAIN_BRANCH), Color::AnsiValue(33));
+        assert_eq!(branches.color("navy"), Color::White);
+    }
+
+    #[test]
+    fn test_configured_branches() {
+        let branches = sample();
+        assert!(branches.contains("navy"));
+        assert_eq!(branches.label("navy"), "⚓ Navy");
+        assert_eq!(branches.label("legion"), "Legion");
+        assert_eq!(branches.color("legion"), Color::AnsiValue(32));
+
+        let mut tags = vec!["industry", "legion", MAIN_BRANCH, "navy"];
+        tags.sort_by_key(|tag| branches.sort_key(tag));
+        assert_eq!(tags, vec![MAIN_BRANCH, "navy", "legion", "industry"]);
+    }
+
+    #[test]
+    fn test_autocomplete_choices() {
+        let branches = sample();
+        assert_eq!(branches.autocomplete_choices("").len(), 3);
+        assert_eq!(
+            branches.autocomplete_choices("LEG"),
+            vec![("Legion".to_owned(), "legion".to_owned())],
+        );
+    }
+}
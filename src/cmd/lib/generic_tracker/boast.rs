@@ -4,7 +4,7 @@ use serenity::all::{CommandInteraction, Mentionable, ResolvedOption};
 
 use azel::discord::ExecutionContext;
 
-use crate::{cmd::RequestError, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}};
+use crate::{cmd::RequestError, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}, locale::Locale};
 
 #[derive(Debug)]
 pub struct Request {
@@ -28,14 +28,13 @@ impl Request {
     pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
         let Self { stat, guild_id, user_id } = self;
         let record = db::TrackerCount::load_for(&ctx.db_cfg, stat, guild_id, user_id).await;
-        ctx.reply_restricted(format_stat_for_boast(stat, user_id, record.map(|r| r.total))).await
+        ctx.reply_restricted(format_stat_for_boast(Locale::of(ctx.cmd), stat, user_id, record.map(|r| r.total))).await
     }
 }
 
-fn format_stat_for_boast(stat: TrackerStat, user_id: DiscordUserId, total: Option<BigDecimal>) -> String {
-    format!(
-        "@here {} has {}!",
-        user_id.inner().mention(),
-        stat.format_count_as_past_participle(total.unwrap_or_default()),
-    )
+fn format_stat_for_boast(locale: Locale, stat: TrackerStat, user_id: DiscordUserId, total: Option<BigDecimal>) -> String {
+    locale.format("@here {} has {}!", &[
+        &user_id.inner().mention(),
+        &stat.format_count_as_past_participle_in(locale, total.unwrap_or_default()),
+    ])
 }
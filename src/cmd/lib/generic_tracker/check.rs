@@ -1,22 +1,25 @@
 use bigdecimal::BigDecimal;
+use chrono::Utc;
 use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue};
 use tracing as trc;
 
 use azel::discord::ExecutionContext;
 
-use crate::{cmd::RequestError, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}};
+use crate::{calendar::{self, Period}, cmd::{RequestError, lib::streaks}, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}, locale::Locale};
 
 #[derive(Debug)]
 pub struct Request {
     stat: TrackerStat,
     guild_id: DiscordGuildId,
     user_id: DiscordUserId,
+    period: Option<Period>,
 }
 
 impl Request {
     pub fn parse(cmd: &CommandInteraction, stat: TrackerStat, options: &[ResolvedOption]) -> Result<Self, RequestError> {
         let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a server.".into()))?.into();
         let mut user_id = cmd.user.id;
+        let mut period = None;
         for opt in options {
             match opt.name {
                 "user" => {
@@ -26,6 +29,15 @@ impl Request {
                     };
                     user_id = u.id;
                 }
+                "period" => {
+                    let ResolvedValue::String(p) = opt.value else {
+                        trc::error!("Bad value for `period` in `{} check` {:?}", stat.cmd_name(), opt);
+                        return Err(RequestError::Internal(format!("Bad value for `period` in `{} check`.", stat.cmd_name()).into()));
+                    };
+                    if !calendar::is_all_time(p) {
+                        period = Some(p.parse::<Period>().map_err(|e| RequestError::User(e.to_string().into()))?);
+                    }
+                },
                 "stat" => {},
                 _ => {
                     trc::error!("Unknown option `{}` for `{} check`", stat.cmd_name(), opt.name);
@@ -39,18 +51,76 @@ impl Request {
             stat,
             guild_id,
             user_id,
+            period,
         })
     }
 
     pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
-        let Self { stat, guild_id, user_id } = self;
-        let record = db::TrackerCount::load_for(&ctx.db_cfg, stat, guild_id, user_id).await;
-        ctx.reply_restricted(format_stat_for_check(stat, user_id, record.map(|r| r.total))).await
+        let Self { stat, guild_id, user_id, period } = self;
+        let locale = Locale::of(ctx.cmd);
+        let mut msg = match period {
+            None => {
+                let record = db::TrackerCount::load_for(&ctx.db_cfg, stat, guild_id, user_id).await;
+                format_stat_for_check(locale, stat, user_id, record.map(|r| r.total), None)
+            },
+            Some(period) => {
+                let tz = match db::GuildSettings::load_timezone(&ctx.db_cfg, guild_id).await {
+                    Ok(tz) => tz,
+                    Err(e) => {
+                        trc::error!("Failed to load timezone for {guild_id:?} due to {e:?}.");
+                        return Err(RequestError::Internal("Failed to load server settings.".into()));
+                    },
+                };
+                let range = period.to_utc_range(tz, Utc::now());
+                let totals = match db::TrackerCount::load_period_totals(&ctx.db_cfg, stat, guild_id, range).await {
+                    Ok(totals) => totals,
+                    Err(e) => {
+                        trc::error!("Failed to load totals for {range:?} due to {e:?}.");
+                        return Err(RequestError::Internal("Failed to load totals.".into()));
+                    },
+                };
+                let total = totals.into_iter().find(|(u, _)| *u == user_id).map(|(_, total)| total);
+                format_stat_for_check(locale, stat, user_id, total, Some(period))
+            },
+        };
+
+        if stat == TrackerStat::EventParticipation {
+            let history = streaks::History::load(ctx, stat, guild_id).await?;
+            let now = Utc::now();
+            let streak = history.streaks(now).remove(&user_id).unwrap_or_default();
+            let (range, window) = match period {
+                Some(period) => (period.to_utc_range(history.tz, now), period.describe_in(locale)),
+                None => (history.default_window(now), locale.format("over the last {} weeks", &[&streaks::DEFAULT_ATTENDANCE_WEEKS])),
+            };
+            let attendance = match streaks::Attendance::load_events(ctx, guild_id, user_id, range).await? {
+                Some(attendance) => attendance,
+                None => history.attendance(range, user_id),
+            };
+            msg.push_str(locale.format("\nStreak: {} (longest {}).\nAttendance: {} {}.", &[
+                &format_weeks(locale, streak.current),
+                &format_weeks(locale, streak.longest),
+                &attendance.describe_in(locale),
+                &window,
+            ]).as_str());
+        }
+
+        ctx.reply_restricted(msg).await
     }
 }
 
-fn format_stat_for_check(stat: TrackerStat, user_id: DiscordUserId, total: Option<BigDecimal>) -> String {
+/// e.g. "1 week" or "3 weeks".
+pub fn format_weeks(locale: Locale, weeks: u32) -> String {
+    match weeks {
+        1 => locale.translate("1 week").to_owned(),
+        weeks => locale.format("{} weeks", &[&weeks]),
+    }
+}
+
+fn format_stat_for_check(locale: Locale, stat: TrackerStat, user_id: DiscordUserId, total: Option<BigDecimal>, period: Option<Period>) -> String {
     let mention = user_id.inner().mention();
-    let total = total.unwrap_or_default();
-    format!("We have {} recorded for {}.", stat.format_count(total), mention)
+    let total = stat.format_count_in(locale, total.unwrap_or_default());
+    match period {
+        Some(period) => locale.format("We have {} recorded for {} {}.", &[&total, &mention, &period.describe_in(locale)]),
+        None => locale.format("We have {} recorded for {}.", &[&total, &mention]),
+    }
 }
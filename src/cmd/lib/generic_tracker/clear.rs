@@ -1,80 +1,40 @@
-use tracing as trc;
-
-use serenity::all::{CommandInteraction, DiscordJsonError, Error as SerenityError, ErrorResponse, HttpError, ResolvedOption, StatusCode};
+use serenity::all::{CommandInteraction, ResolvedOption};
 
 use azel::discord::ExecutionContext;
 
-use crate::{cmd::RequestError, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}};
+use crate::{cmd::{RequestError, lib::members}, db::{DiscordGuildId, TrackerStat}};
 
 #[derive(Debug)]
 pub struct Request {
-    stat: TrackerStat,
-    deleter: DiscordUserId,
     guild_id: DiscordGuildId,
 }
 
-const QUERY_LIMIT: i64 = 100;
-
 impl Request {
-    pub fn parse(cmd: &CommandInteraction, stat: TrackerStat, _options: &[ResolvedOption]) -> Result<Self, RequestError> {
+    pub fn parse(cmd: &CommandInteraction, _stat: TrackerStat, _options: &[ResolvedOption]) -> Result<Self, RequestError> {
         let Some(guild_id) = cmd.guild_id else {
             return Err(RequestError::User("This command must be used in a server.".into()));
         };
 
         Ok(Self {
-            stat,
-            deleter: cmd.user.id.into(),
             guild_id: guild_id.into(),
         })
     }
 
     pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
-        let mut current_offset = 0;
-        let mut records_to_delete = vec![];
-
-        while let Some(active_records) = match db::TrackerCount::load_asc(&ctx.db_cfg, self.stat, self.guild_id, current_offset, QUERY_LIMIT).await {
-            Ok(v) => if v.is_empty() {
-                None
-            } else {
-                current_offset += v.len() as i64;
-                Some(v)
-            },
-            Err(e) => {
-                trc::error!("Failed to get scoreboard items from top due to {e:?}.");
-                return Err(RequestError::Internal("failed to get scoreboard items".into()));
-            },
-        } {
-            for record in active_records {
-                let member = match self.guild_id.inner().member(&ctx.ctx, record.user_id).await {
-                    Ok(m) => Some(m),
-                    Err(SerenityError::Http(HttpError::UnsuccessfulRequest(e @ ErrorResponse { status_code: StatusCode::NOT_FOUND, error: DiscordJsonError { code: 10007, .. }, .. }))) => {
-                        trc::debug!("guild member check: {:?} {e:?}", self.guild_id);
-                        None
-                    },
-                    Err(e) => {
-                        trc::error!("Failed to look up guild member {:?} due to {e:?}.", record.id);
-                        return Err(RequestError::Internal("failed to get scoreboard items".into()));
-                    },
-                };
-                if member.is_none() {
-                    trc::info!("Removing entry {:?}", record);
-                    records_to_delete.push(record.id);
-                }
-            }
+        // Listing members can take a while for large servers.
+        ctx.defer().await?;
+
+        // Departures are normally archived as they happen, this only catches up on anything missed. Archiving covers
+        // every stat, not just this one.
+        let reconciliation = members::reconcile(&ctx.ctx, &ctx.db_cfg, *self.guild_id.inner()).await?;
+        if reconciliation.archived == 0 && reconciliation.restored == 0 {
+            return ctx.reply("Scoreboards are already up to date.".to_owned()).await;
         }
 
-        match db::TrackerCount::delete(&ctx.db_cfg, self.deleter, records_to_delete.as_slice()).await {
-            Ok(count) => if count == 0 {
-                ctx.reply("No records deleted.".to_owned()).await
-            } else if count == 1 {
-                ctx.reply("Deleted records for 1 user.".to_owned()).await
-            } else {
-                ctx.reply(format!("Deleted records for {count} users.")).await
-            },
-            Err(e) => {
-                trc::error!("Failed to delete {:?} guild members due to {e:?}.", records_to_delete);
-                Err(RequestError::Internal("failed to clear scoreboard".into()))
-            },
-        }
+        ctx.reply(format!(
+            "Archived {} records of members who left and restored {} records of members who came back. Use `/members purge` to delete archived records for good.",
+            reconciliation.archived,
+            reconciliation.restored,
+        )).await
     }
 }
@@ -5,7 +5,7 @@ use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedVal
 
 use azel::discord::ExecutionContext;
 
-use crate::{cmd::RequestError, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}};
+use crate::{cmd::RequestError, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}, locale::Locale};
 
 #[derive(Debug)]
 pub struct Request {
@@ -17,6 +17,7 @@ pub struct Request {
 
 impl Request {
     pub fn parse(cmd: &CommandInteraction, stat: TrackerStat, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let locale = Locale::of(cmd);
         let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
 
         let mut total = stat.default_add_remove_total();
@@ -41,7 +42,10 @@ impl Request {
                     };
                     if k.is_negative() {
                         trc::error!("Bad value for `total` in `{} delete` {:?}", stat.cmd_name(), opt);
-                        return Err(RequestError::User(format!("Negative value for `total` in `{} delete`. Were you looking for `{} record`?", stat.cmd_name(), stat.cmd_name()).into()));
+                        return Err(RequestError::User(locale.format(
+                            "Negative value for `total` in `{} delete`. Were you looking for `{} record`?",
+                            &[&stat.cmd_name(), &stat.cmd_name()],
+                        ).into()));
                     }
                     total = k;
                 }
@@ -86,15 +90,14 @@ impl Request {
             return Err(RequestError::Internal("Count update failed".into()));
         };
 
-        ctx.reply(format_delete_for_stat(stat, user_id, total, new_total)).await
+        ctx.reply(format_delete_for_stat(Locale::of(ctx.cmd), stat, user_id, total, new_total)).await
     }
 }
 
-fn format_delete_for_stat(stat: TrackerStat, user_id: DiscordUserId, delta: BigDecimal, new_total: BigDecimal) -> String {
-    format!(
-        "Removed {} from {} (total {}).",
-        stat.format_count(delta),
-        user_id.inner().mention(),
-        stat.display_value(new_total),
-    )
+fn format_delete_for_stat(locale: Locale, stat: TrackerStat, user_id: DiscordUserId, delta: BigDecimal, new_total: BigDecimal) -> String {
+    locale.format("Removed {} from {} (total {}).", &[
+        &stat.format_count_in(locale, delta),
+        &user_id.inner().mention(),
+        &stat.display_value(new_total),
+    ])
 }
@@ -0,0 +1,193 @@
+use std::collections::{HashMap, hash_map::Entry};
+
+use bigdecimal::{BigDecimal, Signed};
+use chrono::Utc;
+use serenity::all::{ChannelId, Context, CreateAllowedMentions, CreateMessage, Mentionable};
+use tracing as trc;
+
+use azel::{db::Connector, discord::ExecutionContext};
+
+use crate::{calendar, cmd::RequestError, db::{self, DiscordGuildId, NewTrackerCountChange, StatLimitBreach, StatLimitKind, TrackerStat}, locale::Locale};
+
+/// Checks `changes` against the guild's limits before they're recorded. Any hard limit rejects the whole request,
+/// soft limits come back as lines to pass on to [`flag`] once the changes have gone through.
+pub async fn enforce(ctx: &ExecutionContext<'_>, guild_id: DiscordGuildId, changes: &[NewTrackerCountChange]) -> Result<Vec<String>, RequestError> {
+    enforce_in(&ctx.db_cfg, Locale::of(ctx.cmd), guild_id, changes).await
+}
+
+/// [`enforce`] for changes that don't come from a command, like a roll call confirmed from a menu.
+pub async fn enforce_in(db_cfg: &impl Connector, locale: Locale, guild_id: DiscordGuildId, changes: &[NewTrackerCountChange]) -> Result<Vec<String>, RequestError> {
+    let mut limits = HashMap::new();
+    for change in changes {
+        if let Entry::Vacant(entry) = limits.entry(change.stat) {
+            match db::GuildStatLimit::load_for(db_cfg, guild_id, change.stat).await {
+                Ok(limit) => {
+                    entry.insert(limit);
+                },
+                Err(e) => {
+                    trc::error!("Failed to load limits of {:?} for {guild_id:?} due to {e:?}.", change.stat);
+                    return Err(RequestError::Internal("Failed to load stat limits.".into()));
+                },
+            }
+        }
+    }
+    if limits.values().all(Option::is_none) {
+        return Ok(vec![]);
+    }
+
+    let tz = match db::GuildSettings::load_timezone(db_cfg, guild_id).await {
+        Ok(tz) => tz,
+        Err(e) => {
+            trc::error!("Failed to load timezone for {guild_id:?} due to {e:?}.");
+            return Err(RequestError::Internal("Failed to load server settings.".into()));
+        },
+    };
+    let today = calendar::start_of_day(tz, Utc::now().with_timezone(&tz).date_naive());
+
+    let mut flags = vec![];
+    for change in combine(changes) {
+        let Some(Some(limit)) = limits.get(&change.stat) else {
+            continue;
+        };
+
+        let recorded_today = db::TrackerCount::sum_recorded_since(db_cfg, change.stat, guild_id, change.target, None, today).await;
+        let self_recorded_today = match change.updater == change.target {
+            true => db::TrackerCount::sum_recorded_since(db_cfg, change.stat, guild_id, change.target, Some(change.updater), today).await,
+            false => Ok(BigDecimal::default()),
+        };
+        let (recorded_today, self_recorded_today) = match (recorded_today, self_recorded_today) {
+            (Ok(recorded_today), Ok(self_recorded_today)) => (recorded_today, self_recorded_today),
+            (Err(e), _) | (_, Err(e)) => {
+                trc::error!("Failed to load today's records of {:?} for {:?} due to {e:?}.", change.stat, change.target);
+                return Err(RequestError::Internal("Failed to check stat limits.".into()));
+            },
+        };
+
+        let breaches = limit.check(&change.total, change.updater == change.target, &recorded_today, &self_recorded_today);
+        if let Some(breach) = breaches.iter().find(|breach| breach.hard) {
+            return Err(RequestError::User(locale.format(
+                "Can't record {} for {}: {}.",
+                &[
+                    &change.stat.format_count_in(locale, change.total.clone()),
+                    &change.target.inner().mention(),
+                    &describe(locale, change.stat, breach, &change.total),
+                ],
+            ).into()));
+        }
+        for breach in breaches {
+            flags.push(format!(
+                "{} recorded {} for {}: {}.",
+                change.updater.inner().mention(),
+                change.stat.format_count(change.total.clone()),
+                change.target.inner().mention(),
+                describe(Locale::English, change.stat, &breach, &change.total),
+            ));
+        }
+    }
+    Ok(flags)
+}
+
+/// Adds up what each member is given of each stat, so a request can't get around a limit by splitting a change in
+/// two, e.g. a profit run owner who's also crew gets both their share and what they keep. Removals are left out, as
+/// limits only apply to additions.
+fn combine(changes: &[NewTrackerCountChange]) -> Vec<NewTrackerCountChange> {
+    let mut combined: Vec<NewTrackerCountChange> = vec![];
+    for change in changes.iter().filter(|change| change.total.is_positive()) {
+        let same = combined.iter_mut()
+            .find(|other| other.stat == change.stat && other.target == change.target && other.updater == change.updater);
+        match same {
+            Some(other) => other.total += &change.total,
+            None => combined.push(change.clone()),
+        }
+    }
+    combined
+}
+
+/// Posts soft limit breaches to the officer channel, if the guild has one. Failing to post doesn't fail the request,
+/// since the changes have already been recorded.
+pub async fn flag(ctx: &ExecutionContext<'_>, guild_id: DiscordGuildId, flags: Vec<String>) {
+    flag_in(ctx.ctx, &ctx.db_cfg, guild_id, ctx.cmd.channel_id, flags).await
+}
+
+/// [`flag`] for changes recorded from `source` outside of a command.
+pub async fn flag_in(ctx: &Context, db_cfg: &impl Connector, guild_id: DiscordGuildId, source: ChannelId, flags: Vec<String>) {
+    if flags.is_empty() {
+        return;
+    }
+
+    let channel_id = match db::GuildSettings::load_for(db_cfg, guild_id).await {
+        Ok(settings) => settings.and_then(|settings| settings.officer_channel),
+        Err(e) => {
+            trc::error!("Failed to load settings for {guild_id:?} due to {e:?}.");
+            return;
+        },
+    };
+    let Some(channel_id) = channel_id else {
+        trc::info!("No officer channel for {guild_id:?}, dropping flags {flags:?}.");
+        return;
+    };
+
+    let msg = format!(
+        "**Flagged record in {}:**\n- {}",
+        source.mention(),
+        flags.join("\n- "),
+    );
+    let message = CreateMessage::new()
+        .content(msg)
+        .allowed_mentions(CreateAllowedMentions::new());
+    if let Err(e) = channel_id.inner().send_message(ctx, message).await {
+        trc::error!("Failed to flag record to {channel_id:?} due to {e:?}.");
+    }
+}
+
+fn describe(locale: Locale, stat: TrackerStat, breach: &StatLimitBreach, total: &BigDecimal) -> String {
+    let limit = stat.format_count_in(locale, breach.limit.clone());
+    let already = stat.format_count_in(locale, &breach.attempted - total);
+    let msgid = match (breach.kind, breach.hard) {
+        (StatLimitKind::MaxChange, true) => "over the limit of {} at once",
+        (StatLimitKind::MaxChange, false) => "over the soft limit of {} at once",
+        (StatLimitKind::DailyCap, true) => "over the limit of {} per member per day ({} already today)",
+        (StatLimitKind::DailyCap, false) => "over the soft limit of {} per member per day ({} already today)",
+        (StatLimitKind::SelfDaily, true) => "over the limit of {} recorded for yourself per day ({} already today)",
+        (StatLimitKind::SelfDaily, false) => "over the soft limit of {} recorded for yourself per day ({} already today)",
+    };
+    locale.format(msgid, &[&limit, &already])
+}
+
+#[cfg(test)]
+mod test {
+    use bigdecimal::BigDecimal;
+    use serenity::all::{GuildId, UserId};
+
+    use crate::db::{NewTrackerCountChange, TrackerStat};
+
+    use super::combine;
+
+    fn change(stat: TrackerStat, target: u64, total: i64) -> NewTrackerCountChange {
+        NewTrackerCountChange {
+            stat,
+            guild_id: GuildId::new(1).into(),
+            updater: UserId::new(1).into(),
+            target: UserId::new(target).into(),
+            total: total.into(),
+            user_note: None,
+        }
+    }
+
+    #[test]
+    fn test_combine_sums_changes_to_the_same_target() {
+        let combined = combine(&[
+            change(TrackerStat::IndustryAuec, 1, 600),
+            change(TrackerStat::IndustryAuec, 2, 300),
+            change(TrackerStat::IndustryAuec, 1, 500),
+            change(TrackerStat::GroundKill, 1, 2),
+            change(TrackerStat::IndustryAuec, 2, -100),
+        ]);
+        let totals: Vec<_> = combined.iter().map(|change| (change.stat, change.target.inner().get(), change.total.clone())).collect();
+        assert_eq!(totals, vec![
+            (TrackerStat::IndustryAuec, 1, BigDecimal::from(1100)),
+            (TrackerStat::IndustryAuec, 2, BigDecimal::from(300)),
+            (TrackerStat::GroundKill, 1, BigDecimal::from(2)),
+        ]);
+    }
+}
@@ -4,3 +4,6 @@ pub mod boast;
 pub mod check;
 pub mod clear;
 pub mod scoreboard;
+pub mod limits;
+pub mod share;
+pub mod streak_scoreboard;
@@ -1,11 +1,13 @@
 use std::marker::PhantomData;
 use tracing as trc;
 
+use bigdecimal::BigDecimal;
+use chrono::Utc;
 use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue};
 
 use azel::discord::ExecutionContext;
 
-use crate::{cmd::RequestError, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}};
+use crate::{calendar::{self, Period}, cmd::{RequestError, lib::pages, season}, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}, locale::Locale};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Locator {
@@ -22,6 +24,9 @@ pub struct Request<'a> {
     guild_id: DiscordGuildId,
     limit: i64,
     at: Locator,
+    period: Option<Period>,
+    /// Lifetime totals were asked for explicitly, rather than the running season's.
+    all_time: bool,
     standin: PhantomData<&'a ()>,
 }
 
@@ -32,6 +37,8 @@ impl<'a> Request<'a> {
         let mut at_discrim = "top";
         let mut rank = None;
         let mut someone = None;
+        let mut period = None;
+        let mut all_time = false;
         for opt in options {
             match opt.name {
                 "stat" => {},
@@ -60,6 +67,17 @@ impl<'a> Request<'a> {
                     };
                     someone = Some(u.id);
                 },
+                "period" => {
+                    let ResolvedValue::String(p) = opt.value else {
+                        trc::error!("Bad value for `period` in `{} scoreboard` {:?}", stat.cmd_name(), opt);
+                        return Err(RequestError::Internal(format!("Bad value for `period` in `{} scoreboard`", stat.cmd_name()).into()));
+                    };
+                    if calendar::is_all_time(p) {
+                        all_time = true;
+                    } else {
+                        period = Some(p.parse::<Period>().map_err(|e| RequestError::User(e.to_string().into()))?);
+                    }
+                },
                 "rank" => {
                     let ResolvedValue::Integer(r) = opt.value else {
                         trc::error!("Bad value for `rank` in `{} scoreboard` {:?}", stat.cmd_name(), opt);
@@ -114,14 +132,20 @@ impl<'a> Request<'a> {
             guild_id,
             limit,
             at,
+            period,
+            all_time,
             standin: PhantomData
         })
     }
 
     pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
-        let Self { stat, guild_id, limit, at, .. } = self;
+        let Self { stat, guild_id, limit, at, period, all_time, .. } = self;
+        let locale = Locale::of(ctx.cmd);
         if limit == 0 {
-            return ctx.reply("Scoreboard:".to_owned()).await;
+            return ctx.reply(locale.translate("Scoreboard:").to_owned()).await;
+        }
+        if let Some((period, label)) = resolve_period(ctx, locale, guild_id, period, all_time).await? {
+            return execute_for_period(ctx, stat, guild_id, limit, at, period, label).await;
         }
 
         let (start, ordering) = match at {
@@ -182,7 +206,7 @@ impl<'a> Request<'a> {
                 (start + 1, v)
             },
             Locator::Someone(u) => {
-                let rank = match db::TrackerCount::get_rank_of(&ctx.db_cfg, stat, guild_id, u.into()).await {
+                let rank = match db::TrackerCount::get_rank_of(&ctx.db_cfg, stat, guild_id, u).await {
                     Ok(r) => r,
                     Err(e) => {
                         trc::error!("Failed to get scoreboard items from me {:?} due to {e:?}.", u);
@@ -201,20 +225,107 @@ impl<'a> Request<'a> {
             },
         };
 
-        let mut buffer = "**Scoreboard:**\n".to_owned();
-        for (offset, record) in ordering.into_iter().enumerate() {
-            append_row_for_stat(stat, start + offset as i64, record, &mut buffer);
-        }
+        let rows = ordering.into_iter()
+            .enumerate()
+            .map(|(offset, record)| row(locale, stat, start + offset as i64, record.user_id, record.total));
+        pages::reply_paged(ctx, pages::text_pages(pages::split_pages(locale.translate("**Scoreboard:**\n"), rows, pages::MESSAGE_MAX_LEN))).await
+    }
+}
 
-        ctx.reply_restricted(buffer).await
+/// The period a scoreboard covers along with its label, or `None` for lifetime totals. Without an explicit period,
+/// scoreboards start from zero for the running season.
+pub async fn resolve_period(ctx: &ExecutionContext<'_>, locale: Locale, guild_id: DiscordGuildId, period: Option<Period>, all_time: bool) -> Result<Option<(Period, String)>, RequestError> {
+    if let Some(period) = period {
+        return Ok(Some((period, period.describe_in(locale))));
+    }
+    if all_time {
+        return Ok(None);
+    }
+    match season::load_current(&ctx.db_cfg, guild_id, Utc::now()).await {
+        Ok(current) => Ok(current.map(|current| (current.period(), locale.format("season {}", &[&current.name])))),
+        Err(e) => {
+            trc::error!("Failed to load current season for {guild_id:?} due to {e:?}.");
+            Err(RequestError::Internal("Failed to load seasons.".into()))
+        },
     }
 }
 
-fn append_row_for_stat(stat: TrackerStat, rank: i64, record: db::TrackerCount, buffer: &mut String) {
-    buffer.push_str(format!(
+async fn execute_for_period(ctx: &ExecutionContext<'_>, stat: TrackerStat, guild_id: DiscordGuildId, limit: i64, at: Locator, period: Period, label: String) -> Result<(), RequestError> {
+    let tz = match db::GuildSettings::load_timezone(&ctx.db_cfg, guild_id).await {
+        Ok(tz) => tz,
+        Err(e) => {
+            trc::error!("Failed to load timezone for {guild_id:?} due to {e:?}.");
+            return Err(RequestError::Internal("Failed to load server settings.".into()));
+        },
+    };
+    let range = period.to_utc_range(tz, Utc::now());
+    let totals = match db::TrackerCount::load_period_totals(&ctx.db_cfg, stat, guild_id, range).await {
+        Ok(totals) => totals,
+        Err(e) => {
+            trc::error!("Failed to get scoreboard items for {range:?} due to {e:?}.");
+            return Err(RequestError::Internal("failed to get scoreboard items".into()));
+        },
+    };
+
+    let locale = Locale::of(ctx.cmd);
+    let start = period_window_start(&totals, limit, at, ctx.cmd.user.id.into());
+    let rows = totals.into_iter()
+        .skip(start)
+        .take(limit as usize)
+        .enumerate()
+        .map(|(offset, (user_id, total))| row(locale, stat, (start + offset + 1) as i64, user_id, total));
+    let header = locale.format("**Scoreboard ({}):**\n", &[&label]);
+    pages::reply_paged(ctx, pages::text_pages(pages::split_pages(header.as_str(), rows, pages::MESSAGE_MAX_LEN))).await
+}
+
+/// Index of the first row to show from a fully loaded, ranked list of totals.
+fn period_window_start(totals: &[(DiscordUserId, BigDecimal)], limit: i64, at: Locator, me: DiscordUserId) -> usize {
+    let limit = limit.max(0) as usize;
+    let around = |user_id: DiscordUserId| {
+        let rank = totals.iter().position(|(u, _)| *u == user_id).unwrap_or(totals.len());
+        rank.saturating_sub(limit / 2)
+    };
+    match at {
+        Locator::Top => 0,
+        Locator::Bottom => totals.len().saturating_sub(limit),
+        Locator::Rank(r) => r.max(0) as usize,
+        Locator::Me => around(me),
+        Locator::Someone(user_id) => around(user_id),
+    }
+}
+
+fn row(locale: Locale, stat: TrackerStat, rank: i64, user_id: DiscordUserId, total: BigDecimal) -> String {
+    format!(
         "\t{}) {}: {}\n",
         rank,
-        record.user_id.inner().mention(),
-        stat.format_count(record.total),
-    ).as_str());
+        user_id.inner().mention(),
+        stat.format_count_in(locale, total),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use bigdecimal::BigDecimal;
+    use serenity::all::UserId;
+
+    use crate::db::DiscordUserId;
+
+    use super::{Locator, period_window_start};
+
+    fn user(id: u64) -> DiscordUserId {
+        UserId::new(id).into()
+    }
+
+    #[test]
+    fn test_period_window_start() {
+        let totals: Vec<_> = (1..=10).map(|id| (user(id), BigDecimal::from(100 - id))).collect();
+        assert_eq!(period_window_start(&totals, 4, Locator::Top, user(1)), 0);
+        assert_eq!(period_window_start(&totals, 4, Locator::Bottom, user(1)), 6);
+        assert_eq!(period_window_start(&totals, 20, Locator::Bottom, user(1)), 0);
+        assert_eq!(period_window_start(&totals, 4, Locator::Rank(3), user(1)), 3);
+        assert_eq!(period_window_start(&totals, 4, Locator::Me, user(6)), 3);
+        assert_eq!(period_window_start(&totals, 4, Locator::Someone(user(2)), user(6)), 0);
+        // Users without any records in the period sit below everyone else.
+        assert_eq!(period_window_start(&totals, 4, Locator::Me, user(42)), 8);
+    }
 }
@@ -0,0 +1,201 @@
+use bigdecimal::{BigDecimal, FromPrimitive, Signed, ToPrimitive};
+use tracing as trc;
+
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue, UserId};
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::{generic_tracker::limits, options}}, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}, locale::Locale};
+
+/// Matches the number of `crewN` options the command is registered with.
+pub const MAX_CREW: usize = 8;
+
+#[derive(Debug)]
+pub struct Request {
+    stat: TrackerStat,
+    guild_id: DiscordGuildId,
+    /// In the stat's smallest unit, e.g. fourths of a victory.
+    units: u64,
+    crew: Vec<(DiscordUserId, u64)>,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, stat: TrackerStat, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let locale = Locale::of(cmd);
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+
+        let mut total = stat.default_add_remove_total();
+        let mut crew: [Option<UserId>; MAX_CREW] = [None; MAX_CREW];
+        let mut weights: [u64; MAX_CREW] = [1; MAX_CREW];
+        for opt in options {
+            if let Some(slot) = options::numbered_slot(opt.name, "crew", MAX_CREW) {
+                let ResolvedValue::User(u, _) = opt.value else {
+                    trc::error!("Bad value for `{}` in `{} share` {:?}", opt.name, stat.cmd_name(), opt);
+                    return Err(RequestError::Internal(format!("Bad value for `{}` in `{} share`.", opt.name, stat.cmd_name()).into()));
+                };
+                crew[slot] = Some(u.id);
+                continue;
+            }
+            if let Some(slot) = options::numbered_slot(opt.name, "weight", MAX_CREW) {
+                let ResolvedValue::Integer(w) = opt.value else {
+                    trc::error!("Bad value for `{}` in `{} share` {:?}", opt.name, stat.cmd_name(), opt);
+                    return Err(RequestError::Internal(format!("Bad value for `{}` in `{} share`.", opt.name, stat.cmd_name()).into()));
+                };
+                let Ok(w) = u64::try_from(w) else {
+                    return Err(RequestError::User(locale.format("`{}` can't be negative.", &[&opt.name]).into()));
+                };
+                weights[slot] = w;
+                continue;
+            }
+            match opt.name {
+                "total" => {
+                    let ResolvedValue::Number(k) = opt.value else {
+                        trc::error!("Bad value for `total` in `{} share` {:?}", stat.cmd_name(), opt);
+                        return Err(RequestError::Internal(format!("Bad value for `total` in `{} share`.", stat.cmd_name()).into()));
+                    };
+                    let Some(k) = BigDecimal::from_f64(k) else {
+                        trc::error!("Bad value for `total` in `{} share` {:?}", stat.cmd_name(), opt);
+                        return Err(RequestError::Internal(format!("Bad value for `total` in `{} share`.", stat.cmd_name()).into()));
+                    };
+                    if !k.is_positive() {
+                        return Err(RequestError::User("There has to be something to share.".into()));
+                    }
+                    total = k;
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `{} share`", opt.name, stat.cmd_name());
+                    return Err(RequestError::Internal(format!("Unknown option in `{} share`", stat.cmd_name()).into()));
+                },
+            }
+        }
+
+        let units = total * stat.denominator();
+        let Some(units) = units.is_integer().then(|| units.to_u64()).flatten() else {
+            return Err(RequestError::User(locale.format(
+                "{} can only be shared in steps of {}.",
+                &[&stat.display_name_in(locale), &(BigDecimal::from(1) / stat.denominator())],
+            ).into()));
+        };
+
+        let mut seen = vec![];
+        let mut members = vec![];
+        for (user_id, weight) in crew.into_iter().zip(weights).filter_map(|(user_id, weight)| Some((user_id?, weight))) {
+            if seen.contains(&user_id) {
+                return Err(RequestError::User(locale.format("{} is listed more than once.", &[&user_id.mention()]).into()));
+            }
+            seen.push(user_id);
+            members.push((user_id.into(), weight));
+        }
+        if members.is_empty() {
+            trc::error!("Missing crew in `{} share`", stat.cmd_name());
+            return Err(RequestError::Internal(format!("Missing crew in `{} share`.", stat.cmd_name()).into()));
+        }
+        if members.iter().all(|(_, weight)| *weight == 0) {
+            return Err(RequestError::User("At least one crew member needs a weight above zero.".into()));
+        }
+
+        Ok(Self {
+            stat,
+            guild_id,
+            units,
+            crew: members,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let Self { stat, guild_id, units, crew } = self;
+        let weights: Vec<u64> = crew.iter().map(|(_, weight)| *weight).collect();
+        let total_weight: u64 = weights.iter().sum();
+        let shares = split(units, &weights);
+        let locale = Locale::of(ctx.cmd);
+        // Kept with each change, so it stays in English like the rest of the history.
+        let shared = stat.format_count(BigDecimal::from(units));
+
+        let (recipients, changes): (Vec<_>, Vec<_>) = crew.iter()
+            .zip(shares)
+            .filter(|(_, share)| *share > 0)
+            .map(|((user_id, weight), share)| ((*user_id, *weight, share), db::NewTrackerCountChange {
+                stat,
+                guild_id,
+                updater: ctx.cmd.user.id.into(),
+                target: *user_id,
+                total: BigDecimal::from(share),
+                user_note: Some(format!("Share of {shared} (weight {weight} of {total_weight})")),
+            }))
+            .unzip();
+
+        let flags = limits::enforce(ctx, guild_id, &changes).await?;
+
+        let new_totals = match db::TrackerCount::adjust_counts(&ctx.db_cfg, changes).await {
+            Ok(new_totals) => new_totals,
+            Err(e) => {
+                trc::error!("Failed to record shares for {} share. {e:?}", stat.cmd_name());
+                return Err(RequestError::Internal("Count update failed".into()));
+            },
+        };
+
+        let mut buffer = locale.format("Shared {} between {} crew:\n", &[&stat.format_count_in(locale, BigDecimal::from(units)), &crew.len()]);
+        for ((user_id, weight, share), new_total) in recipients.into_iter().zip(new_totals) {
+            buffer.push_str(locale.format("- {}: {} (weight {}, total {})\n", &[
+                &user_id.inner().mention(),
+                &stat.format_count_in(locale, BigDecimal::from(share)),
+                &weight,
+                &stat.display_value(new_total),
+            ]).as_str());
+        }
+        let reply = ctx.reply(buffer).await;
+        limits::flag(ctx, guild_id, flags).await;
+        reply
+    }
+}
+
+
+/// Splits `units` in proportion to `weights`, using the largest remainder method so the shares always add up to
+/// `units`. Leftover units go one at a time to whoever lost the most to rounding, earlier entries first on ties.
+pub fn split(units: u64, weights: &[u64]) -> Vec<u64> {
+    let total_weight: u128 = weights.iter().map(|weight| u128::from(*weight)).sum();
+    if total_weight == 0 {
+        return vec![0; weights.len()];
+    }
+
+    let exact: Vec<(u128, u128)> = weights.iter()
+        .map(|weight| {
+            let scaled = u128::from(units) * u128::from(*weight);
+            (scaled / total_weight, scaled % total_weight)
+        })
+        .collect();
+    let mut shares: Vec<u64> = exact.iter().map(|(share, _)| *share as u64).collect();
+    let leftover = units - shares.iter().sum::<u64>();
+
+    let mut by_remainder: Vec<usize> = (0..weights.len()).collect();
+    by_remainder.sort_by(|a, b| exact[*b].1.cmp(&exact[*a].1).then(a.cmp(b)));
+    for index in by_remainder.into_iter().take(leftover as usize) {
+        shares[index] += 1;
+    }
+    shares
+}
+
+#[cfg(test)]
+mod test {
+    use super::split;
+
+    #[test]
+    fn test_split_even() {
+        assert_eq!(split(8, &[1, 1, 1, 1]), vec![2, 2, 2, 2]);
+        assert_eq!(split(12, &[2, 1, 1]), vec![6, 3, 3]);
+    }
+
+    #[test]
+    fn test_split_remainder_is_deterministic() {
+        // One victory in fourths between three equal crew, the first two get the leftover.
+        assert_eq!(split(4, &[1, 1, 1]), vec![2, 1, 1]);
+        // Largest remainder wins over position.
+        assert_eq!(split(4, &[1, 2, 2]), vec![1, 2, 1]);
+        assert_eq!(split(5, &[1, 3]), vec![1, 4]);
+        // Zero weights get nothing.
+        assert_eq!(split(4, &[0, 1, 1]), vec![0, 2, 2]);
+        for (units, weights) in [(7, vec![3, 2, 2]), (1, vec![1, 1, 1, 1, 1]), (13, vec![5, 1, 1, 1])] {
+            assert_eq!(split(units, &weights).iter().sum::<u64>(), units);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use chrono::Utc;
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::{generic_tracker::check::format_weeks, pages, streaks}}, db::{DiscordGuildId, TrackerStat}, locale::Locale};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RankBy {
+    Current,
+    Longest,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    stat: TrackerStat,
+    guild_id: DiscordGuildId,
+    rank_by: RankBy,
+    limit: usize,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, stat: TrackerStat, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a server.".into()))?.into();
+        let mut rank_by = RankBy::Current;
+        let mut limit = 10;
+        for opt in options {
+            match opt.name {
+                "rank_by" => {
+                    rank_by = match opt.value {
+                        ResolvedValue::String("current") => RankBy::Current,
+                        ResolvedValue::String("longest") => RankBy::Longest,
+                        _ => {
+                            trc::error!("Bad value for `rank_by` in `{} streaks` {:?}", stat.cmd_name(), opt);
+                            return Err(RequestError::Internal(format!("Bad value for `rank_by` in `{} streaks`.", stat.cmd_name()).into()));
+                        },
+                    };
+                },
+                "limit" => {
+                    let ResolvedValue::Integer(lim) = opt.value else {
+                        trc::error!("Bad value for `limit` in `{} streaks` {:?}", stat.cmd_name(), opt);
+                        return Err(RequestError::Internal(format!("Bad value for `limit` in `{} streaks`.", stat.cmd_name()).into()));
+                    };
+                    limit = usize::try_from(lim).unwrap_or_default();
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `{} streaks`", opt.name, stat.cmd_name());
+                    return Err(RequestError::Internal(format!("Unknown option in `{} streaks`", stat.cmd_name()).into()));
+                },
+            }
+        }
+
+        Ok(Self {
+            stat,
+            guild_id,
+            rank_by,
+            limit,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let history = streaks::History::load(ctx, self.stat, self.guild_id).await?;
+        let mut ranked: Vec<_> = history.streaks(Utc::now())
+            .into_iter()
+            .map(|(user_id, streak)| {
+                let key = match self.rank_by {
+                    RankBy::Current => (streak.current, streak.longest),
+                    RankBy::Longest => (streak.longest, streak.current),
+                };
+                (user_id, streak, key)
+            })
+            .filter(|(_, _, (primary, _))| *primary > 0)
+            .collect();
+        ranked.sort_by(|(a_user, _, a_key), (b_user, _, b_key)| b_key.cmp(a_key).then(a_user.cmp(b_user)));
+
+        if ranked.is_empty() {
+            return ctx.reply_restricted(locale.translate("Nobody has a streak going yet!").to_owned()).await;
+        }
+
+        let header = match self.rank_by {
+            RankBy::Current => "**Current streaks:**\n",
+            RankBy::Longest => "**Longest streaks:**\n",
+        };
+        let rows = ranked.into_iter().take(self.limit).enumerate().map(|(index, (user_id, streak, _))| locale.format("\t{}) {}: {} (longest {})\n", &[
+            &(index + 1),
+            &user_id.inner().mention(),
+            &format_weeks(locale, streak.current),
+            &format_weeks(locale, streak.longest),
+        ]));
+        pages::reply_paged(ctx, pages::text_pages(pages::split_pages(locale.translate(header), rows, pages::MESSAGE_MAX_LEN))).await
+    }
+}
@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+
+use serenity::all::{CacheHttp, GuildId, Member, UserId};
+use tracing as trc;
+
+use azel::db::Connector;
+
+use crate::{cmd::RequestError, db::{self, DiscordUserId, MemberReconciliation}};
+
+/// The most members Discord returns per page when listing a guild's members.
+const MEMBER_PAGE_SIZE: u64 = 1000;
+
+/// Everyone currently in the guild, fetched a page at a time.
+pub async fn fetch_members(http: impl CacheHttp, guild_id: GuildId) -> serenity::Result<Vec<Member>> {
+    let mut members = vec![];
+    let mut after: Option<UserId> = None;
+    loop {
+        let page = guild_id.members(http.http(), Some(MEMBER_PAGE_SIZE), after).await?;
+        after = page.last().map(|member| member.user.id);
+        let page_len = page.len();
+        members.extend(page);
+        if (page_len as u64) < MEMBER_PAGE_SIZE {
+            break;
+        }
+    }
+    Ok(members)
+}
+
+pub async fn fetch_member_ids(http: impl CacheHttp, guild_id: GuildId) -> serenity::Result<HashSet<DiscordUserId>> {
+    Ok(fetch_members(http, guild_id).await?
+        .into_iter()
+        .map(|member| DiscordUserId::from(member.user.id))
+        .collect())
+}
+
+/// Brings the guild's departures in line with its actual member list, for anything missed while the bot was offline.
+pub async fn reconcile(http: impl CacheHttp, connection_maker: &impl Connector, guild_id: GuildId) -> Result<MemberReconciliation, RequestError> {
+    let member_ids = match fetch_member_ids(http, guild_id).await {
+        Ok(member_ids) => member_ids,
+        Err(e) => {
+            trc::error!("Failed to list members of {guild_id:?} due to {e:?}.");
+            return Err(RequestError::Internal("Failed to list server members.".into()));
+        },
+    };
+    match db::GuildMemberDeparture::reconcile(connection_maker, guild_id.into(), &member_ids).await {
+        Ok(reconciliation) => {
+            trc::info!("Reconciled members of {guild_id:?}: {reconciliation:?}");
+            Ok(reconciliation)
+        },
+        Err(e) => {
+            trc::error!("Failed to reconcile members of {guild_id:?} due to {e:?}.");
+            Err(RequestError::Internal("Failed to update departed members.".into()))
+        },
+    }
+}
@@ -1 +1,8 @@
+pub mod branch;
 pub mod generic_tracker;
+pub mod members;
+pub mod options;
+pub mod pages;
+pub mod permissions;
+pub mod progress;
+pub mod streaks;
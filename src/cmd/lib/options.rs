@@ -0,0 +1,26 @@
+//! Helpers for commands registered with a fixed number of numbered options, e.g. `crew1` through `crew8`, since
+//! Discord has no list options, and for the choices offered to fill options in.
+
+/// Discord caps autocomplete responses at 25 choices, and select menus at 25 options.
+pub const MAX_CHOICES: usize = 25;
+
+/// Zero-based slot of a numbered option, e.g. `crew3` -> 2 for `prefix` "crew". `None` if `name` isn't one of the
+/// `count` options with that prefix.
+pub fn numbered_slot(name: &str, prefix: &str, count: usize) -> Option<usize> {
+    let slot = name.strip_prefix(prefix)?.parse::<usize>().ok()?;
+    (1..=count).contains(&slot).then(|| slot - 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::numbered_slot;
+
+    #[test]
+    fn test_numbered_slot() {
+        assert_eq!(numbered_slot("crew1", "crew", 8), Some(0));
+        assert_eq!(numbered_slot("weight8", "weight", 8), Some(7));
+        assert_eq!(numbered_slot("crew9", "crew", 8), None);
+        assert_eq!(numbered_slot("crew0", "crew", 8), None);
+        assert_eq!(numbered_slot("total", "crew", 8), None);
+    }
+}
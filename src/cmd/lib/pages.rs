@@ -0,0 +1,281 @@
+//! Replies too long for one message. Content is split between rows or sections where possible, and anything with more
+//! than one page gets Prev/Next buttons for whoever ran the command.
+
+use std::time::Duration;
+
+use serenity::{
+    all::{
+        ButtonStyle, CommandInteraction, ComponentInteraction, Context, CreateActionRow, CreateAllowedMentions, CreateButton,
+        CreateInteractionResponse, CreateInteractionResponseFollowup, CreateInteractionResponseMessage, EditInteractionResponse,
+        MessageId, UserId,
+    },
+    collector::ComponentInteractionCollector,
+    futures::StreamExt,
+};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::cmd::{RequestError, lib::progress::EmbedLayout};
+
+/// Discord's limit on message content.
+pub const MESSAGE_MAX_LEN: usize = 2000;
+
+/// How long the buttons keep working. Interaction tokens expire after 15 minutes, after which the reply can't be
+/// edited to take them away.
+const PAGER_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+const PAGER_CUSTOM_ID_PREFIX: &str = "pager:";
+
+const CODE_FENCE: &str = "```";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Page {
+    Text(String),
+    Embed(EmbedLayout),
+}
+
+impl Page {
+    fn message(&self, mentions: UserId) -> CreateInteractionResponseMessage {
+        let message = CreateInteractionResponseMessage::new().allowed_mentions(CreateAllowedMentions::new().users([mentions]));
+        match self {
+            Page::Text(content) => message.content(content.as_str()).embeds(vec![]),
+            Page::Embed(layout) => message.content("").embed(layout.to_embed()),
+        }
+    }
+
+    fn followup(&self, mentions: UserId) -> CreateInteractionResponseFollowup {
+        let message = CreateInteractionResponseFollowup::new().allowed_mentions(CreateAllowedMentions::new().users([mentions]));
+        match self {
+            Page::Text(content) => message.content(content.as_str()),
+            Page::Embed(layout) => message.embed(layout.to_embed()),
+        }
+    }
+}
+
+/// Packs `blocks` into as few pages as possible, each starting with `header`. A block only gets broken up if it can't
+/// fit on a page by itself, and then between lines, keeping code blocks whole where they fit.
+pub fn split_pages<S: AsRef<str>>(header: &str, blocks: impl IntoIterator<Item = S>, max_len: usize) -> Vec<String> {
+    let room = max_len.saturating_sub(len(header)).max(1);
+    let mut pages = Vec::new();
+    let mut page = String::new();
+    let mut push = |piece: &str, page: &mut String| {
+        if !page.is_empty() && len(page) + len(piece) > room {
+            pages.push(format!("{header}{}", std::mem::take(page)));
+        }
+        page.push_str(piece);
+    };
+
+    for block in blocks {
+        let block = block.as_ref();
+        if len(block) <= room {
+            push(block, &mut page);
+            continue;
+        }
+        for piece in lines(block) {
+            if len(piece) <= room {
+                push(piece, &mut page);
+                continue;
+            }
+            for chunk in hard_split(piece, room) {
+                push(chunk.as_str(), &mut page);
+            }
+        }
+    }
+    if !page.is_empty() || pages.is_empty() {
+        pages.push(format!("{header}{page}"));
+    }
+    pages
+}
+
+/// Lines with their newline, except that a fenced code block counts as one line.
+fn lines(block: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut fenced = false;
+    for (end, line) in block.split_inclusive('\n').scan(0, |offset, line| {
+        *offset += line.len();
+        Some((*offset, line))
+    }) {
+        // Opening fences can trail text, e.g. "Progress: ```ansi", so count fences rather than look at line starts.
+        if line.matches(CODE_FENCE).count() % 2 == 1 {
+            fenced = !fenced;
+        }
+        if !fenced {
+            pieces.push(&block[start..end]);
+            start = end;
+        }
+    }
+    if start < block.len() {
+        pieces.push(&block[start..]);
+    }
+    pieces
+}
+
+fn hard_split(s: &str, max_len: usize) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    chars.chunks(max_len).map(|chunk| chunk.iter().collect()).collect()
+}
+
+fn len(s: &str) -> usize {
+    s.chars().count()
+}
+
+pub fn text_pages(pages: Vec<String>) -> Vec<Page> {
+    pages.into_iter().map(Page::Text).collect()
+}
+
+/// Sends the first page, and if there are more, lets the person who asked flip through them for a while.
+pub async fn reply_paged(ctx: &ExecutionContext<'_>, pages: Vec<Page>) -> Result<(), RequestError> {
+    let Some(first) = pages.first() else {
+        return Ok(());
+    };
+    let user_id = ctx.cmd.user.id;
+    let paged = pages.len() > 1;
+    let components = match paged {
+        true => buttons(ctx.cmd.id.get(), 0, pages.len()),
+        false => vec![],
+    };
+
+    let mut is_first_response = ctx.is_first_response.lock().await;
+    let sent = match *is_first_response {
+        true => {
+            let message = first.message(user_id).components(components);
+            ctx.cmd.create_response(ctx.ctx, CreateInteractionResponse::Message(message)).await.map(|()| None)
+        },
+        false => ctx.cmd.create_followup(ctx.ctx, first.followup(user_id).components(components)).await
+            .map(|message| Some(message.id)),
+    };
+    let followup = match sent {
+        Ok(followup) => followup,
+        Err(e) => {
+            trc::error!("SEND-FAILED err={e:?}");
+            return Err(RequestError::Internal("Message failed to send.".into()));
+        },
+    };
+    *is_first_response = false;
+    drop(is_first_response);
+
+    if paged {
+        tokio::spawn(turn_pages(ctx.ctx.clone(), ctx.cmd.clone(), followup, pages));
+    }
+    Ok(())
+}
+
+/// Answers button presses until the pager times out, then takes the buttons away.
+async fn turn_pages(ctx: Context, cmd: CommandInteraction, followup: Option<MessageId>, pages: Vec<Page>) {
+    let message_id = match followup {
+        Some(message_id) => message_id,
+        None => match cmd.get_response(&ctx).await {
+            Ok(message) => message.id,
+            Err(e) => {
+                trc::error!("Failed to look up paged reply to {:?} due to {e:?}.", cmd.id);
+                return;
+            },
+        },
+    };
+
+    let mut presses = ComponentInteractionCollector::new(&ctx)
+        .message_id(message_id)
+        .timeout(PAGER_TIMEOUT)
+        .stream();
+    while let Some(press) = presses.next().await {
+        let Some((_, next)) = parse_custom_id(press.data.custom_id.as_str()) else {
+            continue;
+        };
+        if press.user.id != cmd.user.id {
+            refuse(&ctx, &press, cmd.user.id).await;
+            continue;
+        }
+        let current = next.min(pages.len() - 1);
+        let message = pages[current].message(cmd.user.id).components(buttons(cmd.id.get(), current, pages.len()));
+        if let Err(e) = press.create_response(&ctx, CreateInteractionResponse::UpdateMessage(message)).await {
+            trc::error!("Failed to turn to page {current} of reply to {:?} due to {e:?}.", cmd.id);
+        }
+    }
+
+    let result = match followup {
+        Some(message_id) => cmd.edit_followup(&ctx, message_id, CreateInteractionResponseFollowup::new().components(vec![])).await,
+        None => cmd.edit_response(&ctx, EditInteractionResponse::new().components(vec![])).await,
+    };
+    if let Err(e) = result {
+        trc::info!("Failed to remove page buttons from reply to {:?} due to {e:?}.", cmd.id);
+    }
+}
+
+async fn refuse(ctx: &Context, press: &ComponentInteraction, owner: UserId) {
+    let message = CreateInteractionResponseMessage::new()
+        .content(format!("Only <@{owner}> can turn these pages, run the command yourself to get your own copy."))
+        .allowed_mentions(CreateAllowedMentions::new())
+        .ephemeral(true);
+    if let Err(e) = press.create_response(ctx, CreateInteractionResponse::Message(message)).await {
+        trc::error!("Failed to turn away {:?} from someone else's pages due to {e:?}.", press.user.id);
+    }
+}
+
+/// Prev and Next point at the page they lead to, with the page count between them.
+fn buttons(reply_id: u64, current: usize, count: usize) -> Vec<CreateActionRow> {
+    vec![
+        CreateActionRow::Buttons(vec![
+            CreateButton::new(custom_id(reply_id, current.saturating_sub(1)))
+                .label("◀ Prev")
+                .style(ButtonStyle::Secondary)
+                .disabled(current == 0),
+            CreateButton::new(format!("{PAGER_CUSTOM_ID_PREFIX}{reply_id}:page"))
+                .label(format!("{}/{count}", current + 1))
+                .style(ButtonStyle::Secondary)
+                .disabled(true),
+            CreateButton::new(custom_id(reply_id, current + 1))
+                .label("Next ▶")
+                .style(ButtonStyle::Secondary)
+                .disabled(current + 1 >= count),
+        ]),
+    ]
+}
+
+fn custom_id(reply_id: u64, page: usize) -> String {
+    format!("{PAGER_CUSTOM_ID_PREFIX}{reply_id}:{page}")
+}
+
+/// Whether a button belongs to a pager, which answers it itself.
+pub fn is_custom_id(custom_id: &str) -> bool {
+    custom_id.starts_with(PAGER_CUSTOM_ID_PREFIX)
+}
+
+/// e.g. "pager:1234:2" is a button leading to the third page of the reply to interaction 1234.
+pub fn parse_custom_id(custom_id: &str) -> Option<(u64, usize)> {
+    let (reply_id, page) = custom_id.strip_prefix(PAGER_CUSTOM_ID_PREFIX)?.split_once(':')?;
+    Some((reply_id.parse().ok()?, page.parse().ok()?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_custom_id, split_pages};
+
+    #[test]
+    fn test_split_pages_on_blocks() {
+        let rows = ["1) a\n", "2) b\n", "3) c\n"];
+        assert_eq!(split_pages("**Top:**\n", rows, 100), vec!["**Top:**\n1) a\n2) b\n3) c\n"]);
+        assert_eq!(split_pages("**Top:**\n", rows, 19), vec!["**Top:**\n1) a\n2) b\n", "**Top:**\n3) c\n"]);
+        assert_eq!(split_pages("", Vec::<String>::new(), 10), vec![""]);
+    }
+
+    #[test]
+    fn test_split_pages_inside_blocks() {
+        // Too long for a page, so it breaks between lines, and a line that's too long on its own is cut.
+        assert_eq!(split_pages("", ["one\ntwo\nthree\n"], 9), vec!["one\ntwo\n", "three\n"]);
+        assert_eq!(split_pages("", ["abcdefghij\n"], 4), vec!["abcd", "efgh", "ij\n"]);
+        // Code blocks stay in one piece.
+        assert_eq!(
+            split_pages("", ["## Goal\nProgress: ```ansi\n[##  ]\n```\n"], 30),
+            vec!["## Goal\n", "Progress: ```ansi\n[##  ]\n```\n"],
+        );
+    }
+
+    #[test]
+    fn test_parse_custom_id() {
+        assert_eq!(parse_custom_id("pager:1234:2"), Some((1234, 2)));
+        assert_eq!(parse_custom_id("pager:1234:page"), None);
+        assert_eq!(parse_custom_id("monthly_goal_pledge:3"), None);
+    }
+}
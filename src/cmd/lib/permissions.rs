@@ -0,0 +1,7 @@
+use serenity::all::CommandInteraction;
+
+/// Whether whoever ran the command is an officer, i.e. can manage the server. Commands that change the server's
+/// configuration or other members' stats check this, the same permission `/stats` needs.
+pub fn is_officer(cmd: &CommandInteraction) -> bool {
+    cmd.member.as_ref().and_then(|member| member.permissions).is_some_and(|permissions| permissions.manage_guild())
+}
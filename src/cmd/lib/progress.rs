@@ -0,0 +1,434 @@
+//! Progress overviews drawn in the style that reads best where they're shown. ANSI colours only render on desktop, so
+//! mobile gets plain blocks, emoji squares or an embed instead.
+
+use crossterm::style::Color;
+use serenity::all::{Colour, CreateEmbed};
+
+use crate::{cmd::lib::pages::{self, Page}, db::BarStyle};
+
+const ANSI_BAR_WIDTH: usize = 25;
+const UNICODE_BAR_WIDTH: usize = 20;
+const EMOJI_BAR_WIDTH: usize = 10;
+
+/// Discord's embed limits.
+const EMBED_TITLE_MAX_LEN: usize = 256;
+const EMBED_FIELD_NAME_MAX_LEN: usize = 256;
+const EMBED_FIELD_VALUE_MAX_LEN: usize = 1024;
+const EMBED_MAX_FIELDS: usize = 25;
+const EMBED_MAX_LEN: usize = 6000;
+
+const EMPTY_SQUARE: &str = "⬛";
+const EMPTY_SQUARE_ON_BLACK: &str = "⬜";
+
+/// The first 16 ANSI colours, as xterm draws them.
+const ANSI_BASIC: [(u8, u8, u8); 16] = [
+    (0, 0, 0), (205, 0, 0), (0, 205, 0), (205, 205, 0), (0, 0, 238), (205, 0, 205), (0, 205, 205), (229, 229, 229),
+    (127, 127, 127), (255, 0, 0), (0, 255, 0), (255, 255, 0), (92, 92, 255), (255, 0, 255), (0, 255, 255), (255, 255, 255),
+];
+const ANSI_CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    value: usize,
+    max: usize,
+    color: Color,
+}
+
+impl Progress {
+    /// `value` is capped at `max`, and a `max` of 0 counts as 1 so nothing divides by zero.
+    pub fn new(value: usize, max: usize, color: Color) -> Self {
+        let max = max.max(1);
+        Self {
+            value: value.min(max),
+            max,
+            color,
+        }
+    }
+
+    pub fn percent(&self) -> f64 {
+        self.value as f64 / self.max as f64 * 100.
+    }
+
+    fn filled(&self, width: usize) -> usize {
+        self.value * width / self.max
+    }
+}
+
+/// A titled block of an overview, e.g. one goal or one branch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    pub heading: String,
+    /// Markdown, each line ending in a newline.
+    pub text: String,
+    pub progress: Option<Progress>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Overview {
+    pub title: String,
+    pub progress: Option<Progress>,
+    pub sections: Vec<Section>,
+}
+
+/// What goes into an embed, kept apart from serenity's builder so it can be compared in tests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbedLayout {
+    pub title: String,
+    pub description: String,
+    pub colour: Option<(u8, u8, u8)>,
+    pub fields: Vec<(String, String)>,
+}
+
+impl EmbedLayout {
+    pub fn to_embed(&self) -> CreateEmbed {
+        let mut embed = CreateEmbed::new().title(self.title.as_str());
+        if !self.description.is_empty() {
+            embed = embed.description(self.description.as_str());
+        }
+        if let Some((r, g, b)) = self.colour {
+            embed = embed.colour(Colour::from_rgb(r, g, b));
+        }
+        embed.fields(self.fields.iter().map(|(name, value)| (name.as_str(), value.as_str(), false)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rendered {
+    Text(String),
+    Embed(EmbedLayout),
+}
+
+impl Overview {
+    pub fn render(&self, style: BarStyle) -> Rendered {
+        match style {
+            BarStyle::Embed => Rendered::Embed(self.embed_layout()),
+            style => Rendered::Text(self.text(style)),
+        }
+    }
+
+    /// As a message. Embeds don't apply here, so they get Unicode bars.
+    pub fn text(&self, style: BarStyle) -> String {
+        self.heading() + self.text_blocks(style).concat().as_str()
+    }
+
+    /// Split between sections into pages that each fit in a message, with the title on every page.
+    pub fn pages(&self, style: BarStyle) -> Vec<Page> {
+        match style {
+            BarStyle::Embed => self.embed_layouts().into_iter().map(Page::Embed).collect(),
+            style => pages::text_pages(pages::split_pages(self.heading().as_str(), self.text_blocks(style), pages::MESSAGE_MAX_LEN)),
+        }
+    }
+
+    fn heading(&self) -> String {
+        format!("# {}\n", self.title)
+    }
+
+    /// The overall progress, then one block per section.
+    fn text_blocks(&self, style: BarStyle) -> Vec<String> {
+        let overall = self.progress.as_ref().map(|progress| format!("\n{}", progress_line(progress, style)));
+        overall.into_iter()
+            .chain(self.sections.iter().map(|section| format!(
+                "## {}\n{}\n{}",
+                section.heading,
+                section.text,
+                section.progress.as_ref().map(|progress| progress_line(progress, style)).unwrap_or_default(),
+            )))
+            .collect()
+    }
+
+    /// The first embed's worth of sections.
+    pub fn embed_layout(&self) -> EmbedLayout {
+        self.embed_layouts().swap_remove(0)
+    }
+
+    /// One field per section, starting a new embed whenever one fills up. Only the first has the overall progress.
+    pub fn embed_layouts(&self) -> Vec<EmbedLayout> {
+        let title = truncate(self.title.as_str(), EMBED_TITLE_MAX_LEN);
+        let description = self.progress.as_ref()
+            .map(|progress| progress_line(progress, BarStyle::Embed))
+            .unwrap_or_default();
+        let colour = self.progress.and_then(|progress| rgb(progress.color));
+        let mut layouts = vec![];
+        let mut layout = EmbedLayout {
+            title: title.clone(),
+            description: description.trim_end().to_owned(),
+            colour,
+            fields: vec![],
+        };
+        let mut len = title.chars().count() + description.chars().count();
+
+        for section in &self.sections {
+            let name = match section.heading.trim().is_empty() {
+                true => "-".to_owned(),
+                false => truncate(section.heading.as_str(), EMBED_FIELD_NAME_MAX_LEN),
+            };
+            let value = format!(
+                "{}{}",
+                section.text,
+                section.progress.as_ref().map(|progress| progress_line(progress, BarStyle::Embed)).unwrap_or_default(),
+            );
+            let value = match value.trim().is_empty() {
+                true => "-".to_owned(),
+                false => truncate(value.trim_end(), EMBED_FIELD_VALUE_MAX_LEN),
+            };
+
+            let field_len = name.chars().count() + value.chars().count();
+            if layout.fields.len() == EMBED_MAX_FIELDS || len + field_len > EMBED_MAX_LEN {
+                let next = EmbedLayout {
+                    title: title.clone(),
+                    description: String::new(),
+                    colour,
+                    fields: vec![],
+                };
+                layouts.push(std::mem::replace(&mut layout, next));
+                len = title.chars().count();
+            }
+            len += field_len;
+            layout.fields.push((name, value));
+        }
+        layouts.push(layout);
+        layouts
+    }
+}
+
+fn progress_line(progress: &Progress, style: BarStyle) -> String {
+    let percent = progress.percent();
+    match style {
+        BarStyle::Ansi => format!("Progress ({percent:.2}%): ```ansi\n{}\n```\n", ansi_bar(progress)),
+        BarStyle::Unicode | BarStyle::Embed => format!("Progress ({percent:.2}%): `{}`\n", unicode_bar(progress)),
+        BarStyle::Emoji => format!("Progress ({percent:.2}%): {}\n", emoji_bar(progress)),
+    }
+}
+
+pub fn ansi_bar(progress: &Progress) -> String {
+    progrs_bar::Bar::new(progress.value, progress.max).generate_string(ANSI_BAR_WIDTH, progress.color)
+}
+
+/// Full blocks for what's done, light shade for the rest, rounded down so it's only full at 100%.
+pub fn unicode_bar(progress: &Progress) -> String {
+    let filled = progress.filled(UNICODE_BAR_WIDTH);
+    "█".repeat(filled) + "░".repeat(UNICODE_BAR_WIDTH - filled).as_str()
+}
+
+/// Squares in the colour closest to the bar's, on black unless the colour is black itself.
+pub fn emoji_bar(progress: &Progress) -> String {
+    let square = square_for(progress.color);
+    let empty = match square == EMPTY_SQUARE {
+        true => EMPTY_SQUARE_ON_BLACK,
+        false => EMPTY_SQUARE,
+    };
+    let filled = progress.filled(EMOJI_BAR_WIDTH);
+    square.repeat(filled) + empty.repeat(EMOJI_BAR_WIDTH - filled).as_str()
+}
+
+/// The emoji square closest in hue, or black and white for greys. Going by hue rather than distance in RGB keeps dark
+/// greens green.
+pub fn square_for(color: Color) -> &'static str {
+    let Some((r, g, b)) = rgb(color) else {
+        return "⬜";
+    };
+    let (r, g, b) = (f64::from(r) / 255., f64::from(g) / 255., f64::from(b) / 255.);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+    if max == 0. || chroma / max < 0.25 {
+        return if max >= 0.5 { "⬜" } else { "⬛" };
+    }
+
+    let hue = 60. * if max == r {
+        ((g - b) / chroma).rem_euclid(6.)
+    } else if max == g {
+        (b - r) / chroma + 2.
+    } else {
+        (r - g) / chroma + 4.
+    };
+    match hue {
+        hue if hue < 15. => "🟥",
+        hue if hue < 40. && max < 0.65 => "🟫",
+        hue if hue < 40. => "🟧",
+        hue if hue < 70. => "🟨",
+        hue if hue < 170. => "🟩",
+        hue if hue < 260. => "🟦",
+        hue if hue < 330. => "🟪",
+        _ => "🟥",
+    }
+}
+
+/// What a terminal colour looks like, `None` for the terminal's default.
+pub fn rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let ansi = match color {
+        Color::Reset => return None,
+        Color::Rgb { r, g, b } => return Some((r, g, b)),
+        Color::AnsiValue(value) => value,
+        Color::Black => 0,
+        Color::DarkRed => 1,
+        Color::DarkGreen => 2,
+        Color::DarkYellow => 3,
+        Color::DarkBlue => 4,
+        Color::DarkMagenta => 5,
+        Color::DarkCyan => 6,
+        Color::Grey => 7,
+        Color::DarkGrey => 8,
+        Color::Red => 9,
+        Color::Green => 10,
+        Color::Yellow => 11,
+        Color::Blue => 12,
+        Color::Magenta => 13,
+        Color::Cyan => 14,
+        Color::White => 15,
+    };
+    Some(match ansi {
+        0..=15 => ANSI_BASIC[usize::from(ansi)],
+        16..=231 => {
+            let cube = usize::from(ansi - 16);
+            (ANSI_CUBE_LEVELS[cube / 36], ANSI_CUBE_LEVELS[cube / 6 % 6], ANSI_CUBE_LEVELS[cube % 6])
+        },
+        _ => {
+            let grey = 8 + 10 * (ansi - 232);
+            (grey, grey, grey)
+        },
+    })
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    match s.chars().count() > max {
+        true => s.chars().take(max - 1).chain(std::iter::once('…')).collect(),
+        false => s.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crossterm::style::Color;
+
+    use crate::{cmd::lib::pages::Page, db::BarStyle};
+
+    use super::{EmbedLayout, Overview, Progress, Rendered, Section, rgb, square_for};
+
+    fn sample() -> Overview {
+        Overview {
+            title: "Goal Progress: Navy".to_owned(),
+            progress: Some(Progress::new(90, 200, Color::AnsiValue(34))),
+            sections: vec![
+                Section {
+                    heading: "Sink ships".to_owned(),
+                    text: "Ten of them.\n".to_owned(),
+                    progress: Some(Progress::new(40, 100, Color::AnsiValue(34))),
+                },
+                Section {
+                    heading: "Legion".to_owned(),
+                    text: String::new(),
+                    progress: Some(Progress::new(100, 100, Color::White)),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_colours() {
+        assert_eq!(rgb(Color::AnsiValue(34)), Some((0, 175, 0)));
+        assert_eq!(rgb(Color::AnsiValue(33)), Some((0, 135, 255)));
+        assert_eq!(rgb(Color::AnsiValue(244)), Some((128, 128, 128)));
+        assert_eq!(rgb(Color::Reset), None);
+        assert_eq!(square_for(Color::AnsiValue(34)), "🟩");
+        assert_eq!(square_for(Color::AnsiValue(33)), "🟦");
+        assert_eq!(square_for(Color::AnsiValue(196)), "🟥");
+        assert_eq!(square_for(Color::White), "⬜");
+        assert_eq!(square_for(Color::Black), "⬛");
+    }
+
+    #[test]
+    fn test_ansi_snapshot() {
+        assert_eq!(
+            sample().text(BarStyle::Ansi),
+            "\
+                # Goal Progress: Navy\n\
+                \n\
+                Progress (45.00%): ```ansi\n[\x1b[38;5;34m██████████             \x1b[39m]\n```\n\
+                ## Sink ships\n\
+                Ten of them.\n\
+                \n\
+                Progress (40.00%): ```ansi\n[\x1b[38;5;34m████████               \x1b[39m]\n```\n\
+                ## Legion\n\
+                \n\
+                Progress (100.00%): ```ansi\n[\x1b[38;5;15m███████████████████████\x1b[39m]\n```\n\
+            ",
+        );
+    }
+
+    #[test]
+    fn test_unicode_snapshot() {
+        assert_eq!(
+            sample().text(BarStyle::Unicode),
+            "\
+                # Goal Progress: Navy\n\
+                \n\
+                Progress (45.00%): `█████████░░░░░░░░░░░`\n\
+                ## Sink ships\n\
+                Ten of them.\n\
+                \n\
+                Progress (40.00%): `████████░░░░░░░░░░░░`\n\
+                ## Legion\n\
+                \n\
+                Progress (100.00%): `████████████████████`\n\
+            ",
+        );
+    }
+
+    #[test]
+    fn test_emoji_snapshot() {
+        assert_eq!(
+            sample().text(BarStyle::Emoji),
+            "\
+                # Goal Progress: Navy\n\
+                \n\
+                Progress (45.00%): 🟩🟩🟩🟩⬛⬛⬛⬛⬛⬛\n\
+                ## Sink ships\n\
+                Ten of them.\n\
+                \n\
+                Progress (40.00%): 🟩🟩🟩🟩⬛⬛⬛⬛⬛⬛\n\
+                ## Legion\n\
+                \n\
+                Progress (100.00%): ⬜⬜⬜⬜⬜⬜⬜⬜⬜⬜\n\
+            ",
+        );
+    }
+
+    #[test]
+    fn test_embed_snapshot() {
+        assert_eq!(
+            sample().render(BarStyle::Embed),
+            Rendered::Embed(EmbedLayout {
+                title: "Goal Progress: Navy".to_owned(),
+                description: "Progress (45.00%): `█████████░░░░░░░░░░░`".to_owned(),
+                colour: Some((0, 175, 0)),
+                fields: vec![
+                    ("Sink ships".to_owned(), "Ten of them.\nProgress (40.00%): `████████░░░░░░░░░░░░`".to_owned()),
+                    ("Legion".to_owned(), "Progress (100.00%): `████████████████████`".to_owned()),
+                ],
+            }),
+        );
+    }
+
+    #[test]
+    fn test_pages() {
+        let mut overview = sample();
+        overview.sections = (0..30).map(|i| Section {
+            heading: format!("Goal {i}"),
+            text: "x".repeat(100) + "\n",
+            progress: None,
+        }).collect();
+
+        let text_pages = overview.pages(BarStyle::Unicode);
+        assert_eq!(text_pages.len(), 2);
+        assert!(matches!(&text_pages[0], Page::Text(page) if page.starts_with("# Goal Progress: Navy\n\nProgress (45.00%)")));
+        assert!(matches!(&text_pages[1], Page::Text(page) if page.starts_with("# Goal Progress: Navy\n## Goal ")));
+
+        let embed_pages = overview.pages(BarStyle::Embed);
+        let fields: Vec<_> = embed_pages.iter().map(|page| match page {
+            Page::Embed(layout) => layout.fields.len(),
+            Page::Text(_) => panic!("Expected embeds"),
+        }).collect();
+        assert_eq!(fields, vec![25, 5]);
+    }
+}
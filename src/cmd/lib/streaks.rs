@@ -0,0 +1,235 @@
+//! Streaks of consecutive weeks and attendance rates, worked out from the change history of a stat. Used for event
+//! participation, where raw totals mostly reward having been around the longest. Attendance counts the events that
+//! had a roll call, or the days anyone took part in guilds that record participation by hand.
+
+use std::collections::{BTreeSet, HashMap};
+
+use bigdecimal::{BigDecimal, Signed};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{calendar::{self, UtcRange}, cmd::RequestError, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}, locale::Locale};
+
+/// How many weeks back attendance is measured over when no period is given.
+pub const DEFAULT_ATTENDANCE_WEEKS: i64 = 8;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Streak {
+    pub current: u32,
+    pub longest: u32,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AttendanceUnit {
+    /// Days anyone took part, for participation recorded by hand.
+    #[default]
+    EventDay,
+    /// Events that had a roll call.
+    Event,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Attendance {
+    pub attended: usize,
+    pub held: usize,
+    pub unit: AttendanceUnit,
+}
+
+impl Attendance {
+    /// e.g. "5 of 8 event days (63%)".
+    pub fn describe_in(&self, locale: Locale) -> String {
+        let percent = |held: usize| (self.attended * 100 + held / 2) / held;
+        match (self.unit, self.held) {
+            (AttendanceUnit::EventDay, 0) => locale.translate("no event days").to_owned(),
+            (AttendanceUnit::EventDay, held) => locale.format("{} of {} event days ({}%)", &[&self.attended, &held, &percent(held)]),
+            (AttendanceUnit::Event, 0) => locale.translate("no events").to_owned(),
+            (AttendanceUnit::Event, held) => locale.format("{} of {} events ({}%)", &[&self.attended, &held, &percent(held)]),
+        }
+    }
+
+    /// Attendance of the events in `range` that had a roll call, `None` if there weren't any.
+    pub async fn load_events(ctx: &ExecutionContext<'_>, guild_id: DiscordGuildId, user_id: DiscordUserId, range: UtcRange) -> Result<Option<Self>, RequestError> {
+        match db::Event::count_attendance(&ctx.db_cfg, guild_id, user_id, range).await {
+            Ok((0, _)) => Ok(None),
+            Ok((held, attended)) => Ok(Some(Self {
+                attended: usize::try_from(attended).unwrap_or_default(),
+                held: usize::try_from(held).unwrap_or_default(),
+                unit: AttendanceUnit::Event,
+            })),
+            Err(e) => {
+                trc::error!("Failed to count attendance of {user_id:?} in {guild_id:?} due to {e:?}.");
+                Err(RequestError::Internal("Failed to load attendance.".into()))
+            },
+        }
+    }
+}
+
+/// Everything needed to work out streaks and attendance in a guild.
+pub struct History {
+    pub tz: Tz,
+    pub changes: Vec<(DiscordUserId, DateTime<Utc>, BigDecimal)>,
+}
+
+impl History {
+    pub async fn load(ctx: &ExecutionContext<'_>, stat: TrackerStat, guild_id: DiscordGuildId) -> Result<Self, RequestError> {
+        let tz = match db::GuildSettings::load_timezone(&ctx.db_cfg, guild_id).await {
+            Ok(tz) => tz,
+            Err(e) => {
+                trc::error!("Failed to load timezone for {guild_id:?} due to {e:?}.");
+                return Err(RequestError::Internal("Failed to load server settings.".into()));
+            },
+        };
+        let changes = match db::TrackerCount::load_changes(&ctx.db_cfg, stat, guild_id).await {
+            Ok(changes) => changes,
+            Err(e) => {
+                trc::error!("Failed to load changes of {stat:?} for {guild_id:?} due to {e:?}.");
+                return Err(RequestError::Internal("Failed to load history.".into()));
+            },
+        };
+        Ok(Self { tz, changes })
+    }
+
+    /// Monday of the current week in the guild's timezone.
+    pub fn this_week(&self, now: DateTime<Utc>) -> NaiveDate {
+        calendar::start_of_week(now.with_timezone(&self.tz).date_naive())
+    }
+
+    /// The last [`DEFAULT_ATTENDANCE_WEEKS`] weeks, including this one.
+    pub fn default_window(&self, now: DateTime<Utc>) -> UtcRange {
+        let start = self.this_week(now) - Duration::weeks(DEFAULT_ATTENDANCE_WEEKS - 1);
+        UtcRange {
+            start: calendar::start_of_day(self.tz, start),
+            end: now,
+        }
+    }
+
+    pub fn streaks(&self, now: DateTime<Utc>) -> HashMap<DiscordUserId, Streak> {
+        let this_week = self.this_week(now);
+        active_buckets(&self.changes, |t| calendar::start_of_week(t.with_timezone(&self.tz).date_naive()))
+            .into_iter()
+            .map(|(user_id, weeks)| (user_id, streak(&weeks, this_week)))
+            .collect()
+    }
+
+    pub fn attendance(&self, range: UtcRange, user_id: DiscordUserId) -> Attendance {
+        let in_range: Vec<_> = self.changes.iter()
+            .filter(|(_, created, _)| range.contains(*created))
+            .cloned()
+            .collect();
+        attendance(&in_range, user_id, |t| t.with_timezone(&self.tz).date_naive())
+    }
+}
+
+/// The buckets (weeks, days, ...) each member took part in. A bucket only counts if the member's changes in it add
+/// up to more than zero, so a record that was removed again doesn't count.
+fn active_buckets(changes: &[(DiscordUserId, DateTime<Utc>, BigDecimal)], bucket: impl Fn(DateTime<Utc>) -> NaiveDate) -> HashMap<DiscordUserId, BTreeSet<NaiveDate>> {
+    let mut sums: HashMap<(DiscordUserId, NaiveDate), BigDecimal> = HashMap::new();
+    for (user_id, created, total) in changes {
+        *sums.entry((*user_id, bucket(*created))).or_default() += total;
+    }
+
+    let mut active: HashMap<DiscordUserId, BTreeSet<NaiveDate>> = HashMap::new();
+    for ((user_id, date), sum) in sums {
+        if sum.is_positive() {
+            active.entry(user_id).or_default().insert(date);
+        }
+    }
+    active
+}
+
+/// `weeks` are the Mondays of the weeks someone took part in. The current streak isn't broken until a whole week goes
+/// by without taking part, so it carries on from last week while this week is still going.
+fn streak(weeks: &BTreeSet<NaiveDate>, this_week: NaiveDate) -> Streak {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous: Option<NaiveDate> = None;
+    for week in weeks {
+        run = match previous {
+            Some(previous) if *week - previous == Duration::weeks(1) => run + 1,
+            _ => 1,
+        };
+        longest = longest.max(run);
+        previous = Some(*week);
+    }
+
+    let mut week = match weeks.contains(&this_week) {
+        true => this_week,
+        false => this_week - Duration::weeks(1),
+    };
+    let mut current = 0;
+    while weeks.contains(&week) {
+        current += 1;
+        week -= Duration::weeks(1);
+    }
+
+    Streak { current, longest }
+}
+
+/// Events count as held on every day anyone took part, for guilds that don't use roll calls.
+fn attendance(changes: &[(DiscordUserId, DateTime<Utc>, BigDecimal)], user_id: DiscordUserId, day: impl Fn(DateTime<Utc>) -> NaiveDate) -> Attendance {
+    let active = active_buckets(changes, day);
+    Attendance {
+        attended: active.get(&user_id).map(BTreeSet::len).unwrap_or_default(),
+        held: active.values().flatten().collect::<BTreeSet<_>>().len(),
+        unit: AttendanceUnit::EventDay,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+
+    use bigdecimal::BigDecimal;
+    use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+    use serenity::all::UserId;
+
+    use crate::{db::DiscordUserId, locale::Locale};
+
+    use super::{Attendance, AttendanceUnit, Streak, attendance, streak};
+
+    fn d(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn user(id: u64) -> DiscordUserId {
+        UserId::new(id).into()
+    }
+
+    fn at(date: &str) -> DateTime<Utc> {
+        Utc.from_utc_datetime(&d(date).and_hms_opt(20, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn test_streak() {
+        let weeks: BTreeSet<_> = ["2026-05-04", "2026-05-11", "2026-05-18", "2026-06-01", "2026-06-08"].into_iter().map(d).collect();
+        assert_eq!(streak(&weeks, d("2026-06-08")), Streak { current: 2, longest: 3 });
+        // This week isn't over, so last week's streak still stands.
+        assert_eq!(streak(&weeks, d("2026-06-15")), Streak { current: 2, longest: 3 });
+        assert_eq!(streak(&weeks, d("2026-06-22")), Streak { current: 0, longest: 3 });
+        assert_eq!(streak(&BTreeSet::new(), d("2026-06-22")), Streak::default());
+    }
+
+    #[test]
+    fn test_attendance() {
+        let changes = vec![
+            (user(1), at("2026-06-01"), BigDecimal::from(1)),
+            (user(2), at("2026-06-01"), BigDecimal::from(1)),
+            (user(1), at("2026-06-03"), BigDecimal::from(2)),
+            (user(2), at("2026-06-05"), BigDecimal::from(1)),
+            // Recorded by mistake and removed again.
+            (user(1), at("2026-06-05"), BigDecimal::from(1)),
+            (user(1), at("2026-06-05"), BigDecimal::from(-1)),
+        ];
+        let day = |t: DateTime<Utc>| t.date_naive();
+        assert_eq!(attendance(&changes, user(1), day), Attendance { attended: 2, held: 3, unit: AttendanceUnit::EventDay });
+        assert_eq!(attendance(&changes, user(2), day), Attendance { attended: 2, held: 3, unit: AttendanceUnit::EventDay });
+        assert_eq!(attendance(&changes, user(3), day), Attendance { attended: 0, held: 3, unit: AttendanceUnit::EventDay });
+        assert_eq!(attendance(&changes, user(1), day).describe_in(Locale::English), "2 of 3 event days (67%)");
+        assert_eq!(Attendance::default().describe_in(Locale::German), "keine Veranstaltungstage");
+        let events = Attendance { attended: 3, held: 4, unit: AttendanceUnit::Event };
+        assert_eq!(events.describe_in(Locale::English), "3 of 4 events (75%)");
+    }
+}
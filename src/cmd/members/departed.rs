@@ -0,0 +1,48 @@
+use std::borrow::Cow;
+
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::RequestError, db};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, _options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+
+        Ok(Self {
+            guild_id,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let departures = match db::GuildMemberDeparture::load_all(&ctx.db_cfg, self.guild_id).await {
+            Ok(departures) => departures,
+            Err(e) => {
+                trc::error!("Failed to load departed members for {:?} due to {e:?}.", self.guild_id);
+                return Err(RequestError::Internal("Failed to load departed members.".into()));
+            },
+        };
+
+        if departures.is_empty() {
+            return ctx.reply_restricted("Nobody has left the server.".to_owned()).await;
+        }
+
+        let msg: String = std::iter::once(Cow::Borrowed("**Archived until they rejoin:**\n- "))
+            .chain(departures.iter()
+                .map(|departure| Cow::Owned(format!(
+                    "{} left <t:{}:R>",
+                    departure.user_id.inner().mention(),
+                    departure.departed.timestamp(),
+                )))
+                .intersperse(Cow::Borrowed("\n- ")))
+            .collect();
+        ctx.reply_restricted(msg).await
+    }
+}
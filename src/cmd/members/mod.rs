@@ -0,0 +1,2 @@
+pub mod departed;
+pub mod purge;
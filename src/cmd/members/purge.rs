@@ -0,0 +1,65 @@
+use chrono::{Duration, Utc};
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::permissions}, db};
+
+/// How long stats of members who left are kept around in case they come back.
+const DEFAULT_RETENTION_DAYS: i64 = 90;
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+    retention_days: i64,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can purge the stats of members who left.".into()));
+        }
+        let mut retention_days = DEFAULT_RETENTION_DAYS;
+        for opt in options {
+            match opt.name {
+                "older_than_days" => {
+                    let ResolvedValue::Integer(days) = opt.value else {
+                        trc::error!("Bad value for `older_than_days` in `members purge` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `older_than_days` in `members purge`.".into()));
+                    };
+                    retention_days = days;
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `members purge`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `members purge`".into()));
+                },
+            }
+        }
+
+        Ok(Self {
+            guild_id,
+            retention_days,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let cutoff = Utc::now() - Duration::days(self.retention_days);
+        let purged = match db::TrackerCount::purge_archived(&ctx.db_cfg, self.guild_id, cutoff).await {
+            Ok(purged) => purged,
+            Err(e) => {
+                trc::error!("Failed to purge archived stats for {:?} due to {e:?}.", self.guild_id);
+                return Err(RequestError::Internal("Failed to purge archived records.".into()));
+            },
+        };
+
+        if purged == 0 {
+            ctx.reply(format!("No records have been archived for more than {} days.", self.retention_days)).await
+        } else if purged == 1 {
+            ctx.reply(format!("Permanently deleted 1 record archived more than {} days ago.", self.retention_days)).await
+        } else {
+            ctx.reply(format!("Permanently deleted {purged} records archived more than {} days ago.", self.retention_days)).await
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{calendar::{self, Period}, cmd::{RequestError, merit}, db::{self, DiscordUserId}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+    user_id: DiscordUserId,
+    period: Option<Period>,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut user_id = cmd.user.id;
+        let mut period = None;
+        for opt in options {
+            match opt.name {
+                "user" => {
+                    let ResolvedValue::User(u, _) = opt.value else {
+                        trc::error!("Bad value for `user` in `merit check` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `user` in `merit check`.".into()));
+                    };
+                    user_id = u.id;
+                },
+                "period" => {
+                    let ResolvedValue::String(p) = opt.value else {
+                        trc::error!("Bad value for `period` in `merit check` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `period` in `merit check`.".into()));
+                    };
+                    if !calendar::is_all_time(p) {
+                        period = Some(p.parse::<Period>().map_err(|e| RequestError::User(e.to_string().into()))?);
+                    }
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `merit check`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `merit check`".into()));
+                },
+            }
+        }
+
+        Ok(Self {
+            guild_id,
+            user_id: user_id.into(),
+            period,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let (weights, merits) = merit::load(ctx, self.guild_id, self.period).await?;
+        let rank = merits.iter().position(|merit| merit.user_id == self.user_id);
+        let locale = Locale::of(ctx.cmd);
+        let label = self.period.map(|period| period.describe_in(locale)).unwrap_or_else(|| locale.translate("of all time").to_owned());
+
+        let Some(rank) = rank else {
+            let msg = locale.format("{} has no merit {}.", &[&self.user_id.inner().mention(), &label]);
+            return ctx.reply_restricted(msg).await;
+        };
+        let merit = &merits[rank];
+        let mut buffer = locale.format("{} has {} {}, rank {} of {}.\n", &[
+            &merit.user_id.inner().mention(),
+            &merit::format_points_in(locale, &merit.total),
+            &label,
+            &(rank + 1),
+            &merits.len(),
+        ]);
+        for weight in &weights {
+            let Some((_, points)) = merit.by_stat.iter().find(|(stat, _)| *stat == weight.stat) else {
+                continue;
+            };
+            buffer.push_str(format!("- {}: {}\n", weight.stat.display_name_in(locale), merit::format_points_in(locale, points)).as_str());
+        }
+        ctx.reply_restricted(buffer).await
+    }
+}
@@ -0,0 +1,172 @@
+pub mod check;
+pub mod remove_weight;
+pub mod scoreboard;
+pub mod set_weight;
+pub mod weights;
+
+use std::collections::HashMap;
+
+use bigdecimal::{BigDecimal, RoundingMode};
+use chrono::Utc;
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{calendar::Period, cmd::RequestError, db::{self, DiscordGuildId, DiscordUserId, GuildMeritWeight, TrackerStat}, locale::Locale};
+
+/// A member's merit score and what it's made of.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Merit {
+    pub user_id: DiscordUserId,
+    pub total: BigDecimal,
+    /// Merit per weighted stat, in the order of the weights.
+    pub by_stat: Vec<(TrackerStat, BigDecimal)>,
+}
+
+/// Combines everyone's totals using the guild's weights, highest merit first. Stats without a weight don't count.
+pub fn compute(weights: &[GuildMeritWeight], totals: &HashMap<TrackerStat, Vec<(DiscordUserId, BigDecimal)>>) -> Vec<Merit> {
+    let mut merits: HashMap<DiscordUserId, Merit> = HashMap::new();
+    for weight in weights {
+        for (user_id, total) in totals.get(&weight.stat).into_iter().flatten() {
+            let merit = merits.entry(*user_id).or_insert_with(|| Merit {
+                user_id: *user_id,
+                total: BigDecimal::default(),
+                by_stat: vec![],
+            });
+            let points = weight.merit_for(total.clone());
+            merit.total += &points;
+            merit.by_stat.push((weight.stat, points));
+        }
+    }
+
+    let mut merits: Vec<Merit> = merits.into_values().collect();
+    merits.sort_by(|a, b| b.total.cmp(&a.total).then(a.user_id.cmp(&b.user_id)));
+    merits
+}
+
+/// Decimal places kept of weights and shown of merit.
+const SCALE: i64 = 2;
+
+/// Rounds a weight's `points` or `per` to two decimals, without trailing zeros. Discord sends numbers as floats, so
+/// `0.1` would otherwise be kept as the long binary expansion closest to it.
+pub fn round_weight(value: &BigDecimal) -> BigDecimal {
+    value.with_scale_round(SCALE, RoundingMode::HalfEven).normalized()
+}
+
+/// Rounded to two decimals, without trailing zeros.
+pub fn format_points_in(locale: Locale, points: &BigDecimal) -> String {
+    locale.format("{} merit", &[&points.with_scale_round(SCALE, RoundingMode::HalfEven).normalized()])
+}
+
+/// The guild's weights and everyone's merit, over `period` or of all time.
+pub async fn load(ctx: &ExecutionContext<'_>, guild_id: DiscordGuildId, period: Option<Period>) -> Result<(Vec<GuildMeritWeight>, Vec<Merit>), RequestError> {
+    let weights = match db::GuildMeritWeight::load_all(&ctx.db_cfg, guild_id).await {
+        Ok(weights) => weights,
+        Err(e) => {
+            trc::error!("Failed to load merit weights for {guild_id:?} due to {e:?}.");
+            return Err(RequestError::Internal("Failed to load merit weights.".into()));
+        },
+    };
+    if weights.is_empty() {
+        return Err(RequestError::User("No merit weights have been set up! Use `/merit set_weight` to add some.".into()));
+    }
+
+    let range = match period {
+        Some(period) => match db::GuildSettings::load_timezone(&ctx.db_cfg, guild_id).await {
+            Ok(tz) => Some(period.to_utc_range(tz, Utc::now())),
+            Err(e) => {
+                trc::error!("Failed to load timezone for {guild_id:?} due to {e:?}.");
+                return Err(RequestError::Internal("Failed to load server settings.".into()));
+            },
+        },
+        None => None,
+    };
+
+    let mut totals = HashMap::new();
+    for weight in &weights {
+        let stat_totals = match range {
+            Some(range) => db::TrackerCount::load_period_totals(&ctx.db_cfg, weight.stat, guild_id, range).await,
+            None => db::TrackerCount::load_totals(&ctx.db_cfg, weight.stat, guild_id).await,
+        };
+        match stat_totals {
+            Ok(stat_totals) => {
+                totals.insert(weight.stat, stat_totals);
+            },
+            Err(e) => {
+                trc::error!("Failed to load totals of {:?} for {guild_id:?} due to {e:?}.", weight.stat);
+                return Err(RequestError::Internal("Failed to load totals.".into()));
+            },
+        }
+    }
+
+    let merits = compute(&weights, &totals);
+    Ok((weights, merits))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, str::FromStr};
+
+    use bigdecimal::BigDecimal;
+    use chrono::Utc;
+    use serenity::all::{GuildId, UserId};
+
+    use crate::{db::{DiscordUserId, GuildMeritWeight, TrackerStat}, locale::Locale};
+
+    use bigdecimal::FromPrimitive;
+
+    use super::{compute, format_points_in, round_weight};
+
+    fn user(id: u64) -> DiscordUserId {
+        UserId::new(id).into()
+    }
+
+    fn weight(stat: TrackerStat, points: i64, per: i64) -> GuildMeritWeight {
+        GuildMeritWeight {
+            guild_id: GuildId::new(1).into(),
+            stat,
+            created: Utc::now(),
+            updated: Utc::now(),
+            points: points.into(),
+            per: per.into(),
+        }
+    }
+
+    #[test]
+    fn test_compute() {
+        let weights = vec![
+            weight(TrackerStat::EventParticipation, 1, 1),
+            weight(TrackerStat::NavyVictory, 10, 1),
+            weight(TrackerStat::IndustryAuec, 1, 100_000),
+        ];
+        let totals = HashMap::from([
+            (TrackerStat::EventParticipation, vec![(user(1), 5.into()), (user(2), 12.into())]),
+            // Stored in fourths, so 6 is one and a half victories.
+            (TrackerStat::NavyVictory, vec![(user(1), 6.into())]),
+            (TrackerStat::IndustryAuec, vec![(user(2), 250_000.into())]),
+            // Not weighted, so it doesn't count.
+            (TrackerStat::GroundKill, vec![(user(3), 1000.into())]),
+        ]);
+
+        let merits: Vec<_> = compute(&weights, &totals).into_iter().map(|merit| (merit.user_id, merit.total)).collect();
+        assert_eq!(merits, vec![
+            (user(1), BigDecimal::from(20)),
+            (user(2), BigDecimal::from_str("14.5").unwrap()),
+        ]);
+    }
+
+    #[test]
+    fn test_format_points() {
+        assert_eq!(format_points_in(Locale::English, &BigDecimal::from_str("14.50").unwrap()), "14.5 merit");
+        assert_eq!(format_points_in(Locale::English, &BigDecimal::from_str("1.23456").unwrap()), "1.23 merit");
+        assert_eq!(format_points_in(Locale::English, &BigDecimal::from(20)), "20 merit");
+    }
+
+    #[test]
+    fn test_round_weight() {
+        let points = BigDecimal::from_f64(0.1).unwrap();
+        assert_ne!(points, BigDecimal::from_str("0.1").unwrap());
+        assert_eq!(round_weight(&points).to_string(), "0.1");
+        assert_eq!(round_weight(&BigDecimal::from_f64(100000.0).unwrap()).to_string(), "100000");
+    }
+}
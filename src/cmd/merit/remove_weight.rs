@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::permissions}, db::{self, TrackerStat}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+    stat: TrackerStat,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can change merit weights.".into()));
+        }
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut stat = None;
+        for opt in options {
+            match opt.name {
+                "stat" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `stat` in `merit remove_weight` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `stat` in `merit remove_weight`.".into()));
+                    };
+                    let Ok(s) = TrackerStat::from_str(s) else {
+                        trc::error!("Unknown stat {s:?} in `merit remove_weight`");
+                        return Err(RequestError::Internal("Unknown stat in `merit remove_weight`.".into()));
+                    };
+                    stat = Some(s);
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `merit remove_weight`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `merit remove_weight`".into()));
+                },
+            }
+        }
+
+        let Some(stat) = stat else {
+            trc::error!("Missing value for `stat` in `merit remove_weight`");
+            return Err(RequestError::Internal("Missing value for `stat` in `merit remove_weight`.".into()));
+        };
+
+        Ok(Self {
+            guild_id,
+            stat,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let Self { guild_id, stat } = self;
+        match db::GuildMeritWeight::remove(&ctx.db_cfg, guild_id, stat).await {
+            Ok(0) => Err(RequestError::User(locale.format("{} doesn't count towards merit.", &[&stat.display_name_in(locale)]).into())),
+            Ok(_) => {
+                let msg = locale.format("{} no longer counts towards merit.", &[&stat.display_name_in(locale)]);
+                ctx.reply_restricted(msg).await
+            },
+            Err(e) => {
+                trc::error!("Failed to remove merit weight of {stat:?} for {guild_id:?} due to {e:?}.");
+                Err(RequestError::Internal("Failed to remove merit weight.".into()))
+            },
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{calendar::{self, Period}, cmd::{RequestError, lib::pages, merit}, db, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+    limit: usize,
+    period: Option<Period>,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut limit = 10;
+        let mut period = None;
+        for opt in options {
+            match opt.name {
+                "limit" => {
+                    let ResolvedValue::Integer(lim) = opt.value else {
+                        trc::error!("Bad value for `limit` in `merit scoreboard` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `limit` in `merit scoreboard`.".into()));
+                    };
+                    limit = usize::try_from(lim).unwrap_or_default();
+                },
+                "period" => {
+                    let ResolvedValue::String(p) = opt.value else {
+                        trc::error!("Bad value for `period` in `merit scoreboard` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `period` in `merit scoreboard`.".into()));
+                    };
+                    if !calendar::is_all_time(p) {
+                        period = Some(p.parse::<Period>().map_err(|e| RequestError::User(e.to_string().into()))?);
+                    }
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `merit scoreboard`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `merit scoreboard`".into()));
+                },
+            }
+        }
+
+        Ok(Self {
+            guild_id,
+            limit,
+            period,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let (_, merits) = merit::load(ctx, self.guild_id, self.period).await?;
+
+        let locale = Locale::of(ctx.cmd);
+        let label = self.period.map(|period| period.describe_in(locale)).unwrap_or_else(|| locale.translate("all time").to_owned());
+        let header = locale.format("**Merit scoreboard ({}):**\n", &[&label]);
+        let rows = merits.into_iter().take(self.limit).enumerate().map(|(index, merit)| format!(
+            "\t{}) {}: {}\n",
+            index + 1,
+            merit.user_id.inner().mention(),
+            merit::format_points_in(locale, &merit.total),
+        ));
+        pages::reply_paged(ctx, pages::text_pages(pages::split_pages(header.as_str(), rows, pages::MESSAGE_MAX_LEN))).await
+    }
+}
@@ -0,0 +1,97 @@
+use std::str::FromStr;
+
+use bigdecimal::{BigDecimal, FromPrimitive, Signed};
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::permissions, merit}, db::{self, TrackerStat}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+    stat: TrackerStat,
+    points: BigDecimal,
+    per: BigDecimal,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can change merit weights.".into()));
+        }
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut stat = None;
+        let mut points = None;
+        let mut per = BigDecimal::from(1);
+        for opt in options {
+            match opt.name {
+                "stat" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `stat` in `merit set_weight` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `stat` in `merit set_weight`.".into()));
+                    };
+                    let Ok(s) = TrackerStat::from_str(s) else {
+                        trc::error!("Unknown stat {s:?} in `merit set_weight`");
+                        return Err(RequestError::Internal("Unknown stat in `merit set_weight`.".into()));
+                    };
+                    stat = Some(s);
+                },
+                "points" | "per" => {
+                    let ResolvedValue::Number(n) = opt.value else {
+                        trc::error!("Bad value for `{}` in `merit set_weight` {:?}", opt.name, opt);
+                        return Err(RequestError::Internal(format!("Bad value for `{}` in `merit set_weight`.", opt.name).into()));
+                    };
+                    let Some(n) = BigDecimal::from_f64(n).map(|n| merit::round_weight(&n)) else {
+                        trc::error!("Bad value for `{}` in `merit set_weight` {:?}", opt.name, opt);
+                        return Err(RequestError::Internal(format!("Bad value for `{}` in `merit set_weight`.", opt.name).into()));
+                    };
+                    if opt.name == "points" {
+                        if n.is_negative() {
+                            return Err(RequestError::User("`points` can't be negative.".into()));
+                        }
+                        points = Some(n);
+                    } else {
+                        if !n.is_positive() {
+                            return Err(RequestError::User("`per` has to be at least 0.01.".into()));
+                        }
+                        per = n;
+                    }
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `merit set_weight`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `merit set_weight`".into()));
+                },
+            }
+        }
+
+        let (Some(stat), Some(points)) = (stat, points) else {
+            trc::error!("Missing value for `stat` or `points` in `merit set_weight`");
+            return Err(RequestError::Internal("Missing value in `merit set_weight`.".into()));
+        };
+
+        Ok(Self {
+            guild_id,
+            stat,
+            points,
+            per,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let Self { guild_id, stat, points, per } = self;
+        if let Err(e) = db::GuildMeritWeight::set(&ctx.db_cfg, guild_id, stat, points.clone(), per.clone()).await {
+            trc::error!("Failed to set merit weight of {stat:?} for {guild_id:?} due to {e:?}.");
+            return Err(RequestError::Internal("Failed to set merit weight.".into()));
+        }
+
+        let locale = Locale::of(ctx.cmd);
+        let msg = locale.format("{} now earns {} merit per {}.", &[
+            &stat.display_name_in(locale),
+            &merit::round_weight(&points),
+            &stat.format_count_in(locale, stat.db_value(per)),
+        ]);
+        ctx.reply_restricted(msg).await
+    }
+}
@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use serenity::all::{CommandInteraction, ResolvedOption};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, merit}, db, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, _options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+
+        Ok(Self {
+            guild_id,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let weights = match db::GuildMeritWeight::load_all(&ctx.db_cfg, self.guild_id).await {
+            Ok(weights) => weights,
+            Err(e) => {
+                trc::error!("Failed to load merit weights for {:?} due to {e:?}.", self.guild_id);
+                return Err(RequestError::Internal("Failed to load merit weights.".into()));
+            },
+        };
+
+        let locale = Locale::of(ctx.cmd);
+        if weights.is_empty() {
+            return ctx.reply_restricted(locale.translate("No merit weights have been set up! Use `/merit set_weight` to add some.").to_owned()).await;
+        }
+
+        let msg: String = std::iter::once(Cow::Borrowed("- "))
+            .chain(weights.iter()
+                .map(|weight| Cow::Owned(locale.format("{}: {} merit per {}", &[
+                    &weight.stat.display_name_in(locale),
+                    &merit::round_weight(&weight.points),
+                    &weight.stat.format_count_in(locale, weight.stat.db_value(merit::round_weight(&weight.per))),
+                ])))
+                .intersperse(Cow::Borrowed("\n- ")))
+            .collect();
+        ctx.reply_restricted(msg).await
+    }
+}
@@ -1,17 +1,31 @@
 // Things used for implementing most things.
 pub mod lib;
 
+pub mod autocomplete;
+pub mod components;
+
+pub mod branch;
+pub mod event;
+pub mod group;
+pub mod members;
+pub mod merit;
 pub mod monthly_goal;
+pub mod profit_run;
+pub mod season;
+pub mod settings;
+pub mod stats;
 
 use std::{borrow::Cow, str::FromStr};
 
 use tracing as trc;
 
-use serenity::all::{CommandInteraction, CommandType, ResolvedOption, ResolvedValue};
+use serenity::all::{CommandInteraction, CommandType, Permissions, ResolvedOption, ResolvedValue};
 use strum::{EnumCount, EnumDiscriminants, EnumIter, IntoEnumIterator};
 
 use azel::{cmd::{CommandTreeTop, CommandTreeIntermediate, DiscordCommandArgs, DiscordCommandDescriptor, RawCommandOptionEntry, RequestError}, discord::ExecutionContext};
 
+use crate::locale::Locale;
+
 #[derive(Debug)]
 #[derive(EnumDiscriminants)]
 #[strum_discriminants(derive(Hash, EnumCount, EnumIter))]
@@ -22,6 +36,9 @@ pub enum RequestArgs<'a> {
     EventParticipantRecord(lib::generic_tracker::record::Request),
     EventParticipantRemove(lib::generic_tracker::delete::Request),
     EventParticipantCheck(lib::generic_tracker::check::Request),
+    EventParticipantStreaks(lib::generic_tracker::streak_scoreboard::Request),
+    EventCreate(event::create::Request<'a>),
+    EventRollCall(event::roll_call::Request),
 
     IndustryMiningRockRecord,
 
@@ -32,6 +49,11 @@ pub enum RequestArgs<'a> {
     IndustryProfitScoreboard(lib::generic_tracker::scoreboard::Request<'a>),
     IndustryProfitClearUnknown(lib::generic_tracker::clear::Request),
 
+    IndustryRunCreate(profit_run::create::Request<'a>),
+    IndustryRunShow(profit_run::show::Request),
+    IndustryRunOutstanding(profit_run::outstanding::Request),
+    IndustryRunPaid(profit_run::paid::Request),
+
     // Dummy variants needed for the request kind enum, these are
     // subsumed into the ones below.
     #[allow(dead_code)]
@@ -45,6 +67,7 @@ pub enum RequestArgs<'a> {
     NavyVictoryCheck(lib::generic_tracker::check::Request),
     NavyVictoryScoreboard(lib::generic_tracker::scoreboard::Request<'a>),
     NavyVictoryClearUnknown(lib::generic_tracker::clear::Request),
+    NavyVictoryShare(lib::generic_tracker::share::Request),
 
     NavyTackleAssistRecord(lib::generic_tracker::record::Request),
     NavyTackleAssistDelete(lib::generic_tracker::delete::Request),
@@ -63,7 +86,16 @@ pub enum RequestArgs<'a> {
     MonthlyGoalCheck(monthly_goal::check::Request<'a>),
     MonthlyGoalSet(monthly_goal::set::Request<'a>),
     MonthlyGoalClear(monthly_goal::clear::Request<'a>),
+    MonthlyGoalEdit(monthly_goal::edit::Request<'a>),
+    MonthlyGoalBoardPublish(monthly_goal::board_publish::Request<'a>),
+    MonthlyGoalBoardRemove(monthly_goal::board_remove::Request<'a>),
     MonthlyGoalAdminList(monthly_goal::admin_list::Request<'a>),
+    MonthlyGoalShare(monthly_goal::share::Request<'a>),
+    MonthlyGoalHistory(monthly_goal::history::Request<'a>),
+    MonthlyGoalRestore(monthly_goal::restore::Request<'a>),
+    MonthlyGoalItemAdd(monthly_goal::item_add::Request<'a>),
+    MonthlyGoalItemComplete(monthly_goal::item_status::Request<'a>),
+    MonthlyGoalItemReopen(monthly_goal::item_status::Request<'a>),
 
     MonthlyGoalProgressRecord(lib::generic_tracker::record::Request),
     MonthlyGoalProgressDelete(lib::generic_tracker::delete::Request),
@@ -71,6 +103,40 @@ pub enum RequestArgs<'a> {
     MonthlyGoalProgressCheck(lib::generic_tracker::check::Request),
     MonthlyGoalProgressScoreboard(lib::generic_tracker::scoreboard::Request<'a>),
     MonthlyGoalProgressClearUnknown(lib::generic_tracker::clear::Request),
+
+    BranchSet(branch::set::Request<'a>),
+    BranchRemove(branch::remove::Request<'a>),
+    BranchList(branch::list::Request),
+
+    SettingsTimezone(settings::timezone::Request),
+    SettingsShow(settings::show::Request),
+    SettingsOfficerChannel(settings::officer_channel::Request),
+    SettingsBarStyle(settings::bar_style::Request),
+    SettingsApiToken(settings::api_token::Request),
+
+    MembersDeparted(members::departed::Request),
+    MembersPurge(members::purge::Request),
+
+    StatsTransfer(stats::transfer::Request),
+    StatsLimitSet(stats::limit_set::Request),
+    StatsLimitClear(stats::limit_clear::Request),
+    StatsLimits(stats::limits::Request),
+
+    SeasonCreate(season::create::Request<'a>),
+    SeasonList(season::list::Request),
+    SeasonRemove(season::remove::Request<'a>),
+    SeasonResults(season::results::Request<'a>),
+
+    GroupAdd(group::add::Request<'a>),
+    GroupRemove(group::remove::Request<'a>),
+    GroupList(group::list::Request),
+    GroupScoreboard(group::scoreboard::Request),
+
+    MeritSetWeight(merit::set_weight::Request),
+    MeritRemoveWeight(merit::remove_weight::Request),
+    MeritWeights(merit::weights::Request),
+    MeritScoreboard(merit::scoreboard::Request),
+    MeritCheck(merit::check::Request),
 }
 
 impl DiscordCommandDescriptor for RequestKind {
@@ -91,6 +157,15 @@ impl DiscordCommandDescriptor for RequestKind {
             RequestKind::EventParticipantCheck => {
                 "check"
             },
+            RequestKind::EventParticipantStreaks => {
+                "streaks"
+            },
+            RequestKind::EventCreate => {
+                "create"
+            },
+            RequestKind::EventRollCall => {
+                "roll_call"
+            },
 
             RequestKind::IndustryMiningRockRecord => {
                 "record"
@@ -115,6 +190,19 @@ impl DiscordCommandDescriptor for RequestKind {
                 "clear_unknown"
             },
 
+            RequestKind::IndustryRunCreate => {
+                "create"
+            },
+            RequestKind::IndustryRunShow => {
+                "show"
+            },
+            RequestKind::IndustryRunOutstanding => {
+                "outstanding"
+            },
+            RequestKind::IndustryRunPaid => {
+                "paid"
+            },
+
             RequestKind::NavyVictoryRecordOneUser => {
                 "Record One Naval Victory"
             },
@@ -140,6 +228,9 @@ impl DiscordCommandDescriptor for RequestKind {
             RequestKind::NavyVictoryClearUnknown => {
                 "clear_unknown"
             },
+            RequestKind::NavyVictoryShare => {
+                "share"
+            },
 
             RequestKind::NavyTackleAssistRecord => {
                 "record"
@@ -188,9 +279,37 @@ impl DiscordCommandDescriptor for RequestKind {
             RequestKind::MonthlyGoalClear => {
                 "clear"
             },
+            RequestKind::MonthlyGoalEdit => {
+                "edit"
+            },
+            RequestKind::MonthlyGoalBoardPublish => {
+                "board_publish"
+            },
+            RequestKind::MonthlyGoalBoardRemove => {
+                "board_remove"
+            },
             RequestKind::MonthlyGoalAdminList => {
                 "admin_list"
             },
+            RequestKind::MonthlyGoalShare => {
+                "share"
+            },
+            RequestKind::MonthlyGoalHistory => {
+                "history"
+            },
+            RequestKind::MonthlyGoalRestore => {
+                "restore"
+            },
+
+            RequestKind::MonthlyGoalItemAdd => {
+                "add"
+            },
+            RequestKind::MonthlyGoalItemComplete => {
+                "complete"
+            },
+            RequestKind::MonthlyGoalItemReopen => {
+                "reopen"
+            },
 
             RequestKind::MonthlyGoalProgressRecord => {
                 "record"
@@ -210,6 +329,94 @@ impl DiscordCommandDescriptor for RequestKind {
             RequestKind::MonthlyGoalProgressClearUnknown => {
                 "clear_unknown"
             },
+
+            RequestKind::BranchSet => {
+                "set"
+            },
+            RequestKind::BranchRemove => {
+                "remove"
+            },
+            RequestKind::BranchList => {
+                "list"
+            },
+
+            RequestKind::SettingsTimezone => {
+                "timezone"
+            },
+            RequestKind::SettingsShow => {
+                "show"
+            },
+            RequestKind::SettingsOfficerChannel => {
+                "officer_channel"
+            },
+            RequestKind::SettingsBarStyle => {
+                "bar_style"
+            },
+            RequestKind::SettingsApiToken => {
+                "api_token"
+            },
+
+            RequestKind::MembersDeparted => {
+                "departed"
+            },
+            RequestKind::MembersPurge => {
+                "purge"
+            },
+
+            RequestKind::StatsTransfer => {
+                "transfer"
+            },
+            RequestKind::StatsLimitSet => {
+                "limit_set"
+            },
+            RequestKind::StatsLimitClear => {
+                "limit_clear"
+            },
+            RequestKind::StatsLimits => {
+                "limits"
+            },
+
+            RequestKind::SeasonCreate => {
+                "create"
+            },
+            RequestKind::SeasonList => {
+                "list"
+            },
+            RequestKind::SeasonRemove => {
+                "remove"
+            },
+            RequestKind::SeasonResults => {
+                "results"
+            },
+
+            RequestKind::GroupAdd => {
+                "add"
+            },
+            RequestKind::GroupRemove => {
+                "remove"
+            },
+            RequestKind::GroupList => {
+                "list"
+            },
+            RequestKind::GroupScoreboard => {
+                "scoreboard"
+            },
+
+            RequestKind::MeritSetWeight => {
+                "set_weight"
+            },
+            RequestKind::MeritRemoveWeight => {
+                "remove_weight"
+            },
+            RequestKind::MeritWeights => {
+                "weights"
+            },
+            RequestKind::MeritScoreboard => {
+                "scoreboard"
+            },
+            RequestKind::MeritCheck => {
+                "check"
+            },
         }.into()
     }
 
@@ -228,6 +435,15 @@ impl DiscordCommandDescriptor for RequestKind {
             RequestKind::EventParticipantCheck => {
                 "Check how many events a participant has been part of"
             },
+            RequestKind::EventParticipantStreaks => {
+                "Rank members by how many weeks in a row they've taken part in events"
+            },
+            RequestKind::EventCreate => {
+                "Schedule an event and post a card members can RSVP on"
+            },
+            RequestKind::EventRollCall => {
+                "Confirm who attended an event and credit their participation"
+            },
 
             RequestKind::IndustryMiningRockRecord => {
                 "Records rocks"
@@ -251,6 +467,19 @@ impl DiscordCommandDescriptor for RequestKind {
             RequestKind::IndustryProfitClearUnknown => {
                 "Removes old unknown users from the scoreboard"
             },
+
+            RequestKind::IndustryRunCreate => {
+                "Record a crewed run, split its profit and credit everyone's share"
+            },
+            RequestKind::IndustryRunShow => {
+                "Show a run's profit split and which shares have been paid"
+            },
+            RequestKind::IndustryRunOutstanding => {
+                "List crew shares that haven't been paid out yet"
+            },
+            RequestKind::IndustryRunPaid => {
+                "Mark a crew member's share of a run as paid"
+            },
             RequestKind::MonthlyGoalProgressRecord => {
                 "Record saved personnel"
             },
@@ -295,6 +524,9 @@ impl DiscordCommandDescriptor for RequestKind {
             RequestKind::NavyVictoryClearUnknown => {
                 "Removes old unknown users from the scoreboard"
             },
+            RequestKind::NavyVictoryShare => {
+                "Split victories between a crew, optionally weighted by role"
+            },
 
             RequestKind::NavyTackleAssistRecord => {
                 "Records a certain number of naval tackle assists for a user."
@@ -343,9 +575,124 @@ impl DiscordCommandDescriptor for RequestKind {
             RequestKind::MonthlyGoalClear => {
                 "Clear all monthly goals"
             },
+            RequestKind::MonthlyGoalEdit => {
+                "Edit a goal's header and body in a form, with a preview before saving"
+            },
+            RequestKind::MonthlyGoalBoardPublish => {
+                "Post a goal board to a channel that updates itself whenever goals change"
+            },
+            RequestKind::MonthlyGoalBoardRemove => {
+                "Stop updating a goal board and delete its message"
+            },
             RequestKind::MonthlyGoalAdminList => {
                 "List out goals including shortnames"
             },
+            RequestKind::MonthlyGoalShare => {
+                "Post a goal here with a button members can use to pledge their help"
+            },
+            RequestKind::MonthlyGoalHistory => {
+                "Show how a goal's text and progress changed over time"
+            },
+            RequestKind::MonthlyGoalRestore => {
+                "Put a goal back the way it was as of an earlier revision"
+            },
+            RequestKind::MonthlyGoalItemAdd => {
+                "Add an item to a goal's checklist, which then sets the goal's progress"
+            },
+            RequestKind::MonthlyGoalItemComplete => {
+                "Tick off an item of a goal's checklist"
+            },
+            RequestKind::MonthlyGoalItemReopen => {
+                "Mark a completed checklist item as not done after all"
+            },
+
+            RequestKind::BranchSet => {
+                "Create or update a branch of the org"
+            },
+            RequestKind::BranchRemove => {
+                "Remove a branch of the org"
+            },
+            RequestKind::BranchList => {
+                "List the branches of the org"
+            },
+
+            RequestKind::SettingsTimezone => {
+                "Set the timezone used for weeks and months"
+            },
+            RequestKind::SettingsShow => {
+                "Show this server's settings"
+            },
+            RequestKind::SettingsOfficerChannel => {
+                "Set where records over a soft limit get flagged"
+            },
+            RequestKind::SettingsBarStyle => {
+                "Set how progress bars are drawn, e.g. without colour codes for mobile"
+            },
+            RequestKind::SettingsApiToken => {
+                "Create a new token for the read-only HTTP API, replacing any old one"
+            },
+
+            RequestKind::MembersDeparted => {
+                "List members who left and whose stats are archived"
+            },
+            RequestKind::MembersPurge => {
+                "Permanently delete stats of members who left a while ago"
+            },
+
+            RequestKind::StatsTransfer => {
+                "Move stats from one account to another, e.g. after a member switches accounts"
+            },
+            RequestKind::StatsLimitSet => {
+                "Limit how much of a stat can be recorded, replacing its current limits"
+            },
+            RequestKind::StatsLimitClear => {
+                "Remove all limits on a stat"
+            },
+            RequestKind::StatsLimits => {
+                "List the limits on recording stats"
+            },
+
+            RequestKind::SeasonCreate => {
+                "Start a season, scoreboards count from zero while it runs"
+            },
+            RequestKind::SeasonList => {
+                "List this server's seasons"
+            },
+            RequestKind::SeasonRemove => {
+                "Remove a season that hasn't finished yet"
+            },
+            RequestKind::SeasonResults => {
+                "Show a finished season's hall of fame"
+            },
+
+            RequestKind::GroupAdd => {
+                "Rank everyone with a role together, e.g. a wing or squad"
+            },
+            RequestKind::GroupRemove => {
+                "Stop ranking a role as a group"
+            },
+            RequestKind::GroupList => {
+                "List the roles ranked as groups"
+            },
+            RequestKind::GroupScoreboard => {
+                "Rank groups by the combined stats of their members"
+            },
+
+            RequestKind::MeritSetWeight => {
+                "Set how much a stat counts towards merit"
+            },
+            RequestKind::MeritRemoveWeight => {
+                "Stop a stat from counting towards merit"
+            },
+            RequestKind::MeritWeights => {
+                "List how much each stat counts towards merit"
+            },
+            RequestKind::MeritScoreboard => {
+                "Rank members by merit"
+            },
+            RequestKind::MeritCheck => {
+                "Check a member's merit and what it's made of"
+            },
         }.into()
     }
 
@@ -400,136 +747,137 @@ impl DiscordCommandDescriptor for RequestKind {
                         description: "Person being checked. Leaving this out means that you're checking your own participation.",
                         required: false,
                     },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count records from this period, e.g. `this_month` or `2026-03-01..2026-03-15`.",
+                        required: false,
+                    },
                 ]
             },
-
-            RequestKind::IndustryMiningRockRecord => {
-                vec![]
-            },
-
-            RequestKind::IndustryProfitRecord => {
+            RequestKind::EventParticipantStreaks => {
                 vec![
-                    RawCommandOptionEntry::Integer {
-                        name: "auec",
-                        description: "Number of alpha UEC. Defaults to 1000.",
+                    RawCommandOptionEntry::StringSelect {
+                        name: "rank_by",
+                        description: "Rank by the streak members have going now, or their longest ever. Defaults to current.",
                         required: false,
+                        choices: vec![
+                            ("Current streak", "current"),
+                            ("Longest streak", "longest"),
+                        ],
                     },
-                    RawCommandOptionEntry::User {
-                        name: "user",
-                        description: "Person being recorded for. Leaving this out means that you're recording your own profits.",
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "limit",
+                        description: "How many members to show, defaults to 10",
                         required: false,
+                        max: 50,
+                        min: 1,
                     },
                 ]
             },
-            RequestKind::IndustryProfitDelete => {
+            RequestKind::EventCreate => {
                 vec![
-                    RawCommandOptionEntry::Integer {
-                        name: "total",
-                        description: "Number of alpha UEC.",
-                        required: false,
+                    RawCommandOptionEntry::String {
+                        name: "name",
+                        description: "What the event is called",
+                        required: true,
                     },
-                    RawCommandOptionEntry::User {
-                        name: "user",
-                        description: "Person being recorded for. Leaving this out means that you're recording your own profits.",
+                    RawCommandOptionEntry::String {
+                        name: "starts",
+                        description: "When it starts, as YYYY-MM-DD HH:MM in the server's timezone",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::String {
+                        name: "description",
+                        description: "What to bring, where to meet, and so on",
                         required: false,
                     },
                 ]
             },
-            RequestKind::IndustryProfitBoast => {
-                vec![]
-            },
-            RequestKind::IndustryProfitCheck => {
+            RequestKind::EventRollCall => {
                 vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "event",
+                        description: "The event to run the roll call for",
+                        required: true,
+                    },
                     RawCommandOptionEntry::User {
-                        name: "user",
-                        description: "Person to get victories for. Defaults to self. Quieter than boasting.",
+                        name: "extra1",
+                        description: "Someone who attended without RSVPing",
                         required: false,
                     },
-                ]
-            },
-            RequestKind::IndustryProfitScoreboard => {
-                vec![
-                    RawCommandOptionEntry::Integer {
-                        name: "limit",
-                        description: "Maximum entries to return. Max of 20. Defaults to 10.",
+                    RawCommandOptionEntry::User {
+                        name: "extra2",
+                        description: "Someone who attended without RSVPing",
                         required: false,
                     },
-                    RawCommandOptionEntry::StringSelect {
-                        name: "at",
-                        description: "What to orient the scoreboard on.",
+                    RawCommandOptionEntry::User {
+                        name: "extra3",
+                        description: "Someone who attended without RSVPing",
                         required: false,
-                        choices: vec![
-                            ("Me", "me"),
-                            ("Bottom", "bottom"),
-                            ("Top (default)", "top"),
-                            ("Someone", "someone"),
-                            ("Rank", "rank"),
-                        ],
                     },
                     RawCommandOptionEntry::User {
-                        name: "someone",
-                        description: "Should only be provided if \"at\" is set to \"someone\".",
+                        name: "extra4",
+                        description: "Someone who attended without RSVPing",
                         required: false,
                     },
-                    RawCommandOptionEntry::Integer {
-                        name: "rank",
-                        description: "The integer rank to start the scoreboard at. Mutually exclusive with \"someone\"",
+                    RawCommandOptionEntry::User {
+                        name: "extra5",
+                        description: "Someone who attended without RSVPing",
                         required: false,
                     },
                 ]
             },
-            RequestKind::IndustryProfitClearUnknown => {
-                vec![]
-            },
 
-            RequestKind::NavyVictoryRecordOneUser => {
-                vec![]
-            },
-            RequestKind::NavyVictoryCheckUser => {
+            RequestKind::IndustryMiningRockRecord => {
                 vec![]
             },
 
-            RequestKind::NavyVictoryRecord => {
+            RequestKind::IndustryProfitRecord => {
                 vec![
-                    RawCommandOptionEntry::Number {
-                        name: "total",
-                        description: "Number of victories. Only accepts values in intervals of 0.25. Defaults to 1.",
+                    RawCommandOptionEntry::Integer {
+                        name: "auec",
+                        description: "Number of alpha UEC. Defaults to 1000.",
                         required: false,
                     },
                     RawCommandOptionEntry::User {
                         name: "user",
-                        description: "Person being recorded for. Leaving this out means that you're recording your own victories.",
+                        description: "Person being recorded for. Leaving this out means that you're recording your own profits.",
                         required: false,
                     },
                 ]
             },
-            RequestKind::NavyVictoryDelete => {
+            RequestKind::IndustryProfitDelete => {
                 vec![
-                    RawCommandOptionEntry::Number {
+                    RawCommandOptionEntry::Integer {
                         name: "total",
-                        description: "Number of victories. Only accepts values in intervals of 0.25. Defaults to 1.",
+                        description: "Number of alpha UEC.",
                         required: false,
                     },
                     RawCommandOptionEntry::User {
                         name: "user",
-                        description: "Person being recorded for. Leaving this out means that you're recording your own victories.",
+                        description: "Person being recorded for. Leaving this out means that you're recording your own profits.",
                         required: false,
                     },
                 ]
             },
-            RequestKind::NavyVictoryBoast => {
+            RequestKind::IndustryProfitBoast => {
                 vec![]
             },
-            RequestKind::NavyVictoryCheck => {
+            RequestKind::IndustryProfitCheck => {
                 vec![
                     RawCommandOptionEntry::User {
                         name: "user",
                         description: "Person to get victories for. Defaults to self. Quieter than boasting.",
                         required: false,
                     },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count records from this period, e.g. `this_month` or `2026-03-01..2026-03-15`.",
+                        required: false,
+                    },
                 ]
             },
-            RequestKind::NavyVictoryScoreboard => {
+            RequestKind::IndustryProfitScoreboard => {
                 vec![
                     RawCommandOptionEntry::Integer {
                         name: "limit",
@@ -558,53 +906,212 @@ impl DiscordCommandDescriptor for RequestKind {
                         description: "The integer rank to start the scoreboard at. Mutually exclusive with \"someone\"",
                         required: false,
                     },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count this period, e.g. `this_month`. Defaults to the running season, or `all_time`.",
+                        required: false,
+                    },
                 ]
             },
-            RequestKind::NavyVictoryClearUnknown => {
+            RequestKind::IndustryProfitClearUnknown => {
                 vec![]
             },
 
-            RequestKind::NavyTackleAssistRecord => {
+            RequestKind::IndustryRunCreate => {
                 vec![
+                    RawCommandOptionEntry::String {
+                        name: "name",
+                        description: "What the run was, e.g. `Hull-C to ARC-L1`",
+                        required: true,
+                    },
                     RawCommandOptionEntry::Integer {
-                        name: "total",
-                        description: "Number of tackle assists. Defaults to 1.",
+                        name: "gross",
+                        description: "Total revenue of the run in aUEC",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::Integer {
+                        name: "costs",
+                        description: "Costs of the run in aUEC, taken off before splitting. Defaults to 0.",
                         required: false,
                     },
                     RawCommandOptionEntry::User {
-                        name: "user",
-                        description: "Person being recorded for. Leaving this out means that you're recording your own tackle assists.",
+                        name: "owner",
+                        description: "Who funded the run and owes the crew. Defaults to you.",
                         required: false,
                     },
-                ]
-            },
-            RequestKind::NavyTackleAssistDelete => {
-                vec![
-                    RawCommandOptionEntry::Integer {
-                        name: "total",
-                        description: "Number of tackle assists. Defaults to 1.",
+                    RawCommandOptionEntry::User {
+                        name: "crew1",
+                        description: "Crew member getting a share",
                         required: false,
                     },
                     RawCommandOptionEntry::User {
-                        name: "user",
-                        description: "Person being recorded for. Leaving this out means that you're recording your own tackle assists.",
+                        name: "crew2",
+                        description: "Crew member getting a share",
                         required: false,
                     },
-                ]
-            },
-            RequestKind::NavyTackleAssistBoast => {
-                vec![]
-            },
-            RequestKind::NavyTackleAssistCheck => {
-                vec![
                     RawCommandOptionEntry::User {
-                        name: "user",
-                        description: "Person to get tackle assists for. Defaults to self. Quieter than boasting.",
+                        name: "crew3",
+                        description: "Crew member getting a share",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "crew4",
+                        description: "Crew member getting a share",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "crew5",
+                        description: "Crew member getting a share",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "crew6",
+                        description: "Crew member getting a share",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "crew7",
+                        description: "Crew member getting a share",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "crew8",
+                        description: "Crew member getting a share",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "share1",
+                        description: "Percentage of the net profit going to crew1, e.g. 25",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "share2",
+                        description: "Percentage of the net profit going to crew2, e.g. 25",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "share3",
+                        description: "Percentage of the net profit going to crew3, e.g. 25",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "share4",
+                        description: "Percentage of the net profit going to crew4, e.g. 25",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "share5",
+                        description: "Percentage of the net profit going to crew5, e.g. 25",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "share6",
+                        description: "Percentage of the net profit going to crew6, e.g. 25",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "share7",
+                        description: "Percentage of the net profit going to crew7, e.g. 25",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "share8",
+                        description: "Percentage of the net profit going to crew8, e.g. 25",
                         required: false,
                     },
                 ]
             },
-            RequestKind::NavyTackleAssistScoreboard => {
+            RequestKind::IndustryRunShow => {
+                vec![
+                    RawCommandOptionEntry::Integer {
+                        name: "run",
+                        description: "Number of the run",
+                        required: true,
+                    },
+                ]
+            },
+            RequestKind::IndustryRunOutstanding => {
+                vec![
+                    RawCommandOptionEntry::User {
+                        name: "owner",
+                        description: "Only show shares owed by this person",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "member",
+                        description: "Only show shares owed to this person",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::IndustryRunPaid => {
+                vec![
+                    RawCommandOptionEntry::Integer {
+                        name: "run",
+                        description: "Number of the run",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "member",
+                        description: "Crew member who was paid. Defaults to you.",
+                        required: false,
+                    },
+                ]
+            },
+
+            RequestKind::NavyVictoryRecordOneUser => {
+                vec![]
+            },
+            RequestKind::NavyVictoryCheckUser => {
+                vec![]
+            },
+
+            RequestKind::NavyVictoryRecord => {
+                vec![
+                    RawCommandOptionEntry::Number {
+                        name: "total",
+                        description: "Number of victories. Only accepts values in intervals of 0.25. Defaults to 1.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "user",
+                        description: "Person being recorded for. Leaving this out means that you're recording your own victories.",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::NavyVictoryDelete => {
+                vec![
+                    RawCommandOptionEntry::Number {
+                        name: "total",
+                        description: "Number of victories. Only accepts values in intervals of 0.25. Defaults to 1.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "user",
+                        description: "Person being recorded for. Leaving this out means that you're recording your own victories.",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::NavyVictoryBoast => {
+                vec![]
+            },
+            RequestKind::NavyVictoryCheck => {
+                vec![
+                    RawCommandOptionEntry::User {
+                        name: "user",
+                        description: "Person to get victories for. Defaults to self. Quieter than boasting.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count records from this period, e.g. `this_month` or `2026-03-01..2026-03-15`.",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::NavyVictoryScoreboard => {
                 vec![
                     RawCommandOptionEntry::Integer {
                         name: "limit",
@@ -633,53 +1140,168 @@ impl DiscordCommandDescriptor for RequestKind {
                         description: "The integer rank to start the scoreboard at. Mutually exclusive with \"someone\"",
                         required: false,
                     },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count this period, e.g. `this_month`. Defaults to the running season, or `all_time`.",
+                        required: false,
+                    },
                 ]
             },
-            RequestKind::NavyTackleAssistClearUnknown => {
+            RequestKind::NavyVictoryClearUnknown => {
                 vec![]
             },
+            RequestKind::NavyVictoryShare => {
+                vec![
+                    RawCommandOptionEntry::User {
+                        name: "crew1",
+                        description: "Crew member sharing in the victories",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "total",
+                        description: "Number of victories to share. Only accepts values in intervals of 0.25. Defaults to 1.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "crew2",
+                        description: "Crew member sharing in the victories",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "crew3",
+                        description: "Crew member sharing in the victories",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "crew4",
+                        description: "Crew member sharing in the victories",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "crew5",
+                        description: "Crew member sharing in the victories",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "crew6",
+                        description: "Crew member sharing in the victories",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "crew7",
+                        description: "Crew member sharing in the victories",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "crew8",
+                        description: "Crew member sharing in the victories",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "weight1",
+                        description: "Relative share of crew1, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.",
+                        required: false,
+                        max: 100,
+                        min: 0,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "weight2",
+                        description: "Relative share of crew2, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.",
+                        required: false,
+                        max: 100,
+                        min: 0,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "weight3",
+                        description: "Relative share of crew3, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.",
+                        required: false,
+                        max: 100,
+                        min: 0,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "weight4",
+                        description: "Relative share of crew4, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.",
+                        required: false,
+                        max: 100,
+                        min: 0,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "weight5",
+                        description: "Relative share of crew5, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.",
+                        required: false,
+                        max: 100,
+                        min: 0,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "weight6",
+                        description: "Relative share of crew6, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.",
+                        required: false,
+                        max: 100,
+                        min: 0,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "weight7",
+                        description: "Relative share of crew7, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.",
+                        required: false,
+                        max: 100,
+                        min: 0,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "weight8",
+                        description: "Relative share of crew8, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.",
+                        required: false,
+                        max: 100,
+                        min: 0,
+                    },
+                ]
+            },
 
-            RequestKind::LegionKillRecord => {
+            RequestKind::NavyTackleAssistRecord => {
                 vec![
                     RawCommandOptionEntry::Integer {
                         name: "total",
-                        description: "Number of kills. Only accepts values in intervals of 0.25. Defaults to 1.",
+                        description: "Number of tackle assists. Defaults to 1.",
                         required: false,
                     },
                     RawCommandOptionEntry::User {
                         name: "user",
-                        description: "Person being recorded for. Leaving this out means that you're recording your own kills.",
+                        description: "Person being recorded for. Leaving this out means that you're recording your own tackle assists.",
                         required: false,
                     },
                 ]
             },
-            RequestKind::LegionKillDelete => {
+            RequestKind::NavyTackleAssistDelete => {
                 vec![
                     RawCommandOptionEntry::Integer {
                         name: "total",
-                        description: "Number of kills. Only accepts values in intervals of 0.25. Defaults to 1.",
+                        description: "Number of tackle assists. Defaults to 1.",
                         required: false,
                     },
                     RawCommandOptionEntry::User {
                         name: "user",
-                        description: "Person being recorded for. Leaving this out means that you're recording your own kills.",
+                        description: "Person being recorded for. Leaving this out means that you're recording your own tackle assists.",
                         required: false,
                     },
                 ]
             },
-            RequestKind::LegionKillBoast => {
+            RequestKind::NavyTackleAssistBoast => {
                 vec![]
             },
-            RequestKind::LegionKillCheck => {
+            RequestKind::NavyTackleAssistCheck => {
                 vec![
                     RawCommandOptionEntry::User {
                         name: "user",
-                        description: "Person to get kills for. Defaults to self. Quieter than boasting.",
+                        description: "Person to get tackle assists for. Defaults to self. Quieter than boasting.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count records from this period, e.g. `this_month` or `2026-03-01..2026-03-15`.",
                         required: false,
                     },
                 ]
             },
-            RequestKind::LegionKillScoreboard => {
+            RequestKind::NavyTackleAssistScoreboard => {
                 vec![
                     RawCommandOptionEntry::Integer {
                         name: "limit",
@@ -708,161 +1330,673 @@ impl DiscordCommandDescriptor for RequestKind {
                         description: "The integer rank to start the scoreboard at. Mutually exclusive with \"someone\"",
                         required: false,
                     },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count this period, e.g. `this_month`. Defaults to the running season, or `all_time`.",
+                        required: false,
+                    },
                 ]
             },
-            RequestKind::LegionKillClearUnknown => {
+            RequestKind::NavyTackleAssistClearUnknown => {
                 vec![]
             },
 
-            RequestKind::MonthlyGoalCheck => {
+            RequestKind::LegionKillRecord => {
                 vec![
-                    RawCommandOptionEntry::StringSelect {
-                        name: "branch",
-                        description: "Which branch to set the goal for, or the org",
+                    RawCommandOptionEntry::Integer {
+                        name: "total",
+                        description: "Number of kills. Only accepts values in intervals of 0.25. Defaults to 1.",
                         required: false,
-                        choices: vec![
-                            ("Main (default)", "main"),
-                            ("Navy", "navy"),
-                            ("Legion", "legion"),
-                            ("Industry", "industry"),
-                        ],
                     },
-                    RawCommandOptionEntry::Boolean {
-                        name: "show_details",
-                        description: "Whether to show additional detail (defaults to false)",
+                    RawCommandOptionEntry::User {
+                        name: "user",
+                        description: "Person being recorded for. Leaving this out means that you're recording your own kills.",
                         required: false,
                     },
-                    RawCommandOptionEntry::Boolean {
-                        name: "show_branches",
-                        description: "Whether to show additional detail (defaults to false), only applies if checking Main goals",
+                ]
+            },
+            RequestKind::LegionKillDelete => {
+                vec![
+                    RawCommandOptionEntry::Integer {
+                        name: "total",
+                        description: "Number of kills. Only accepts values in intervals of 0.25. Defaults to 1.",
                         required: false,
                     },
-                    RawCommandOptionEntry::Boolean {
-                        name: "skip_progress",
-                        description: "whether to show progress bars",
+                    RawCommandOptionEntry::User {
+                        name: "user",
+                        description: "Person being recorded for. Leaving this out means that you're recording your own kills.",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::LegionKillBoast => {
+                vec![]
+            },
+            RequestKind::LegionKillCheck => {
+                vec![
+                    RawCommandOptionEntry::User {
+                        name: "user",
+                        description: "Person to get kills for. Defaults to self. Quieter than boasting.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count records from this period, e.g. `this_month` or `2026-03-01..2026-03-15`.",
                         required: false,
                     },
                 ]
             },
-            RequestKind::MonthlyGoalSet => {
+            RequestKind::LegionKillScoreboard => {
+                vec![
+                    RawCommandOptionEntry::Integer {
+                        name: "limit",
+                        description: "Maximum entries to return. Max of 20. Defaults to 10.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringSelect {
+                        name: "at",
+                        description: "What to orient the scoreboard on.",
+                        required: false,
+                        choices: vec![
+                            ("Me", "me"),
+                            ("Bottom", "bottom"),
+                            ("Top (default)", "top"),
+                            ("Someone", "someone"),
+                            ("Rank", "rank"),
+                        ],
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "someone",
+                        description: "Should only be provided if \"at\" is set to \"someone\".",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Integer {
+                        name: "rank",
+                        description: "The integer rank to start the scoreboard at. Mutually exclusive with \"someone\"",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count this period, e.g. `this_month`. Defaults to the running season, or `all_time`.",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::LegionKillClearUnknown => {
+                vec![]
+            },
+
+            RequestKind::MonthlyGoalCheck => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "branch",
+                        description: "Which branch to check the goal for, or the org (defaults to main)",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Boolean {
+                        name: "show_details",
+                        description: "Whether to show additional detail (defaults to false)",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Boolean {
+                        name: "show_branches",
+                        description: "Whether to show additional detail (defaults to false), only applies if checking Main goals",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Boolean {
+                        name: "skip_progress",
+                        description: "whether to show progress bars",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringSelect {
+                        name: "style",
+                        description: "How to draw progress bars, defaults to the server's setting",
+                        required: false,
+                        choices: vec![
+                            ("ANSI colours (desktop only)", "ansi"),
+                            ("Unicode blocks", "unicode"),
+                            ("Emoji squares", "emoji"),
+                            ("Embed", "embed"),
+                        ],
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalSet => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "shortname",
+                        description: "Name for the goal. If active doesn't exist, will create",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "progress",
+                        description: "Progress of the goal, up to 100",
+                        required: false,
+                        max: 100_000_000,
+                        min: 0,
+                    },
+                    RawCommandOptionEntry::String {
+                        name: "header",
+                        description: "Header of the message, max 256 chars",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::String {
+                        name: "body",
+                        description: "Body of the message, max 4096 chars. Use `monthly_goal edit` for anything long.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::String {
+                        name: "deadline",
+                        description: "Due date as YYYY-MM-DD or YYYY-MM-DD HH:MM in the server's timezone, or `none`",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::String {
+                        name: "milestones",
+                        description: "Extra percentages to announce besides 25/50/75/100, e.g. `10,90`, or `none`",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "owner",
+                        description: "Officer responsible for the goal, who gets a DM on pledges and progress",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Boolean {
+                        name: "clear_owner",
+                        description: "Leave the goal without an owner",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "branch",
+                        description: "Which branch to set the goal for, or the org (defaults to main)",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalClear => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "shortname",
+                        description: "Shortname to clear",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "branch",
+                        description: "Branch to clear",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalEdit => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "shortname",
+                        description: "Shortname of the goal to edit",
+                        required: true,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalBoardPublish => {
+                vec![
+                    RawCommandOptionEntry::Channel {
+                        name: "channel",
+                        description: "Where to post the board, defaults to this channel",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "branch",
+                        description: "Which branch the board is for, or the org (defaults to main)",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Boolean {
+                        name: "show_details",
+                        description: "Whether to show each goal (defaults to false)",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Boolean {
+                        name: "show_branches",
+                        description: "Whether to show each branch (defaults to false), only applies to the Main board",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalBoardRemove => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "branch",
+                        description: "Which branch's board to remove (defaults to main)",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalAdminList => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "branch",
+                        description: "Tag of the goal. will get all if not provided",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalShare => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "shortname",
+                        description: "Shortname of the goal to share",
+                        required: true,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalHistory => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "shortname",
+                        description: "Shortname of the goal",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "limit",
+                        description: "How many revisions to show, defaults to 5",
+                        required: false,
+                        max: 25,
+                        min: 1,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalRestore => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "shortname",
+                        description: "Shortname of the goal",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "revision",
+                        description: "Number of the revision, as shown by `monthly_goal history`",
+                        required: true,
+                        max: i32::MAX as u64,
+                        min: 1,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalItemAdd => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "shortname",
+                        description: "Shortname of the goal",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::String {
+                        name: "description",
+                        description: "What needs doing, max 256 chars",
+                        required: true,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalItemComplete | RequestKind::MonthlyGoalItemReopen => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "shortname",
+                        description: "Shortname of the goal",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "item",
+                        description: "Number of the item, as shown by `monthly_goal check`",
+                        required: true,
+                        max: i32::MAX as u64,
+                        min: 1,
+                    },
+                ]
+            },
+
+            RequestKind::MonthlyGoalProgressRecord => {
+                vec![
+                    RawCommandOptionEntry::StringSelect {
+                        name: "stat",
+                        description: "Relevant tracked stat for command",
+                        required: true,
+                        choices: crate::db::TrackerStat::iter()
+                            .filter(|stat| stat.is_monthly_goal())
+                            .map(|stat| {
+                                (stat.as_command_opt_display_name(), stat.as_str())
+                            })
+                            .collect(),
+                    },
+                    RawCommandOptionEntry::Integer {
+                        name: "total",
+                        description: "Total to add. Defaults to 1.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "user",
+                        description: "Person being recorded for. Leaving this out means that you're recording your own profits.",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalProgressDelete => {
+                vec![
+                    RawCommandOptionEntry::StringSelect {
+                        name: "stat",
+                        description: "Relevant tracked stat for command",
+                        required: true,
+                        choices: crate::db::TrackerStat::iter()
+                            .filter(|stat| stat.is_monthly_goal())
+                            .map(|stat| {
+                                (stat.as_command_opt_display_name(), stat.as_str())
+                            })
+                            .collect(),
+                    },
+                    RawCommandOptionEntry::Integer {
+                        name: "total",
+                        description: "Total to remove. Defaults to 1.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "user",
+                        description: "Person being recorded for. Leaving this out means that you're recording your own profits.",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalProgressBoast => {
+                vec![
+                    RawCommandOptionEntry::StringSelect {
+                        name: "stat",
+                        description: "Relevant tracked stat for command",
+                        required: true,
+                        choices: crate::db::TrackerStat::iter()
+                            .filter(|stat| stat.is_monthly_goal())
+                            .map(|stat| {
+                                (stat.as_command_opt_display_name(), stat.as_str())
+                            })
+                            .collect(),
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalProgressCheck => {
+                vec![
+                    RawCommandOptionEntry::StringSelect {
+                        name: "stat",
+                        description: "Relevant tracked stat for command",
+                        required: true,
+                        choices: crate::db::TrackerStat::iter()
+                            .filter(|stat| stat.is_monthly_goal())
+                            .map(|stat| {
+                                (stat.as_command_opt_display_name(), stat.as_str())
+                            })
+                            .collect(),
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "user",
+                        description: "Person to get victories for. Defaults to self. Quieter than boasting.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count records from this period, e.g. `this_month` or `2026-03-01..2026-03-15`.",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalProgressScoreboard => {
+                vec![
+                    RawCommandOptionEntry::StringSelect {
+                        name: "stat",
+                        description: "Relevant tracked stat for command",
+                        required: true,
+                        choices: crate::db::TrackerStat::iter()
+                            .filter(|stat| stat.is_monthly_goal())
+                            .map(|stat| {
+                                (stat.as_command_opt_display_name(), stat.as_str())
+                            })
+                            .collect(),
+                    },
+                    RawCommandOptionEntry::Integer {
+                        name: "limit",
+                        description: "Maximum entries to return. Max of 20. Defaults to 10.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringSelect {
+                        name: "at",
+                        description: "What to orient the scoreboard on.",
+                        required: false,
+                        choices: vec![
+                            ("Me", "me"),
+                            ("Bottom", "bottom"),
+                            ("Top (default)", "top"),
+                            ("Someone", "someone"),
+                            ("Rank", "rank"),
+                        ],
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "someone",
+                        description: "Should only be provided if \"at\" is set to \"someone\".",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Integer {
+                        name: "rank",
+                        description: "The integer rank to start the scoreboard at. Mutually exclusive with \"someone\"",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count this period, e.g. `this_month`. Defaults to the running season, or `all_time`.",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::MonthlyGoalProgressClearUnknown => {
+                vec![
+                    RawCommandOptionEntry::StringSelect {
+                        name: "stat",
+                        description: "Relevant tracked stat for command",
+                        required: true,
+                        choices: crate::db::TrackerStat::iter()
+                            .filter(|stat| stat.is_monthly_goal())
+                            .map(|stat| {
+                                (stat.as_command_opt_display_name(), stat.as_str())
+                            })
+                            .collect(),
+                    },
+                ]
+            },
+
+            RequestKind::BranchSet => {
+                vec![
+                    RawCommandOptionEntry::String {
+                        name: "tag",
+                        description: "Tag goals use for this branch, e.g. `navy`. Updates the branch if it already exists.",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::String {
+                        name: "display_name",
+                        description: "Name shown for the branch. Defaults to the tag.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "color",
+                        description: "ANSI color (0 - 255) for progress bars. Defaults to white.",
+                        required: false,
+                        max: 255,
+                        min: 0,
+                    },
+                    RawCommandOptionEntry::String {
+                        name: "emoji",
+                        description: "Emoji shown next to the branch name.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Boolean {
+                        name: "clear_emoji",
+                        description: "Remove the branch's emoji.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Integer {
+                        name: "sort_order",
+                        description: "Branches with lower values are listed first. Defaults to 0.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Channel {
+                        name: "channel",
+                        description: "Where goal reminders and milestones for this branch are posted.",
+                        required: false,
+                    },
+                ]
+            },
+            RequestKind::BranchRemove => {
+                vec![
+                    RawCommandOptionEntry::String {
+                        name: "tag",
+                        description: "Tag of the branch to remove",
+                        required: true,
+                    },
+                ]
+            },
+            RequestKind::BranchList => {
+                vec![]
+            },
+
+            RequestKind::SettingsTimezone => {
                 vec![
-                    RawCommandOptionEntry::String {
-                        name: "shortname",
-                        description: "Name for the goal. If active doesn't exist, will create",
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "timezone",
+                        description: "IANA timezone name, e.g. `America/New_York` or `Australia/Sydney`.",
                         required: true,
                     },
-                    RawCommandOptionEntry::LimitedInteger {
-                        name: "progress",
-                        description: "Progress of the goal, up to 100",
-                        required: false,
-                        max: 100_000_000,
-                        min: 0,
-                    },
-                    RawCommandOptionEntry::String {
-                        name: "header",
-                        description: "Header of the message, as of 2025/12/16 max 100 chars",
-                        required: false,
-                    },
-                    RawCommandOptionEntry::String {
-                        name: "body",
-                        description: "Body of the message, as of 2025/12/16 max 5000 chars",
+                ]
+            },
+            RequestKind::SettingsShow => {
+                vec![]
+            },
+            RequestKind::SettingsOfficerChannel => {
+                vec![
+                    RawCommandOptionEntry::Channel {
+                        name: "channel",
+                        description: "Channel for flagged records. Leave out to stop flagging.",
                         required: false,
                     },
+                ]
+            },
+            RequestKind::SettingsBarStyle => {
+                vec![
                     RawCommandOptionEntry::StringSelect {
-                        name: "branch",
-                        description: "Which branch to set the goal for, or the org",
-                        required: false,
+                        name: "style",
+                        description: "How to draw progress bars unless a command asks otherwise.",
+                        required: true,
                         choices: vec![
-                            ("Main (default)", "main"),
-                            ("Navy", "navy"),
-                            ("Legion", "legion"),
-                            ("Industry", "industry"),
+                            ("ANSI colours (desktop only)", "ansi"),
+                            ("Unicode blocks", "unicode"),
+                            ("Emoji squares", "emoji"),
+                            ("Embed", "embed"),
                         ],
                     },
                 ]
             },
-            RequestKind::MonthlyGoalClear => {
+            RequestKind::SettingsApiToken => {
                 vec![
-                    RawCommandOptionEntry::String {
-                        name: "shortname",
-                        description: "Shortname to clear",
-                        required: false,
-                    },
-                    RawCommandOptionEntry::String {
-                        name: "branch",
-                        description: "Branch to clear",
+                    RawCommandOptionEntry::Boolean {
+                        name: "revoke",
+                        description: "Revoke the token instead, turning off API access for this server.",
                         required: false,
                     },
                 ]
             },
-            RequestKind::MonthlyGoalAdminList => {
+
+            RequestKind::MembersDeparted => {
+                vec![]
+            },
+            RequestKind::MembersPurge => {
                 vec![
-                    RawCommandOptionEntry::String {
-                        name: "branch",
-                        description: "Tag of the goal. will get all if not provided",
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "older_than_days",
+                        description: "Only delete stats archived at least this many days ago. Defaults to 90.",
                         required: false,
+                        max: 3650,
+                        min: 1,
                     },
                 ]
             },
 
-            RequestKind::MonthlyGoalProgressRecord => {
+            RequestKind::StatsTransfer => {
                 vec![
+                    RawCommandOptionEntry::User {
+                        name: "from",
+                        description: "Account the stats are moved away from",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::User {
+                        name: "to",
+                        description: "Account receiving the stats. Totals are added to anything it already has.",
+                        required: true,
+                    },
                     RawCommandOptionEntry::StringSelect {
                         name: "stat",
-                        description: "Relevant tracked stat for command",
-                        required: true,
+                        description: "Only move this stat. Defaults to all stats.",
+                        required: false,
                         choices: crate::db::TrackerStat::iter()
-                            .filter(|stat| stat.is_monthly_goal())
                             .map(|stat| {
                                 (stat.as_command_opt_display_name(), stat.as_str())
                             })
                             .collect(),
                     },
-                    RawCommandOptionEntry::Integer {
-                        name: "total",
-                        description: "Total to add. Defaults to 1.",
-                        required: false,
-                    },
-                    RawCommandOptionEntry::User {
-                        name: "user",
-                        description: "Person being recorded for. Leaving this out means that you're recording your own profits.",
+                    RawCommandOptionEntry::Boolean {
+                        name: "confirm",
+                        description: "Apply the transfer. Without this, only a preview is shown.",
                         required: false,
                     },
                 ]
             },
-            RequestKind::MonthlyGoalProgressDelete => {
+            RequestKind::StatsLimitSet => {
                 vec![
                     RawCommandOptionEntry::StringSelect {
                         name: "stat",
-                        description: "Relevant tracked stat for command",
+                        description: "Stat to limit",
                         required: true,
                         choices: crate::db::TrackerStat::iter()
-                            .filter(|stat| stat.is_monthly_goal())
                             .map(|stat| {
                                 (stat.as_command_opt_display_name(), stat.as_str())
                             })
                             .collect(),
                     },
-                    RawCommandOptionEntry::Integer {
-                        name: "total",
-                        description: "Total to remove. Defaults to 1.",
+                    RawCommandOptionEntry::Number {
+                        name: "max_change_soft",
+                        description: "Flag records bigger than this",
                         required: false,
                     },
-                    RawCommandOptionEntry::User {
-                        name: "user",
-                        description: "Person being recorded for. Leaving this out means that you're recording your own profits.",
+                    RawCommandOptionEntry::Number {
+                        name: "max_change_hard",
+                        description: "Reject records bigger than this",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "daily_cap_soft",
+                        description: "Flag once a member has been given more than this in a day",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "daily_cap_hard",
+                        description: "Reject once a member would be given more than this in a day",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "self_daily_soft",
+                        description: "Flag once a member has recorded more than this for themselves in a day",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "self_daily_hard",
+                        description: "Reject once a member would record more than this for themselves in a day. 0 forbids it.",
                         required: false,
                     },
                 ]
             },
-            RequestKind::MonthlyGoalProgressBoast => {
+            RequestKind::StatsLimitClear => {
                 vec![
                     RawCommandOptionEntry::StringSelect {
                         name: "stat",
-                        description: "Relevant tracked stat for command",
+                        description: "Stat to remove the limits of",
                         required: true,
                         choices: crate::db::TrackerStat::iter()
-                            .filter(|stat| stat.is_monthly_goal())
                             .map(|stat| {
                                 (stat.as_command_opt_display_name(), stat.as_str())
                             })
@@ -870,76 +2004,147 @@ impl DiscordCommandDescriptor for RequestKind {
                     },
                 ]
             },
-            RequestKind::MonthlyGoalProgressCheck => {
+            RequestKind::StatsLimits => {
+                vec![]
+            },
+
+            RequestKind::SeasonCreate => {
+                vec![
+                    RawCommandOptionEntry::String {
+                        name: "name",
+                        description: "Name of the season, e.g. `Summer 2026`",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::String {
+                        name: "starts",
+                        description: "First day of the season, e.g. `2026-07-01`",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::String {
+                        name: "ends",
+                        description: "Last day of the season, e.g. `2026-09-30`",
+                        required: true,
+                    },
+                ]
+            },
+            RequestKind::SeasonList => {
+                vec![]
+            },
+            RequestKind::SeasonRemove => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "name",
+                        description: "Season to remove",
+                        required: true,
+                    },
+                ]
+            },
+            RequestKind::SeasonResults => {
                 vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "name",
+                        description: "Season to show. Defaults to the most recently finished one.",
+                        required: false,
+                    },
                     RawCommandOptionEntry::StringSelect {
                         name: "stat",
-                        description: "Relevant tracked stat for command",
-                        required: true,
+                        description: "Only show this stat",
+                        required: false,
                         choices: crate::db::TrackerStat::iter()
-                            .filter(|stat| stat.is_monthly_goal())
                             .map(|stat| {
                                 (stat.as_command_opt_display_name(), stat.as_str())
                             })
                             .collect(),
                     },
-                    RawCommandOptionEntry::User {
-                        name: "user",
-                        description: "Person to get victories for. Defaults to self. Quieter than boasting.",
+                ]
+            },
+
+            RequestKind::GroupAdd => {
+                vec![
+                    RawCommandOptionEntry::Role {
+                        name: "role",
+                        description: "Role whose members make up the group",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::String {
+                        name: "name",
+                        description: "Name shown on scoreboards. Defaults to the role's name.",
                         required: false,
                     },
                 ]
             },
-            RequestKind::MonthlyGoalProgressScoreboard => {
+            RequestKind::GroupRemove => {
+                vec![
+                    RawCommandOptionEntry::Role {
+                        name: "role",
+                        description: "Role to stop ranking as a group",
+                        required: true,
+                    },
+                ]
+            },
+            RequestKind::GroupList => {
+                vec![]
+            },
+            RequestKind::GroupScoreboard => {
                 vec![
                     RawCommandOptionEntry::StringSelect {
                         name: "stat",
-                        description: "Relevant tracked stat for command",
+                        description: "Stat to rank groups by",
                         required: true,
                         choices: crate::db::TrackerStat::iter()
-                            .filter(|stat| stat.is_monthly_goal())
                             .map(|stat| {
                                 (stat.as_command_opt_display_name(), stat.as_str())
                             })
                             .collect(),
                     },
-                    RawCommandOptionEntry::Integer {
-                        name: "limit",
-                        description: "Maximum entries to return. Max of 20. Defaults to 10.",
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count this period, e.g. `this_month`. Defaults to the running season, or `all_time`.",
                         required: false,
                     },
                     RawCommandOptionEntry::StringSelect {
-                        name: "at",
-                        description: "What to orient the scoreboard on.",
+                        name: "rank_by",
+                        description: "Rank by the group's total, or its average per member. Defaults to total.",
                         required: false,
                         choices: vec![
-                            ("Me", "me"),
-                            ("Bottom", "bottom"),
-                            ("Top (default)", "top"),
-                            ("Someone", "someone"),
-                            ("Rank", "rank"),
+                            ("Total", "total"),
+                            ("Average per member", "average"),
                         ],
                     },
-                    RawCommandOptionEntry::User {
-                        name: "someone",
-                        description: "Should only be provided if \"at\" is set to \"someone\".",
-                        required: false,
+                ]
+            },
+
+            RequestKind::MeritSetWeight => {
+                vec![
+                    RawCommandOptionEntry::StringSelect {
+                        name: "stat",
+                        description: "Stat to weigh",
+                        required: true,
+                        choices: crate::db::TrackerStat::iter()
+                            .map(|stat| {
+                                (stat.as_command_opt_display_name(), stat.as_str())
+                            })
+                            .collect(),
                     },
-                    RawCommandOptionEntry::Integer {
-                        name: "rank",
-                        description: "The integer rank to start the scoreboard at. Mutually exclusive with \"someone\"",
+                    RawCommandOptionEntry::Number {
+                        name: "points",
+                        description: "Merit earned for every `per` of the stat",
+                        required: true,
+                    },
+                    RawCommandOptionEntry::Number {
+                        name: "per",
+                        description: "How much of the stat earns `points` merit, e.g. 100000 aUEC. Defaults to 1.",
                         required: false,
                     },
                 ]
             },
-            RequestKind::MonthlyGoalProgressClearUnknown => {
+            RequestKind::MeritRemoveWeight => {
                 vec![
                     RawCommandOptionEntry::StringSelect {
                         name: "stat",
-                        description: "Relevant tracked stat for command",
+                        description: "Stat to stop counting",
                         required: true,
                         choices: crate::db::TrackerStat::iter()
-                            .filter(|stat| stat.is_monthly_goal())
                             .map(|stat| {
                                 (stat.as_command_opt_display_name(), stat.as_str())
                             })
@@ -947,16 +2152,55 @@ impl DiscordCommandDescriptor for RequestKind {
                     },
                 ]
             },
+            RequestKind::MeritWeights => {
+                vec![]
+            },
+            RequestKind::MeritScoreboard => {
+                vec![
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count this period, e.g. `this_month`. Defaults to all time.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::LimitedInteger {
+                        name: "limit",
+                        description: "How many members to show, defaults to 10",
+                        required: false,
+                        max: 50,
+                        min: 1,
+                    },
+                ]
+            },
+            RequestKind::MeritCheck => {
+                vec![
+                    RawCommandOptionEntry::User {
+                        name: "user",
+                        description: "Member to check. Defaults to you.",
+                        required: false,
+                    },
+                    RawCommandOptionEntry::StringAutocomplete {
+                        name: "period",
+                        description: "Only count this period, e.g. `this_month`. Defaults to all time.",
+                        required: false,
+                    },
+                ]
+            },
         }
     }
 
     fn parse<'a>(cmd: &'a CommandInteraction) -> Result<Self::Args<'a>, RequestError> {
+        RequestKind::parse_args(cmd).map_err(|e| Locale::of(cmd).localize_error(e))
+    }
+}
+
+impl RequestKind {
+    fn parse_args<'a>(cmd: &'a CommandInteraction) -> Result<RequestArgs<'a>, RequestError> {
         match cmd.data.name.as_str() {
             "ping" => {
                 Ok(RequestArgs::Ping)
             },
             "event" => {
-                let tier0_options = cmd.data.options();
+                let tier0_options: Vec<ResolvedOption<'a>> = cmd.data.options();
                 let Some(tier1) = tier0_options.first() else {
                     return Err(RequestError::Internal("Missing options for `event`.".into()));
                 };
@@ -981,12 +2225,27 @@ impl DiscordCommandDescriptor for RequestKind {
                             "check" => {
                                 Ok(RequestArgs::EventParticipantCheck(lib::generic_tracker::check::Request::parse(cmd, crate::db::TrackerStat::EventParticipation, tier2_options.as_slice())?))
                             },
+                            "streaks" => {
+                                Ok(RequestArgs::EventParticipantStreaks(lib::generic_tracker::streak_scoreboard::Request::parse(cmd, crate::db::TrackerStat::EventParticipation, tier2_options.as_slice())?))
+                            },
                             _ => {
                                 trc::warn!("Unknown subcommand {:?}", tier1);
                                 Err(RequestError::Internal("Unknown subcommand for `event participation`".into()))
                             },
                         }
                     },
+                    "create" => {
+                        let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                            return Err(RequestError::Internal("Missing subcommand for `event create`.".into()));
+                        };
+                        Ok(RequestArgs::EventCreate(event::create::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "roll_call" => {
+                        let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                            return Err(RequestError::Internal("Missing subcommand for `event roll_call`.".into()));
+                        };
+                        Ok(RequestArgs::EventRollCall(event::roll_call::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
                     _ => {
                         Err(RequestError::Internal("Bad subcommand for `event`.".into()))
                     },
@@ -1033,6 +2292,35 @@ impl DiscordCommandDescriptor for RequestKind {
                             },
                         }
                     },
+                    "run" => {
+                        let ResolvedValue::SubCommandGroup(ref tier1_options) = tier1.value else {
+                            return Err(RequestError::Internal("Missing subcommand group for `industry`.".into()));
+                        };
+                        let Some(tier2) = tier1_options.first() else {
+                            return Err(RequestError::Internal("Missing options for `industry run`.".into()));
+                        };
+                        let ResolvedValue::SubCommand(ref tier2_options) = tier2.value else {
+                            return Err(RequestError::Internal("Missing subcommand for `industry run`.".into()));
+                        };
+                        match tier2.name {
+                            "create" => {
+                                Ok(RequestArgs::IndustryRunCreate(profit_run::create::Request::parse(cmd, tier2_options.as_slice())?))
+                            },
+                            "show" => {
+                                Ok(RequestArgs::IndustryRunShow(profit_run::show::Request::parse(cmd, tier2_options.as_slice())?))
+                            },
+                            "outstanding" => {
+                                Ok(RequestArgs::IndustryRunOutstanding(profit_run::outstanding::Request::parse(cmd, tier2_options.as_slice())?))
+                            },
+                            "paid" => {
+                                Ok(RequestArgs::IndustryRunPaid(profit_run::paid::Request::parse(cmd, tier2_options.as_slice())?))
+                            },
+                            _ => {
+                                trc::warn!("Unknown subcommand {:?}", tier1);
+                                Err(RequestError::Internal("Unknown subcommand for `industry run`".into()))
+                            },
+                        }
+                    },
                     "mining" => {
                         let ResolvedValue::SubCommandGroup(ref tier1_options) = tier1.value else {
                             return Err(RequestError::Internal("Missing subcommand group for `industry`.".into()));
@@ -1097,6 +2385,9 @@ impl DiscordCommandDescriptor for RequestKind {
                             "clear_unknown" => {
                                 Ok(RequestArgs::NavyVictoryClearUnknown(lib::generic_tracker::clear::Request::parse(cmd, crate::db::TrackerStat::NavyVictory, &[])?))
                             },
+                            "share" => {
+                                Ok(RequestArgs::NavyVictoryShare(lib::generic_tracker::share::Request::parse(cmd, crate::db::TrackerStat::NavyVictory, tier2_options.as_slice())?))
+                            },
                             _ => {
                                 trc::warn!("Unknown subcommand {:?}", tier1);
                                 Err(RequestError::Internal("Unknown subcommand for `navy victory`".into()))
@@ -1215,12 +2506,74 @@ impl DiscordCommandDescriptor for RequestKind {
                         };
                         Ok(RequestArgs::MonthlyGoalClear(monthly_goal::clear::Request::parse(cmd, tier1_options.as_slice())?))
                     },
+                    "edit" => {
+                        let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                            return Err(RequestError::Internal("Missing options for `monthly_goal edit`".into()));
+                        };
+                        Ok(RequestArgs::MonthlyGoalEdit(monthly_goal::edit::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "board_publish" => {
+                        let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                            return Err(RequestError::Internal("Missing options for `monthly_goal board_publish`".into()));
+                        };
+                        Ok(RequestArgs::MonthlyGoalBoardPublish(monthly_goal::board_publish::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "board_remove" => {
+                        let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                            return Err(RequestError::Internal("Missing options for `monthly_goal board_remove`".into()));
+                        };
+                        Ok(RequestArgs::MonthlyGoalBoardRemove(monthly_goal::board_remove::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
                     "admin_list" => {
                         let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
                             return Err(RequestError::Internal("Missing options for `monthly_goal check`".into()));
                         };
                         Ok(RequestArgs::MonthlyGoalAdminList(monthly_goal::admin_list::Request::parse(cmd, tier1_options.as_slice())?))
                     },
+                    "share" => {
+                        let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                            return Err(RequestError::Internal("Missing options for `monthly_goal share`".into()));
+                        };
+                        Ok(RequestArgs::MonthlyGoalShare(monthly_goal::share::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "history" => {
+                        let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                            return Err(RequestError::Internal("Missing options for `monthly_goal history`".into()));
+                        };
+                        Ok(RequestArgs::MonthlyGoalHistory(monthly_goal::history::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "restore" => {
+                        let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                            return Err(RequestError::Internal("Missing options for `monthly_goal restore`".into()));
+                        };
+                        Ok(RequestArgs::MonthlyGoalRestore(monthly_goal::restore::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "item" => {
+                        let ResolvedValue::SubCommandGroup(ref tier1_options) = tier1.value else {
+                            return Err(RequestError::Internal("Missing subcommand group for `monthly_goal item`.".into()));
+                        };
+                        let Some(tier2) = tier1_options.first() else {
+                            return Err(RequestError::Internal("Missing options for `monthly_goal item`.".into()));
+                        };
+                        let ResolvedValue::SubCommand(ref tier2_options) = tier2.value else {
+                            return Err(RequestError::Internal("Missing subcommand for `monthly_goal item`.".into()));
+                        };
+                        match tier2.name {
+                            "add" => {
+                                Ok(RequestArgs::MonthlyGoalItemAdd(monthly_goal::item_add::Request::parse(cmd, tier2_options.as_slice())?))
+                            },
+                            "complete" => {
+                                Ok(RequestArgs::MonthlyGoalItemComplete(monthly_goal::item_status::Request::parse(cmd, true, tier2_options.as_slice())?))
+                            },
+                            "reopen" => {
+                                Ok(RequestArgs::MonthlyGoalItemReopen(monthly_goal::item_status::Request::parse(cmd, false, tier2_options.as_slice())?))
+                            },
+                            _ => {
+                                trc::warn!("Unknown subcommand {:?}", tier2);
+                                Err(RequestError::Internal("Unknown subcommand for `monthly_goal item`".into()))
+                            },
+                        }
+                    },
                     "progress" => {
                         let ResolvedValue::SubCommandGroup(ref tier1_options) = tier1.value else {
                             return Err(RequestError::Internal("Missing subcommand group for `industry`.".into()));
@@ -1275,6 +2628,192 @@ impl DiscordCommandDescriptor for RequestKind {
                     },
                 }
             },
+            "branch" => {
+                let tier0_options: Vec<ResolvedOption<'a>> = cmd.data.options();
+                let Some(tier1) = tier0_options.first() else {
+                    return Err(RequestError::Internal("Missing options for `branch`.".into()));
+                };
+                let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                    return Err(RequestError::Internal("Missing subcommand for `branch`".into()));
+                };
+                match tier1.name {
+                    "set" => {
+                        Ok(RequestArgs::BranchSet(branch::set::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "remove" => {
+                        Ok(RequestArgs::BranchRemove(branch::remove::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "list" => {
+                        Ok(RequestArgs::BranchList(branch::list::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    _ => {
+                        trc::warn!("Unknown subcommand {:?}", tier1);
+                        Err(RequestError::Internal("Unknown subcommand for `branch`".into()))
+                    },
+                }
+            },
+            "settings" => {
+                let tier0_options: Vec<ResolvedOption<'a>> = cmd.data.options();
+                let Some(tier1) = tier0_options.first() else {
+                    return Err(RequestError::Internal("Missing options for `settings`.".into()));
+                };
+                let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                    return Err(RequestError::Internal("Missing subcommand for `settings`".into()));
+                };
+                match tier1.name {
+                    "timezone" => {
+                        Ok(RequestArgs::SettingsTimezone(settings::timezone::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "show" => {
+                        Ok(RequestArgs::SettingsShow(settings::show::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "officer_channel" => {
+                        Ok(RequestArgs::SettingsOfficerChannel(settings::officer_channel::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "bar_style" => {
+                        Ok(RequestArgs::SettingsBarStyle(settings::bar_style::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "api_token" => {
+                        Ok(RequestArgs::SettingsApiToken(settings::api_token::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    _ => {
+                        trc::warn!("Unknown subcommand {:?}", tier1);
+                        Err(RequestError::Internal("Unknown subcommand for `settings`".into()))
+                    },
+                }
+            },
+            "members" => {
+                let tier0_options: Vec<ResolvedOption<'a>> = cmd.data.options();
+                let Some(tier1) = tier0_options.first() else {
+                    return Err(RequestError::Internal("Missing options for `members`.".into()));
+                };
+                let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                    return Err(RequestError::Internal("Missing subcommand for `members`".into()));
+                };
+                match tier1.name {
+                    "departed" => {
+                        Ok(RequestArgs::MembersDeparted(members::departed::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "purge" => {
+                        Ok(RequestArgs::MembersPurge(members::purge::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    _ => {
+                        trc::warn!("Unknown subcommand {:?}", tier1);
+                        Err(RequestError::Internal("Unknown subcommand for `members`".into()))
+                    },
+                }
+            },
+            "stats" => {
+                let tier0_options: Vec<ResolvedOption<'a>> = cmd.data.options();
+                let Some(tier1) = tier0_options.first() else {
+                    return Err(RequestError::Internal("Missing options for `stats`.".into()));
+                };
+                let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                    return Err(RequestError::Internal("Missing subcommand for `stats`".into()));
+                };
+                match tier1.name {
+                    "transfer" => {
+                        Ok(RequestArgs::StatsTransfer(stats::transfer::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "limit_set" => {
+                        Ok(RequestArgs::StatsLimitSet(stats::limit_set::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "limit_clear" => {
+                        Ok(RequestArgs::StatsLimitClear(stats::limit_clear::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "limits" => {
+                        Ok(RequestArgs::StatsLimits(stats::limits::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    _ => {
+                        trc::warn!("Unknown subcommand {:?}", tier1);
+                        Err(RequestError::Internal("Unknown subcommand for `stats`".into()))
+                    },
+                }
+            },
+            "season" => {
+                let tier0_options: Vec<ResolvedOption<'a>> = cmd.data.options();
+                let Some(tier1) = tier0_options.first() else {
+                    return Err(RequestError::Internal("Missing options for `season`.".into()));
+                };
+                let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                    return Err(RequestError::Internal("Missing subcommand for `season`".into()));
+                };
+                match tier1.name {
+                    "create" => {
+                        Ok(RequestArgs::SeasonCreate(season::create::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "list" => {
+                        Ok(RequestArgs::SeasonList(season::list::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "remove" => {
+                        Ok(RequestArgs::SeasonRemove(season::remove::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "results" => {
+                        Ok(RequestArgs::SeasonResults(season::results::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    _ => {
+                        trc::warn!("Unknown subcommand {:?}", tier1);
+                        Err(RequestError::Internal("Unknown subcommand for `season`".into()))
+                    },
+                }
+            },
+            "group" => {
+                let tier0_options: Vec<ResolvedOption<'a>> = cmd.data.options();
+                let Some(tier1) = tier0_options.first() else {
+                    return Err(RequestError::Internal("Missing options for `group`.".into()));
+                };
+                let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                    return Err(RequestError::Internal("Missing subcommand for `group`".into()));
+                };
+                match tier1.name {
+                    "add" => {
+                        Ok(RequestArgs::GroupAdd(group::add::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "remove" => {
+                        Ok(RequestArgs::GroupRemove(group::remove::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "list" => {
+                        Ok(RequestArgs::GroupList(group::list::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "scoreboard" => {
+                        Ok(RequestArgs::GroupScoreboard(group::scoreboard::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    _ => {
+                        trc::warn!("Unknown subcommand {:?}", tier1);
+                        Err(RequestError::Internal("Unknown subcommand for `group`".into()))
+                    },
+                }
+            },
+            "merit" => {
+                let tier0_options: Vec<ResolvedOption<'a>> = cmd.data.options();
+                let Some(tier1) = tier0_options.first() else {
+                    return Err(RequestError::Internal("Missing options for `merit`.".into()));
+                };
+                let ResolvedValue::SubCommand(ref tier1_options) = tier1.value else {
+                    return Err(RequestError::Internal("Missing subcommand for `merit`".into()));
+                };
+                match tier1.name {
+                    "set_weight" => {
+                        Ok(RequestArgs::MeritSetWeight(merit::set_weight::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "remove_weight" => {
+                        Ok(RequestArgs::MeritRemoveWeight(merit::remove_weight::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "weights" => {
+                        Ok(RequestArgs::MeritWeights(merit::weights::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "scoreboard" => {
+                        Ok(RequestArgs::MeritScoreboard(merit::scoreboard::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    "check" => {
+                        Ok(RequestArgs::MeritCheck(merit::check::Request::parse(cmd, tier1_options.as_slice())?))
+                    },
+                    _ => {
+                        trc::warn!("Unknown subcommand {:?}", tier1);
+                        Err(RequestError::Internal("Unknown subcommand for `merit`".into()))
+                    },
+                }
+            },
             _ => {
                 trc::error!("Unknown command {:?} received", cmd);
                 Err(RequestError::Internal("Unknown command.".into()))
@@ -1285,6 +2824,13 @@ impl DiscordCommandDescriptor for RequestKind {
 
 impl <'a> DiscordCommandArgs for RequestArgs<'a> {
     async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        self.run(ctx).await.map_err(|e| locale.localize_error(e))
+    }
+}
+
+impl <'a> RequestArgs<'a> {
+    async fn run(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
         match self {
             RequestArgs::Ping => {
                 // Just try pong.
@@ -1300,6 +2846,15 @@ impl <'a> DiscordCommandArgs for RequestArgs<'a> {
             RequestArgs::EventParticipantCheck(req) => {
                 req.execute(ctx).await
             },
+            RequestArgs::EventParticipantStreaks(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::EventCreate(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::EventRollCall(req) => {
+                req.execute(ctx).await
+            },
 
             RequestArgs::MonthlyGoalProgressRecord(req) => {
                 req.execute(ctx).await
@@ -1343,6 +2898,19 @@ impl <'a> DiscordCommandArgs for RequestArgs<'a> {
                 req.execute(ctx).await
             },
 
+            RequestArgs::IndustryRunCreate(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::IndustryRunShow(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::IndustryRunOutstanding(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::IndustryRunPaid(req) => {
+                req.execute(ctx).await
+            },
+
             RequestArgs::NavyVictoryRecord(req) => {
                 req.execute(ctx).await
             },
@@ -1361,6 +2929,9 @@ impl <'a> DiscordCommandArgs for RequestArgs<'a> {
             RequestArgs::NavyVictoryClearUnknown(req) => {
                 req.execute(ctx).await
             },
+            RequestArgs::NavyVictoryShare(req) => {
+                req.execute(ctx).await
+            },
 
             RequestArgs::NavyTackleAssistRecord(req) => {
                 req.execute(ctx).await
@@ -1409,9 +2980,124 @@ impl <'a> DiscordCommandArgs for RequestArgs<'a> {
             RequestArgs::MonthlyGoalClear(req) => {
                 req.execute(ctx).await
             },
+            RequestArgs::MonthlyGoalEdit(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::MonthlyGoalBoardPublish(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::MonthlyGoalBoardRemove(req) => {
+                req.execute(ctx).await
+            },
             RequestArgs::MonthlyGoalAdminList(req) => {
                 req.execute(ctx).await
             },
+            RequestArgs::MonthlyGoalShare(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::MonthlyGoalHistory(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::MonthlyGoalRestore(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::MonthlyGoalItemAdd(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::MonthlyGoalItemComplete(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::MonthlyGoalItemReopen(req) => {
+                req.execute(ctx).await
+            },
+
+            RequestArgs::BranchSet(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::BranchRemove(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::BranchList(req) => {
+                req.execute(ctx).await
+            },
+
+            RequestArgs::SettingsTimezone(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::SettingsShow(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::SettingsOfficerChannel(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::SettingsBarStyle(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::SettingsApiToken(req) => {
+                req.execute(ctx).await
+            },
+
+            RequestArgs::MembersDeparted(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::MembersPurge(req) => {
+                req.execute(ctx).await
+            },
+
+            RequestArgs::StatsTransfer(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::StatsLimitSet(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::StatsLimitClear(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::StatsLimits(req) => {
+                req.execute(ctx).await
+            },
+
+            RequestArgs::SeasonCreate(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::SeasonList(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::SeasonRemove(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::SeasonResults(req) => {
+                req.execute(ctx).await
+            },
+
+            RequestArgs::GroupAdd(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::GroupRemove(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::GroupList(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::GroupScoreboard(req) => {
+                req.execute(ctx).await
+            },
+
+            RequestArgs::MeritSetWeight(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::MeritRemoveWeight(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::MeritWeights(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::MeritScoreboard(req) => {
+                req.execute(ctx).await
+            },
+            RequestArgs::MeritCheck(req) => {
+                req.execute(ctx).await
+            },
         }
     }
 }
@@ -1425,7 +3111,10 @@ pub fn generate_command_descriptions() -> Vec<CommandTreeTop<RequestKind>> {
             description: "Event commands".into(),
             kind: CommandType::ChatInput,
             opt_default_perm: None,
-            subcommands: vec![],
+            subcommands: vec![
+                RequestKind::EventCreate,
+                RequestKind::EventRollCall,
+            ],
             subcommand_groups: vec![
                 CommandTreeIntermediate {
                     name: "participation".into(),
@@ -1434,6 +3123,7 @@ pub fn generate_command_descriptions() -> Vec<CommandTreeTop<RequestKind>> {
                         RequestKind::EventParticipantRecord,
                         RequestKind::EventParticipantRemove,
                         RequestKind::EventParticipantCheck,
+                        RequestKind::EventParticipantStreaks,
                     ],
                 },
             ],
@@ -1464,6 +3154,16 @@ pub fn generate_command_descriptions() -> Vec<CommandTreeTop<RequestKind>> {
                         RequestKind::IndustryProfitClearUnknown,
                     ],
                 },
+                CommandTreeIntermediate {
+                    name: "run".into(),
+                    description: "Commands for crewed profit runs and their payouts".into(),
+                    children: vec![
+                        RequestKind::IndustryRunCreate,
+                        RequestKind::IndustryRunShow,
+                        RequestKind::IndustryRunOutstanding,
+                        RequestKind::IndustryRunPaid,
+                    ],
+                },
             ],
         },
 
@@ -1486,6 +3186,7 @@ pub fn generate_command_descriptions() -> Vec<CommandTreeTop<RequestKind>> {
                         RequestKind::NavyVictoryCheck,
                         RequestKind::NavyVictoryScoreboard,
                         RequestKind::NavyVictoryClearUnknown,
+                        RequestKind::NavyVictoryShare,
                     ],
                 },
                 CommandTreeIntermediate {
@@ -1532,9 +3233,24 @@ pub fn generate_command_descriptions() -> Vec<CommandTreeTop<RequestKind>> {
                 RequestKind::MonthlyGoalSet,
                 RequestKind::MonthlyGoalCheck,
                 RequestKind::MonthlyGoalClear,
+                RequestKind::MonthlyGoalEdit,
+                RequestKind::MonthlyGoalBoardPublish,
+                RequestKind::MonthlyGoalBoardRemove,
                 RequestKind::MonthlyGoalAdminList,
+                RequestKind::MonthlyGoalShare,
+                RequestKind::MonthlyGoalHistory,
+                RequestKind::MonthlyGoalRestore,
             ],
             subcommand_groups: vec![
+                CommandTreeIntermediate {
+                    name: "item".into(),
+                    description: "Commands for managing a goal's checklist".into(),
+                    children: vec![
+                        RequestKind::MonthlyGoalItemAdd,
+                        RequestKind::MonthlyGoalItemComplete,
+                        RequestKind::MonthlyGoalItemReopen,
+                    ],
+                },
                 CommandTreeIntermediate {
                     name: "progress".into(),
                     description: "Commands for managing records around goal progress".into(),
@@ -1549,6 +3265,96 @@ pub fn generate_command_descriptions() -> Vec<CommandTreeTop<RequestKind>> {
                 },
             ],
         },
+        CommandTreeTop::Complex {
+            name: "branch".into(),
+            description: "Commands for managing the branches of the org".into(),
+            kind: CommandType::ChatInput,
+            opt_default_perm: None,
+            subcommands: vec![
+                RequestKind::BranchSet,
+                RequestKind::BranchRemove,
+                RequestKind::BranchList,
+            ],
+            subcommand_groups: vec![],
+        },
+        CommandTreeTop::Complex {
+            name: "settings".into(),
+            description: "Commands for managing server-wide settings".into(),
+            kind: CommandType::ChatInput,
+            opt_default_perm: None,
+            subcommands: vec![
+                RequestKind::SettingsTimezone,
+                RequestKind::SettingsShow,
+                RequestKind::SettingsOfficerChannel,
+                RequestKind::SettingsBarStyle,
+                RequestKind::SettingsApiToken,
+            ],
+            subcommand_groups: vec![],
+        },
+        CommandTreeTop::Complex {
+            name: "members".into(),
+            description: "Commands for managing stats of members who left".into(),
+            kind: CommandType::ChatInput,
+            opt_default_perm: None,
+            subcommands: vec![
+                RequestKind::MembersDeparted,
+                RequestKind::MembersPurge,
+            ],
+            subcommand_groups: vec![],
+        },
+        CommandTreeTop::Complex {
+            name: "stats".into(),
+            description: "Officer commands for managing members' stats".into(),
+            kind: CommandType::ChatInput,
+            opt_default_perm: Some(Permissions::MANAGE_GUILD),
+            subcommands: vec![
+                RequestKind::StatsTransfer,
+                RequestKind::StatsLimitSet,
+                RequestKind::StatsLimitClear,
+                RequestKind::StatsLimits,
+            ],
+            subcommand_groups: vec![],
+        },
+        CommandTreeTop::Complex {
+            name: "season".into(),
+            description: "Commands for seasons and their hall of fame".into(),
+            kind: CommandType::ChatInput,
+            opt_default_perm: None,
+            subcommands: vec![
+                RequestKind::SeasonCreate,
+                RequestKind::SeasonList,
+                RequestKind::SeasonRemove,
+                RequestKind::SeasonResults,
+            ],
+            subcommand_groups: vec![],
+        },
+        CommandTreeTop::Complex {
+            name: "group".into(),
+            description: "Commands for ranking wings, squads and other role groups".into(),
+            kind: CommandType::ChatInput,
+            opt_default_perm: None,
+            subcommands: vec![
+                RequestKind::GroupAdd,
+                RequestKind::GroupRemove,
+                RequestKind::GroupList,
+                RequestKind::GroupScoreboard,
+            ],
+            subcommand_groups: vec![],
+        },
+        CommandTreeTop::Complex {
+            name: "merit".into(),
+            description: "Commands for weighted merit scores built from tracked stats".into(),
+            kind: CommandType::ChatInput,
+            opt_default_perm: None,
+            subcommands: vec![
+                RequestKind::MeritSetWeight,
+                RequestKind::MeritRemoveWeight,
+                RequestKind::MeritWeights,
+                RequestKind::MeritScoreboard,
+                RequestKind::MeritCheck,
+            ],
+            subcommand_groups: vec![],
+        },
     ]
 }
 
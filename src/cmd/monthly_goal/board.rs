@@ -0,0 +1,78 @@
+//! Goal boards: `monthly_goal check` published into a channel, kept up to date whenever the guild's goals change.
+
+use chrono::Utc;
+use serenity::all::{CacheHttp, CreateAllowedMentions, CreateEmbed, EditMessage, GuildId};
+use tracing as trc;
+
+use azel::db::Connector;
+
+use crate::{cmd::{RequestError, lib::{pages, progress::Rendered}, monthly_goal::check}, db::{self, GoalBoard}};
+
+const TRUNCATED_NOTE: &str = "-# …see `/monthly_goal check` for the rest.";
+
+/// Content and embeds of the board's message, in the guild's bar style.
+pub async fn render(db_cfg: &impl Connector, guild_id: GuildId, view: check::Request<'_>) -> Result<(String, Vec<CreateEmbed>), RequestError> {
+    let updated = format!("-# Updated <t:{}:R>", Utc::now().timestamp());
+    Ok(match view.render(db_cfg, guild_id).await? {
+        Rendered::Text(summary) => (format!("{}\n{updated}", first_page(summary.as_str(), updated.chars().count() + 1)), vec![]),
+        Rendered::Embed(layout) => (updated, vec![layout.to_embed()]),
+    })
+}
+
+/// A board is a single message, so anything past the first page is left for `monthly_goal check`.
+fn first_page(summary: &str, reserved: usize) -> String {
+    let max_len = pages::MESSAGE_MAX_LEN - reserved - TRUNCATED_NOTE.chars().count();
+    let pages = pages::split_pages("", [summary], max_len);
+    let more = pages.len() > 1;
+    let first = pages.into_iter().next().unwrap_or_default();
+    match more {
+        true => format!("{first}{TRUNCATED_NOTE}"),
+        false => first,
+    }
+}
+
+/// Re-renders every board in the guild. Called after anything that changes its goals, so failures are only logged.
+/// Boards whose message has been deleted are dropped.
+pub async fn refresh(http: impl CacheHttp, db_cfg: &impl Connector, guild_id: GuildId) {
+    let boards = match db::GoalBoard::load_all(db_cfg, guild_id.into()).await {
+        Ok(boards) => boards,
+        Err(e) => {
+            trc::error!("Failed to load goal boards for {guild_id:?} due to {e:?}.");
+            return;
+        },
+    };
+
+    for board in boards {
+        let view = check::Request::new(board.branch.as_str(), board.show_details, board.show_branches);
+        let (content, embeds) = match render(db_cfg, guild_id, view).await {
+            Ok(rendered) => rendered,
+            Err(e) => {
+                trc::error!("Failed to render goal board {:?} for {guild_id:?} due to {e:?}.", board.branch);
+                continue;
+            },
+        };
+        let edit = EditMessage::new()
+            .content(content)
+            .embeds(embeds)
+            .allowed_mentions(CreateAllowedMentions::new());
+        match board.channel_id.inner().edit_message(&http, board.message_id.inner(), edit).await {
+            Ok(_) => {},
+            Err(serenity::Error::Http(e)) if e.status_code().map(|status| status.as_u16()) == Some(404) => {
+                trc::info!("Goal board {:?} for {guild_id:?} is gone, dropping it.", board.branch);
+                if let Err(e) = db::GoalBoard::remove(db_cfg, board.guild_id, board.branch.as_str()).await {
+                    trc::error!("Failed to drop goal board {:?} for {guild_id:?} due to {e:?}.", board.branch);
+                }
+            },
+            Err(e) => {
+                trc::warn!("Failed to update goal board {:?} for {guild_id:?} due to {e:?}.", board.branch);
+            },
+        }
+    }
+}
+
+/// Deletes the message of a board that's been replaced or removed. It's fine if it's already gone.
+pub async fn delete_message(http: impl CacheHttp, board: &GoalBoard) {
+    if let Err(e) = board.channel_id.inner().delete_message(http.http(), board.message_id.inner()).await {
+        trc::info!("Failed to delete goal board message {:?} due to {e:?}.", board.message_id);
+    }
+}
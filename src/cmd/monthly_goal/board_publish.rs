@@ -0,0 +1,133 @@
+use serenity::all::{CommandInteraction, CreateAllowedMentions, CreateMessage, Mentionable, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::{branch::{Branches, MAIN_BRANCH}, permissions}, monthly_goal::{board, check}}, db::{self, DiscordChannelId, DiscordGuildId}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    guild_id: DiscordGuildId,
+    channel_id: DiscordChannelId,
+    branch: &'a str,
+    show_details: bool,
+    show_branches: bool,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut channel_id = cmd.channel_id.into();
+        let mut branch = MAIN_BRANCH;
+        let mut show_details = false;
+        let mut show_branches = false;
+        for opt in options {
+            match opt.name {
+                "channel" => {
+                    let ResolvedValue::Channel(c) = opt.value else {
+                        trc::error!("Bad value for `channel` in `monthly_goal board_publish` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `channel` in `monthly_goal board_publish`.".into()));
+                    };
+                    channel_id = c.id.into();
+                },
+                "branch" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `branch` in `monthly_goal board_publish` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `branch` in `monthly_goal board_publish`.".into()));
+                    };
+                    branch = u;
+                },
+                "show_details" => {
+                    let ResolvedValue::Boolean(u) = opt.value else {
+                        trc::error!("Bad value for `show_details` in `monthly_goal board_publish` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `show_details` in `monthly_goal board_publish`.".into()));
+                    };
+                    show_details = u;
+                },
+                "show_branches" => {
+                    let ResolvedValue::Boolean(u) = opt.value else {
+                        trc::error!("Bad value for `show_branches` in `monthly_goal board_publish` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `show_branches` in `monthly_goal board_publish`.".into()));
+                    };
+                    show_branches = u;
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `monthly_goal board_publish`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `monthly_goal board_publish`".into()));
+                },
+            }
+        }
+
+        Ok(Self {
+            guild_id,
+            channel_id,
+            branch,
+            show_details,
+            show_branches,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        // Publishing boards posts into channels, so it's kept to officers.
+        if !permissions::is_officer(ctx.cmd) {
+            return Err(RequestError::User("Only officers can publish goal boards.".into()));
+        }
+        let branches = match Branches::load(&ctx.db_cfg, self.guild_id).await {
+            Ok(branches) => branches,
+            Err(e) => {
+                trc::error!("Failed to load branches for {:?} due to {e:?}.", self.guild_id);
+                return Err(RequestError::Internal("Failed to load branches.".into()));
+            },
+        };
+        if !branches.contains(self.branch) {
+            return Err(RequestError::User(locale.format(
+                "Unknown branch `{}`. Use `/branch list` to see the branches for this server.",
+                &[&self.branch],
+            ).into()));
+        }
+
+        let guild_id = *self.guild_id.inner();
+        let (content, embeds) = board::render(&ctx.db_cfg, guild_id, check::Request::new(self.branch, self.show_details, self.show_branches)).await?;
+        let message = CreateMessage::new()
+            .content(content)
+            .embeds(embeds)
+            .allowed_mentions(CreateAllowedMentions::new());
+        let message = match self.channel_id.inner().send_message(ctx.ctx, message).await {
+            Ok(message) => message,
+            Err(e) => {
+                trc::warn!("Failed to post goal board to {:?} due to {e:?}.", self.channel_id);
+                return Err(RequestError::User(locale.format(
+                    "Couldn't post in {}, check that I can send messages there.",
+                    &[&self.channel_id.inner().mention()],
+                ).into()));
+            },
+        };
+
+        let previous = db::GoalBoard::set(&ctx.db_cfg, db::NewGoalBoard {
+            guild_id: self.guild_id,
+            branch: self.branch,
+            channel_id: self.channel_id,
+            message_id: message.id.into(),
+            show_details: self.show_details,
+            show_branches: self.show_branches,
+        }).await;
+        match previous {
+            Ok(Some(previous)) => board::delete_message(ctx.ctx, &previous).await,
+            Ok(None) => {},
+            Err(e) => {
+                trc::error!("Failed to save goal board for {guild_id:?} due to {e:?}.");
+                if let Err(e) = message.delete(ctx.ctx).await {
+                    trc::warn!("Failed to delete unsaved goal board {:?} due to {e:?}.", message.id);
+                }
+                return Err(RequestError::Internal("Failed to save goal board.".into()));
+            },
+        }
+
+        ctx.reply_restricted(format!(
+            "Published the goal board for {} in {}. It'll update whenever goals change.",
+            branches.label(self.branch),
+            self.channel_id.inner().mention(),
+        )).await
+    }
+}
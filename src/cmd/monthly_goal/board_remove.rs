@@ -0,0 +1,57 @@
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::{branch::MAIN_BRANCH, permissions}, monthly_goal::board}, db::{self, DiscordGuildId}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    guild_id: DiscordGuildId,
+    branch: &'a str,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut branch = MAIN_BRANCH;
+        for opt in options {
+            match opt.name {
+                "branch" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `branch` in `monthly_goal board_remove` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `branch` in `monthly_goal board_remove`.".into()));
+                    };
+                    branch = u;
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `monthly_goal board_remove`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `monthly_goal board_remove`".into()));
+                },
+            }
+        }
+
+        Ok(Self {
+            guild_id,
+            branch,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        if !permissions::is_officer(ctx.cmd) {
+            return Err(RequestError::User("Only officers can remove goal boards.".into()));
+        }
+        let removed = match db::GoalBoard::remove(&ctx.db_cfg, self.guild_id, self.branch).await {
+            Ok(Some(removed)) => removed,
+            Ok(None) => return Err(RequestError::User(locale.format("There's no goal board for branch `{}`.", &[&self.branch]).into())),
+            Err(e) => {
+                trc::error!("Failed to remove goal board {:?} for {:?} due to {e:?}.", self.branch, self.guild_id);
+                return Err(RequestError::Internal("Failed to remove goal board.".into()));
+            },
+        };
+        board::delete_message(ctx.ctx, &removed).await;
+
+        ctx.reply_restricted(format!("Removed the goal board for branch `{}`.", self.branch)).await
+    }
+}
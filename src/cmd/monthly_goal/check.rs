@@ -1,9 +1,17 @@
+use std::{collections::HashMap, str::FromStr};
+
 use serenity::all::{CommandInteraction, GuildId, ResolvedOption, ResolvedValue};
 use tracing as trc;
 
-use azel::discord::ExecutionContext;
+use azel::{db::Connector, discord::ExecutionContext};
+
+use crate::{
+    cmd::{RequestError, lib::{branch::{Branches, MAIN_BRANCH}, pages, progress::{Overview, Progress, Rendered, Section}}, monthly_goal::pledges},
+    db::{self, BarStyle},
+    locale::Locale,
+};
 
-use crate::{cmd::RequestError, db};
+const NO_GOALS: &str = "No goals have been set up!";
 
 #[derive(Debug)]
 pub struct Request<'a> {
@@ -11,14 +19,17 @@ pub struct Request<'a> {
     show_details: bool,
     show_branches: bool,
     skip_progress: bool,
+    style: Option<BarStyle>,
+    locale: Locale,
 }
 
 impl<'a> Request<'a> {
-    pub fn parse(_cmd: &'a CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
-        let mut branch = "main";
+    pub fn parse(cmd: &'a CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        let mut branch = MAIN_BRANCH;
         let mut show_branches = false;
         let mut show_details = false;
         let mut skip_progress = false;
+        let mut style = None;
         for opt in options {
             match opt.name {
                 "show_branches" => {
@@ -49,6 +60,17 @@ impl<'a> Request<'a> {
                     };
                     skip_progress = u;
                 }
+                "style" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `style` in `monthly_goal check` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `style` in `monthly_goal check`.".into()));
+                    };
+                    let Ok(u) = BarStyle::from_str(u) else {
+                        trc::error!("Unknown style {u:?} in `monthly_goal check`");
+                        return Err(RequestError::Internal("Unknown value for `style` in `monthly_goal check`.".into()));
+                    };
+                    style = Some(u);
+                }
                 _ => {
                     trc::error!("Unknown option `{}` for `monthly_goal check`", opt.name);
                     return Err(RequestError::Internal("Unknown option in `monthly_goal check`".into()));
@@ -61,64 +83,102 @@ impl<'a> Request<'a> {
             show_details,
             show_branches,
             skip_progress,
+            style,
+            locale: Locale::of(cmd),
         })
     }
 
+    /// The same view as the command, for a published goal board. Boards are seen by everyone, so they're in English.
+    pub fn new(branch: &'a str, show_details: bool, show_branches: bool) -> Self {
+        Self {
+            branch,
+            show_details,
+            show_branches,
+            skip_progress: false,
+            style: None,
+            locale: Locale::English,
+        }
+    }
+
     pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
         let guild_id = ctx.cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
-        if self.skip_progress {
-            self.execute_simple_list(ctx, guild_id).await
-        } else if self.branch != "main" {
-            self.execute_branch_summary(ctx, guild_id).await
-        } else {
-            self.execute_main_summary(ctx, guild_id).await
+        match self.overview(&ctx.db_cfg, guild_id).await? {
+            (Some(overview), style) => pages::reply_paged(ctx, overview.pages(style)).await,
+            (None, _) => ctx.reply_restricted(self.locale.translate(NO_GOALS).to_owned()).await,
         }
     }
 
-    pub async fn execute_simple_list(self, ctx: &ExecutionContext<'_>, guild_id: GuildId) -> Result<(), RequestError> {
-        let Ok(data) = db::MonthlyGoal::load_detailed_summary(&ctx.db_cfg, guild_id, self.branch).await else {
+    /// In the requested style, or the guild's if none was asked for.
+    pub async fn render(&self, db_cfg: &impl Connector, guild_id: GuildId) -> Result<Rendered, RequestError> {
+        Ok(match self.overview(db_cfg, guild_id).await? {
+            (Some(overview), style) => overview.render(style),
+            (None, _) => Rendered::Text(self.locale.translate(NO_GOALS).to_owned()),
+        })
+    }
+
+    /// Along with the style to show it in.
+    async fn overview(&self, db_cfg: &impl Connector, guild_id: GuildId) -> Result<(Option<Overview>, BarStyle), RequestError> {
+        let branches = match Branches::load(db_cfg, guild_id.into()).await {
+            Ok(branches) => branches,
+            Err(e) => {
+                trc::error!("Failed to load branches for {:?} due to {e:?}.", guild_id);
+                return Err(RequestError::Internal("Failed to load branches.".into()));
+            },
+        };
+        let style = match self.style {
+            Some(style) => style,
+            None => db::GuildSettings::load_bar_style(db_cfg, guild_id.into()).await.unwrap_or_else(|e| {
+                trc::error!("Failed to load bar style for {guild_id:?} due to {e:?}.");
+                BarStyle::default()
+            }),
+        };
+
+        let overview = if self.skip_progress {
+            self.simple_list(db_cfg, guild_id, &branches).await?
+        } else if self.branch != MAIN_BRANCH {
+            self.branch_summary(db_cfg, guild_id, &branches).await?
+        } else {
+            self.main_summary(db_cfg, guild_id, &branches).await?
+        };
+        Ok((overview, style))
+    }
+
+    async fn simple_list(&self, db_cfg: &impl Connector, guild_id: GuildId, branches: &Branches) -> Result<Option<Overview>, RequestError> {
+        let Ok(data) = db::MonthlyGoal::load_detailed_summary(db_cfg, guild_id, self.branch).await else {
             return Err(RequestError::Internal("Failed to load monthly goals.".into()));
         };
 
         if data.is_empty() {
-            ctx.reply_restricted("No goals have been set up!".to_owned()).await?;
-            return Ok(());
+            return Ok(None);
         }
 
-        let msg: String = std::iter::once(format!("# Goals for {}\n", fetch_branch_display_name(self.branch)))
-            .chain(self.show_details.then(|| data.into_iter().map(|goal| {
-                format!(
-                    "\
-                        ## {}\n\
-                        {}\n\
-                        \n\
-                    ",
-                    goal.header,
-                    goal.body,
-                )
-            })).into_flat_iter())
-            .collect();
-
-        ctx.reply_restricted(msg).await?;
-
-        Ok(())
+        Ok(Some(Overview {
+            title: self.locale.format("Goals for {}", &[&branches.label(self.branch)]),
+            progress: None,
+            sections: self.show_details.then(|| data.into_iter().map(|goal| Section {
+                heading: goal.header,
+                text: format!("{}\n", goal.body),
+                progress: None,
+            })).into_flat_iter().collect(),
+        }))
     }
 
-    pub async fn execute_main_summary(self, ctx: &ExecutionContext<'_>, guild_id: GuildId) -> Result<(), RequestError> {
-        let Ok(main_data) = db::MonthlyGoal::load_detailed_summary(&ctx.db_cfg, guild_id, "main").await else {
+    async fn main_summary(&self, db_cfg: &impl Connector, guild_id: GuildId, branches: &Branches) -> Result<Option<Overview>, RequestError> {
+        let Ok(main_data) = db::MonthlyGoal::load_detailed_summary(db_cfg, guild_id, MAIN_BRANCH).await else {
             return Err(RequestError::Internal("Failed to load monthly goals.".into()));
         };
 
-        let Ok(mut branch_data) = db::MonthlyGoal::load_primary_summary(&ctx.db_cfg, guild_id).await else {
+        let Ok(mut branch_data) = db::MonthlyGoal::load_primary_summary(db_cfg, guild_id).await else {
             return Err(RequestError::Internal("Failed to load monthly goals.".into()));
         };
         // We're calculating this on the side, ignore any values from the db for this.
-        branch_data.remove("main");
+        branch_data.remove(MAIN_BRANCH);
+        let mut branch_data: Vec<_> = branch_data.into_iter().collect();
+        branch_data.sort_by(|(a, _), (b, _)| branches.sort_key(a).cmp(&branches.sort_key(b)));
         let branch_data = branch_data;
 
         if main_data.is_empty() && branch_data.is_empty() {
-            ctx.reply_restricted("No goals have been set up!".to_owned()).await?;
-            return Ok(());
+            return Ok(None);
         }
 
         let all_progress: usize = main_data.iter().map(|goal| goal.progress as f64)
@@ -127,143 +187,111 @@ impl<'a> Request<'a> {
             .sum();
         let total_possible_progress = 100 * (main_data.len() + branch_data.len());
 
-        let msg: String = std::iter::once(format!(
-            "\
-                # Goal Progress: Main\n\
-                \n\
-                Progress ({:.2}%): ```ansi\n{}\n```\n\
-            ",
-            (all_progress as f64 / total_possible_progress as f64).clamp(0., 1.) * 100.,
-            progrs_bar::Bar::new(
-                all_progress.min(
-                    total_possible_progress.max(1)
-                ),
-                total_possible_progress.max(1)
-            ).generate_string(25, fetch_branch_color("main"))
-        ))
-            .chain(self.show_branches.then(|| branch_data.into_iter().map(|(branch_name, (branch_progress, branch_goals_count))| {
-                format!(
-                    "\
-                        ## {}\n\
-                        \n\
-                        Progress ({:.2}%): ```ansi\n{}\n```\n\
-                    ",
-                    fetch_branch_display_name(branch_name.as_str()),
-                    (branch_progress as f64 / (branch_goals_count * 100).max(1) as f64).clamp(0., 1.) * 100.,
-                    progrs_bar::Bar::new(
-                        usize::try_from(branch_progress).unwrap_or(0).min(
-                            usize::try_from((branch_goals_count * 100).max(1)).unwrap_or(1),
-                        ),
-                        usize::try_from((branch_goals_count * 100).max(1)).unwrap_or(1),
-                    ).generate_string(25, fetch_branch_color(branch_name.as_str())),
-                )
-            })).into_flat_iter())
-            .chain(self.show_details.then(|| main_data.into_iter().map(|goal| {
-                format!(
-                    "\
-                        ## {}\n\
-                        {}\n\
-                        \n\
-                        Progress ({:.2}%): ```ansi\n{}\n```\n\
-                    ",
-                    goal.header,
-                    goal.body,
-                    goal.progress as f64,
-                    progrs_bar::Bar::new(
-                        usize::try_from(goal.progress).unwrap_or(0).min(
-                            100
-                        ),
-                        100
-                    ).generate_string(25, fetch_branch_color(goal.tag.as_str())),
-                )
-            })).into_flat_iter())
-            .collect();
-
-        ctx.reply_restricted(msg).await?;
-
-        Ok(())
+        let branch_sections = self.show_branches.then(|| branch_data.into_iter().map(|(branch_name, (branch_progress, branch_goals_count))| Section {
+            heading: branches.label(branch_name.as_str()).into_owned(),
+            text: String::new(),
+            progress: Some(Progress::new(
+                usize::try_from(branch_progress).unwrap_or(0),
+                usize::try_from(branch_goals_count * 100).unwrap_or(0),
+                branches.color(branch_name.as_str()),
+            )),
+        })).into_flat_iter();
+        let goal_sections = match self.show_details {
+            true => goal_sections(db_cfg, self.locale, main_data, branches).await?,
+            false => vec![],
+        };
+
+        Ok(Some(Overview {
+            title: self.locale.translate("Goal Progress: Main").to_owned(),
+            progress: Some(Progress::new(all_progress, total_possible_progress, branches.color(MAIN_BRANCH))),
+            sections: branch_sections.chain(goal_sections).collect(),
+        }))
     }
 
-    pub async fn execute_branch_summary(self, ctx: &ExecutionContext<'_>, guild_id: GuildId) -> Result<(), RequestError> {
-        let Ok(data) = db::MonthlyGoal::load_detailed_summary(&ctx.db_cfg, guild_id, self.branch).await else {
+    async fn branch_summary(&self, db_cfg: &impl Connector, guild_id: GuildId, branches: &Branches) -> Result<Option<Overview>, RequestError> {
+        let Ok(data) = db::MonthlyGoal::load_detailed_summary(db_cfg, guild_id, self.branch).await else {
             return Err(RequestError::Internal("Failed to load monthly goals.".into()));
         };
 
         if data.is_empty() {
-            ctx.reply_restricted("No goals have been set up!".to_owned()).await?;
-            return Ok(());
+            return Ok(None);
         }
 
-        let branch_color = fetch_branch_color(self.branch);
-
         let all_progress: usize = data.iter().map(|goal| usize::try_from(goal.progress).unwrap_or(0)).sum();
         let total_possible_progress = 100 * data.len();
+        let title = self.locale.format("Goal Progress: {}", &[&branches.label(self.branch)]);
 
-        let msg: String = std::iter::once(format!(
-                "\
-                    # Goal Progress: {}\n\
-                    Progress ({:.2}%): ```ansi\n{}\n```\n\
-                ",
-                fetch_branch_display_name(self.branch),
-                (all_progress as f64 / total_possible_progress as f64) * 100.,
-                progrs_bar::Bar::new(
-                    all_progress.min(total_possible_progress),
-                    total_possible_progress
-                ).generate_string(25, branch_color),
-            ))
-            .chain(self.show_details.then(|| data.into_iter().map(|goal| {
-                format!(
-                    "\
-                        ## {}\n\
-                        {}\n\
-                        \n\
-                        Progress ({:.2}%): ```ansi\n{}\n```\n\
-                    ",
-                    goal.header,
-                    goal.body,
-                    goal.progress as f64,
-                    progrs_bar::Bar::new(
-                        usize::try_from(goal.progress).unwrap_or(0).min(100),
-                        100,
-                    ).generate_string(25, branch_color),
-                )
-            })).into_flat_iter())
-            .collect();
-
-        ctx.reply_restricted(msg).await?;
-
-        Ok(())
+        Ok(Some(Overview {
+            title,
+            progress: Some(Progress::new(all_progress, total_possible_progress, branches.color(self.branch))),
+            sections: match self.show_details {
+                true => goal_sections(db_cfg, self.locale, data, branches).await?,
+                false => vec![],
+            },
+        }))
     }
 }
 
-pub fn fetch_branch_display_name(branch: &str) -> &'static str {
-    match branch {
-        "navy" => "Navy",
-        "legion" => "Legion",
-        "industry" => "Industry",
-        "main" => "Main",
-        _ => "Other",
+/// One section per goal, with everything there is to know about it.
+async fn goal_sections(db_cfg: &impl Connector, locale: Locale, goals: Vec<db::MonthlyGoal>, branches: &Branches) -> Result<Vec<Section>, RequestError> {
+    let checklists = load_checklists(db_cfg, &goals).await?;
+    let pledges = load_pledges(db_cfg, &goals).await?;
+
+    Ok(goals.into_iter().map(|goal| Section {
+        text: format!(
+            "{}\n{}{}{}",
+            goal.body,
+            due_line(locale, &goal),
+            pledges::people_lines(locale, &goal, pledges.get(&goal.id).map(Vec::as_slice).unwrap_or_default()),
+            checklist(checklists.get(&goal.id)),
+        ),
+        progress: Some(Progress::new(usize::try_from(goal.progress).unwrap_or(0), 100, branches.color(goal.tag.as_str()))),
+        heading: goal.header,
+    }).collect())
+}
+
+async fn load_checklists(db_cfg: &impl Connector, goals: &[db::MonthlyGoal]) -> Result<HashMap<i64, Vec<db::GoalItem>>, RequestError> {
+    let ids: Vec<_> = goals.iter().map(|goal| goal.id).collect();
+    let items = match db::GoalItem::load_for_goals(db_cfg, &ids).await {
+        Ok(items) => items,
+        Err(e) => {
+            trc::error!("Failed to load goal items due to {e:?}.");
+            return Err(RequestError::Internal("Failed to load goal checklists.".into()));
+        },
+    };
+
+    let mut checklists: HashMap<_, Vec<_>> = HashMap::new();
+    for item in items {
+        checklists.entry(item.goal_id).or_default().push(item);
     }
+    Ok(checklists)
 }
 
-pub fn fetch_branch_color(branch: &str) -> crossterm::style::Color {
-    match branch {
-        "navy" => Some(crossterm::style::Color::AnsiValue(34)),
-        "legion" => Some(crossterm::style::Color::AnsiValue(32)),
-        "industry" => Some(crossterm::style::Color::AnsiValue(35)),
-        "main" => Some(crossterm::style::Color::AnsiValue(33)),
-        _ => Some(crossterm::style::Color::White),
-    }.expect(branch)
+async fn load_pledges(db_cfg: &impl Connector, goals: &[db::MonthlyGoal]) -> Result<HashMap<i64, Vec<db::GoalPledge>>, RequestError> {
+    let ids: Vec<_> = goals.iter().map(|goal| goal.id).collect();
+    let mut by_goal: HashMap<_, Vec<_>> = HashMap::new();
+    for pledge in pledges::load_pledges(db_cfg, &ids).await? {
+        by_goal.entry(pledge.goal_id).or_default().push(pledge);
+    }
+    Ok(by_goal)
 }
 
-#[cfg(test)]
-mod test {
-    use crate::cmd::monthly_goal::check::fetch_branch_color;
+/// One numbered line per item, ticked off once completed.
+fn checklist(items: Option<&Vec<db::GoalItem>>) -> String {
+    items.into_iter()
+        .flatten()
+        .map(|item| format!(
+            "{} {}. {}\n",
+            if item.completed.is_some() { "☑" } else { "☐" },
+            item.position,
+            item.description,
+        ))
+        .collect()
+}
 
-    #[test]
-    fn test_ansi_parse() {
-        for branch in ["navy", "legion", "industry"] {
-            fetch_branch_color(branch);
-        }
-    }
+/// e.g. "Due <t:1785535200:R>", on its own line, for goals with a deadline.
+fn due_line(locale: Locale, goal: &db::MonthlyGoal) -> String {
+    goal.deadline
+        .map(|deadline| locale.format("Due <t:{}:R>\n", &[&deadline.timestamp()]))
+        .unwrap_or_default()
 }
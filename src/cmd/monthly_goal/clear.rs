@@ -3,7 +3,7 @@ use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
 
 use azel::discord::ExecutionContext;
 
-use crate::{cmd::RequestError, db};
+use crate::{cmd::{RequestError, monthly_goal}, db};
 
 #[derive(Debug)]
 pub struct Request<'a> {
@@ -46,28 +46,59 @@ impl<'a> Request<'a> {
 
     pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
         let guild_id = ctx.cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
-        if let Some(branch) = self.branch {
-            let Ok(_) = db::MonthlyGoal::clear_active_by_tag(&ctx.db_cfg, guild_id, branch).await else {
-                return Err(RequestError::Internal("Failure to write".into()));
-            };
-        }
+        let mut lines = vec![];
+        let mut matched = false;
 
+        // Shortname first, so a goal that's also in the branch is reported under its name.
         if let Some(shortname) = self.shortname {
-            // TODO Detect if shortname doesn't actually exist
-            let Ok(_) = db::MonthlyGoal::clear_active_by_shortname(&ctx.db_cfg, guild_id, shortname).await else {
-                return Err(RequestError::Internal("Failure to write".into()));
-            };
+            match db::MonthlyGoal::clear_active_by_shortname(&ctx.db_cfg, guild_id, shortname).await {
+                Ok(0) => lines.push(format!("No active goal is called `{shortname}`.")),
+                Ok(_) => {
+                    matched = true;
+                    lines.push(format!("Cleared goal `{shortname}`."));
+                },
+                Err(e) => {
+                    trc::error!("Failed to clear goal {shortname:?} for {guild_id:?} due to {e:?}.");
+                    return Err(RequestError::Internal("Failure to write".into()));
+                },
+            }
+        }
+
+        if let Some(branch) = self.branch {
+            match db::MonthlyGoal::clear_active_by_tag(&ctx.db_cfg, guild_id, branch).await {
+                Ok(0) if self.shortname.is_some() => lines.push(format!("No active goals left in branch `{branch}`.")),
+                Ok(0) => lines.push(format!("No active goals in branch `{branch}`.")),
+                Ok(n) => {
+                    matched = true;
+                    lines.push(format!("Cleared {n} goals in branch `{branch}`."));
+                },
+                Err(e) => {
+                    trc::error!("Failed to clear goals of branch {branch:?} for {guild_id:?} due to {e:?}.");
+                    return Err(RequestError::Internal("Failure to write".into()));
+                },
+            }
         }
 
         if self.shortname.is_none() && self.branch.is_none() {
-            let Ok(_) = db::MonthlyGoal::clear_active(&ctx.db_cfg, guild_id).await else {
-                return Err(RequestError::Internal("Failure to write".into()));
-            };
+            match db::MonthlyGoal::clear_active(&ctx.db_cfg, guild_id).await {
+                Ok(0) => lines.push("There are no active monthly goals.".to_owned()),
+                Ok(n) => {
+                    matched = true;
+                    lines.push(format!("Cleared {n} monthly goals."));
+                },
+                Err(e) => {
+                    trc::error!("Failed to clear goals for {guild_id:?} due to {e:?}.");
+                    return Err(RequestError::Internal("Failure to write".into()));
+                },
+            }
         }
 
-        ctx.reply_restricted("Monthly goals cleared.".into()).await?;
+        if !matched {
+            return Err(RequestError::User(lines.join("\n").into()));
+        }
+        monthly_goal::board::refresh(ctx.ctx, &ctx.db_cfg, guild_id).await;
+        ctx.reply_restricted(lines.join("\n")).await?;
 
         Ok(())
     }
 }
-
@@ -0,0 +1,234 @@
+//! Goal deadlines with reminders as they approach, and announcements when a goal's progress crosses a milestone.
+//! Both are posted in the goal's branch channel.
+
+use bigdecimal::ToPrimitive;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
+use serenity::all::{CacheHttp, ChannelId, CreateAllowedMentions, CreateMessage, GuildId};
+use tracing as trc;
+
+use azel::db::{Connector, DbResult};
+
+use crate::{calendar, cmd::RequestError, db::{self, MonthlyGoal}, locale::Locale};
+
+/// Announced for every goal, on top of its own milestones.
+pub const DEFAULT_MILESTONES: [i16; 4] = [25, 50, 75, 100];
+const MAX_MILESTONES: usize = 10;
+/// How long before a deadline each reminder goes out, furthest first.
+const REMINDER_LEAD_HOURS: [i64; 2] = [7 * 24, 24];
+const CLEAR_KEYWORD: &str = "none";
+
+/// `YYYY-MM-DD HH:MM`, or `YYYY-MM-DD` for the end of that day, in the guild's timezone. `none` clears the deadline.
+pub fn parse_deadline(s: &str, tz: Tz, locale: Locale) -> Result<Option<DateTime<Utc>>, RequestError> {
+    let s = s.trim();
+    if s.eq_ignore_ascii_case(CLEAR_KEYWORD) {
+        return Ok(None);
+    }
+    if let Some(deadline) = calendar::parse_local_datetime(s, tz) {
+        return Ok(Some(deadline));
+    }
+    match NaiveDate::parse_from_str(s, "%Y-%m-%d").ok().and_then(|date| date.succ_opt()) {
+        Some(next_day) => Ok(Some(calendar::start_of_day(tz, next_day))),
+        None => Err(RequestError::User(locale.format("Couldn't read `{}` as a deadline, use YYYY-MM-DD or YYYY-MM-DD HH:MM.", &[&s]).into())),
+    }
+}
+
+/// Comma separated percentages, or `none` to only announce the defaults.
+pub fn parse_milestones(s: &str, locale: Locale) -> Result<Vec<i16>, RequestError> {
+    let s = s.trim();
+    if s.eq_ignore_ascii_case(CLEAR_KEYWORD) {
+        return Ok(vec![]);
+    }
+    let mut milestones = vec![];
+    for part in s.split(',') {
+        let part = part.trim().trim_end_matches('%');
+        match part.parse::<i16>() {
+            Ok(percent) if (1..=100).contains(&percent) => milestones.push(percent),
+            _ => return Err(RequestError::User(locale.format("Milestones must be percentages between 1 and 100, not `{}`.", &[&part]).into())),
+        }
+    }
+    milestones.sort_unstable();
+    milestones.dedup();
+    if milestones.len() > MAX_MILESTONES {
+        return Err(RequestError::User(locale.format("A goal can have at most {} milestones.", &[&MAX_MILESTONES]).into()));
+    }
+    Ok(milestones)
+}
+
+/// Milestones passed going from `old` to `new` progress, lowest first.
+pub fn crossed(old: i16, new: i16, extra: &[i16]) -> Vec<i16> {
+    let mut milestones: Vec<i16> = DEFAULT_MILESTONES.iter().chain(extra).copied()
+        .filter(|milestone| old < *milestone && *milestone <= new)
+        .collect();
+    milestones.sort_unstable();
+    milestones.dedup();
+    milestones
+}
+
+/// How many reminders should have gone out by `now`, if that's more than the `sent` that have.
+fn due_reminder(deadline: DateTime<Utc>, sent: i16, now: DateTime<Utc>) -> Option<i16> {
+    if now >= deadline {
+        return None;
+    }
+    let due = REMINDER_LEAD_HOURS.iter().filter(|hours| now >= deadline - Duration::hours(**hours)).count() as i16;
+    (due > sent).then_some(due)
+}
+
+fn guild_of(goal: &MonthlyGoal) -> Option<GuildId> {
+    goal.guild_id.to_u64().filter(|id| *id != 0).map(GuildId::new)
+}
+
+/// Where the goal's branch posts, if it's been given a channel.
+async fn branch_channel(db_cfg: &impl Connector, guild_id: GuildId, tag: &str) -> DbResult<Option<ChannelId>> {
+    Ok(db::GuildBranch::load_for(db_cfg, guild_id.into(), tag).await?
+        .and_then(|branch| branch.channel_id)
+        .map(|channel_id| *channel_id.inner()))
+}
+
+async fn post(http: impl CacheHttp, channel_id: ChannelId, content: String) -> serenity::Result<()> {
+    let message = CreateMessage::new()
+        .content(content)
+        .allowed_mentions(CreateAllowedMentions::new());
+    channel_id.send_message(http, message).await.map(|_| ())
+}
+
+pub fn milestone_message(goal: &MonthlyGoal, milestone: i16) -> String {
+    match milestone {
+        100 => format!("🏆 **{}** is complete!", goal.header),
+        _ => format!("🎉 **{}** has reached {milestone}%!", goal.header),
+    }
+}
+
+/// Posts the highest milestone a goal just crossed in its branch channel. Without one, the announcement is handed back
+/// so it can go out with the command's reply instead.
+pub async fn announce(http: impl CacheHttp, db_cfg: &impl Connector, goal: &MonthlyGoal, milestone: i16) -> Option<String> {
+    let msg = milestone_message(goal, milestone);
+    let channel_id = match guild_of(goal) {
+        Some(guild_id) => branch_channel(db_cfg, guild_id, goal.tag.as_str()).await,
+        None => Ok(None),
+    };
+    match channel_id {
+        Ok(Some(channel_id)) => match post(http, channel_id, msg.clone()).await {
+            Ok(()) => None,
+            Err(e) => {
+                trc::warn!("Failed to announce milestone of goal {} in {channel_id:?} due to {e:?}.", goal.id);
+                Some(msg)
+            },
+        },
+        Ok(None) => Some(msg),
+        Err(e) => {
+            trc::error!("Failed to load branch channel for goal {} due to {e:?}.", goal.id);
+            Some(msg)
+        },
+    }
+}
+
+/// Announces the highest milestone, default or extra, that the goal crossed on its way up from `previous`.
+pub async fn announce_progress(http: impl CacheHttp, db_cfg: &impl Connector, goal: &MonthlyGoal, previous: i16) -> Option<String> {
+    let extra = MonthlyGoal::load_milestones(db_cfg, goal.id).await.unwrap_or_else(|e| {
+        trc::error!("Failed to load milestones of goal {} due to {e:?}.", goal.id);
+        vec![]
+    });
+    let milestone = *crossed(previous, goal.progress, &extra).last()?;
+    announce(http, db_cfg, goal, milestone).await
+}
+
+fn reminder_message(goal: &MonthlyGoal, deadline: DateTime<Utc>) -> String {
+    format!("⏰ **{}** (`{}`) is due <t:{}:R> and is at {}%.", goal.header, goal.shortname, deadline.timestamp(), goal.progress)
+}
+
+/// Posts any reminders that have come due. Run from the scheduler, across every guild.
+pub async fn send_reminders(http: impl CacheHttp, db_cfg: &impl Connector, now: DateTime<Utc>) -> DbResult<usize> {
+    let furthest = Duration::hours(REMINDER_LEAD_HOURS.iter().copied().max().unwrap_or_default());
+    let mut sent = 0;
+    for goal in db::MonthlyGoal::load_upcoming_deadlines(db_cfg, now, furthest).await? {
+        let Some(deadline) = goal.deadline else {
+            continue;
+        };
+        // Nothing left to remind anyone about.
+        if goal.progress >= 100 {
+            continue;
+        }
+        let Some(due) = due_reminder(deadline, goal.reminders_sent, now) else {
+            continue;
+        };
+        let Some(guild_id) = guild_of(&goal) else {
+            trc::error!("Goal {} has a bad guild id {}.", goal.id, goal.guild_id);
+            continue;
+        };
+        // One guild's failure mustn't hold up the others' reminders.
+        let channel_id = match branch_channel(db_cfg, guild_id, goal.tag.as_str()).await {
+            Ok(channel_id) => channel_id,
+            Err(e) => {
+                trc::error!("Failed to load the channel of branch {:?} in {guild_id:?} due to {e:?}.", goal.tag);
+                continue;
+            },
+        };
+        match channel_id {
+            Some(channel_id) => {
+                // Only the scheduler sends reminders, one at a time, so marking after the post can't send it twice. A
+                // failed post is tried again next tick.
+                if let Err(e) = post(&http, channel_id, reminder_message(&goal, deadline)).await {
+                    trc::warn!("Failed to post reminder for goal {} in {channel_id:?} due to {e:?}.", goal.id);
+                    continue;
+                }
+                sent += 1;
+            },
+            None => trc::info!("No channel for branch {:?} in {guild_id:?}, skipping reminder for goal {}.", goal.tag, goal.id),
+        }
+        if let Err(e) = db::MonthlyGoal::mark_reminded(db_cfg, goal.id, due).await {
+            trc::error!("Failed to mark reminder {due} of goal {} as sent due to {e:?}.", goal.id);
+        }
+    }
+    Ok(sent)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, Duration, Utc};
+
+    use crate::locale::Locale;
+
+    use super::{crossed, due_reminder, parse_deadline, parse_milestones};
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).expect("valid test timestamp").with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_parse_deadline() {
+        let tz = chrono_tz::Europe::Berlin;
+        assert_eq!(parse_deadline("2026-07-31", tz, Locale::English).ok(), Some(Some(utc("2026-07-31T22:00:00Z"))));
+        assert_eq!(parse_deadline("2026-07-31 18:00", tz, Locale::English).ok(), Some(Some(utc("2026-07-31T16:00:00Z"))));
+        assert_eq!(parse_deadline("None", tz, Locale::English).ok(), Some(None));
+        assert!(parse_deadline("next friday", tz, Locale::English).is_err());
+    }
+
+    #[test]
+    fn test_parse_milestones() {
+        assert_eq!(parse_milestones("90, 10%,10", Locale::English).ok(), Some(vec![10, 90]));
+        assert_eq!(parse_milestones("none", Locale::English).ok(), Some(vec![]));
+        assert!(parse_milestones("0", Locale::English).is_err());
+        assert!(parse_milestones("101", Locale::English).is_err());
+        assert!(parse_milestones("1,2,3,4,5,6,7,8,9,10,11", Locale::English).is_err());
+    }
+
+    #[test]
+    fn test_crossed() {
+        assert_eq!(crossed(0, 60, &[]), vec![25, 50]);
+        assert_eq!(crossed(25, 25, &[]), Vec::<i16>::new());
+        assert_eq!(crossed(20, 100, &[30, 50]), vec![25, 30, 50, 75, 100]);
+        assert_eq!(crossed(60, 40, &[]), Vec::<i16>::new());
+    }
+
+    #[test]
+    fn test_due_reminder() {
+        let deadline = utc("2026-07-31T22:00:00Z");
+        assert_eq!(due_reminder(deadline, 0, deadline - Duration::days(8)), None);
+        assert_eq!(due_reminder(deadline, 0, deadline - Duration::days(6)), Some(1));
+        assert_eq!(due_reminder(deadline, 1, deadline - Duration::days(6)), None);
+        // A late start skips straight to the last reminder.
+        assert_eq!(due_reminder(deadline, 0, deadline - Duration::hours(2)), Some(2));
+        assert_eq!(due_reminder(deadline, 0, deadline), None);
+    }
+}
@@ -0,0 +1,228 @@
+//! Editing a goal's header and body through a modal, since a 4096 character body doesn't fit in a slash option. The
+//! submitted text is shown back as an embed preview, and saving reads it from there, so nothing is kept in between.
+
+use serenity::all::{
+    ActionRow, ActionRowComponent, ButtonStyle, CommandInteraction, ComponentInteraction, Context, CreateActionRow, CreateButton, CreateEmbed,
+    CreateInputText, CreateInteractionResponse, CreateInteractionResponseMessage, CreateModal, InputTextStyle, ModalInteraction, ResolvedOption,
+    ResolvedValue,
+};
+use tracing as trc;
+
+use azel::{db::Connector, discord::ExecutionContext};
+
+use crate::{cmd::{RequestError, monthly_goal}, db, locale::Locale};
+
+/// Discord caps text inputs at 4000 characters, a bit short of what the body column holds.
+const INPUT_MAX_LEN: usize = 4000;
+/// Discord caps modal titles at 45 characters.
+const MODAL_TITLE_MAX_LEN: usize = 45;
+
+const MODAL_PREFIX: &str = "monthly_goal_edit";
+const SAVE_PREFIX: &str = "monthly_goal_save";
+pub const CANCEL_CUSTOM_ID: &str = "monthly_goal_cancel";
+
+const HEADER_INPUT: &str = "header";
+const BODY_INPUT: &str = "body";
+
+pub fn modal_custom_id(goal_id: i64) -> String {
+    format!("{MODAL_PREFIX}:{goal_id}")
+}
+
+pub fn parse_modal_custom_id(custom_id: &str) -> Option<i64> {
+    parse_custom_id(MODAL_PREFIX, custom_id)
+}
+
+pub fn save_custom_id(goal_id: i64) -> String {
+    format!("{SAVE_PREFIX}:{goal_id}")
+}
+
+pub fn parse_save_custom_id(custom_id: &str) -> Option<i64> {
+    parse_custom_id(SAVE_PREFIX, custom_id)
+}
+
+fn parse_custom_id(expected: &str, custom_id: &str) -> Option<i64> {
+    let (prefix, goal_id) = custom_id.split_once(':')?;
+    if prefix != expected {
+        return None;
+    }
+    goal_id.parse().ok()
+}
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    shortname: &'a str,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(_cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        let mut shortname = None;
+        for opt in options {
+            match opt.name {
+                "shortname" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `shortname` in `monthly_goal edit` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `shortname` in `monthly_goal edit`.".into()));
+                    };
+                    shortname = Some(u);
+                }
+                _ => {
+                    trc::error!("Unknown option `{}` for `monthly_goal edit`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `monthly_goal edit`".into()));
+                }
+            }
+        }
+
+        let Some(shortname) = shortname else {
+            trc::error!("Missing value for `shortname` in `monthly_goal edit`");
+            return Err(RequestError::Internal("Missing value for `shortname` in `monthly_goal edit`.".into()));
+        };
+
+        Ok(Self {
+            shortname,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let guild_id = ctx.cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
+        let goal = monthly_goal::load_active(&ctx.db_cfg, guild_id, self.shortname, locale).await?;
+        if goal.body.chars().count() > INPUT_MAX_LEN {
+            return Err(RequestError::User(locale.format(
+                "The body of `{}` is longer than the editor can hold ({} characters), use `monthly_goal set` instead.",
+                &[&goal.shortname, &INPUT_MAX_LEN],
+            ).into()));
+        }
+
+        let title: String = format!("Edit {}", goal.shortname).chars().take(MODAL_TITLE_MAX_LEN).collect();
+        let modal = CreateModal::new(modal_custom_id(goal.id), title)
+            .components(vec![
+                CreateActionRow::InputText(
+                    CreateInputText::new(InputTextStyle::Short, "Header", HEADER_INPUT)
+                        .value(goal.header)
+                        .max_length(monthly_goal::HEADER_MAX_LEN as u16)
+                        .required(true)
+                ),
+                CreateActionRow::InputText(
+                    CreateInputText::new(InputTextStyle::Paragraph, "Body", BODY_INPUT)
+                        .value(goal.body)
+                        .max_length(INPUT_MAX_LEN as u16)
+                        .required(true)
+                ),
+            ]);
+        if let Err(e) = ctx.cmd.create_response(ctx.ctx, CreateInteractionResponse::Modal(modal)).await {
+            trc::error!("Failed to open editor for goal {} due to {e:?}.", goal.id);
+            return Err(RequestError::Internal("Failed to open the editor.".into()));
+        }
+        *ctx.is_first_response.lock().await = false;
+        Ok(())
+    }
+}
+
+fn input_value<'c>(components: &'c [ActionRow], custom_id: &str) -> Option<&'c str> {
+    components.iter()
+        .flat_map(|row| row.components.iter())
+        .find_map(|component| match component {
+            ActionRowComponent::InputText(input) if input.custom_id == custom_id => input.value.as_deref(),
+            _ => None,
+        })
+}
+
+/// The goal as it'll look once saved.
+fn preview(header: &str, body: &str) -> CreateEmbed {
+    CreateEmbed::new()
+        .title(header)
+        .description(body)
+}
+
+/// The editor was submitted, show what it'll look like before saving.
+pub async fn submit(ctx: &Context, db_cfg: &impl Connector, modal: &ModalInteraction, goal_id: i64) -> Result<(), RequestError> {
+    let guild_id = modal.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
+    let (Some(header), Some(body)) = (input_value(&modal.data.components, HEADER_INPUT), input_value(&modal.data.components, BODY_INPUT)) else {
+        trc::error!("Missing inputs in goal editor {:?}", modal.data);
+        return Err(RequestError::Internal("Missing inputs in the goal editor.".into()));
+    };
+    monthly_goal::validate(Some(header), Some(body), Locale::from_discord(modal.locale.as_str()))?;
+
+    let goal = match db::MonthlyGoal::load_active_by_id(db_cfg, guild_id, goal_id).await {
+        Ok(Some(goal)) => goal,
+        Ok(None) => return Err(RequestError::User("This goal has been cleared in the meantime.".into())),
+        Err(e) => {
+            trc::error!("Failed to load goal {goal_id} due to {e:?}.");
+            return Err(RequestError::Internal("Failed to load monthly goals.".into()));
+        },
+    };
+
+    let message = CreateInteractionResponseMessage::new()
+        .content(format!("Preview of `{}`:", goal.shortname))
+        .embed(preview(header, body))
+        .components(vec![
+            CreateActionRow::Buttons(vec![
+                CreateButton::new(save_custom_id(goal.id)).label("Save").style(ButtonStyle::Success),
+                CreateButton::new(CANCEL_CUSTOM_ID).label("Cancel").style(ButtonStyle::Secondary),
+            ]),
+        ])
+        .ephemeral(true);
+    if let Err(e) = modal.create_response(ctx, CreateInteractionResponse::Message(message)).await {
+        trc::error!("Failed to preview goal {goal_id} due to {e:?}.");
+        return Err(RequestError::Internal("Failed to show the preview.".into()));
+    }
+    Ok(())
+}
+
+/// Save was pressed on a preview.
+pub async fn save(ctx: &Context, db_cfg: &impl Connector, component: &ComponentInteraction, goal_id: i64) -> Result<(), RequestError> {
+    let Some(embed) = component.message.embeds.first() else {
+        trc::error!("Goal preview without an embed {:?}", component.message);
+        return Err(RequestError::Internal("Missing goal preview.".into()));
+    };
+    let header = embed.title.as_deref().unwrap_or_default();
+    let body = embed.description.as_deref().unwrap_or_default();
+    monthly_goal::validate(Some(header), Some(body), Locale::from_discord(component.locale.as_str()))?;
+
+    let guild_id = component.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
+    let updater = u64::from(component.user.id).into();
+    match db::MonthlyGoal::update_text(db_cfg, guild_id, goal_id, updater, header, body).await {
+        Ok(true) => {},
+        Ok(false) => return Err(RequestError::User("This goal has been cleared in the meantime.".into())),
+        Err(e) => {
+            trc::error!("Failed to save goal {goal_id} due to {e:?}.");
+            return Err(RequestError::Internal("Failure to write".into()));
+        },
+    }
+    monthly_goal::board::refresh(ctx, db_cfg, guild_id).await;
+
+    let message = CreateInteractionResponseMessage::new()
+        .content("Saved.")
+        .embed(preview(header, body))
+        .components(vec![]);
+    if let Err(e) = component.create_response(ctx, CreateInteractionResponse::UpdateMessage(message)).await {
+        trc::error!("Failed to confirm saving goal {goal_id} due to {e:?}.");
+    }
+    Ok(())
+}
+
+/// Cancel was pressed on a preview.
+pub async fn cancel(ctx: &Context, component: &ComponentInteraction) -> Result<(), RequestError> {
+    let message = CreateInteractionResponseMessage::new()
+        .content("Edit cancelled, nothing was saved.")
+        .embeds(vec![])
+        .components(vec![]);
+    if let Err(e) = component.create_response(ctx, CreateInteractionResponse::UpdateMessage(message)).await {
+        trc::error!("Failed to cancel goal edit due to {e:?}.");
+        return Err(RequestError::Internal("Failed to cancel the edit.".into()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CANCEL_CUSTOM_ID, modal_custom_id, parse_modal_custom_id, parse_save_custom_id, save_custom_id};
+
+    #[test]
+    fn test_custom_ids() {
+        assert_eq!(parse_modal_custom_id(&modal_custom_id(7)), Some(7));
+        assert_eq!(parse_save_custom_id(&save_custom_id(7)), Some(7));
+        assert_eq!(parse_save_custom_id(&modal_custom_id(7)), None);
+        assert_eq!(parse_save_custom_id(CANCEL_CUSTOM_ID), None);
+    }
+}
@@ -0,0 +1,274 @@
+//! What a goal used to say, and how its progress moved, newest change first.
+
+use bigdecimal::ToPrimitive;
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue, UserId};
+use tracing as trc;
+
+use azel::{db::Connector, discord::ExecutionContext};
+
+use crate::{cmd::{RequestError, lib::pages, monthly_goal}, db::{self, GoalRevision}, locale::Locale};
+
+const DEFAULT_LIMIT: usize = 5;
+/// Changed body lines shown per revision, so one big rewrite doesn't push out everything else.
+const DIFF_MAX_LINES: usize = 10;
+/// Cells of the table `line_diff` may build, about 2 MB. Past this the body is shown as replaced instead.
+const DIFF_MAX_CELLS: usize = 250_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLine<'s> {
+    Same(&'s str),
+    Removed(&'s str),
+    Added(&'s str),
+}
+
+/// Line by line diff, through the longest common subsequence of what's between the lines both texts start and end
+/// with. `None` if that's too much to compare, since the table is quadratic.
+pub fn line_diff<'s>(old: &'s str, new: &'s str) -> Option<Vec<DiffLine<'s>>> {
+    let old: Vec<_> = old.lines().collect();
+    let new: Vec<_> = new.lines().collect();
+    let prefix = old.iter().zip(new.iter()).take_while(|(old, new)| old == new).count();
+    let suffix = old[prefix..].iter().rev().zip(new[prefix..].iter().rev()).take_while(|(old, new)| old == new).count();
+    let (old_rest, new_rest) = (&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix]);
+    if (old_rest.len() + 1).saturating_mul(new_rest.len() + 1) > DIFF_MAX_CELLS {
+        return None;
+    }
+
+    let mut diff = Vec::with_capacity(old.len().max(new.len()));
+    diff.extend(old[..prefix].iter().map(|line| DiffLine::Same(line)));
+    diff.extend(common_subsequence_diff(old_rest, new_rest));
+    diff.extend(old[old.len() - suffix..].iter().map(|line| DiffLine::Same(line)));
+    Some(diff)
+}
+
+fn common_subsequence_diff<'s>(old: &[&'s str], new: &[&'s str]) -> Vec<DiffLine<'s>> {
+    // common[i][j] is the length of the longest common subsequence of old[i..] and new[j..].
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = match old[i] == new[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+
+    let mut diff = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            diff.push(DiffLine::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if common[i + 1][j] >= common[i][j + 1] {
+            diff.push(DiffLine::Removed(old[i]));
+            i += 1;
+        } else {
+            diff.push(DiffLine::Added(new[j]));
+            j += 1;
+        }
+    }
+    diff.extend(old[i..].iter().map(|line| DiffLine::Removed(line)));
+    diff.extend(new[j..].iter().map(|line| DiffLine::Added(line)));
+    diff
+}
+
+/// One revision, numbered from the goal's creation.
+pub fn render_revision(number: usize, revision: &GoalRevision) -> String {
+    let by = match revision.updater.to_u64().filter(|id| *id != 0) {
+        Some(id) => UserId::new(id).mention().to_string(),
+        None => "someone".to_owned(),
+    };
+    let mut entry = format!("**#{number}** <t:{}:R> by {by}\n", revision.created.timestamp());
+
+    let Some(old_header) = &revision.old_header else {
+        entry.push_str(format!("Created as **{}** at {}%\n", revision.header, revision.progress).as_str());
+        return entry;
+    };
+    if let Some(old_progress) = revision.old_progress.filter(|old| *old != revision.progress) {
+        entry.push_str(format!("Progress: {old_progress}% → {}%\n", revision.progress).as_str());
+    }
+    if *old_header != revision.header {
+        entry.push_str(format!("Header: ~~{old_header}~~ → {}\n", revision.header).as_str());
+    }
+    if let Some(old_body) = revision.old_body.as_deref().filter(|old| *old != revision.body) {
+        let Some(diff) = line_diff(old_body, &revision.body) else {
+            entry.push_str("Body replaced, too long to compare line by line.\n");
+            return entry;
+        };
+        let changed: Vec<_> = diff.into_iter()
+            .filter_map(|line| match line {
+                DiffLine::Same(_) => None,
+                DiffLine::Removed(line) => Some(format!("- {line}")),
+                DiffLine::Added(line) => Some(format!("+ {line}")),
+            })
+            .collect();
+        let mut shown = changed.iter().take(DIFF_MAX_LINES).cloned().collect::<Vec<_>>().join("\n");
+        if changed.len() > DIFF_MAX_LINES {
+            shown.push_str(format!("\n… {} more lines", changed.len() - DIFF_MAX_LINES).as_str());
+        }
+        // Body lines can't close the block early.
+        entry.push_str(format!("```diff\n{}\n```\n", shown.replace("```", "'''")).as_str());
+    }
+    entry
+}
+
+/// The last `limit` revisions newest first, split into pages.
+pub fn render_history(shortname: &str, revisions: &[GoalRevision], limit: usize) -> Vec<String> {
+    if revisions.is_empty() {
+        return vec![format!("`{shortname}` has no recorded changes yet.")];
+    }
+
+    let header = format!("# History of `{shortname}`\n");
+    let mut entries: Vec<String> = revisions.iter().enumerate().rev().take(limit)
+        .map(|(index, revision)| render_revision(index + 1, revision))
+        .collect();
+    match revisions.len().saturating_sub(limit) {
+        0 => {},
+        1 => entries.push("-# …and 1 older revision, raise `limit` to see it.".to_owned()),
+        hidden => entries.push(format!("-# …and {hidden} older revisions, raise `limit` to see them.")),
+    }
+    pages::split_pages(header.as_str(), entries, pages::MESSAGE_MAX_LEN)
+}
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    shortname: &'a str,
+    limit: usize,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(_cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        let mut shortname = None;
+        let mut limit = DEFAULT_LIMIT;
+        for opt in options {
+            match opt.name {
+                "shortname" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `shortname` in `monthly_goal history` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `shortname` in `monthly_goal history`.".into()));
+                    };
+                    shortname = Some(u);
+                }
+                "limit" => {
+                    let ResolvedValue::Integer(u) = opt.value else {
+                        trc::error!("Bad value for `limit` in `monthly_goal history` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `limit` in `monthly_goal history`.".into()));
+                    };
+                    limit = usize::try_from(u).unwrap_or(DEFAULT_LIMIT);
+                }
+                _ => {
+                    trc::error!("Unknown option `{}` for `monthly_goal history`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `monthly_goal history`".into()));
+                }
+            }
+        }
+
+        let Some(shortname) = shortname else {
+            trc::error!("Missing value for `shortname` in `monthly_goal history`");
+            return Err(RequestError::Internal("Missing value for `shortname` in `monthly_goal history`.".into()));
+        };
+
+        Ok(Self {
+            shortname,
+            limit,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let guild_id = ctx.cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
+        let goal = monthly_goal::load_active(&ctx.db_cfg, guild_id, self.shortname, Locale::of(ctx.cmd)).await?;
+        let revisions = load_revisions(&ctx.db_cfg, goal.id).await?;
+
+        pages::reply_paged(ctx, pages::text_pages(render_history(&goal.shortname, &revisions, self.limit))).await
+    }
+}
+
+pub async fn load_revisions(db_cfg: &impl Connector, goal_id: i64) -> Result<Vec<GoalRevision>, RequestError> {
+    db::GoalRevision::load_for(db_cfg, goal_id).await.map_err(|e| {
+        trc::error!("Failed to load revisions of goal {goal_id} due to {e:?}.");
+        RequestError::Internal("Failed to load the goal's history.".into())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use bigdecimal::BigDecimal;
+    use chrono::Utc;
+
+    use crate::{cmd::lib::pages::MESSAGE_MAX_LEN, db::GoalRevision};
+
+    use super::{DiffLine, line_diff, render_history};
+
+    fn revision(old: Option<(&str, &str, i16)>, new: (&str, &str, i16)) -> GoalRevision {
+        GoalRevision {
+            id: 0,
+            goal_id: 0,
+            created: Utc::now(),
+            updater: BigDecimal::from(7),
+            old_header: old.map(|(header, _, _)| header.to_owned()),
+            old_body: old.map(|(_, body, _)| body.to_owned()),
+            old_progress: old.map(|(_, _, progress)| progress),
+            header: new.0.to_owned(),
+            body: new.1.to_owned(),
+            progress: new.2,
+        }
+    }
+
+    #[test]
+    fn test_line_diff() {
+        assert_eq!(
+            line_diff("a\nb\nc", "a\nx\nc\nd"),
+            Some(vec![DiffLine::Same("a"), DiffLine::Removed("b"), DiffLine::Added("x"), DiffLine::Same("c"), DiffLine::Added("d")]),
+        );
+        assert_eq!(line_diff("", "a"), Some(vec![DiffLine::Added("a")]));
+        assert_eq!(line_diff("a", "a"), Some(vec![DiffLine::Same("a")]));
+        assert_eq!(line_diff("a\na", "a"), Some(vec![DiffLine::Same("a"), DiffLine::Removed("a")]));
+    }
+
+    #[test]
+    fn test_line_diff_long_bodies() {
+        let old: String = (0..2000).map(|i| format!("{i}\n")).collect();
+        let new = old.replacen("1000\n", "one thousand\n", 1);
+        let diff = line_diff(&old, &new).unwrap();
+        assert_eq!(diff.len(), 2001);
+        assert_eq!(diff[1000], DiffLine::Removed("1000"));
+        assert_eq!(diff[1001], DiffLine::Added("one thousand"));
+
+        let rewritten: String = (0..2000).map(|i| format!("new {i}\n")).collect();
+        assert_eq!(line_diff(&old, &rewritten), None);
+        let pages = render_history("ops", &[revision(Some(("Ops", &old, 0)), ("Ops", &rewritten, 0))], 5);
+        assert_eq!(pages.len(), 1);
+        assert!(pages[0].contains("Body replaced, too long to compare line by line."));
+        assert!(!pages[0].contains("older revision"));
+    }
+
+    #[test]
+    fn test_render_history() {
+        assert_eq!(render_history("ops", &[], 5), vec!["`ops` has no recorded changes yet.".to_owned()]);
+
+        let revisions = vec![
+            revision(None, ("Ops", "one", 0)),
+            revision(Some(("Ops", "one", 0)), ("Ops", "one\ntwo", 40)),
+            revision(Some(("Ops", "one\ntwo", 40)), ("Big ops", "one\ntwo", 40)),
+        ];
+        let pages = render_history("ops", &revisions, 2);
+        assert_eq!(pages.len(), 1);
+        let msg = &pages[0];
+        let newest = msg.find("**#3**").unwrap();
+        let older = msg.find("**#2**").unwrap();
+        assert!(newest < older);
+        assert!(!msg.contains("**#1**"));
+        assert!(msg.contains("Header: ~~Ops~~ → Big ops"));
+        assert!(msg.contains("Progress: 0% → 40%"));
+        assert!(msg.contains("```diff\n+ two\n```"));
+        assert!(msg.ends_with("…and 1 older revision, raise `limit` to see it."));
+
+        // Histories too long for one message go on more pages rather than being cut short.
+        let long: Vec<_> = (0..20).map(|i| revision(Some(("Ops", "one", i)), ("Ops", &format!("one\n{}", "x".repeat(300)), i + 1))).collect();
+        let pages = render_history("ops", &long, 20);
+        assert!(pages.len() > 1);
+        assert!(pages.iter().all(|page| page.starts_with("# History of `ops`\n") && page.chars().count() <= MESSAGE_MAX_LEN));
+        assert!(pages[0].contains("**#20**"));
+        assert!(pages.last().unwrap().contains("**#1**"));
+    }
+}
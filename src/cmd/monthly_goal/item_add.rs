@@ -0,0 +1,91 @@
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, monthly_goal}, db, locale::Locale};
+
+/// Size of the `description` column of `goal_items`.
+pub const ITEM_MAX_LEN: usize = 256;
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    shortname: &'a str,
+    description: &'a str,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(_cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        let mut shortname = None;
+        let mut description = None;
+        for opt in options {
+            match opt.name {
+                "shortname" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `shortname` in `monthly_goal item add` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `shortname` in `monthly_goal item add`.".into()));
+                    };
+                    shortname = Some(u);
+                }
+                "description" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `description` in `monthly_goal item add` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `description` in `monthly_goal item add`.".into()));
+                    };
+                    description = Some(u);
+                }
+                _ => {
+                    trc::error!("Unknown option `{}` for `monthly_goal item add`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `monthly_goal item add`".into()));
+                }
+            }
+        }
+
+        let Some(shortname) = shortname else {
+            trc::error!("Missing value for `shortname` in `monthly_goal item add`");
+            return Err(RequestError::Internal("Missing value for `shortname` in `monthly_goal item add`.".into()));
+        };
+        let Some(description) = description else {
+            trc::error!("Missing value for `description` in `monthly_goal item add`");
+            return Err(RequestError::Internal("Missing value for `description` in `monthly_goal item add`.".into()));
+        };
+
+        Ok(Self {
+            shortname,
+            description,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let guild_id = ctx.cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
+        let description = self.description.trim();
+        if description.is_empty() {
+            return Err(RequestError::User("The item needs a description.".into()));
+        }
+        if description.chars().count() > ITEM_MAX_LEN {
+            return Err(RequestError::User(locale.format("Items can be at most {} characters.", &[&ITEM_MAX_LEN]).into()));
+        }
+
+        let goal = monthly_goal::load_active(&ctx.db_cfg, guild_id, self.shortname, locale).await?;
+        let (item, change) = match db::GoalItem::add(&ctx.db_cfg, goal.id, description, ctx.cmd.user.id.into()).await {
+            Ok(added) => added,
+            Err(e) => {
+                trc::error!("Failed to add item to goal {} due to {e:?}.", goal.id);
+                return Err(RequestError::Internal("Failed to add the item.".into()));
+            },
+        };
+        monthly_goal::board::refresh(ctx.ctx, &ctx.db_cfg, guild_id).await;
+        let goal = db::MonthlyGoal { progress: change.current, ..goal };
+        monthly_goal::owners::notify_progress(ctx.ctx, &goal, change.previous, ctx.cmd.user.id).await;
+
+        ctx.reply_restricted(format!(
+            "Added item {} to `{}`, which is now at {}% (was {}%).",
+            item.position,
+            goal.shortname,
+            change.current,
+            change.previous,
+        )).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+//! Completing and reopening checklist items, which share everything but the direction.
+
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, monthly_goal::{self, deadlines}}, db, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    shortname: &'a str,
+    item: i32,
+    complete: bool,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(cmd: &CommandInteraction, complete: bool, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        let locale = Locale::of(cmd);
+        let command = if complete { "monthly_goal item complete" } else { "monthly_goal item reopen" };
+        let mut shortname = None;
+        let mut item = None;
+        for opt in options {
+            match opt.name {
+                "shortname" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `shortname` in `{command}` {:?}", opt);
+                        return Err(RequestError::Internal(format!("Bad value for `shortname` in `{command}`.").into()));
+                    };
+                    shortname = Some(u);
+                }
+                "item" => {
+                    let ResolvedValue::Integer(u) = opt.value else {
+                        trc::error!("Bad value for `item` in `{command}` {:?}", opt);
+                        return Err(RequestError::Internal(format!("Bad value for `item` in `{command}`.").into()));
+                    };
+                    let Ok(u) = i32::try_from(u) else {
+                        return Err(RequestError::User(locale.format("There's no item {}.", &[&u]).into()));
+                    };
+                    item = Some(u);
+                }
+                _ => {
+                    trc::error!("Unknown option `{}` for `{command}`", opt.name);
+                    return Err(RequestError::Internal(format!("Unknown option in `{command}`").into()));
+                }
+            }
+        }
+
+        let Some(shortname) = shortname else {
+            trc::error!("Missing value for `shortname` in `{command}`");
+            return Err(RequestError::Internal(format!("Missing value for `shortname` in `{command}`.").into()));
+        };
+        let Some(item) = item else {
+            trc::error!("Missing value for `item` in `{command}`");
+            return Err(RequestError::Internal(format!("Missing value for `item` in `{command}`.").into()));
+        };
+
+        Ok(Self {
+            shortname,
+            item,
+            complete,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let guild_id = ctx.cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
+        let goal = monthly_goal::load_active(&ctx.db_cfg, guild_id, self.shortname, locale).await?;
+
+        let (item, change) = match db::GoalItem::set_completed(&ctx.db_cfg, goal.id, self.item, self.complete, ctx.cmd.user.id.into()).await {
+            Ok(Some(updated)) => updated,
+            Ok(None) => return Err(RequestError::User(locale.format("`{}` has no item {}.", &[&goal.shortname, &self.item]).into())),
+            Err(e) => {
+                trc::error!("Failed to update item {} of goal {} due to {e:?}.", self.item, goal.id);
+                return Err(RequestError::Internal("Failed to update the item.".into()));
+            },
+        };
+        if change.current != change.previous {
+            monthly_goal::board::refresh(ctx.ctx, &ctx.db_cfg, guild_id).await;
+        }
+
+        let mut msg = format!(
+            "{} item {} of `{}`: {}\nThe goal is at {}%.",
+            if self.complete { "Completed" } else { "Reopened" },
+            item.position,
+            goal.shortname,
+            item.description,
+            change.current,
+        );
+        let goal = db::MonthlyGoal { progress: change.current, ..goal };
+        monthly_goal::owners::notify_progress(ctx.ctx, &goal, change.previous, ctx.cmd.user.id).await;
+        if let Some(announcement) = deadlines::announce_progress(ctx.ctx, &ctx.db_cfg, &goal, change.previous).await {
+            msg.push_str(format!("\n{announcement}").as_str());
+        }
+        ctx.reply_restricted(msg).await?;
+
+        Ok(())
+    }
+}
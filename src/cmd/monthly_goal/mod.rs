@@ -2,3 +2,124 @@ pub mod set;
 pub mod check;
 pub mod clear;
 pub mod admin_list;
+pub mod board;
+pub mod board_publish;
+pub mod board_remove;
+pub mod deadlines;
+pub mod edit;
+pub mod history;
+pub mod item_add;
+pub mod item_status;
+pub mod owners;
+pub mod pledges;
+pub mod restore;
+pub mod share;
+
+use serenity::all::GuildId;
+use tracing as trc;
+
+use azel::db::Connector;
+
+use crate::{cmd::{RequestError, lib::options}, db::MonthlyGoal, locale::Locale};
+
+/// Column sizes of `monthly_goals`, which happen to match the title and description limits of an embed.
+pub const HEADER_MAX_LEN: usize = 256;
+pub const BODY_MAX_LEN: usize = 4096;
+
+/// Discord caps the names of autocomplete choices at 100 characters.
+const MAX_CHOICE_NAME_LEN: usize = 100;
+
+/// Active goals as `(name, value)` pairs for a shortname option, matching what the user has typed so far and the
+/// branch they've picked, if any.
+pub fn shortname_choices(goals: &[MonthlyGoal], branch: Option<&str>, partial: &str) -> Vec<(String, String)> {
+    let partial = partial.trim().to_lowercase();
+    goals.iter()
+        .filter(|goal| branch.is_none_or(|branch| goal.tag == branch))
+        .filter(|goal| goal.shortname.to_lowercase().starts_with(partial.as_str()))
+        .take(options::MAX_CHOICES)
+        .map(|goal| {
+            let name = format!("{} ({}): {}", goal.shortname, goal.tag, goal.header);
+            (name.chars().take(MAX_CHOICE_NAME_LEN).collect(), goal.shortname.clone())
+        })
+        .collect()
+}
+
+/// The active goal by that shortname, or a user error if there isn't one.
+pub async fn load_active(db_cfg: &impl Connector, guild_id: GuildId, shortname: &str, locale: Locale) -> Result<MonthlyGoal, RequestError> {
+    match MonthlyGoal::load_for(db_cfg, guild_id, shortname).await {
+        Ok(Some(goal)) if goal.disabled.is_none() => Ok(goal),
+        Ok(_) => Err(RequestError::User(locale.format("No active goal is called `{}`.", &[&shortname]).into())),
+        Err(e) => {
+            trc::error!("Failed to load goal {shortname:?} for {guild_id:?} due to {e:?}.");
+            Err(RequestError::Internal("Failed to load monthly goals.".into()))
+        },
+    }
+}
+
+/// Checks a header and body against the column sizes before they're written.
+pub fn validate(header: Option<&str>, body: Option<&str>, locale: Locale) -> Result<(), RequestError> {
+    let fields = [
+        ("The header can be at most {} characters, this one is {}.", header, HEADER_MAX_LEN),
+        ("The body can be at most {} characters, this one is {}.", body, BODY_MAX_LEN),
+    ];
+    for (msgid, value, max) in fields {
+        let Some(value) = value else {
+            continue;
+        };
+        let len = value.chars().count();
+        if len > max {
+            return Err(RequestError::User(locale.format(msgid, &[&max, &len]).into()));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+
+    use crate::{db::MonthlyGoal, locale::Locale};
+
+    use super::{BODY_MAX_LEN, HEADER_MAX_LEN, shortname_choices, validate};
+
+    fn goal(shortname: &str, tag: &str) -> MonthlyGoal {
+        MonthlyGoal {
+            id: 0,
+            created: Utc::now(),
+            updater: 1.into(),
+            tag: tag.to_owned(),
+            header: "Header".to_owned(),
+            body: "Body".to_owned(),
+            progress: 0,
+            shortname: shortname.to_owned(),
+            disabled: None,
+            guild_id: 1.into(),
+            deadline: None,
+            reminders_sent: 0,
+            owner_id: None,
+        }
+    }
+
+    #[test]
+    fn test_shortname_choices() {
+        let goals = vec![goal("mining", "main"), goal("minesweep", "navy"), goal("recruit", "main")];
+        let values = |branch, partial| -> Vec<String> {
+            shortname_choices(&goals, branch, partial).into_iter().map(|(_, value)| value).collect()
+        };
+        assert_eq!(values(None, ""), vec!["mining", "minesweep", "recruit"]);
+        assert_eq!(values(None, "MIN"), vec!["mining", "minesweep"]);
+        assert_eq!(values(Some("navy"), "min"), vec!["minesweep"]);
+        assert_eq!(values(Some("legion"), ""), Vec::<String>::new());
+        assert_eq!(shortname_choices(&goals, None, "rec")[0].0, "recruit (main): Header");
+    }
+
+    #[test]
+    fn test_validate() {
+        let header = "é".repeat(HEADER_MAX_LEN);
+        assert!(validate(Some(header.as_str()), None, Locale::English).is_ok());
+        assert!(validate(Some(format!("{header}!").as_str()), None, Locale::English).is_err());
+        assert!(validate(None, Some("x".repeat(BODY_MAX_LEN).as_str()), Locale::English).is_ok());
+        assert!(validate(None, Some("x".repeat(BODY_MAX_LEN + 1).as_str()), Locale::English).is_err());
+        assert!(validate(None, None, Locale::English).is_ok());
+    }
+}
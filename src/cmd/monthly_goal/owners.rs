@@ -0,0 +1,43 @@
+//! Direct messages keeping a goal's owner up to date. These are best-effort, plenty of members keep their DMs closed.
+
+use serenity::all::{CacheHttp, CreateAllowedMentions, CreateMessage, Mentionable, UserId};
+use tracing as trc;
+
+use crate::db::{DiscordUserId, MonthlyGoal};
+
+pub fn pledge_message(goal: &MonthlyGoal, user_id: UserId) -> String {
+    format!("🤝 {} pledged to help with **{}** (`{}`).", user_id.mention(), goal.header, goal.shortname)
+}
+
+pub fn progress_message(goal: &MonthlyGoal, previous: i16, user_id: UserId) -> String {
+    format!(
+        "📈 {} moved **{}** (`{}`) from {previous}% to {}%.",
+        user_id.mention(),
+        goal.header,
+        goal.shortname,
+        goal.progress,
+    )
+}
+
+/// DMs the goal's owner, unless they're the one who did it.
+pub async fn notify(http: impl CacheHttp, goal: &MonthlyGoal, actor: UserId, content: String) {
+    let Some(owner) = goal.owner_id else {
+        return;
+    };
+    if owner == DiscordUserId::from(actor) {
+        return;
+    }
+    let message = CreateMessage::new()
+        .content(content)
+        .allowed_mentions(CreateAllowedMentions::new());
+    if let Err(e) = owner.inner().direct_message(http, message).await {
+        trc::info!("Couldn't DM {owner:?} about goal {} due to {e:?}.", goal.id);
+    }
+}
+
+/// Tells the owner about a change in progress, if there was one.
+pub async fn notify_progress(http: impl CacheHttp, goal: &MonthlyGoal, previous: i16, actor: UserId) {
+    if goal.progress != previous {
+        notify(http, goal, actor, progress_message(goal, previous, actor)).await;
+    }
+}
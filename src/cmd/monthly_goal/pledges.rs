@@ -0,0 +1,128 @@
+//! Goal cards members can pledge their help on with a button.
+
+use serenity::all::{
+    ButtonStyle, ComponentInteraction, Context, CreateActionRow, CreateAllowedMentions, CreateButton, CreateInteractionResponse,
+    CreateInteractionResponseMessage, Mentionable,
+};
+use tracing as trc;
+
+use azel::db::Connector;
+
+use crate::{cmd::{RequestError, monthly_goal::owners}, db::{self, GoalPledge, MonthlyGoal}, locale::Locale};
+
+const PLEDGE_PREFIX: &str = "monthly_goal_pledge";
+const WITHDRAW_PREFIX: &str = "monthly_goal_withdraw";
+
+pub fn pledge_custom_id(goal_id: i64) -> String {
+    format!("{PLEDGE_PREFIX}:{goal_id}")
+}
+
+pub fn withdraw_custom_id(goal_id: i64) -> String {
+    format!("{WITHDRAW_PREFIX}:{goal_id}")
+}
+
+/// The goal the button is for, and whether it pledges or withdraws.
+pub fn parse_custom_id(custom_id: &str) -> Option<(i64, bool)> {
+    let (prefix, goal_id) = custom_id.split_once(':')?;
+    let pledging = match prefix {
+        PLEDGE_PREFIX => true,
+        WITHDRAW_PREFIX => false,
+        _ => return None,
+    };
+    Some((goal_id.parse().ok()?, pledging))
+}
+
+/// Owner and pledgers of a goal, one line each, leaving out whichever it doesn't have.
+pub fn people_lines(locale: Locale, goal: &MonthlyGoal, pledges: &[GoalPledge]) -> String {
+    let mut lines = String::new();
+    if let Some(owner) = goal.owner_id {
+        lines.push_str(locale.format("Owner: {}\n", &[&owner.inner().mention()]).as_str());
+    }
+    if !pledges.is_empty() {
+        let users: Vec<String> = pledges.iter().map(|pledge| pledge.user_id.inner().mention().to_string()).collect();
+        lines.push_str(locale.format("Pledged ({}): {}\n", &[&users.len(), &users.join(", ")]).as_str());
+    }
+    lines
+}
+
+pub fn render_card(goal: &MonthlyGoal, pledges: &[GoalPledge]) -> String {
+    let mut card = format!("**{}** (`{}`)\nProgress: {}%\n", goal.header, goal.shortname, goal.progress);
+    card.push_str(people_lines(Locale::English, goal, pledges).as_str());
+    if pledges.is_empty() {
+        card.push_str("Nobody has pledged to help yet.\n");
+    }
+    card
+}
+
+pub fn buttons(goal: &MonthlyGoal) -> Vec<CreateActionRow> {
+    vec![
+        CreateActionRow::Buttons(vec![
+            CreateButton::new(pledge_custom_id(goal.id)).label("Pledge to help").style(ButtonStyle::Success),
+            CreateButton::new(withdraw_custom_id(goal.id)).label("Withdraw").style(ButtonStyle::Secondary),
+        ]),
+    ]
+}
+
+pub async fn load_pledges(db_cfg: &impl Connector, goal_ids: &[i64]) -> Result<Vec<GoalPledge>, RequestError> {
+    GoalPledge::load_for_goals(db_cfg, goal_ids).await.map_err(|e| {
+        trc::error!("Failed to load pledges of goals {goal_ids:?} due to {e:?}.");
+        RequestError::Internal("Failed to load pledges.".into())
+    })
+}
+
+/// Someone pressed one of the buttons on a goal card.
+pub async fn respond(ctx: &Context, db_cfg: &impl Connector, component: &ComponentInteraction, goal_id: i64, pledging: bool) -> Result<(), RequestError> {
+    let Some(guild_id) = component.guild_id else {
+        return Err(RequestError::User("Goals only work within a server.".into()));
+    };
+    let goal = match MonthlyGoal::load_active_by_id(db_cfg, guild_id, goal_id).await {
+        Ok(Some(goal)) => goal,
+        Ok(None) => return Err(RequestError::User("This goal has been cleared.".into())),
+        Err(e) => {
+            trc::error!("Failed to load goal {goal_id} due to {e:?}.");
+            return Err(RequestError::Internal("Failed to load the goal.".into()));
+        },
+    };
+
+    let user_id = component.user.id;
+    let changed = match pledging {
+        true => db::GoalPledge::pledge(db_cfg, goal.id, user_id.into()).await,
+        false => db::GoalPledge::withdraw(db_cfg, goal.id, user_id.into()).await,
+    };
+    match changed {
+        Ok(true) => {},
+        Ok(false) if pledging => return Err(RequestError::User("You've already pledged to help with this goal.".into())),
+        Ok(false) => return Err(RequestError::User("You haven't pledged to help with this goal.".into())),
+        Err(e) => {
+            trc::error!("Failed to save pledge of {user_id:?} to goal {} due to {e:?}.", goal.id);
+            return Err(RequestError::Internal("Failed to save your pledge.".into()));
+        },
+    }
+    let pledges = load_pledges(db_cfg, &[goal.id]).await?;
+
+    let message = CreateInteractionResponseMessage::new()
+        .content(render_card(&goal, &pledges))
+        .allowed_mentions(CreateAllowedMentions::new());
+    if let Err(e) = component.create_response(ctx, CreateInteractionResponse::UpdateMessage(message)).await {
+        trc::error!("Failed to update card of goal {} due to {e:?}.", goal.id);
+        return Err(RequestError::Internal("Failed to update the goal card.".into()));
+    }
+
+    if pledging {
+        owners::notify(ctx, &goal, user_id, owners::pledge_message(&goal, user_id)).await;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_custom_id, pledge_custom_id, withdraw_custom_id};
+
+    #[test]
+    fn test_custom_ids() {
+        assert_eq!(parse_custom_id(&pledge_custom_id(12)), Some((12, true)));
+        assert_eq!(parse_custom_id(&withdraw_custom_id(12)), Some((12, false)));
+        assert_eq!(parse_custom_id("monthly_goal_save:12"), None);
+        assert_eq!(parse_custom_id("monthly_goal_pledge:x"), None);
+    }
+}
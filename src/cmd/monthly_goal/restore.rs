@@ -0,0 +1,95 @@
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, monthly_goal::{self, history}}, db, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    shortname: &'a str,
+    revision: usize,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(_cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        let mut shortname = None;
+        let mut revision = None;
+        for opt in options {
+            match opt.name {
+                "shortname" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `shortname` in `monthly_goal restore` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `shortname` in `monthly_goal restore`.".into()));
+                    };
+                    shortname = Some(u);
+                }
+                "revision" => {
+                    let ResolvedValue::Integer(u) = opt.value else {
+                        trc::error!("Bad value for `revision` in `monthly_goal restore` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `revision` in `monthly_goal restore`.".into()));
+                    };
+                    revision = Some(usize::try_from(u).unwrap_or(0));
+                }
+                _ => {
+                    trc::error!("Unknown option `{}` for `monthly_goal restore`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `monthly_goal restore`".into()));
+                }
+            }
+        }
+
+        let Some(shortname) = shortname else {
+            trc::error!("Missing value for `shortname` in `monthly_goal restore`");
+            return Err(RequestError::Internal("Missing value for `shortname` in `monthly_goal restore`.".into()));
+        };
+        let Some(revision) = revision else {
+            trc::error!("Missing value for `revision` in `monthly_goal restore`");
+            return Err(RequestError::Internal("Missing value for `revision` in `monthly_goal restore`.".into()));
+        };
+
+        Ok(Self {
+            shortname,
+            revision,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let guild_id = ctx.cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
+        let goal = monthly_goal::load_active(&ctx.db_cfg, guild_id, self.shortname, locale).await?;
+        let revisions = history::load_revisions(&ctx.db_cfg, goal.id).await?;
+        let Some(revision) = self.revision.checked_sub(1).and_then(|index| revisions.get(index)) else {
+            return Err(RequestError::User(locale.format(
+                "`{}` has no revision #{}, see `monthly_goal history` for the ones it has.",
+                &[&goal.shortname, &self.revision],
+            ).into()));
+        };
+
+        // Progress of a goal with a checklist comes from the checklist, so only its text goes back.
+        let has_checklist = match db::GoalItem::count_for(&ctx.db_cfg, goal.id).await {
+            Ok(count) => count > 0,
+            Err(e) => {
+                trc::error!("Failed to count items of goal {} due to {e:?}.", goal.id);
+                return Err(RequestError::Internal("Failed to load goal checklist.".into()));
+            },
+        };
+        let updater = u64::from(ctx.cmd.user.id).into();
+        let restored = match db::MonthlyGoal::restore(&ctx.db_cfg, revision, updater, !has_checklist).await {
+            Ok(Some(restored)) => restored,
+            Ok(None) => return Err(RequestError::User("This goal has been cleared in the meantime.".into())),
+            Err(e) => {
+                trc::error!("Failed to restore goal {} to revision {} due to {e:?}.", goal.id, revision.id);
+                return Err(RequestError::Internal("Failure to write".into()));
+            },
+        };
+        monthly_goal::board::refresh(ctx.ctx, &ctx.db_cfg, guild_id).await;
+        monthly_goal::owners::notify_progress(ctx.ctx, &restored, goal.progress, ctx.cmd.user.id).await;
+
+        let mut msg = format!("Restored `{}` to revision #{}.", goal.shortname, self.revision);
+        if has_checklist {
+            msg.push_str(" Its progress still comes from its checklist.");
+        }
+        ctx.reply_restricted(msg).await?;
+        Ok(())
+    }
+}
@@ -1,9 +1,10 @@
-use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use chrono::Utc;
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue, UserId};
 use tracing as trc;
 
 use azel::discord::ExecutionContext;
 
-use crate::{cmd::RequestError, db};
+use crate::{cmd::{RequestError, lib::branch::{Branches, MAIN_BRANCH}, monthly_goal::{self, deadlines}}, db, locale::Locale};
 
 #[derive(Debug)]
 pub struct Request<'a> {
@@ -12,15 +13,23 @@ pub struct Request<'a> {
     header: Option<&'a str>,
     body: Option<&'a str>,
     progress: Option<i16>,
+    deadline: Option<&'a str>,
+    milestones: Option<&'a str>,
+    owner: Option<UserId>,
+    clear_owner: bool,
 }
 
 impl <'a> Request<'a> {
     pub fn parse(_cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
         let mut shortname = None;
-        let mut branch = "main";
+        let mut branch = MAIN_BRANCH;
         let mut header = None;
         let mut body = None;
         let mut progress = None;
+        let mut deadline = None;
+        let mut milestones = None;
+        let mut owner = None;
+        let mut clear_owner = false;
         for opt in options {
             match opt.name {
                 "shortname" => {
@@ -66,6 +75,34 @@ impl <'a> Request<'a> {
                     };
                     progress = Some(u);
                 }
+                "deadline" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `deadline` in `monthly_goal set` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `deadline` in `monthly_goal set`.".into()));
+                    };
+                    deadline = Some(u);
+                }
+                "milestones" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `milestones` in `monthly_goal set` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `milestones` in `monthly_goal set`.".into()));
+                    };
+                    milestones = Some(u);
+                }
+                "owner" => {
+                    let ResolvedValue::User(u, _) = opt.value else {
+                        trc::error!("Bad value for `owner` in `monthly_goal set` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `owner` in `monthly_goal set`.".into()));
+                    };
+                    owner = Some(u.id);
+                }
+                "clear_owner" => {
+                    let ResolvedValue::Boolean(u) = opt.value else {
+                        trc::error!("Bad value for `clear_owner` in `monthly_goal set` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `clear_owner` in `monthly_goal set`.".into()));
+                    };
+                    clear_owner = u;
+                }
                 _ => {
                     trc::error!("Unknown option `{}` for `monthly_goal set`", opt.name);
                     return Err(RequestError::Internal("Unknown option in `monthly_goal set`".into()));
@@ -73,6 +110,10 @@ impl <'a> Request<'a> {
             }
         }
 
+        if owner.is_some() && clear_owner {
+            return Err(RequestError::User("Pick an `owner` or `clear_owner`, not both.".into()));
+        }
+
         let Some(shortname) = shortname else {
             trc::error!("Missing value for `shortname` in `monthly_goal set`");
             return Err(RequestError::Internal("Missing value for `shortname` in `monthly goal check`.".into()));
@@ -84,12 +125,73 @@ impl <'a> Request<'a> {
             header,
             body,
             progress,
+            deadline,
+            milestones,
+            owner,
+            clear_owner,
         })
     }
 
     pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
         let guild_id = ctx.cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
-        match db::MonthlyGoal::upsert(&ctx.db_cfg, db::NewMonthlyGoal {
+        let branches = match Branches::load(&ctx.db_cfg, guild_id.into()).await {
+            Ok(branches) => branches,
+            Err(e) => {
+                trc::error!("Failed to load branches for {:?} due to {e:?}.", guild_id);
+                return Err(RequestError::Internal("Failed to load branches.".into()));
+            },
+        };
+        if !branches.contains(self.branch) {
+            return Err(RequestError::User(locale.format(
+                "Unknown branch `{}`. Use `/branch list` to see the branches for this server.",
+                &[&self.branch],
+            ).into()));
+        }
+        monthly_goal::validate(self.header, self.body, locale)?;
+        let milestones = self.milestones.map(|milestones| deadlines::parse_milestones(milestones, locale)).transpose()?;
+        let deadline = match self.deadline {
+            Some(deadline) => {
+                let tz = match db::GuildSettings::load_timezone(&ctx.db_cfg, guild_id.into()).await {
+                    Ok(tz) => tz,
+                    Err(e) => {
+                        trc::error!("Failed to load timezone for {guild_id:?} due to {e:?}.");
+                        return Err(RequestError::Internal("Failed to load server settings.".into()));
+                    },
+                };
+                let deadline = deadlines::parse_deadline(deadline, tz, locale)?;
+                if deadline.is_some_and(|deadline| deadline <= Utc::now()) {
+                    return Err(RequestError::User("The deadline has to be in the future.".into()));
+                }
+                Some(deadline)
+            },
+            None => None,
+        };
+
+        // Milestones are worked out from how far the goal was before this update.
+        let previous = match db::MonthlyGoal::load_for(&ctx.db_cfg, guild_id, self.shortname).await {
+            Ok(goal) => goal.filter(|goal| goal.disabled.is_none()),
+            Err(e) => {
+                trc::error!("Failed to load goal {:?} for {guild_id:?} due to {e:?}.", self.shortname);
+                return Err(RequestError::Internal("Failed to load monthly goals.".into()));
+            },
+        };
+        if let (Some(previous), Some(_)) = (&previous, self.progress) {
+            match db::GoalItem::count_for(&ctx.db_cfg, previous.id).await {
+                Ok(0) => {},
+                Ok(_) => return Err(RequestError::User(locale.format(
+                    "The progress of `{}` comes from its checklist, use `monthly_goal item complete` instead.",
+                    &[&self.shortname],
+                ).into())),
+                Err(e) => {
+                    trc::error!("Failed to count items of goal {} due to {e:?}.", previous.id);
+                    return Err(RequestError::Internal("Failed to load goal checklist.".into()));
+                },
+            }
+        }
+        let previous_progress = previous.map(|goal| goal.progress).unwrap_or(0);
+
+        let goal = match db::MonthlyGoal::upsert(&ctx.db_cfg, db::NewMonthlyGoal {
             updater: u64::from(ctx.cmd.user.id).into(),
             shortname: self.shortname,
             tag: self.branch,
@@ -97,14 +199,35 @@ impl <'a> Request<'a> {
             body: self.body,
             progress: self.progress,
             guild_id: u64::from(guild_id).into(),
-        }).await {
-            Ok(_) => {},
+            owner_id: self.owner.map(Into::into),
+        }, deadline).await {
+            Ok(goal) => goal,
             Err(e) => {
                 return Err(RequestError::Internal(format!("Failure to write {:?}", e).into()));
             },
         };
+        if self.clear_owner {
+            if let Err(e) = db::MonthlyGoal::clear_owner(&ctx.db_cfg, goal.id).await {
+                trc::error!("Failed to clear the owner of goal {} due to {e:?}.", goal.id);
+                return Err(RequestError::Internal("Failed to clear the goal's owner.".into()));
+            }
+        }
+        if let Some(milestones) = &milestones {
+            if let Err(e) = db::MonthlyGoal::set_milestones(&ctx.db_cfg, goal.id, milestones).await {
+                trc::error!("Failed to save milestones of goal {} due to {e:?}.", goal.id);
+                return Err(RequestError::Internal("Failed to save milestones.".into()));
+            }
+        }
+        monthly_goal::board::refresh(ctx.ctx, &ctx.db_cfg, guild_id).await;
 
-        ctx.reply_restricted(format!("Updated monthly goal for {}", self.branch)).await?;
+        let mut msg = format!("Updated monthly goal for {}", branches.label(self.branch));
+        if self.progress.is_some() {
+            monthly_goal::owners::notify_progress(ctx.ctx, &goal, previous_progress, ctx.cmd.user.id).await;
+            if let Some(announcement) = deadlines::announce_progress(ctx.ctx, &ctx.db_cfg, &goal, previous_progress).await {
+                msg.push_str(format!("\n{announcement}").as_str());
+            }
+        }
+        ctx.reply_restricted(msg).await?;
 
         Ok(())
     }
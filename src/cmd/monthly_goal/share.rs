@@ -0,0 +1,58 @@
+use serenity::all::{CommandInteraction, CreateAllowedMentions, CreateInteractionResponse, CreateInteractionResponseMessage, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, monthly_goal::{self, pledges}}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    shortname: &'a str,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(_cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        let mut shortname = None;
+        for opt in options {
+            match opt.name {
+                "shortname" => {
+                    let ResolvedValue::String(u) = opt.value else {
+                        trc::error!("Bad value for `shortname` in `monthly_goal share` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `shortname` in `monthly_goal share`.".into()));
+                    };
+                    shortname = Some(u);
+                }
+                _ => {
+                    trc::error!("Unknown option `{}` for `monthly_goal share`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `monthly_goal share`".into()));
+                }
+            }
+        }
+
+        let Some(shortname) = shortname else {
+            trc::error!("Missing value for `shortname` in `monthly_goal share`");
+            return Err(RequestError::Internal("Missing value for `shortname` in `monthly_goal share`.".into()));
+        };
+
+        Ok(Self {
+            shortname,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let guild_id = ctx.cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?;
+        let goal = monthly_goal::load_active(&ctx.db_cfg, guild_id, self.shortname, Locale::of(ctx.cmd)).await?;
+        let pledges = pledges::load_pledges(&ctx.db_cfg, &[goal.id]).await?;
+
+        let message = CreateInteractionResponseMessage::new()
+            .content(pledges::render_card(&goal, &pledges))
+            .components(pledges::buttons(&goal))
+            .allowed_mentions(CreateAllowedMentions::new());
+        if let Err(e) = ctx.cmd.create_response(ctx.ctx, CreateInteractionResponse::Message(message)).await {
+            trc::error!("Failed to post card of goal {} due to {e:?}.", goal.id);
+            return Err(RequestError::Internal("Failed to post the goal card.".into()));
+        }
+        *ctx.is_first_response.lock().await = false;
+        Ok(())
+    }
+}
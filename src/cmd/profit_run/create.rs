@@ -0,0 +1,202 @@
+use bigdecimal::{BigDecimal, FromPrimitive, Signed, Zero};
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue, UserId};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::{generic_tracker::{limits, share::MAX_CREW}, options, permissions}, profit_run}, db::{self, CrewShare, DiscordGuildId, DiscordUserId, TrackerStat}, locale::Locale};
+
+// Matches the column width of `profit_runs`.
+const MAX_NAME_LENGTH: usize = 100;
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    guild_id: DiscordGuildId,
+    name: &'a str,
+    owner: DiscordUserId,
+    gross: BigDecimal,
+    costs: BigDecimal,
+    crew: Vec<(DiscordUserId, BigDecimal)>,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can record profit runs.".into()));
+        }
+        let locale = Locale::of(cmd);
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut name = None;
+        let mut owner = cmd.user.id;
+        let mut gross = None;
+        let mut costs = BigDecimal::zero();
+        let mut crew: [Option<UserId>; MAX_CREW] = [None; MAX_CREW];
+        let mut percents: [Option<BigDecimal>; MAX_CREW] = Default::default();
+        for opt in options {
+            if let Some(slot) = options::numbered_slot(opt.name, "crew", MAX_CREW) {
+                let ResolvedValue::User(u, _) = opt.value else {
+                    trc::error!("Bad value for `{}` in `industry run create` {:?}", opt.name, opt);
+                    return Err(RequestError::Internal(format!("Bad value for `{}` in `industry run create`.", opt.name).into()));
+                };
+                crew[slot] = Some(u.id);
+                continue;
+            }
+            if let Some(slot) = options::numbered_slot(opt.name, "share", MAX_CREW) {
+                let ResolvedValue::Number(p) = opt.value else {
+                    trc::error!("Bad value for `{}` in `industry run create` {:?}", opt.name, opt);
+                    return Err(RequestError::Internal(format!("Bad value for `{}` in `industry run create`.", opt.name).into()));
+                };
+                let Some(p) = BigDecimal::from_f64(p).map(|p| profit_run::round_percent(&p)).filter(|p| p.is_positive() && *p <= BigDecimal::from(100)) else {
+                    return Err(RequestError::User(locale.format("`{}` must be a percentage above 0 and at most 100.", &[&opt.name]).into()));
+                };
+                percents[slot] = Some(p);
+                continue;
+            }
+            match opt.name {
+                "name" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `name` in `industry run create` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `name` in `industry run create`.".into()));
+                    };
+                    name = Some(s.trim());
+                },
+                "owner" => {
+                    let ResolvedValue::User(u, _) = opt.value else {
+                        trc::error!("Bad value for `owner` in `industry run create` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `owner` in `industry run create`.".into()));
+                    };
+                    owner = u.id;
+                },
+                "gross" | "costs" => {
+                    let ResolvedValue::Integer(k) = opt.value else {
+                        trc::error!("Bad value for `{}` in `industry run create` {:?}", opt.name, opt);
+                        return Err(RequestError::Internal(format!("Bad value for `{}` in `industry run create`.", opt.name).into()));
+                    };
+                    if k < 0 {
+                        return Err(RequestError::User(locale.format("`{}` can't be negative.", &[&opt.name]).into()));
+                    }
+                    if opt.name == "gross" {
+                        gross = Some(BigDecimal::from(k));
+                    } else {
+                        costs = BigDecimal::from(k);
+                    }
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `industry run create`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `industry run create`".into()));
+                },
+            }
+        }
+
+        let (Some(name), Some(gross)) = (name, gross) else {
+            trc::error!("Missing value for `name` or `gross` in `industry run create`");
+            return Err(RequestError::Internal("Missing value in `industry run create`.".into()));
+        };
+        if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+            return Err(RequestError::User(locale.format("Run names must be between 1 and {} characters.", &[&MAX_NAME_LENGTH]).into()));
+        }
+        if costs > gross {
+            return Err(RequestError::User("The run cost more than it made, so there's no profit to split.".into()));
+        }
+
+        let mut members: Vec<(DiscordUserId, BigDecimal)> = vec![];
+        for (slot, (user_id, percent)) in crew.into_iter().zip(percents).enumerate() {
+            match (user_id, percent) {
+                (Some(user_id), Some(percent)) => {
+                    let user_id = DiscordUserId::from(user_id);
+                    if members.iter().any(|(member, _)| *member == user_id) {
+                        return Err(RequestError::User(locale.format("{} is listed more than once.", &[&user_id.inner().mention()]).into()));
+                    }
+                    members.push((user_id, percent));
+                },
+                (Some(_), None) => return Err(RequestError::User(locale.format("`crew{}` needs a `share{}`.", &[&(slot + 1), &(slot + 1)]).into())),
+                (None, Some(_)) => return Err(RequestError::User(locale.format(
+                    "`share{}` was given without a `crew{}`.",
+                    &[&(slot + 1), &(slot + 1)],
+                ).into())),
+                (None, None) => {},
+            }
+        }
+        if members.iter().map(|(_, percent)| percent).sum::<BigDecimal>() > BigDecimal::from(100) {
+            return Err(RequestError::User("The crew's shares add up to more than 100%.".into()));
+        }
+
+        Ok(Self {
+            guild_id,
+            name,
+            owner: owner.into(),
+            gross,
+            costs,
+            crew: members,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let Self { guild_id, name, owner, gross, costs, crew } = self;
+        let stat = TrackerStat::IndustryAuec;
+        let net = &gross - &costs;
+        let percents: Vec<BigDecimal> = crew.iter().map(|(_, percent)| percent.clone()).collect();
+        let shares: Vec<CrewShare> = crew.into_iter()
+            .zip(profit_run::compute_shares(&net, &percents))
+            .map(|((user_id, percent), amount)| CrewShare { user_id, percent, amount })
+            .collect();
+        let kept = &net - shares.iter().map(|share| &share.amount).sum::<BigDecimal>();
+
+        let note = format!("Profit run `{name}`");
+        let credits: Vec<_> = shares.iter()
+            .map(|share| (share.user_id, share.amount.clone()))
+            .chain(std::iter::once((owner, kept.clone())))
+            .filter(|(_, amount)| amount.is_positive())
+            .map(|(target, amount)| db::NewTrackerCountChange {
+                stat,
+                guild_id,
+                updater: ctx.cmd.user.id.into(),
+                target,
+                total: amount,
+                user_note: Some(note.clone()),
+            })
+            .collect();
+        let flags = limits::enforce(ctx, guild_id, &credits).await?;
+
+        let run = match db::ProfitRun::create(&ctx.db_cfg, db::NewProfitRun {
+            guild_id,
+            owner,
+            recorded_by: ctx.cmd.user.id.into(),
+            name,
+            gross,
+            costs,
+        }, shares.clone(), credits).await {
+            Ok(run) => run,
+            Err(e) => {
+                trc::error!("Failed to record profit run {name:?} due to {e:?}.");
+                return Err(RequestError::Internal("Failed to record profit run.".into()));
+            },
+        };
+
+        let locale = Locale::of(ctx.cmd);
+        let mut buffer = locale.format("Recorded profit run #{} `{}`: {} net, funded by {}.\n", &[
+            &run.id,
+            &run.name,
+            &stat.format_count_in(locale, run.net()),
+            &owner.inner().mention(),
+        ]);
+        for share in &shares {
+            let owed = if share.user_id == owner {
+                ""
+            } else {
+                locale.translate(", owed by the owner")
+            };
+            buffer.push_str(format!(
+                "- {}: {} ({}%){owed}\n",
+                share.user_id.inner().mention(),
+                stat.format_count_in(locale, share.amount.clone()),
+                profit_run::round_percent(&share.percent),
+            ).as_str());
+        }
+        buffer.push_str(locale.format("- {} keeps {}\n", &[&owner.inner().mention(), &stat.format_count_in(locale, kept)]).as_str());
+        buffer.push_str(locale.format("Use `/industry run paid run:{}` once a share has been paid out.", &[&run.id]).as_str());
+        let reply = ctx.reply(buffer).await;
+        limits::flag(ctx, guild_id, flags).await;
+        reply
+    }
+}
@@ -0,0 +1,58 @@
+pub mod create;
+pub mod outstanding;
+pub mod paid;
+pub mod show;
+
+use bigdecimal::{BigDecimal, RoundingMode};
+
+/// Decimal places kept of a crew member's percentage.
+const PERCENT_SCALE: i64 = 2;
+
+/// Rounds a percentage to hundredths. Discord sends numbers as floats, so `33.3` would otherwise be kept as the long
+/// binary expansion closest to it.
+pub fn round_percent(percent: &BigDecimal) -> BigDecimal {
+    percent.with_scale_round(PERCENT_SCALE, RoundingMode::HalfEven).normalized()
+}
+
+/// Each crew member's cut of `net` in whole credits, rounded down. Whatever rounding leaves over stays with the owner.
+pub fn compute_shares(net: &BigDecimal, percents: &[BigDecimal]) -> Vec<BigDecimal> {
+    percents.iter()
+        .map(|percent| (net * percent / BigDecimal::from(100)).with_scale_round(0, RoundingMode::Down))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    use bigdecimal::FromPrimitive;
+
+    use super::{compute_shares, round_percent};
+
+    fn dec(s: &str) -> BigDecimal {
+        BigDecimal::from_str(s).expect("valid test decimal")
+    }
+
+    #[test]
+    fn test_compute_shares() {
+        let shares = compute_shares(&dec("100000"), &[dec("50"), dec("25"), dec("12.5")]);
+        assert_eq!(shares, vec![dec("50000"), dec("25000"), dec("12500")]);
+
+        // Fractions of a credit are dropped rather than rounded up, so the crew is never paid more than the run made.
+        let shares = compute_shares(&dec("1000"), &[dec("33.3"), dec("33.3"), dec("33.3")]);
+        assert_eq!(shares, vec![dec("333"), dec("333"), dec("333")]);
+        assert!(shares.iter().sum::<BigDecimal>() <= dec("1000"));
+    }
+
+    #[test]
+    fn test_round_percent() {
+        let percent = BigDecimal::from_f64(33.3).expect("finite");
+        assert_ne!(percent, dec("33.3"));
+        assert_eq!(round_percent(&percent), dec("33.3"));
+        assert_eq!(round_percent(&percent).to_string(), "33.3");
+        assert_eq!(round_percent(&dec("12.345")).to_string(), "12.34");
+        assert_eq!(round_percent(&dec("50")).to_string(), "50");
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use bigdecimal::BigDecimal;
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::RequestError, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: DiscordGuildId,
+    owner: Option<DiscordUserId>,
+    member: Option<DiscordUserId>,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut owner = None;
+        let mut member = None;
+        for opt in options {
+            match opt.name {
+                "owner" | "member" => {
+                    let ResolvedValue::User(u, _) = opt.value else {
+                        trc::error!("Bad value for `{}` in `industry run outstanding` {:?}", opt.name, opt);
+                        return Err(RequestError::Internal(format!("Bad value for `{}` in `industry run outstanding`.", opt.name).into()));
+                    };
+                    if opt.name == "owner" {
+                        owner = Some(u.id.into());
+                    } else {
+                        member = Some(u.id.into());
+                    }
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `industry run outstanding`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `industry run outstanding`".into()));
+                },
+            }
+        }
+
+        Ok(Self {
+            guild_id,
+            owner,
+            member,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let stat = TrackerStat::IndustryAuec;
+        let outstanding = match db::ProfitRun::load_outstanding(&ctx.db_cfg, self.guild_id, self.owner, self.member).await {
+            Ok(outstanding) => outstanding,
+            Err(e) => {
+                trc::error!("Failed to load outstanding payouts for {:?} due to {e:?}.", self.guild_id);
+                return Err(RequestError::Internal("Failed to load outstanding payouts.".into()));
+            },
+        };
+
+        if outstanding.is_empty() {
+            return ctx.reply_restricted(locale.translate("No payouts are outstanding.").to_owned()).await;
+        }
+
+        let mut owed_by: BTreeMap<DiscordUserId, BigDecimal> = BTreeMap::new();
+        let mut buffer = locale.translate("**Outstanding payouts:**\n").to_owned();
+        for (run, share) in outstanding {
+            buffer.push_str(locale.format("- #{} `{}`: {} owes {} {}\n", &[
+                &run.id,
+                &run.name,
+                &run.owner.inner().mention(),
+                &share.user_id.inner().mention(),
+                &stat.format_count_in(locale, share.amount.clone()),
+            ]).as_str());
+            *owed_by.entry(run.owner).or_default() += share.amount;
+        }
+        for (owner, total) in owed_by {
+            buffer.push_str(locale.format("{} owes {} in total.\n", &[&owner.inner().mention(), &stat.format_count_in(locale, total)]).as_str());
+        }
+        ctx.reply_restricted(buffer).await
+    }
+}
@@ -0,0 +1,93 @@
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::RequestError, db::{self, DiscordGuildId, DiscordUserId, TrackerStat}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: DiscordGuildId,
+    run_id: i64,
+    member: DiscordUserId,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut run_id = None;
+        let mut member = cmd.user.id;
+        for opt in options {
+            match opt.name {
+                "run" => {
+                    let ResolvedValue::Integer(r) = opt.value else {
+                        trc::error!("Bad value for `run` in `industry run paid` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `run` in `industry run paid`.".into()));
+                    };
+                    run_id = Some(r);
+                },
+                "member" => {
+                    let ResolvedValue::User(u, _) = opt.value else {
+                        trc::error!("Bad value for `member` in `industry run paid` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `member` in `industry run paid`.".into()));
+                    };
+                    member = u.id;
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `industry run paid`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `industry run paid`".into()));
+                },
+            }
+        }
+
+        let Some(run_id) = run_id else {
+            trc::error!("Missing value for `run` in `industry run paid`");
+            return Err(RequestError::Internal("Missing value for `run` in `industry run paid`.".into()));
+        };
+
+        Ok(Self {
+            guild_id,
+            run_id,
+            member: member.into(),
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let Self { guild_id, run_id, member } = self;
+        let (run, shares) = match db::ProfitRun::load_with_shares(&ctx.db_cfg, guild_id, run_id).await {
+            Ok(Some(loaded)) => loaded,
+            Ok(None) => return Err(RequestError::User(locale.format("There's no profit run #{}.", &[&run_id]).into())),
+            Err(e) => {
+                trc::error!("Failed to load profit run {run_id} due to {e:?}.");
+                return Err(RequestError::Internal("Failed to load profit run.".into()));
+            },
+        };
+
+        // Either side of the payout can confirm it, but nobody else.
+        let caller = DiscordUserId::from(ctx.cmd.user.id);
+        if caller != run.owner && caller != member {
+            return Err(RequestError::User("Only the run's owner or the crew member being paid can mark a share as paid.".into()));
+        }
+        let Some(share) = shares.iter().find(|share| share.user_id == member) else {
+            return Err(RequestError::User(locale.format("{} wasn't crew on run #{}.", &[&member.inner().mention(), &run_id]).into()));
+        };
+
+        match db::ProfitRun::mark_paid(&ctx.db_cfg, guild_id, run.id, member).await {
+            Ok(0) => Err(RequestError::User(locale.format("{}'s share of run #{} was already paid.", &[&member.inner().mention(), &run_id]).into())),
+            Ok(_) => {
+                let msg = locale.format("Marked {}'s share of {} from run #{} `{}` as paid.", &[
+                    &member.inner().mention(),
+                    &TrackerStat::IndustryAuec.format_count_in(locale, share.amount.clone()),
+                    &run_id,
+                    &run.name,
+                ]);
+                ctx.reply(msg).await
+            },
+            Err(e) => {
+                trc::error!("Failed to mark share of {member:?} in run {run_id} as paid due to {e:?}.");
+                Err(RequestError::Internal("Failed to mark payout as paid.".into()))
+            },
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use bigdecimal::BigDecimal;
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, profit_run}, db::{self, DiscordGuildId, TrackerStat}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: DiscordGuildId,
+    run_id: i64,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut run_id = None;
+        for opt in options {
+            match opt.name {
+                "run" => {
+                    let ResolvedValue::Integer(r) = opt.value else {
+                        trc::error!("Bad value for `run` in `industry run show` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `run` in `industry run show`.".into()));
+                    };
+                    run_id = Some(r);
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `industry run show`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `industry run show`".into()));
+                },
+            }
+        }
+
+        let Some(run_id) = run_id else {
+            trc::error!("Missing value for `run` in `industry run show`");
+            return Err(RequestError::Internal("Missing value for `run` in `industry run show`.".into()));
+        };
+
+        Ok(Self {
+            guild_id,
+            run_id,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let stat = TrackerStat::IndustryAuec;
+        let (run, shares) = match db::ProfitRun::load_with_shares(&ctx.db_cfg, self.guild_id, self.run_id).await {
+            Ok(Some(loaded)) => loaded,
+            Ok(None) => return Err(RequestError::User(locale.format("There's no profit run #{}.", &[&self.run_id]).into())),
+            Err(e) => {
+                trc::error!("Failed to load profit run {} due to {e:?}.", self.run_id);
+                return Err(RequestError::Internal("Failed to load profit run.".into()));
+            },
+        };
+
+        let kept = run.net() - shares.iter().map(|share| &share.amount).sum::<BigDecimal>();
+        let mut buffer = locale.format("**Profit run #{} `{}`** ({}), funded by {}\nGross {}, costs {}, net {}.\n", &[
+            &run.id,
+            &run.name,
+            &run.created.format("%Y-%m-%d"),
+            &run.owner.inner().mention(),
+            &stat.format_count_in(locale, run.gross.clone()),
+            &stat.format_count_in(locale, run.costs.clone()),
+            &stat.format_count_in(locale, run.net()),
+        ]);
+        for share in shares {
+            let status = match share.paid {
+                _ if share.user_id == run.owner => locale.translate("own share").to_owned(),
+                Some(paid) => locale.format("paid {}", &[&paid.format("%Y-%m-%d")]),
+                None => locale.translate("**unpaid**").to_owned(),
+            };
+            buffer.push_str(format!(
+                "- {}: {} ({}%), {status}\n",
+                share.user_id.inner().mention(),
+                stat.format_count_in(locale, share.amount),
+                profit_run::round_percent(&share.percent),
+            ).as_str());
+        }
+        buffer.push_str(locale.format("- {} keeps {}\n", &[&run.owner.inner().mention(), &stat.format_count_in(locale, kept)]).as_str());
+        ctx.reply_restricted(buffer).await
+    }
+}
@@ -0,0 +1,116 @@
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::permissions, season}, db, locale::Locale};
+
+// Matches the column width of `seasons`.
+const MAX_NAME_LENGTH: usize = 100;
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    guild_id: db::DiscordGuildId,
+    name: &'a str,
+    starts: chrono::NaiveDate,
+    ends: chrono::NaiveDate,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can change seasons.".into()));
+        }
+        let locale = Locale::of(cmd);
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut name = None;
+        let mut starts = None;
+        let mut ends = None;
+        for opt in options {
+            match opt.name {
+                "name" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `name` in `season create` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `name` in `season create`.".into()));
+                    };
+                    name = Some(s.trim());
+                },
+                "starts" | "ends" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `{}` in `season create` {:?}", opt.name, opt);
+                        return Err(RequestError::Internal(format!("Bad value for `{}` in `season create`.", opt.name).into()));
+                    };
+                    let date = season::parse_date(s)
+                        .ok_or_else(|| RequestError::User(locale.format("`{}` isn't a date like `2026-07-01`.", &[&s]).into()))?;
+                    if opt.name == "starts" {
+                        starts = Some(date);
+                    } else {
+                        ends = Some(date);
+                    }
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `season create`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `season create`".into()));
+                },
+            }
+        }
+
+        let (Some(name), Some(starts), Some(ends)) = (name, starts, ends) else {
+            trc::error!("Missing value for `name`, `starts` or `ends` in `season create`");
+            return Err(RequestError::Internal("Missing value in `season create`.".into()));
+        };
+        if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+            return Err(RequestError::User(locale.format("Season names must be between 1 and {} characters.", &[&MAX_NAME_LENGTH]).into()));
+        }
+        if starts > ends {
+            return Err(RequestError::User("The season has to start before it ends.".into()));
+        }
+
+        Ok(Self {
+            guild_id,
+            name,
+            starts,
+            ends,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let seasons = match db::Season::load_all(&ctx.db_cfg, self.guild_id).await {
+            Ok(seasons) => seasons,
+            Err(e) => {
+                trc::error!("Failed to load seasons for {:?} due to {e:?}.", self.guild_id);
+                return Err(RequestError::Internal("Failed to load seasons.".into()));
+            },
+        };
+        if seasons.iter().any(|season| season.name == self.name) {
+            return Err(RequestError::User(locale.format("There's already a season called `{}`.", &[&self.name]).into()));
+        }
+        if let Some(overlapping) = seasons.iter().find(|season| season.overlaps(self.starts, self.ends)) {
+            return Err(RequestError::User(locale.format(
+                "That overlaps with `{}` ({} to {}). Seasons can't overlap.",
+                &[&overlapping.name, &overlapping.starts, &overlapping.ends],
+            ).into()));
+        }
+
+        let created = match db::Season::create(&ctx.db_cfg, db::NewSeason {
+            guild_id: self.guild_id,
+            name: self.name,
+            starts: self.starts,
+            ends: self.ends,
+        }).await {
+            Ok(created) => created,
+            Err(e) => {
+                trc::error!("Failed to create season {:?} due to {e:?}.", self.name);
+                return Err(RequestError::Internal("Failed to create season.".into()));
+            },
+        };
+
+        ctx.reply(format!(
+            "Created season `{}`, running from {} to {}. Scoreboards will only count records from the season while it runs.",
+            created.name,
+            created.starts,
+            created.ends,
+        )).await
+    }
+}
@@ -0,0 +1,60 @@
+use std::borrow::Cow;
+
+use chrono::Utc;
+use serenity::all::{CommandInteraction, ResolvedOption};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::RequestError, db};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, _options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+
+        Ok(Self {
+            guild_id,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let (seasons, tz) = match (
+            db::Season::load_all(&ctx.db_cfg, self.guild_id).await,
+            db::GuildSettings::load_timezone(&ctx.db_cfg, self.guild_id).await,
+        ) {
+            (Ok(seasons), Ok(tz)) => (seasons, tz),
+            (Err(e), _) | (_, Err(e)) => {
+                trc::error!("Failed to load seasons for {:?} due to {e:?}.", self.guild_id);
+                return Err(RequestError::Internal("Failed to load seasons.".into()));
+            },
+        };
+
+        if seasons.is_empty() {
+            return ctx.reply_restricted("No seasons have been set up! Use `/season create` to add one.".to_owned()).await;
+        }
+
+        let today = Utc::now().with_timezone(&tz).date_naive();
+        let msg: String = std::iter::once(Cow::Borrowed("- "))
+            .chain(seasons.iter()
+                .map(|season| {
+                    let status = if season.finalized.is_some() {
+                        "finished"
+                    } else if season.contains(today) {
+                        "running"
+                    } else if season.starts > today {
+                        "upcoming"
+                    } else {
+                        "tallying"
+                    };
+                    Cow::Owned(format!("`{}` {} to {} ({})", season.name, season.starts, season.ends, status))
+                })
+                .intersperse(Cow::Borrowed("\n- ")))
+            .collect();
+        ctx.reply_restricted(msg).await
+    }
+}
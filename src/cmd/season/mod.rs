@@ -0,0 +1,126 @@
+pub mod create;
+pub mod list;
+pub mod remove;
+pub mod results;
+
+use bigdecimal::{BigDecimal, Zero};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use strum::IntoEnumIterator;
+use tracing as trc;
+
+use azel::db::{Connector, DbResult};
+
+use crate::{cmd::lib::options, db::{self, DiscordGuildId, DiscordUserId, NewSeasonResult, Season, TrackerStat}};
+
+/// How many places per stat make it into the hall of fame.
+pub const HALL_OF_FAME_SIZE: usize = 10;
+
+/// The season running on `today` (in the guild's timezone), if any. Seasons in a guild never overlap.
+pub fn current(seasons: &[Season], today: NaiveDate) -> Option<&Season> {
+    seasons.iter().find(|season| season.contains(today))
+}
+
+pub async fn load_current(connection_maker: &impl Connector, guild_id: DiscordGuildId, now: DateTime<Utc>) -> DbResult<Option<Season>> {
+    let seasons = db::Season::load_all(connection_maker, guild_id).await?;
+    let tz = db::GuildSettings::load_timezone(connection_maker, guild_id).await?;
+    let today = now.with_timezone(&tz).date_naive();
+    Ok(current(&seasons, today).cloned())
+}
+
+/// Standard competition ranking ("1224") of the top `size` places. Ties share a place, and everyone tied for the last
+/// place shown makes it in. Only positive totals are ranked.
+pub fn rank_top(totals: &[(DiscordUserId, BigDecimal)], size: usize) -> Vec<(i32, DiscordUserId, BigDecimal)> {
+    let mut ranked: Vec<(i32, DiscordUserId, BigDecimal)> = vec![];
+    for (position, (user_id, total)) in totals.iter().filter(|(_, total)| total > &BigDecimal::zero()).enumerate() {
+        let rank = match ranked.last() {
+            Some((last_rank, _, last_total)) if last_total == total => *last_rank,
+            _ if position >= size => break,
+            _ => position as i32 + 1,
+        };
+        ranked.push((rank, *user_id, total.clone()));
+    }
+    ranked
+}
+
+/// Writes the final standings of every season that has ended, in its guild's timezone, to the hall of fame.
+pub async fn finalize_ended(connection_maker: &impl Connector, now: DateTime<Utc>) -> DbResult<usize> {
+    // A day of slack covers every timezone, the exact end is checked per guild below.
+    let candidates = db::Season::load_unfinalized_ending_by(connection_maker, now.date_naive() + Duration::days(1)).await?;
+    let mut finalized = 0;
+    for season in candidates {
+        // One season's failure mustn't hold up the others, it's tried again next tick.
+        match finalize(connection_maker, &season, now).await {
+            Ok(true) => {
+                trc::info!("Finalized season {:?} ({}) of {:?}.", season.name, season.id, season.guild_id);
+                finalized += 1;
+            },
+            Ok(false) => {},
+            Err(e) => trc::error!("Failed to finalize season {:?} ({}) of {:?} due to {e:?}.", season.name, season.id, season.guild_id),
+        }
+    }
+    Ok(finalized)
+}
+
+/// Returns false if the season hasn't ended yet in its guild's timezone, or has already been finalized.
+async fn finalize(connection_maker: &impl Connector, season: &Season, now: DateTime<Utc>) -> DbResult<bool> {
+    let tz = db::GuildSettings::load_timezone(connection_maker, season.guild_id).await?;
+    let range = season.period().to_utc_range(tz, now);
+    if range.end > now {
+        return Ok(false);
+    }
+
+    let mut results = vec![];
+    for stat in TrackerStat::iter() {
+        let totals = db::TrackerCount::load_period_totals(connection_maker, stat, season.guild_id, range).await?;
+        results.extend(rank_top(&totals, HALL_OF_FAME_SIZE).into_iter().map(|(rank, user_id, total)| NewSeasonResult {
+            season_id: season.id,
+            stat,
+            rank,
+            user_id,
+            total,
+        }));
+    }
+    db::Season::finalize(connection_maker, season.id, results).await
+}
+
+/// Choices for a season option as `(name, value)` pairs, filtered by what the user has typed so far.
+pub fn autocomplete_choices(seasons: &[Season], partial: &str) -> Vec<(String, String)> {
+    let partial = partial.trim().to_lowercase();
+    seasons.iter()
+        .filter(|season| season.name.to_lowercase().contains(partial.as_str()))
+        .take(options::MAX_CHOICES)
+        .map(|season| (
+            format!("{} ({} to {})", season.name, season.starts, season.ends),
+            season.name.clone(),
+        ))
+        .collect()
+}
+
+pub fn parse_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").ok()
+}
+
+#[cfg(test)]
+mod test {
+    use bigdecimal::BigDecimal;
+    use serenity::all::UserId;
+
+    use crate::db::DiscordUserId;
+
+    use super::rank_top;
+
+    fn user(id: u64) -> DiscordUserId {
+        UserId::new(id).into()
+    }
+
+    #[test]
+    fn test_rank_top_ties() {
+        let totals: Vec<_> = [(1, 9), (2, 7), (3, 7), (4, 5), (5, 5), (6, 3), (7, 0)]
+            .into_iter()
+            .map(|(id, total)| (user(id), BigDecimal::from(total)))
+            .collect();
+        let ranks: Vec<_> = rank_top(&totals, 4).into_iter().map(|(rank, user_id, _)| (rank, user_id)).collect();
+        assert_eq!(ranks, vec![(1, user(1)), (2, user(2)), (2, user(3)), (4, user(4)), (4, user(5))]);
+        assert_eq!(rank_top(&totals, 100).len(), 6);
+    }
+}
@@ -0,0 +1,67 @@
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::permissions}, db, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    guild_id: db::DiscordGuildId,
+    name: &'a str,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can change seasons.".into()));
+        }
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut name = None;
+        for opt in options {
+            match opt.name {
+                "name" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `name` in `season remove` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `name` in `season remove`.".into()));
+                    };
+                    name = Some(s);
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `season remove`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `season remove`".into()));
+                },
+            }
+        }
+
+        let Some(name) = name else {
+            trc::error!("Missing value for `name` in `season remove`");
+            return Err(RequestError::Internal("Missing value for `name` in `season remove`.".into()));
+        };
+
+        Ok(Self {
+            guild_id,
+            name,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let removed = match db::Season::remove(&ctx.db_cfg, self.guild_id, self.name).await {
+            Ok(removed) => removed,
+            Err(e) => {
+                trc::error!("Failed to remove season {:?} due to {e:?}.", self.name);
+                return Err(RequestError::Internal("Failed to remove season.".into()));
+            },
+        };
+
+        if removed == 0 {
+            return Err(RequestError::User(locale.format(
+                "No unfinished season called `{}` exists. Seasons that already made it into the hall of fame can't be removed.",
+                &[&self.name],
+            ).into()));
+        }
+
+        ctx.reply(format!("Removed season `{}`. No records were changed.", self.name)).await
+    }
+}
@@ -0,0 +1,111 @@
+use std::str::FromStr;
+
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue};
+use strum::IntoEnumIterator;
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::RequestError, db::{self, Season, SeasonResult, TrackerStat}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    guild_id: db::DiscordGuildId,
+    name: Option<&'a str>,
+    stat: Option<TrackerStat>,
+}
+
+impl<'a> Request<'a> {
+    pub fn parse(cmd: &CommandInteraction, options: &'_ [ResolvedOption<'a>]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut name = None;
+        let mut stat = None;
+        for opt in options {
+            match opt.name {
+                "name" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `name` in `season results` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `name` in `season results`.".into()));
+                    };
+                    name = Some(s);
+                },
+                "stat" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `stat` in `season results` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `stat` in `season results`.".into()));
+                    };
+                    let Ok(s) = TrackerStat::from_str(s) else {
+                        trc::error!("Unknown stat {s:?} in `season results`");
+                        return Err(RequestError::Internal("Unknown stat in `season results`.".into()));
+                    };
+                    stat = Some(s);
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `season results`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `season results`".into()));
+                },
+            }
+        }
+
+        Ok(Self {
+            guild_id,
+            name,
+            stat,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let seasons = match db::Season::load_all(&ctx.db_cfg, self.guild_id).await {
+            Ok(seasons) => seasons,
+            Err(e) => {
+                trc::error!("Failed to load seasons for {:?} due to {e:?}.", self.guild_id);
+                return Err(RequestError::Internal("Failed to load seasons.".into()));
+            },
+        };
+
+        // Defaults to the most recently finished season.
+        let season = match self.name {
+            Some(name) => seasons.iter().find(|season| season.name == name)
+                .ok_or_else(|| RequestError::User(locale.format("No season called `{}` exists.", &[&name]).into()))?,
+            None => seasons.iter().find(|season| season.finalized.is_some())
+                .ok_or_else(|| RequestError::User("No season has finished yet.".into()))?,
+        };
+        if season.finalized.is_none() {
+            return Err(RequestError::User(locale.format(
+                "`{}` hasn't finished yet. Its results are added to the hall of fame once it ends on {}.",
+                &[&season.name, &season.ends],
+            ).into()));
+        }
+
+        let results = match db::Season::load_results(&ctx.db_cfg, season.id).await {
+            Ok(results) => results,
+            Err(e) => {
+                trc::error!("Failed to load results of season {:?} due to {e:?}.", season.id);
+                return Err(RequestError::Internal("Failed to load season results.".into()));
+            },
+        };
+
+        ctx.reply(format_results(locale, season, &results, self.stat)).await
+    }
+}
+
+fn format_results(locale: Locale, season: &Season, results: &[SeasonResult], only: Option<TrackerStat>) -> String {
+    let mut buffer = locale.format("**Hall of fame: {}** ({} to {})\n", &[&season.name, &season.starts, &season.ends]);
+    for stat in TrackerStat::iter().filter(|stat| only.is_none_or(|only| only == *stat)) {
+        let placings: Vec<_> = results.iter().filter(|result| result.stat == stat).collect();
+        if placings.is_empty() {
+            continue;
+        }
+        buffer.push_str(format!("__{}__\n", stat.display_name_in(locale)).as_str());
+        for result in placings {
+            buffer.push_str(format!(
+                "\t{}) {}: {}\n",
+                result.rank,
+                result.user_id.inner().mention(),
+                stat.format_count_in(locale, result.total.clone()),
+            ).as_str());
+        }
+    }
+    buffer
+}
@@ -0,0 +1,80 @@
+use rand::Rng;
+use serenity::all::{CommandInteraction, CreateInteractionResponse, CreateInteractionResponseMessage, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{api, cmd::{RequestError, lib::permissions}, db};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+    user_id: db::DiscordUserId,
+    revoke: bool,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can manage the API token.".into()));
+        }
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut revoke = false;
+        for opt in options {
+            match opt.name {
+                "revoke" => {
+                    let ResolvedValue::Boolean(b) = opt.value else {
+                        trc::error!("Bad value for `revoke` in `settings api_token` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `revoke` in `settings api_token`.".into()));
+                    };
+                    revoke = b;
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `settings api_token`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `settings api_token`".into()));
+                },
+            }
+        }
+
+        Ok(Self {
+            guild_id,
+            user_id: cmd.user.id.into(),
+            revoke,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        if self.revoke {
+            return match db::GuildApiToken::revoke(&ctx.db_cfg, self.guild_id).await {
+                Ok(0) => Err(RequestError::User("This server doesn't have an API token.".into())),
+                Ok(_) => ctx.reply_restricted("The API token has been revoked.".to_owned()).await,
+                Err(e) => {
+                    trc::error!("Failed to revoke API token for {:?} due to {e:?}.", self.guild_id);
+                    Err(RequestError::Internal("Failed to revoke API token.".into()))
+                },
+            };
+        }
+
+        let token = generate_token();
+        if let Err(e) = db::GuildApiToken::set(&ctx.db_cfg, self.guild_id, self.user_id, api::hash_token(token.as_str()).as_str()).await {
+            trc::error!("Failed to save API token for {:?} due to {e:?}.", self.guild_id);
+            return Err(RequestError::Internal("Failed to save API token.".into()));
+        }
+
+        // Only the hash is kept, so this is the one time the token can be shown, and only to whoever asked for it.
+        let message = CreateInteractionResponseMessage::new()
+            .content(format!("New API token, any previous one no longer works. It won't be shown again:\n||`{token}`||"))
+            .ephemeral(true);
+        if let Err(e) = ctx.cmd.create_response(ctx.ctx, CreateInteractionResponse::Message(message)).await {
+            trc::error!("Failed to send API token for {:?} due to {e:?}.", self.guild_id);
+            return Err(RequestError::Internal("Failed to send API token.".into()));
+        }
+        *ctx.is_first_response.lock().await = false;
+        Ok(())
+    }
+}
+
+/// 256 random bits as hex.
+fn generate_token() -> String {
+    rand::thread_rng().gen::<[u8; 32]>().iter().map(|byte| format!("{byte:02x}")).collect()
+}
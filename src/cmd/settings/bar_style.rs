@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::permissions, monthly_goal}, db::{self, BarStyle}};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+    style: BarStyle,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can change the server's bar style.".into()));
+        }
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut style = None;
+        for opt in options {
+            match opt.name {
+                "style" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `style` in `settings bar_style` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `style` in `settings bar_style`.".into()));
+                    };
+                    let Ok(s) = BarStyle::from_str(s) else {
+                        trc::error!("Unknown style {s:?} in `settings bar_style`");
+                        return Err(RequestError::Internal("Unknown value for `style` in `settings bar_style`.".into()));
+                    };
+                    style = Some(s);
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `settings bar_style`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `settings bar_style`".into()));
+                },
+            }
+        }
+
+        let Some(style) = style else {
+            trc::error!("Missing value for `style` in `settings bar_style`");
+            return Err(RequestError::Internal("Missing value for `style` in `settings bar_style`.".into()));
+        };
+
+        Ok(Self {
+            guild_id,
+            style,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        if let Err(e) = db::GuildSettings::set_bar_style(&ctx.db_cfg, self.guild_id, self.style).await {
+            trc::error!("Failed to set bar style for {:?} due to {e:?}.", self.guild_id);
+            return Err(RequestError::Internal("Failed to save bar style.".into()));
+        }
+        monthly_goal::board::refresh(ctx.ctx, &ctx.db_cfg, *self.guild_id.inner()).await;
+
+        ctx.reply_restricted(format!("Progress bars will be drawn as `{}` unless a command asks otherwise.", self.style.as_str())).await
+    }
+}
@@ -0,0 +1,5 @@
+pub mod timezone;
+pub mod officer_channel;
+pub mod show;
+pub mod bar_style;
+pub mod api_token;
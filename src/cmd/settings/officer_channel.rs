@@ -0,0 +1,54 @@
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, lib::permissions}, db::{self, DiscordChannelId}};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+    channel_id: Option<DiscordChannelId>,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can change the officer channel.".into()));
+        }
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut channel_id = None;
+        for opt in options {
+            match opt.name {
+                "channel" => {
+                    let ResolvedValue::Channel(c) = opt.value else {
+                        trc::error!("Bad value for `channel` in `settings officer_channel` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `channel` in `settings officer_channel`.".into()));
+                    };
+                    channel_id = Some(c.id.into());
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `settings officer_channel`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `settings officer_channel`".into()));
+                },
+            }
+        }
+
+        Ok(Self {
+            guild_id,
+            channel_id,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        if let Err(e) = db::GuildSettings::set_officer_channel(&ctx.db_cfg, self.guild_id, self.channel_id).await {
+            trc::error!("Failed to set officer channel for {:?} due to {e:?}.", self.guild_id);
+            return Err(RequestError::Internal("Failed to save officer channel.".into()));
+        }
+
+        match self.channel_id {
+            Some(channel_id) => ctx.reply_restricted(format!("Records over a soft limit will be flagged in {}.", channel_id.inner().mention())).await,
+            None => ctx.reply_restricted("Records over a soft limit will no longer be flagged.".to_owned()).await,
+        }
+    }
+}
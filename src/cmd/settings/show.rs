@@ -0,0 +1,47 @@
+use chrono::Utc;
+use chrono_tz::Tz;
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::RequestError, db};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, _options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+
+        Ok(Self {
+            guild_id,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let settings = match db::GuildSettings::load_for(&ctx.db_cfg, self.guild_id).await {
+            Ok(settings) => settings,
+            Err(e) => {
+                trc::error!("Failed to load settings for {:?} due to {e:?}.", self.guild_id);
+                return Err(RequestError::Internal("Failed to load server settings.".into()));
+            },
+        };
+        let tz = settings.as_ref().map(db::GuildSettings::tz).unwrap_or(Tz::UTC);
+        let bar_style = settings.as_ref().map(|settings| settings.bar_style).unwrap_or_default();
+        let officer_channel = match settings.and_then(|settings| settings.officer_channel) {
+            Some(channel_id) => channel_id.inner().mention().to_string(),
+            None => "not set".to_owned(),
+        };
+
+        let local_now = Utc::now().with_timezone(&tz);
+        ctx.reply_restricted(format!(
+            "**Settings:**\n- Timezone: `{}` (currently {})\n- Officer channel: {officer_channel}\n- Progress bars: `{}`",
+            tz.name(),
+            local_now.format("%Y-%m-%d %H:%M"),
+            bar_style.as_str(),
+        )).await
+    }
+}
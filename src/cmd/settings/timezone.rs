@@ -0,0 +1,90 @@
+use chrono::Utc;
+use chrono_tz::Tz;
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{calendar, cmd::{RequestError, lib::{options, permissions}}, db, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: db::DiscordGuildId,
+    tz: Tz,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        if !permissions::is_officer(cmd) {
+            return Err(RequestError::User("Only officers can change the server's timezone.".into()));
+        }
+        let locale = Locale::of(cmd);
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut tz = None;
+        for opt in options {
+            match opt.name {
+                "timezone" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `timezone` in `settings timezone` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `timezone` in `settings timezone`.".into()));
+                    };
+                    tz = Some(calendar::parse_timezone(s).ok_or_else(|| RequestError::User(locale.format(
+                        "`{}` isn't a timezone I know. Use an IANA name like `America/New_York` or `Australia/Sydney`.",
+                        &[&s],
+                    ).into()))?);
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `settings timezone`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `settings timezone`".into()));
+                },
+            }
+        }
+
+        let Some(tz) = tz else {
+            trc::error!("Missing value for `timezone` in `settings timezone`");
+            return Err(RequestError::Internal("Missing value for `timezone` in `settings timezone`.".into()));
+        };
+
+        Ok(Self {
+            guild_id,
+            tz,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        if let Err(e) = db::GuildSettings::set_timezone(&ctx.db_cfg, self.guild_id, self.tz).await {
+            trc::error!("Failed to set timezone for {:?} due to {e:?}.", self.guild_id);
+            return Err(RequestError::Internal("Failed to save timezone.".into()));
+        }
+
+        let local_now = Utc::now().with_timezone(&self.tz);
+        ctx.reply_restricted(format!(
+            "Weeks and months are now counted in `{}` (currently {}).",
+            self.tz.name(),
+            local_now.format("%Y-%m-%d %H:%M"),
+        )).await
+    }
+}
+
+/// Choices for a timezone option as `(name, value)` pairs, filtered by what the user has typed so far.
+pub fn autocomplete_choices(partial: &str) -> Vec<(String, String)> {
+    let partial = partial.trim().to_lowercase().replace(' ', "_");
+    chrono_tz::TZ_VARIANTS.iter()
+        .map(|tz| tz.name())
+        .filter(|name| name.to_lowercase().contains(partial.as_str()))
+        .take(options::MAX_CHOICES)
+        .map(|name| (name.to_owned(), name.to_owned()))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::autocomplete_choices;
+
+    #[test]
+    fn test_autocomplete_choices() {
+        assert_eq!(autocomplete_choices("").len(), 25);
+        assert!(autocomplete_choices("new york").iter().any(|(_, value)| value == "America/New_York"));
+        assert!(autocomplete_choices("not a place").is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::RequestError, db::{self, DiscordGuildId, TrackerStat}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: DiscordGuildId,
+    stat: TrackerStat,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut stat = None;
+        for opt in options {
+            match opt.name {
+                "stat" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `stat` in `stats limit_clear` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `stat` in `stats limit_clear`.".into()));
+                    };
+                    let Ok(s) = TrackerStat::from_str(s) else {
+                        trc::error!("Unknown stat {s:?} in `stats limit_clear`");
+                        return Err(RequestError::Internal("Unknown stat in `stats limit_clear`.".into()));
+                    };
+                    stat = Some(s);
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `stats limit_clear`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `stats limit_clear`".into()));
+                },
+            }
+        }
+
+        let Some(stat) = stat else {
+            trc::error!("Missing value for `stat` in `stats limit_clear`");
+            return Err(RequestError::Internal("Missing value for `stat` in `stats limit_clear`.".into()));
+        };
+
+        Ok(Self {
+            guild_id,
+            stat,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let locale = Locale::of(ctx.cmd);
+        let Self { guild_id, stat } = self;
+        match db::GuildStatLimit::remove(&ctx.db_cfg, guild_id, stat).await {
+            Ok(0) => Err(RequestError::User(locale.format("{} doesn't have any limits.", &[&stat.display_name_in(locale)]).into())),
+            Ok(_) => {
+                let msg = locale.format("{} no longer has any limits.", &[&stat.display_name_in(locale)]);
+                ctx.reply_restricted(msg).await
+            },
+            Err(e) => {
+                trc::error!("Failed to clear limits of {stat:?} for {guild_id:?} due to {e:?}.");
+                Err(RequestError::Internal("Failed to clear stat limits.".into()))
+            },
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::str::FromStr;
+
+use bigdecimal::{BigDecimal, FromPrimitive, Signed};
+use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::{RequestError, stats}, db::{self, DiscordGuildId, NewGuildStatLimit, TrackerStat}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: DiscordGuildId,
+    stat: TrackerStat,
+    limits: NewGuildStatLimit,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let locale = Locale::of(cmd);
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut stat = None;
+        let mut limits = NewGuildStatLimit::default();
+        for opt in options {
+            let slot = match opt.name {
+                "stat" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `stat` in `stats limit_set` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `stat` in `stats limit_set`.".into()));
+                    };
+                    let Ok(s) = TrackerStat::from_str(s) else {
+                        trc::error!("Unknown stat {s:?} in `stats limit_set`");
+                        return Err(RequestError::Internal("Unknown stat in `stats limit_set`.".into()));
+                    };
+                    stat = Some(s);
+                    continue;
+                },
+                "max_change_soft" => &mut limits.max_change_soft,
+                "max_change_hard" => &mut limits.max_change_hard,
+                "daily_cap_soft" => &mut limits.daily_cap_soft,
+                "daily_cap_hard" => &mut limits.daily_cap_hard,
+                "self_daily_soft" => &mut limits.self_daily_soft,
+                "self_daily_hard" => &mut limits.self_daily_hard,
+                _ => {
+                    trc::error!("Unknown option `{}` for `stats limit_set`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `stats limit_set`".into()));
+                },
+            };
+            let ResolvedValue::Number(n) = opt.value else {
+                trc::error!("Bad value for `{}` in `stats limit_set` {:?}", opt.name, opt);
+                return Err(RequestError::Internal(format!("Bad value for `{}` in `stats limit_set`.", opt.name).into()));
+            };
+            let Some(n) = BigDecimal::from_f64(n) else {
+                trc::error!("Bad value for `{}` in `stats limit_set` {:?}", opt.name, opt);
+                return Err(RequestError::Internal(format!("Bad value for `{}` in `stats limit_set`.", opt.name).into()));
+            };
+            if n.is_negative() {
+                return Err(RequestError::User(locale.format("`{}` can't be negative.", &[&opt.name]).into()));
+            }
+            *slot = Some(n);
+        }
+
+        let Some(stat) = stat else {
+            trc::error!("Missing value for `stat` in `stats limit_set`");
+            return Err(RequestError::Internal("Missing value for `stat` in `stats limit_set`.".into()));
+        };
+        for (name, soft, hard) in [
+            ("max_change", &limits.max_change_soft, &limits.max_change_hard),
+            ("daily_cap", &limits.daily_cap_soft, &limits.daily_cap_hard),
+            ("self_daily", &limits.self_daily_soft, &limits.self_daily_hard),
+        ] {
+            if let (Some(soft), Some(hard)) = (soft, hard) {
+                if soft > hard {
+                    return Err(RequestError::User(locale.format(
+                        "`{}_soft` has to be at most `{}_hard`, or it would never be flagged.",
+                        &[&name, &name],
+                    ).into()));
+                }
+            }
+        }
+
+        // Limits are entered the way members see the stat, but checked against what's stored.
+        let to_db = |limit: &mut Option<BigDecimal>| *limit = limit.take().map(|limit| stat.db_value(limit));
+        to_db(&mut limits.max_change_soft);
+        to_db(&mut limits.max_change_hard);
+        to_db(&mut limits.daily_cap_soft);
+        to_db(&mut limits.daily_cap_hard);
+        to_db(&mut limits.self_daily_soft);
+        to_db(&mut limits.self_daily_hard);
+
+        Ok(Self {
+            guild_id,
+            stat,
+            limits,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let Self { guild_id, stat, limits } = self;
+        if let Err(e) = db::GuildStatLimit::set(&ctx.db_cfg, guild_id, stat, limits).await {
+            trc::error!("Failed to set limits of {stat:?} for {guild_id:?} due to {e:?}.");
+            return Err(RequestError::Internal("Failed to save stat limits.".into()));
+        }
+
+        let limit = match db::GuildStatLimit::load_for(&ctx.db_cfg, guild_id, stat).await {
+            Ok(Some(limit)) => limit,
+            Ok(None) => {
+                trc::error!("Limits of {stat:?} for {guild_id:?} missing right after being set.");
+                return Err(RequestError::Internal("Failed to load stat limits.".into()));
+            },
+            Err(e) => {
+                trc::error!("Failed to load limits of {stat:?} for {guild_id:?} due to {e:?}.");
+                return Err(RequestError::Internal("Failed to load stat limits.".into()));
+            },
+        };
+        let locale = Locale::of(ctx.cmd);
+        let msg = locale.format("Limits for {} are now:\n{}", &[&stat.display_name_in(locale), &stats::limits::describe(locale, &limit)]);
+        ctx.reply_restricted(msg).await
+    }
+}
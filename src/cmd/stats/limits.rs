@@ -0,0 +1,65 @@
+use serenity::all::{CommandInteraction, ResolvedOption};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::RequestError, db::{self, DiscordGuildId, GuildStatLimit}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: DiscordGuildId,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, _options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+
+        Ok(Self {
+            guild_id,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let limits = match db::GuildStatLimit::load_all(&ctx.db_cfg, self.guild_id).await {
+            Ok(limits) => limits,
+            Err(e) => {
+                trc::error!("Failed to load limits for {:?} due to {e:?}.", self.guild_id);
+                return Err(RequestError::Internal("Failed to load stat limits.".into()));
+            },
+        };
+
+        let locale = Locale::of(ctx.cmd);
+        if limits.is_empty() {
+            return ctx.reply_restricted(locale.translate("No stat limits have been set up! Use `/stats limit_set` to add some.").to_owned()).await;
+        }
+
+        let mut buffer = String::new();
+        for limit in &limits {
+            buffer.push_str(format!("**{}:**\n{}\n", limit.stat.display_name_in(locale), describe(locale, limit)).as_str());
+        }
+        ctx.reply_restricted(buffer).await
+    }
+}
+
+/// One line per kind of limit, e.g. "- At once: flagged over 50, rejected over 200".
+pub fn describe(locale: Locale, limit: &GuildStatLimit) -> String {
+    let stat = limit.stat;
+    [
+        ("At once", &limit.max_change_soft, &limit.max_change_hard),
+        ("Per member per day", &limit.daily_cap_soft, &limit.daily_cap_hard),
+        ("For yourself per day", &limit.self_daily_soft, &limit.self_daily_hard),
+    ]
+        .into_iter()
+        .map(|(name, soft, hard)| {
+            let name = locale.translate(name);
+            let soft = soft.clone().map(|soft| locale.format("flagged over {}", &[&stat.format_count_in(locale, soft)]));
+            let hard = hard.clone().map(|hard| locale.format("rejected over {}", &[&stat.format_count_in(locale, hard)]));
+            let rules: Vec<String> = soft.into_iter().chain(hard).collect();
+            match rules.is_empty() {
+                true => format!("- {name}: {}", locale.translate("no limit")),
+                false => format!("- {name}: {}", rules.join(", ")),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
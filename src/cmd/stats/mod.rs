@@ -0,0 +1,4 @@
+pub mod limit_clear;
+pub mod limit_set;
+pub mod limits;
+pub mod transfer;
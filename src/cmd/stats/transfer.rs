@@ -0,0 +1,137 @@
+use std::str::FromStr;
+
+use serenity::all::{CommandInteraction, Mentionable, ResolvedOption, ResolvedValue};
+use tracing as trc;
+
+use azel::discord::ExecutionContext;
+
+use crate::{cmd::RequestError, db::{self, DiscordGuildId, DiscordUserId, StatTransfer, TrackerStat}, locale::Locale};
+
+#[derive(Debug)]
+pub struct Request {
+    guild_id: DiscordGuildId,
+    from: DiscordUserId,
+    to: DiscordUserId,
+    stat: Option<TrackerStat>,
+    confirm: bool,
+}
+
+impl Request {
+    pub fn parse(cmd: &CommandInteraction, options: &[ResolvedOption]) -> Result<Self, RequestError> {
+        let guild_id = cmd.guild_id.ok_or_else(|| RequestError::User("Command must be run from within a guild.".into()))?.into();
+        let mut from = None;
+        let mut to = None;
+        let mut stat = None;
+        let mut confirm = false;
+        for opt in options {
+            match opt.name {
+                "from" => {
+                    let ResolvedValue::User(u, _) = opt.value else {
+                        trc::error!("Bad value for `from` in `stats transfer` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `from` in `stats transfer`.".into()));
+                    };
+                    from = Some(DiscordUserId::from(u.id));
+                },
+                "to" => {
+                    let ResolvedValue::User(u, _) = opt.value else {
+                        trc::error!("Bad value for `to` in `stats transfer` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `to` in `stats transfer`.".into()));
+                    };
+                    to = Some(DiscordUserId::from(u.id));
+                },
+                "stat" => {
+                    let ResolvedValue::String(s) = opt.value else {
+                        trc::error!("Bad value for `stat` in `stats transfer` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `stat` in `stats transfer`.".into()));
+                    };
+                    let Ok(s) = TrackerStat::from_str(s) else {
+                        trc::error!("Unknown stat {s:?} in `stats transfer`");
+                        return Err(RequestError::Internal("Unknown stat in `stats transfer`.".into()));
+                    };
+                    stat = Some(s);
+                },
+                "confirm" => {
+                    let ResolvedValue::Boolean(b) = opt.value else {
+                        trc::error!("Bad value for `confirm` in `stats transfer` {:?}", opt);
+                        return Err(RequestError::Internal("Bad value for `confirm` in `stats transfer`.".into()));
+                    };
+                    confirm = b;
+                },
+                _ => {
+                    trc::error!("Unknown option `{}` for `stats transfer`", opt.name);
+                    return Err(RequestError::Internal("Unknown option in `stats transfer`".into()));
+                },
+            }
+        }
+
+        let (Some(from), Some(to)) = (from, to) else {
+            trc::error!("Missing value for `from` or `to` in `stats transfer`");
+            return Err(RequestError::Internal("Missing value for `from` or `to` in `stats transfer`.".into()));
+        };
+        if from == to {
+            return Err(RequestError::User("Stats can't be transferred to the same account.".into()));
+        }
+
+        Ok(Self {
+            guild_id,
+            from,
+            to,
+            stat,
+            confirm,
+        })
+    }
+
+    pub async fn execute(self, ctx: &ExecutionContext<'_>) -> Result<(), RequestError> {
+        let Self { guild_id, from, to, stat, confirm } = self;
+        let stats = stat.as_ref().map(std::slice::from_ref);
+
+        let transfers = if confirm {
+            let note = format!("Transferred from {} to {}", from.inner(), to.inner());
+            match db::TrackerCount::transfer(&ctx.db_cfg, guild_id, ctx.cmd.user.id.into(), from, to, stats, note.as_str()).await {
+                Ok(transfers) => transfers,
+                Err(e) => {
+                    trc::error!("Failed to transfer stats from {from:?} to {to:?} due to {e:?}.");
+                    return Err(RequestError::Internal("Failed to transfer stats.".into()));
+                },
+            }
+        } else {
+            let load = |user_id| db::TrackerCount::load_all_for_user(&ctx.db_cfg, guild_id, user_id);
+            match (load(from).await, load(to).await) {
+                (Ok(from_records), Ok(to_records)) => db::plan_transfer(&from_records, &to_records, stats),
+                (Err(e), _) | (_, Err(e)) => {
+                    trc::error!("Failed to load stats for transfer from {from:?} to {to:?} due to {e:?}.");
+                    return Err(RequestError::Internal("Failed to load stats.".into()));
+                },
+            }
+        };
+
+        ctx.reply_restricted(format_transfers(Locale::of(ctx.cmd), from, to, &transfers, confirm)).await
+    }
+}
+
+fn format_transfers(locale: Locale, from: DiscordUserId, to: DiscordUserId, transfers: &[StatTransfer], confirmed: bool) -> String {
+    let from = from.inner().mention();
+    let to = to.inner().mention();
+    if transfers.is_empty() {
+        return locale.format("{} has no matching stats to transfer.", &[&from]);
+    }
+
+    let mut buffer = if confirmed {
+        locale.format("**Transferred from {} to {}:**\n", &[&from, &to])
+    } else {
+        locale.format("**Preview of transfer from {} to {}:**\n", &[&from, &to])
+    };
+    for transfer in transfers {
+        buffer.push_str(format!(
+            "- {}: {} ({} → {})\n",
+            transfer.stat.display_name_in(locale),
+            transfer.stat.format_count_in(locale, transfer.moved.clone()),
+            transfer.stat.display_value(transfer.before.clone()),
+            transfer.stat.display_value(transfer.after.clone()),
+        ).as_str());
+    }
+    if !confirmed {
+        buffer.push_str(locale.translate("Nothing has been changed yet. Run the command again with `confirm` set to apply it."));
+    }
+    buffer
+}
@@ -0,0 +1,237 @@
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, prelude::{Identifiable, Insertable, Queryable}};
+use diesel_async::{AsyncConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
+
+use crate::{calendar::UtcRange, db::{AdjustmentError, DiscordChannelId, DiscordGuildId, DiscordMessageId, DiscordUserId, NewTrackerCountChange, tracker::apply_change_returning_id}, schema};
+
+use azel::db::{Connector, DbResult};
+
+mod rsvp_response {
+    use std::str::FromStr;
+
+    use diesel::{deserialize::FromSqlRow, expression::AsExpression, pg::Pg, sql_types::Text};
+    use diesel_pg_type_utils::impl_sql_convert;
+    use strum::{EnumIter, EnumString, IntoStaticStr};
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[derive(IntoStaticStr, EnumString, EnumIter)]
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(sql_type = Text)]
+    pub enum RsvpResponse {
+        #[strum(serialize = "attending")]
+        Attending,
+        #[strum(serialize = "maybe")]
+        Maybe,
+        #[strum(serialize = "declined")]
+        Declined,
+    }
+
+    impl RsvpResponse {
+        pub fn as_str(&self) -> &'static str {
+            self.into()
+        }
+    }
+
+    impl_sql_convert!(
+        <Pg>
+        Text > String > RsvpResponse
+        |s| {
+            RsvpResponse::from_str(s.as_str())
+                .ok().ok_or("bad value")?
+        }
+        |response| {
+            &response.as_str().to_owned()
+        }
+    );
+}
+pub use rsvp_response::RsvpResponse;
+
+#[derive(Debug, Clone)]
+#[derive(Insertable)]
+#[diesel(table_name = schema::events)]
+pub struct NewEvent<'a> {
+    pub guild_id: DiscordGuildId,
+    pub host: DiscordUserId,
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub starts: DateTime<Utc>,
+    pub channel_id: DiscordChannelId,
+}
+
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::events)]
+pub struct Event {
+    pub id: i64,
+    pub created: DateTime<Utc>,
+    pub guild_id: DiscordGuildId,
+    pub host: DiscordUserId,
+    pub name: String,
+    pub description: Option<String>,
+    pub starts: DateTime<Utc>,
+    pub channel_id: DiscordChannelId,
+    /// The event card, once it's been posted.
+    pub message_id: Option<DiscordMessageId>,
+    pub roll_called: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+#[derive(Queryable)]
+#[diesel(table_name = schema::event_rsvps)]
+pub struct EventRsvp {
+    pub event_id: i64,
+    pub user_id: DiscordUserId,
+    pub updated: DateTime<Utc>,
+    pub response: RsvpResponse,
+}
+
+impl Event {
+    pub async fn create(connection_maker: &impl Connector, new: NewEvent<'_>) -> DbResult<Self> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(diesel::insert_into(schema::events::table)
+            .values(new)
+            .get_result(&mut conn)
+            .await?)
+    }
+
+    pub async fn set_message(connection_maker: &impl Connector, event_id: i64, message_id: DiscordMessageId) -> DbResult<()> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        diesel::update(schema::events::table.filter(schema::events::id.eq(event_id)))
+            .set(schema::events::message_id.eq(Some(message_id)))
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn load(connection_maker: &impl Connector, guild_id: DiscordGuildId, event_id: i64) -> DbResult<Option<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::events::table
+            .filter(schema::events::guild_id.eq(guild_id))
+            .filter(schema::events::id.eq(event_id))
+            .get_result(&mut conn)
+            .await
+            .optional()?)
+    }
+
+    /// Events that haven't had their roll call yet, soonest first.
+    pub async fn load_open(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::events::table
+            .filter(schema::events::guild_id.eq(guild_id))
+            .filter(schema::events::roll_called.is_null())
+            .order_by(schema::events::starts)
+            .get_results(&mut conn)
+            .await?)
+    }
+
+    pub async fn set_rsvp(connection_maker: &impl Connector, event_id: i64, user_id: DiscordUserId, response: RsvpResponse) -> DbResult<()> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        diesel::insert_into(schema::event_rsvps::table)
+            .values((
+                schema::event_rsvps::event_id.eq(event_id),
+                schema::event_rsvps::user_id.eq(user_id),
+                schema::event_rsvps::response.eq(response),
+            ))
+            .on_conflict((schema::event_rsvps::event_id, schema::event_rsvps::user_id))
+            .do_update()
+            .set((
+                schema::event_rsvps::updated.eq(diesel::dsl::now),
+                schema::event_rsvps::response.eq(response),
+            ))
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// In the order people answered.
+    pub async fn load_rsvps(connection_maker: &impl Connector, event_id: i64) -> DbResult<Vec<EventRsvp>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::event_rsvps::table
+            .filter(schema::event_rsvps::event_id.eq(event_id))
+            .order_by((schema::event_rsvps::updated, schema::event_rsvps::user_id))
+            .get_results(&mut conn)
+            .await?)
+    }
+
+    /// Marks the roll call as done and credits every attendee, all or nothing. `credits` are linked to the event by
+    /// their target. Returns false without crediting anyone if the roll call had already been done.
+    pub async fn roll_call(connection_maker: &impl Connector, event_id: i64, credits: Vec<NewTrackerCountChange>) -> Result<bool, AdjustmentError> {
+        let mut conn = connection_maker.async_connect().await
+            .map_err(AdjustmentError::Connect)?;
+
+        conn.transaction::<_, AdjustmentError, _>(|conn| async move {
+            let updated = diesel::update(
+                schema::events::table
+                    .filter(schema::events::id.eq(event_id))
+                    .filter(schema::events::roll_called.is_null())
+            )
+                .set(schema::events::roll_called.eq(Some(Utc::now())))
+                .execute(conn)
+                .await
+                .map_err(AdjustmentError::Change)?;
+            if updated == 0 {
+                return Ok(false);
+            }
+
+            for credit in &credits {
+                let (change_id, _) = apply_change_returning_id(conn, credit).await?;
+                diesel::insert_into(schema::event_attendees::table)
+                    .values((
+                        schema::event_attendees::event_id.eq(event_id),
+                        schema::event_attendees::user_id.eq(credit.target),
+                        schema::event_attendees::change_id.eq(change_id),
+                    ))
+                    .execute(conn)
+                    .await
+                    .map_err(AdjustmentError::Change)?;
+            }
+
+            Ok(true)
+        }.scope_boxed()).await
+    }
+
+    /// How many of the guild's events starting in `range` have had their roll call, and how many of those `user_id`
+    /// attended.
+    pub async fn count_attendance(connection_maker: &impl Connector, guild_id: DiscordGuildId, user_id: DiscordUserId, range: UtcRange) -> DbResult<(i64, i64)> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        let held: i64 = schema::events::table
+            .filter(schema::events::guild_id.eq(guild_id))
+            .filter(schema::events::roll_called.is_not_null())
+            .filter(schema::events::starts.ge(range.start))
+            .filter(schema::events::starts.lt(range.end))
+            .count()
+            .get_result(&mut conn)
+            .await?;
+        let attended: i64 = schema::event_attendees::table
+            .inner_join(schema::events::table)
+            .filter(schema::events::guild_id.eq(guild_id))
+            .filter(schema::events::roll_called.is_not_null())
+            .filter(schema::events::starts.ge(range.start))
+            .filter(schema::events::starts.lt(range.end))
+            .filter(schema::event_attendees::user_id.eq(user_id))
+            .count()
+            .get_result(&mut conn)
+            .await?;
+        Ok((held, attended))
+    }
+
+    pub async fn load_attendees(connection_maker: &impl Connector, event_id: i64) -> DbResult<Vec<DiscordUserId>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::event_attendees::table
+            .filter(schema::event_attendees::event_id.eq(event_id))
+            .select(schema::event_attendees::user_id)
+            .order_by(schema::event_attendees::user_id)
+            .get_results(&mut conn)
+            .await?)
+    }
+}
@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, prelude::{Identifiable, Insertable, Queryable}};
+use diesel_async::{AsyncConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
+
+use crate::{db::{DiscordChannelId, DiscordGuildId, DiscordMessageId}, schema};
+
+use azel::db::{Connector, DbResult};
+
+#[derive(Debug, Clone)]
+#[derive(Insertable)]
+#[diesel(table_name = schema::goal_boards)]
+pub struct NewGoalBoard<'a> {
+    pub guild_id: DiscordGuildId,
+    pub branch: &'a str,
+    pub channel_id: DiscordChannelId,
+    pub message_id: DiscordMessageId,
+    pub show_details: bool,
+    pub show_branches: bool,
+}
+
+/// A published `monthly_goal check`, re-rendered whenever the guild's goals change.
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::goal_boards)]
+#[diesel(primary_key(guild_id, branch))]
+pub struct GoalBoard {
+    pub guild_id: DiscordGuildId,
+    pub branch: String,
+    pub created: DateTime<Utc>,
+    pub channel_id: DiscordChannelId,
+    pub message_id: DiscordMessageId,
+    pub show_details: bool,
+    pub show_branches: bool,
+}
+
+impl GoalBoard {
+    /// Replaces the branch's board, returning the one it replaced so its message can be cleaned up.
+    pub async fn set(connection_maker: &impl Connector, new: NewGoalBoard<'_>) -> DbResult<Option<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            let previous = diesel::delete(
+                schema::goal_boards::table
+                    .filter(schema::goal_boards::guild_id.eq(new.guild_id))
+                    .filter(schema::goal_boards::branch.eq(new.branch))
+            )
+                .get_result(conn)
+                .await
+                .optional()?;
+            diesel::insert_into(schema::goal_boards::table)
+                .values(new)
+                .execute(conn)
+                .await?;
+
+            Ok(previous)
+        }.scope_boxed()).await?)
+    }
+
+    pub async fn remove(connection_maker: &impl Connector, guild_id: DiscordGuildId, branch: &str) -> DbResult<Option<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(diesel::delete(
+            schema::goal_boards::table
+                .filter(schema::goal_boards::guild_id.eq(guild_id))
+                .filter(schema::goal_boards::branch.eq(branch))
+        )
+            .get_result(&mut conn)
+            .await
+            .optional()?)
+    }
+
+    pub async fn load_all(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::goal_boards::table
+            .filter(schema::goal_boards::guild_id.eq(guild_id))
+            .order_by(schema::goal_boards::branch)
+            .get_results(&mut conn)
+            .await?)
+    }
+}
@@ -0,0 +1,158 @@
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, dsl::count_star, prelude::{Identifiable, Queryable}};
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
+
+use crate::{db::{DiscordUserId, MonthlyGoal, goal_revision}, schema};
+
+use azel::db::{Connector, DbResult};
+
+/// One entry of a goal's checklist.
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::goal_items)]
+pub struct GoalItem {
+    pub id: i64,
+    pub goal_id: i64,
+    pub position: i32,
+    pub created: DateTime<Utc>,
+    pub description: String,
+    pub completed: Option<DateTime<Utc>>,
+    pub completed_by: Option<DiscordUserId>,
+}
+
+/// A goal's progress before and after one of its items changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgressChange {
+    pub previous: i16,
+    pub current: i16,
+}
+
+/// Rounded down, so a goal only shows as done once every item is.
+pub fn checklist_progress(completed: i64, total: i64) -> i16 {
+    match total {
+        0 => 0,
+        total => i16::try_from(completed.clamp(0, total) * 100 / total).unwrap_or(0),
+    }
+}
+
+/// Brings the goal's progress in line with its checklist.
+async fn sync_progress(conn: &mut AsyncPgConnection, goal_id: i64, updater: DiscordUserId) -> diesel::QueryResult<ProgressChange> {
+    let old: MonthlyGoal = schema::monthly_goals::table
+        .filter(schema::monthly_goals::id.eq(goal_id))
+        .for_update()
+        .get_result(conn)
+        .await?;
+    let total: i64 = schema::goal_items::table
+        .filter(schema::goal_items::goal_id.eq(goal_id))
+        .select(count_star())
+        .get_result(conn)
+        .await?;
+    let completed: i64 = schema::goal_items::table
+        .filter(schema::goal_items::goal_id.eq(goal_id))
+        .filter(schema::goal_items::completed.is_not_null())
+        .select(count_star())
+        .get_result(conn)
+        .await?;
+
+    let current = checklist_progress(completed, total);
+    let goal: MonthlyGoal = diesel::update(schema::monthly_goals::table.filter(schema::monthly_goals::id.eq(goal_id)))
+        .set(schema::monthly_goals::progress.eq(current))
+        .get_result(conn)
+        .await?;
+    goal_revision::record(conn, u64::from(*updater.inner()).into(), Some(&old), &goal).await?;
+
+    Ok(ProgressChange { previous: old.progress, current })
+}
+
+impl GoalItem {
+    /// Adds an item to the end of the goal's checklist.
+    pub async fn add(connection_maker: &impl Connector, goal_id: i64, description: &str, updater: DiscordUserId) -> DbResult<(Self, ProgressChange)> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        let description = description.to_owned();
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            // Lock the goal so two items added at once can't get the same position.
+            schema::monthly_goals::table
+                .filter(schema::monthly_goals::id.eq(goal_id))
+                .select(schema::monthly_goals::id)
+                .for_update()
+                .get_result::<i64>(conn)
+                .await?;
+            let last: Option<i32> = schema::goal_items::table
+                .filter(schema::goal_items::goal_id.eq(goal_id))
+                .select(diesel::dsl::max(schema::goal_items::position))
+                .get_result(conn)
+                .await?;
+            let item = diesel::insert_into(schema::goal_items::table)
+                .values((
+                    schema::goal_items::goal_id.eq(goal_id),
+                    schema::goal_items::position.eq(last.unwrap_or(0) + 1),
+                    schema::goal_items::description.eq(description),
+                ))
+                .get_result(conn)
+                .await?;
+            let change = sync_progress(conn, goal_id, updater).await?;
+            Ok((item, change))
+        }.scope_boxed()).await?)
+    }
+
+    /// Completes an item, or reopens it when `completed` is false. Returns `None` if the goal has no such item.
+    pub async fn set_completed(connection_maker: &impl Connector, goal_id: i64, position: i32, completed: bool, updater: DiscordUserId) -> DbResult<Option<(Self, ProgressChange)>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            let item: Option<Self> = diesel::update(
+                schema::goal_items::table
+                    .filter(schema::goal_items::goal_id.eq(goal_id))
+                    .filter(schema::goal_items::position.eq(position))
+            )
+                .set((
+                    schema::goal_items::completed.eq(completed.then(Utc::now)),
+                    schema::goal_items::completed_by.eq(completed.then_some(updater)),
+                ))
+                .get_result(conn)
+                .await
+                .optional()?;
+            let Some(item) = item else {
+                return Ok(None);
+            };
+            let change = sync_progress(conn, goal_id, updater).await?;
+            Ok(Some((item, change)))
+        }.scope_boxed()).await?)
+    }
+
+    /// Checklists of all the given goals, each in order.
+    pub async fn load_for_goals(connection_maker: &impl Connector, goal_ids: &[i64]) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::goal_items::table
+            .filter(schema::goal_items::goal_id.eq_any(goal_ids))
+            .order_by((schema::goal_items::goal_id, schema::goal_items::position))
+            .get_results(&mut conn)
+            .await?)
+    }
+
+    pub async fn count_for(connection_maker: &impl Connector, goal_id: i64) -> DbResult<i64> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::goal_items::table
+            .filter(schema::goal_items::goal_id.eq(goal_id))
+            .select(count_star())
+            .get_result(&mut conn)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::checklist_progress;
+
+    #[test]
+    fn test_checklist_progress() {
+        assert_eq!(checklist_progress(0, 0), 0);
+        assert_eq!(checklist_progress(0, 5), 0);
+        assert_eq!(checklist_progress(2, 3), 66);
+        assert_eq!(checklist_progress(4, 5), 80);
+        assert_eq!(checklist_progress(5, 5), 100);
+    }
+}
@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, QueryDsl, prelude::{Identifiable, Queryable}};
+use diesel_async::RunQueryDsl;
+
+use crate::{db::DiscordUserId, schema};
+
+use azel::db::{Connector, DbResult};
+
+/// A member who's offered to help with a goal.
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::goal_pledges)]
+#[diesel(primary_key(goal_id, user_id))]
+pub struct GoalPledge {
+    pub goal_id: i64,
+    pub user_id: DiscordUserId,
+    pub created: DateTime<Utc>,
+}
+
+impl GoalPledge {
+    /// Returns false if they'd already pledged.
+    pub async fn pledge(connection_maker: &impl Connector, goal_id: i64, user_id: DiscordUserId) -> DbResult<bool> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        let inserted = diesel::insert_into(schema::goal_pledges::table)
+            .values((
+                schema::goal_pledges::goal_id.eq(goal_id),
+                schema::goal_pledges::user_id.eq(user_id),
+            ))
+            .on_conflict_do_nothing()
+            .execute(&mut conn)
+            .await?;
+
+        Ok(inserted > 0)
+    }
+
+    /// Returns false if they hadn't pledged.
+    pub async fn withdraw(connection_maker: &impl Connector, goal_id: i64, user_id: DiscordUserId) -> DbResult<bool> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        let deleted = diesel::delete(
+            schema::goal_pledges::table
+                .filter(schema::goal_pledges::goal_id.eq(goal_id))
+                .filter(schema::goal_pledges::user_id.eq(user_id))
+        )
+            .execute(&mut conn)
+            .await?;
+
+        Ok(deleted > 0)
+    }
+
+    /// Pledges to all the given goals, each in the order they were made.
+    pub async fn load_for_goals(connection_maker: &impl Connector, goal_ids: &[i64]) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::goal_pledges::table
+            .filter(schema::goal_pledges::goal_id.eq_any(goal_ids))
+            .order_by((schema::goal_pledges::goal_id, schema::goal_pledges::created, schema::goal_pledges::user_id))
+            .get_results(&mut conn)
+            .await?)
+    }
+}
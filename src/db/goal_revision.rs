@@ -0,0 +1,75 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, QueryDsl, prelude::{Identifiable, Insertable, Queryable}};
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+
+use crate::{db::MonthlyGoal, schema};
+
+use azel::db::{Connector, DbResult};
+
+#[derive(Debug, Clone)]
+#[derive(Insertable)]
+#[diesel(table_name = schema::goal_revisions)]
+struct NewGoalRevision<'a> {
+    goal_id: i64,
+    updater: BigDecimal,
+    old_header: Option<&'a str>,
+    old_body: Option<&'a str>,
+    old_progress: Option<i16>,
+    header: &'a str,
+    body: &'a str,
+    progress: i16,
+}
+
+/// One change to a goal's header, body or progress.
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::goal_revisions)]
+pub struct GoalRevision {
+    pub id: i64,
+    pub goal_id: i64,
+    pub created: DateTime<Utc>,
+    pub updater: BigDecimal,
+    pub old_header: Option<String>,
+    pub old_body: Option<String>,
+    pub old_progress: Option<i16>,
+    pub header: String,
+    pub body: String,
+    pub progress: i16,
+}
+
+/// Records the change from `old` to `new`, if the header, body or progress actually changed. `old` is `None` when the
+/// goal has just been created.
+pub(super) async fn record(conn: &mut AsyncPgConnection, updater: BigDecimal, old: Option<&MonthlyGoal>, new: &MonthlyGoal) -> diesel::QueryResult<()> {
+    if old.is_some_and(|old| old.header == new.header && old.body == new.body && old.progress == new.progress) {
+        return Ok(());
+    }
+
+    diesel::insert_into(schema::goal_revisions::table)
+        .values(NewGoalRevision {
+            goal_id: new.id,
+            updater,
+            old_header: old.map(|old| old.header.as_str()),
+            old_body: old.map(|old| old.body.as_str()),
+            old_progress: old.map(|old| old.progress),
+            header: new.header.as_str(),
+            body: new.body.as_str(),
+            progress: new.progress,
+        })
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+impl GoalRevision {
+    /// Oldest first.
+    pub async fn load_for(connection_maker: &impl Connector, goal_id: i64) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::goal_revisions::table
+            .filter(schema::goal_revisions::goal_id.eq(goal_id))
+            .order_by((schema::goal_revisions::created, schema::goal_revisions::id))
+            .get_results(&mut conn)
+            .await?)
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, prelude::{Identifiable, Queryable}};
+use diesel_async::RunQueryDsl;
+
+use crate::{db::{DiscordGuildId, DiscordUserId}, schema};
+
+use azel::db::{Connector, DbResult};
+
+/// Lets the HTTP API read one guild's scoreboards, profiles and goals. Only the token's hash is stored, so a lost token
+/// has to be replaced rather than looked up.
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::guild_api_tokens)]
+#[diesel(primary_key(guild_id))]
+pub struct GuildApiToken {
+    pub guild_id: DiscordGuildId,
+    pub created: DateTime<Utc>,
+    pub created_by: DiscordUserId,
+    pub token_hash: String,
+}
+
+impl GuildApiToken {
+    /// Replaces the guild's token, so the previous one stops working straight away.
+    pub async fn set(connection_maker: &impl Connector, guild_id: DiscordGuildId, created_by: DiscordUserId, token_hash: &str) -> DbResult<()> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        diesel::insert_into(schema::guild_api_tokens::table)
+            .values((
+                schema::guild_api_tokens::guild_id.eq(guild_id),
+                schema::guild_api_tokens::created_by.eq(created_by),
+                schema::guild_api_tokens::token_hash.eq(token_hash),
+            ))
+            .on_conflict(schema::guild_api_tokens::guild_id)
+            .do_update()
+            .set((
+                schema::guild_api_tokens::created.eq(diesel::dsl::now),
+                schema::guild_api_tokens::created_by.eq(created_by),
+                schema::guild_api_tokens::token_hash.eq(token_hash),
+            ))
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn revoke(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<usize> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(diesel::delete(schema::guild_api_tokens::table.filter(schema::guild_api_tokens::guild_id.eq(guild_id)))
+            .execute(&mut conn)
+            .await?)
+    }
+
+    /// The guild a token belongs to, if it's still valid.
+    pub async fn load_guild_for(connection_maker: &impl Connector, token_hash: &str) -> DbResult<Option<DiscordGuildId>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::guild_api_tokens::table
+            .filter(schema::guild_api_tokens::token_hash.eq(token_hash))
+            .select(schema::guild_api_tokens::guild_id)
+            .get_result(&mut conn)
+            .await
+            .optional()?)
+    }
+}
@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, prelude::{AsChangeset, Identifiable, Insertable, Queryable}};
+use diesel_async::RunQueryDsl;
+
+use crate::{db::{DiscordChannelId, DiscordGuildId}, schema};
+
+use azel::db::{Connector, DbResult};
+
+/// Plain white, used when a branch is created without a color.
+pub const DEFAULT_BRANCH_ANSI_COLOR: i16 = 15;
+
+#[derive(Debug, Clone)]
+#[derive(Insertable)]
+#[diesel(table_name = schema::guild_branches)]
+pub struct NewGuildBranch<'a> {
+    pub guild_id: DiscordGuildId,
+    pub tag: &'a str,
+    pub display_name: Option<&'a str>,
+    pub ansi_color: Option<i16>,
+    pub emoji: Option<&'a str>,
+    pub sort_order: Option<i32>,
+    pub channel_id: Option<DiscordChannelId>,
+}
+
+#[derive(Debug, Clone)]
+#[derive(AsChangeset)]
+#[diesel(table_name = schema::guild_branches)]
+pub struct GuildBranchUpdate<'a> {
+    pub display_name: Option<&'a str>,
+    pub ansi_color: Option<i16>,
+    /// `Some(None)` removes the branch's emoji.
+    pub emoji: Option<Option<&'a str>>,
+    pub sort_order: Option<i32>,
+    pub channel_id: Option<DiscordChannelId>,
+}
+
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::guild_branches)]
+pub struct GuildBranch {
+    pub id: i64,
+    pub created: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    pub guild_id: DiscordGuildId,
+    pub tag: String,
+    pub display_name: String,
+    pub ansi_color: i16,
+    pub emoji: Option<String>,
+    pub sort_order: i32,
+    /// Where reminders and announcements for the branch's goals are posted.
+    pub channel_id: Option<DiscordChannelId>,
+}
+
+impl GuildBranch {
+    /// Creates or updates a branch. Fields left as `None` keep their current value, except the emoji, which
+    /// `clear_emoji` removes.
+    pub async fn upsert(connection_maker: &impl Connector, new: NewGuildBranch<'_>, clear_emoji: bool) -> DbResult<()> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        diesel::insert_into(schema::guild_branches::table)
+            .values(NewGuildBranch {
+                display_name: Some(new.display_name.unwrap_or(new.tag)),
+                ansi_color: Some(new.ansi_color.unwrap_or(DEFAULT_BRANCH_ANSI_COLOR)),
+                ..new.clone()
+            })
+            .on_conflict((schema::guild_branches::guild_id, schema::guild_branches::tag))
+            .do_update()
+            .set((
+                schema::guild_branches::updated.eq(diesel::dsl::now),
+                &GuildBranchUpdate {
+                    display_name: new.display_name,
+                    ansi_color: new.ansi_color,
+                    emoji: match clear_emoji {
+                        true => Some(None),
+                        false => new.emoji.map(Some),
+                    },
+                    sort_order: new.sort_order,
+                    channel_id: new.channel_id,
+                },
+            ))
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove(connection_maker: &impl Connector, guild_id: DiscordGuildId, tag: &str) -> DbResult<usize> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(diesel::delete(
+            schema::guild_branches::table
+                .filter(schema::guild_branches::guild_id.eq(guild_id))
+                .filter(schema::guild_branches::tag.eq(tag))
+        )
+            .execute(&mut conn)
+            .await?)
+    }
+
+    pub async fn load_for(connection_maker: &impl Connector, guild_id: DiscordGuildId, tag: &str) -> DbResult<Option<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::guild_branches::table
+            .filter(schema::guild_branches::guild_id.eq(guild_id))
+            .filter(schema::guild_branches::tag.eq(tag))
+            .get_result(&mut conn)
+            .await
+            .optional()?)
+    }
+
+    pub async fn load_all(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::guild_branches::table
+            .filter(schema::guild_branches::guild_id.eq(guild_id))
+            .order_by((
+                schema::guild_branches::sort_order,
+                schema::guild_branches::tag,
+            ))
+            .get_results(&mut conn)
+            .await?)
+    }
+}
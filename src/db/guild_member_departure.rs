@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, QueryDsl, prelude::{Identifiable, Insertable, Queryable}};
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
+
+use crate::{db::{DiscordGuildId, DiscordUserId}, schema};
+
+use azel::db::{Connector, DbResult};
+
+/// A member who has left a guild they have stats in. Removed again if they rejoin.
+#[derive(Debug, Clone)]
+#[derive(Insertable, Queryable, Identifiable)]
+#[diesel(table_name = schema::guild_member_departures)]
+#[diesel(primary_key(guild_id, user_id))]
+pub struct GuildMemberDeparture {
+    pub guild_id: DiscordGuildId,
+    pub user_id: DiscordUserId,
+    pub departed: DateTime<Utc>,
+}
+
+/// What changed when departures were checked against a guild's full member list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemberReconciliation {
+    pub departed: usize,
+    pub returned: usize,
+    /// Stat rows archived or restored as a result.
+    pub archived: usize,
+    pub restored: usize,
+}
+
+/// Archives every stat the users have in the guild, leaving already archived rows alone.
+async fn archive_stats(conn: &mut AsyncPgConnection, guild_id: DiscordGuildId, user_ids: &[DiscordUserId]) -> Result<usize, diesel::result::Error> {
+    diesel::update(
+        schema::tracker_counts::table
+            .filter(schema::tracker_counts::guild_id.eq(guild_id))
+            .filter(schema::tracker_counts::user_id.eq_any(user_ids))
+            .filter(schema::tracker_counts::archived.is_null())
+    )
+        .set(schema::tracker_counts::archived.eq(Some(Utc::now())))
+        .execute(conn)
+        .await
+}
+
+async fn restore_stats(conn: &mut AsyncPgConnection, guild_id: DiscordGuildId, user_ids: &[DiscordUserId]) -> Result<usize, diesel::result::Error> {
+    diesel::update(
+        schema::tracker_counts::table
+            .filter(schema::tracker_counts::guild_id.eq(guild_id))
+            .filter(schema::tracker_counts::user_id.eq_any(user_ids))
+            .filter(schema::tracker_counts::archived.is_not_null())
+    )
+        .set(schema::tracker_counts::archived.eq(None::<DateTime<Utc>>))
+        .execute(conn)
+        .await
+}
+
+impl GuildMemberDeparture {
+    /// Marks the member as departed and archives their stats. Returns the number of stat rows archived.
+    pub async fn record(connection_maker: &impl Connector, guild_id: DiscordGuildId, user_id: DiscordUserId) -> DbResult<usize> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            diesel::insert_into(schema::guild_member_departures::table)
+                .values((
+                    schema::guild_member_departures::guild_id.eq(guild_id),
+                    schema::guild_member_departures::user_id.eq(user_id),
+                ))
+                .on_conflict_do_nothing()
+                .execute(conn)
+                .await?;
+            archive_stats(conn, guild_id, &[user_id]).await
+        }.scope_boxed()).await?)
+    }
+
+    /// Clears the member's departure and restores their stats. Returns the number of stat rows restored.
+    pub async fn clear(connection_maker: &impl Connector, guild_id: DiscordGuildId, user_id: DiscordUserId) -> DbResult<usize> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            diesel::delete(
+                schema::guild_member_departures::table
+                    .filter(schema::guild_member_departures::guild_id.eq(guild_id))
+                    .filter(schema::guild_member_departures::user_id.eq(user_id))
+            )
+                .execute(conn)
+                .await?;
+            restore_stats(conn, guild_id, &[user_id]).await
+        }.scope_boxed()).await?)
+    }
+
+    pub async fn load_all(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::guild_member_departures::table
+            .filter(schema::guild_member_departures::guild_id.eq(guild_id))
+            .order_by(schema::guild_member_departures::departed)
+            .get_results(&mut conn)
+            .await?)
+    }
+
+    /// Marks everyone with stats in the guild who isn't in `members` as departed, and clears anyone who is. Stats are
+    /// archived or restored to match.
+    pub async fn reconcile(connection_maker: &impl Connector, guild_id: DiscordGuildId, members: &HashSet<DiscordUserId>) -> DbResult<MemberReconciliation> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            let tracked: Vec<DiscordUserId> = schema::tracker_counts::table
+                .filter(schema::tracker_counts::guild_id.eq(guild_id))
+                .select(schema::tracker_counts::user_id)
+                .distinct()
+                .get_results(conn)
+                .await?;
+            let already_departed: HashSet<DiscordUserId> = schema::guild_member_departures::table
+                .filter(schema::guild_member_departures::guild_id.eq(guild_id))
+                .select(schema::guild_member_departures::user_id)
+                .get_results(conn)
+                .await?
+                .into_iter()
+                .collect();
+
+            let newly_departed: Vec<_> = tracked.into_iter()
+                .filter(|user_id| !members.contains(user_id) && !already_departed.contains(user_id))
+                .collect();
+            let (returned, still_departed): (Vec<_>, Vec<_>) = already_departed.into_iter()
+                .partition(|user_id| members.contains(user_id));
+
+            let departed = diesel::insert_into(schema::guild_member_departures::table)
+                .values(newly_departed.iter().map(|user_id| (
+                    schema::guild_member_departures::guild_id.eq(guild_id),
+                    schema::guild_member_departures::user_id.eq(*user_id),
+                )).collect::<Vec<_>>())
+                .on_conflict_do_nothing()
+                .execute(conn)
+                .await?;
+            let returned_count = diesel::delete(
+                schema::guild_member_departures::table
+                    .filter(schema::guild_member_departures::guild_id.eq(guild_id))
+                    .filter(schema::guild_member_departures::user_id.eq_any(returned.as_slice()))
+            )
+                .execute(conn)
+                .await?;
+
+            // Includes anyone recorded for while they were away.
+            let archived = archive_stats(conn, guild_id, newly_departed.into_iter().chain(still_departed).collect::<Vec<_>>().as_slice()).await?;
+            let restored = restore_stats(conn, guild_id, returned.as_slice()).await?;
+
+            Ok(MemberReconciliation {
+                departed,
+                returned: returned_count,
+                archived,
+                restored,
+            })
+        }.scope_boxed()).await?)
+    }
+}
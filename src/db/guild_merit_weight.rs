@@ -0,0 +1,75 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, QueryDsl, prelude::{Identifiable, Queryable}};
+use diesel_async::RunQueryDsl;
+
+use crate::{db::{DiscordGuildId, TrackerStat}, schema};
+
+use azel::db::{Connector, DbResult};
+
+/// How much one stat counts towards a guild's merit score.
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::guild_merit_weights)]
+#[diesel(primary_key(guild_id, stat))]
+pub struct GuildMeritWeight {
+    pub guild_id: DiscordGuildId,
+    pub stat: TrackerStat,
+    pub created: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    /// Merit earned for every `per` of the stat, in display units.
+    pub points: BigDecimal,
+    pub per: BigDecimal,
+}
+
+impl GuildMeritWeight {
+    /// Merit for a running total of the stat, as stored in the database.
+    pub fn merit_for(&self, db_value: BigDecimal) -> BigDecimal {
+        self.stat.display_value(db_value) * &self.points / &self.per
+    }
+
+    pub async fn set(connection_maker: &impl Connector, guild_id: DiscordGuildId, stat: TrackerStat, points: BigDecimal, per: BigDecimal) -> DbResult<()> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        diesel::insert_into(schema::guild_merit_weights::table)
+            .values((
+                schema::guild_merit_weights::guild_id.eq(guild_id),
+                schema::guild_merit_weights::stat.eq(stat),
+                schema::guild_merit_weights::points.eq(&points),
+                schema::guild_merit_weights::per.eq(&per),
+            ))
+            .on_conflict((schema::guild_merit_weights::guild_id, schema::guild_merit_weights::stat))
+            .do_update()
+            .set((
+                schema::guild_merit_weights::updated.eq(diesel::dsl::now),
+                schema::guild_merit_weights::points.eq(&points),
+                schema::guild_merit_weights::per.eq(&per),
+            ))
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove(connection_maker: &impl Connector, guild_id: DiscordGuildId, stat: TrackerStat) -> DbResult<usize> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(diesel::delete(
+            schema::guild_merit_weights::table
+                .filter(schema::guild_merit_weights::guild_id.eq(guild_id))
+                .filter(schema::guild_merit_weights::stat.eq(stat))
+        )
+            .execute(&mut conn)
+            .await?)
+    }
+
+    pub async fn load_all(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::guild_merit_weights::table
+            .filter(schema::guild_merit_weights::guild_id.eq(guild_id))
+            .order_by(schema::guild_merit_weights::stat)
+            .get_results(&mut conn)
+            .await?)
+    }
+}
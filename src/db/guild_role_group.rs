@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, QueryDsl, prelude::{Identifiable, Insertable, Queryable}};
+use diesel_async::RunQueryDsl;
+
+use crate::{db::{DiscordGuildId, DiscordRoleId}, schema};
+
+use azel::db::{Connector, DbResult};
+
+#[derive(Debug, Clone)]
+#[derive(Insertable)]
+#[diesel(table_name = schema::guild_role_groups)]
+pub struct NewGuildRoleGroup<'a> {
+    pub guild_id: DiscordGuildId,
+    pub role_id: DiscordRoleId,
+    pub name: &'a str,
+}
+
+/// A Discord role whose members are ranked together on group scoreboards, e.g. a navy wing or a legion squad.
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::guild_role_groups)]
+pub struct GuildRoleGroup {
+    pub id: i64,
+    pub created: DateTime<Utc>,
+    pub guild_id: DiscordGuildId,
+    pub role_id: DiscordRoleId,
+    pub name: String,
+}
+
+impl GuildRoleGroup {
+    /// Adds the role as a group, or renames it if it already is one.
+    pub async fn upsert(connection_maker: &impl Connector, new: NewGuildRoleGroup<'_>) -> DbResult<()> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        diesel::insert_into(schema::guild_role_groups::table)
+            .values(&new)
+            .on_conflict((schema::guild_role_groups::guild_id, schema::guild_role_groups::role_id))
+            .do_update()
+            .set(schema::guild_role_groups::name.eq(new.name))
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove(connection_maker: &impl Connector, guild_id: DiscordGuildId, role_id: DiscordRoleId) -> DbResult<usize> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(diesel::delete(
+            schema::guild_role_groups::table
+                .filter(schema::guild_role_groups::guild_id.eq(guild_id))
+                .filter(schema::guild_role_groups::role_id.eq(role_id))
+        )
+            .execute(&mut conn)
+            .await?)
+    }
+
+    pub async fn load_all(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::guild_role_groups::table
+            .filter(schema::guild_role_groups::guild_id.eq(guild_id))
+            .order_by(schema::guild_role_groups::name)
+            .get_results(&mut conn)
+            .await?)
+    }
+}
@@ -0,0 +1,162 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, prelude::{Identifiable, Queryable}};
+use diesel_async::RunQueryDsl;
+use tracing as trc;
+
+use crate::{db::{DiscordChannelId, DiscordGuildId}, schema};
+
+use azel::db::{Connector, DbResult};
+
+mod bar_style {
+    use std::str::FromStr;
+
+    use diesel::{deserialize::FromSqlRow, expression::AsExpression, pg::Pg, sql_types::Text};
+    use diesel_pg_type_utils::impl_sql_convert;
+    use strum::{EnumIter, EnumString, IntoStaticStr};
+
+    /// How progress bars are drawn. ANSI colours only show on desktop, the others are for mobile.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+    #[derive(IntoStaticStr, EnumString, EnumIter)]
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(sql_type = Text)]
+    pub enum BarStyle {
+        #[default]
+        #[strum(serialize = "ansi")]
+        Ansi,
+        #[strum(serialize = "unicode")]
+        Unicode,
+        #[strum(serialize = "emoji")]
+        Emoji,
+        #[strum(serialize = "embed")]
+        Embed,
+    }
+
+    impl BarStyle {
+        pub fn as_str(&self) -> &'static str {
+            self.into()
+        }
+    }
+
+    impl_sql_convert!(
+        <Pg>
+        Text > String > BarStyle
+        |s| {
+            BarStyle::from_str(s.as_str())
+                .ok().ok_or("bad value")?
+        }
+        |style| {
+            &style.as_str().to_owned()
+        }
+    );
+}
+pub use bar_style::BarStyle;
+
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::guild_settings)]
+#[diesel(primary_key(guild_id))]
+pub struct GuildSettings {
+    pub guild_id: DiscordGuildId,
+    pub created: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    pub timezone: String,
+    /// Where records that break a soft limit get flagged.
+    pub officer_channel: Option<DiscordChannelId>,
+    /// How progress bars are drawn unless a command asks otherwise.
+    pub bar_style: BarStyle,
+}
+
+impl GuildSettings {
+    pub async fn load_for(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<Option<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::guild_settings::table
+            .filter(schema::guild_settings::guild_id.eq(guild_id))
+            .get_result(&mut conn)
+            .await
+            .optional()?)
+    }
+
+    pub async fn set_timezone(connection_maker: &impl Connector, guild_id: DiscordGuildId, tz: Tz) -> DbResult<()> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        diesel::insert_into(schema::guild_settings::table)
+            .values((
+                schema::guild_settings::guild_id.eq(guild_id),
+                schema::guild_settings::timezone.eq(tz.name()),
+            ))
+            .on_conflict(schema::guild_settings::guild_id)
+            .do_update()
+            .set((
+                schema::guild_settings::updated.eq(diesel::dsl::now),
+                schema::guild_settings::timezone.eq(tz.name()),
+            ))
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_officer_channel(connection_maker: &impl Connector, guild_id: DiscordGuildId, channel_id: Option<DiscordChannelId>) -> DbResult<()> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        diesel::insert_into(schema::guild_settings::table)
+            .values((
+                schema::guild_settings::guild_id.eq(guild_id),
+                schema::guild_settings::officer_channel.eq(channel_id),
+            ))
+            .on_conflict(schema::guild_settings::guild_id)
+            .do_update()
+            .set((
+                schema::guild_settings::updated.eq(diesel::dsl::now),
+                schema::guild_settings::officer_channel.eq(channel_id),
+            ))
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_bar_style(connection_maker: &impl Connector, guild_id: DiscordGuildId, style: BarStyle) -> DbResult<()> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        diesel::insert_into(schema::guild_settings::table)
+            .values((
+                schema::guild_settings::guild_id.eq(guild_id),
+                schema::guild_settings::bar_style.eq(style),
+            ))
+            .on_conflict(schema::guild_settings::guild_id)
+            .do_update()
+            .set((
+                schema::guild_settings::updated.eq(diesel::dsl::now),
+                schema::guild_settings::bar_style.eq(style),
+            ))
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// The guild's bar style, or ANSI if the guild hasn't picked one.
+    pub async fn load_bar_style(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<BarStyle> {
+        Ok(Self::load_for(connection_maker, guild_id).await?.map(|settings| settings.bar_style).unwrap_or_default())
+    }
+
+    /// The guild's timezone, or UTC if the guild hasn't picked one.
+    pub async fn load_timezone(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<Tz> {
+        let Some(settings) = Self::load_for(connection_maker, guild_id).await? else {
+            return Ok(Tz::UTC);
+        };
+        Ok(settings.tz())
+    }
+
+    pub fn tz(&self) -> Tz {
+        Tz::from_str(self.timezone.as_str()).unwrap_or_else(|e| {
+            trc::error!("Stored timezone {:?} for {:?} is invalid ({e}), falling back to UTC.", self.timezone, self.guild_id);
+            Tz::UTC
+        })
+    }
+}
@@ -0,0 +1,191 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, prelude::{Identifiable, Insertable, Queryable}};
+use diesel_async::RunQueryDsl;
+
+use crate::{db::{DiscordGuildId, TrackerStat}, schema};
+
+use azel::db::{Connector, DbResult};
+
+/// Limits on how much of a stat can be recorded, in the same units as tracker counts. Breaking a soft limit still
+/// records the change but flags it to officers, breaking a hard limit rejects it.
+#[derive(Debug, Clone, Default)]
+#[derive(Insertable)]
+#[diesel(table_name = schema::guild_stat_limits)]
+pub struct NewGuildStatLimit {
+    pub max_change_soft: Option<BigDecimal>,
+    pub max_change_hard: Option<BigDecimal>,
+    pub daily_cap_soft: Option<BigDecimal>,
+    pub daily_cap_hard: Option<BigDecimal>,
+    pub self_daily_soft: Option<BigDecimal>,
+    pub self_daily_hard: Option<BigDecimal>,
+}
+
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::guild_stat_limits)]
+#[diesel(primary_key(guild_id, stat))]
+pub struct GuildStatLimit {
+    pub guild_id: DiscordGuildId,
+    pub stat: TrackerStat,
+    pub created: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    pub max_change_soft: Option<BigDecimal>,
+    pub max_change_hard: Option<BigDecimal>,
+    pub daily_cap_soft: Option<BigDecimal>,
+    pub daily_cap_hard: Option<BigDecimal>,
+    pub self_daily_soft: Option<BigDecimal>,
+    pub self_daily_hard: Option<BigDecimal>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StatLimitKind {
+    MaxChange,
+    DailyCap,
+    SelfDaily,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatLimitBreach {
+    pub kind: StatLimitKind,
+    pub hard: bool,
+    pub limit: BigDecimal,
+    /// The single change, or what the day's total would become.
+    pub attempted: BigDecimal,
+}
+
+impl GuildStatLimit {
+    /// Every limit `total` breaks, hard limits first. `recorded_today` is what the target has already been given
+    /// today, and `self_recorded_today` what they've given themselves, which only matters when `is_self`.
+    pub fn check(&self, total: &BigDecimal, is_self: bool, recorded_today: &BigDecimal, self_recorded_today: &BigDecimal) -> Vec<StatLimitBreach> {
+        let day_total = recorded_today + total;
+        let self_day_total = self_recorded_today + total;
+        let mut checks = vec![
+            (StatLimitKind::MaxChange, &self.max_change_hard, &self.max_change_soft, total.clone()),
+            (StatLimitKind::DailyCap, &self.daily_cap_hard, &self.daily_cap_soft, day_total),
+        ];
+        if is_self {
+            checks.push((StatLimitKind::SelfDaily, &self.self_daily_hard, &self.self_daily_soft, self_day_total));
+        }
+
+        let mut breaches: Vec<StatLimitBreach> = vec![];
+        for (kind, hard, soft, attempted) in checks {
+            if let Some(limit) = hard.as_ref().filter(|limit| attempted > **limit) {
+                breaches.push(StatLimitBreach { kind, hard: true, limit: limit.clone(), attempted });
+            } else if let Some(limit) = soft.as_ref().filter(|limit| attempted > **limit) {
+                breaches.push(StatLimitBreach { kind, hard: false, limit: limit.clone(), attempted });
+            }
+        }
+        breaches.sort_by_key(|breach| !breach.hard);
+        breaches
+    }
+
+    /// Replaces all of the stat's limits.
+    pub async fn set(connection_maker: &impl Connector, guild_id: DiscordGuildId, stat: TrackerStat, limits: NewGuildStatLimit) -> DbResult<()> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        diesel::insert_into(schema::guild_stat_limits::table)
+            .values((
+                schema::guild_stat_limits::guild_id.eq(guild_id),
+                schema::guild_stat_limits::stat.eq(stat),
+                limits.clone(),
+            ))
+            .on_conflict((schema::guild_stat_limits::guild_id, schema::guild_stat_limits::stat))
+            .do_update()
+            .set((
+                schema::guild_stat_limits::updated.eq(diesel::dsl::now),
+                schema::guild_stat_limits::max_change_soft.eq(limits.max_change_soft),
+                schema::guild_stat_limits::max_change_hard.eq(limits.max_change_hard),
+                schema::guild_stat_limits::daily_cap_soft.eq(limits.daily_cap_soft),
+                schema::guild_stat_limits::daily_cap_hard.eq(limits.daily_cap_hard),
+                schema::guild_stat_limits::self_daily_soft.eq(limits.self_daily_soft),
+                schema::guild_stat_limits::self_daily_hard.eq(limits.self_daily_hard),
+            ))
+            .execute(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn remove(connection_maker: &impl Connector, guild_id: DiscordGuildId, stat: TrackerStat) -> DbResult<usize> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(diesel::delete(
+            schema::guild_stat_limits::table
+                .filter(schema::guild_stat_limits::guild_id.eq(guild_id))
+                .filter(schema::guild_stat_limits::stat.eq(stat))
+        )
+            .execute(&mut conn)
+            .await?)
+    }
+
+    pub async fn load_for(connection_maker: &impl Connector, guild_id: DiscordGuildId, stat: TrackerStat) -> DbResult<Option<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::guild_stat_limits::table
+            .filter(schema::guild_stat_limits::guild_id.eq(guild_id))
+            .filter(schema::guild_stat_limits::stat.eq(stat))
+            .get_result(&mut conn)
+            .await
+            .optional()?)
+    }
+
+    pub async fn load_all(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::guild_stat_limits::table
+            .filter(schema::guild_stat_limits::guild_id.eq(guild_id))
+            .order_by(schema::guild_stat_limits::stat)
+            .get_results(&mut conn)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bigdecimal::BigDecimal;
+    use chrono::Utc;
+    use serenity::all::GuildId;
+
+    use super::{GuildStatLimit, StatLimitBreach, StatLimitKind, TrackerStat};
+
+    fn limits() -> GuildStatLimit {
+        GuildStatLimit {
+            guild_id: GuildId::new(1).into(),
+            stat: TrackerStat::GroundKill,
+            created: Utc::now(),
+            updated: Utc::now(),
+            max_change_soft: Some(50.into()),
+            max_change_hard: Some(200.into()),
+            daily_cap_soft: None,
+            daily_cap_hard: Some(300.into()),
+            self_daily_soft: Some(20.into()),
+            self_daily_hard: None,
+        }
+    }
+
+    fn breach(kind: StatLimitKind, hard: bool, limit: i64, attempted: i64) -> StatLimitBreach {
+        StatLimitBreach { kind, hard, limit: limit.into(), attempted: attempted.into() }
+    }
+
+    #[test]
+    fn test_check() {
+        let zero = BigDecimal::from(0);
+        assert!(limits().check(&10.into(), true, &zero, &zero).is_empty());
+        assert_eq!(limits().check(&60.into(), false, &zero, &zero), vec![
+            breach(StatLimitKind::MaxChange, false, 50, 60),
+        ]);
+        assert_eq!(limits().check(&60.into(), true, &zero, &zero), vec![
+            breach(StatLimitKind::MaxChange, false, 50, 60),
+            breach(StatLimitKind::SelfDaily, false, 20, 60),
+        ]);
+        // The hard limit takes over from the soft one, and hard limits come first.
+        assert_eq!(limits().check(&250.into(), false, &100.into(), &zero), vec![
+            breach(StatLimitKind::MaxChange, true, 200, 250),
+            breach(StatLimitKind::DailyCap, true, 300, 350),
+        ]);
+        assert_eq!(limits().check(&40.into(), false, &280.into(), &zero), vec![
+            breach(StatLimitKind::DailyCap, true, 300, 320),
+        ]);
+    }
+}
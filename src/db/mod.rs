@@ -1,7 +1,35 @@
+mod event;
+mod goal_board;
+mod goal_item;
+mod goal_pledge;
+mod goal_revision;
+mod guild_api_token;
+mod guild_branch;
+mod guild_member_departure;
+mod guild_merit_weight;
+mod guild_role_group;
+mod guild_settings;
+mod guild_stat_limit;
 mod monthly_goal;
+mod profit_run;
+mod season;
 mod tracker;
 
+pub use event::*;
+pub use goal_board::*;
+pub use goal_item::*;
+pub use goal_pledge::*;
+pub use goal_revision::*;
+pub use guild_api_token::*;
+pub use guild_branch::*;
+pub use guild_member_departure::*;
+pub use guild_merit_weight::*;
+pub use guild_role_group::*;
+pub use guild_settings::*;
+pub use guild_stat_limit::*;
 pub use monthly_goal::*;
+pub use profit_run::*;
+pub use season::*;
 pub use tracker::*;
 
 mod discord_id_wrapping {
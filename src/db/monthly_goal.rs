@@ -1,11 +1,11 @@
 use std::collections::HashMap;
 
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use diesel::{BoolExpressionMethods, DecoratableTarget, ExpressionMethods, OptionalExtension, QueryDsl, prelude::{AsChangeset, Identifiable, Insertable, Queryable}};
-use diesel_async::RunQueryDsl;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
 use serenity::all::GuildId;
-use crate::schema;
+use crate::{calendar::UtcRange, db::{DiscordUserId, GoalRevision, goal_revision}, schema};
 
 use azel::db::{Connector, DbResult};
 
@@ -20,6 +20,7 @@ pub struct NewMonthlyGoal<'a> {
     pub progress: Option<i16>,
     pub shortname: &'a str,
     pub guild_id: BigDecimal,
+    pub owner_id: Option<DiscordUserId>,
 }
 
 #[derive(Debug, Clone)]
@@ -29,6 +30,9 @@ pub struct MonthlyGoalUpdate<'a> {
     pub header: Option<&'a str>,
     pub body: Option<&'a str>,
     pub progress: Option<i16>,
+    pub deadline: Option<Option<DateTime<Utc>>>,
+    pub reminders_sent: Option<i16>,
+    pub owner_id: Option<DiscordUserId>,
 }
 
 #[derive(Debug, Clone)]
@@ -45,30 +49,52 @@ pub struct MonthlyGoal {
     pub shortname: String,
     pub disabled: Option<DateTime<Utc>>,
     pub guild_id: BigDecimal,
+    pub deadline: Option<DateTime<Utc>>,
+    pub reminders_sent: i16,
+    /// The officer responsible for the goal.
+    pub owner_id: Option<DiscordUserId>,
 }
 
 impl MonthlyGoal {
-    pub async fn upsert(connection_maker: &impl Connector, new: NewMonthlyGoal<'_>) -> DbResult<()> {
-        let mut conn = connection_maker.async_connect().await?;
-
-        diesel::insert_into(schema::monthly_goals::table)
-            .values(NewMonthlyGoal {
-                header: Some(new.header.unwrap_or("placeholder title")),
-                body: Some(new.body.unwrap_or("placeholder body")),
-                ..new.clone()
-            })
-            .on_conflict((schema::monthly_goals::shortname, schema::monthly_goals::guild_id))
-            .filter_target(schema::monthly_goals::disabled.is_null())
-            .do_update()
-            .set(&MonthlyGoalUpdate {
-                header: new.header,
-                body: new.body,
-                progress: new.progress,
-            })
-            .execute(&mut conn)
-            .await?;
+    /// `owner_id` is left alone when `None`, as is `deadline`, which is cleared when `Some(None)`. Moving the deadline
+    /// starts its reminders over. Changes to the header, body or progress are kept as a revision.
+    pub async fn upsert(connection_maker: &impl Connector, new: NewMonthlyGoal<'_>, deadline: Option<Option<DateTime<Utc>>>) -> DbResult<Self> {
+        let mut conn = connection_maker.async_connect().await?;
 
-        Ok(())
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            let old: Option<Self> = schema::monthly_goals::table
+                .filter(schema::monthly_goals::guild_id.eq(&new.guild_id))
+                .filter(schema::monthly_goals::shortname.eq(new.shortname))
+                .filter(schema::monthly_goals::disabled.is_null())
+                .for_update()
+                .get_result(conn)
+                .await
+                .optional()?;
+            let goal: Self = diesel::insert_into(schema::monthly_goals::table)
+                .values((
+                    NewMonthlyGoal {
+                        header: Some(new.header.unwrap_or("placeholder title")),
+                        body: Some(new.body.unwrap_or("placeholder body")),
+                        ..new.clone()
+                    },
+                    schema::monthly_goals::deadline.eq(deadline.flatten()),
+                ))
+                .on_conflict((schema::monthly_goals::shortname, schema::monthly_goals::guild_id))
+                .filter_target(schema::monthly_goals::disabled.is_null())
+                .do_update()
+                .set(&MonthlyGoalUpdate {
+                    header: new.header,
+                    body: new.body,
+                    progress: new.progress,
+                    deadline,
+                    reminders_sent: deadline.map(|_| 0),
+                    owner_id: new.owner_id,
+                })
+                .get_result(conn)
+                .await?;
+            goal_revision::record(conn, new.updater, old.as_ref(), &goal).await?;
+            Ok(goal)
+        }.scope_boxed()).await?)
     }
 
     pub async fn load_for(connection_maker: &impl Connector, guild_id: GuildId, shortname: &str) -> DbResult<Option<Self>> {
@@ -83,45 +109,115 @@ impl MonthlyGoal {
             .optional()?)
     }
 
-    pub async fn clear_active(connection_maker: &impl Connector, guild_id: GuildId) -> DbResult<()> {
+    pub async fn load_active_by_id(connection_maker: &impl Connector, guild_id: GuildId, id: i64) -> DbResult<Option<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+        Ok(schema::monthly_goals::table
+            .filter(schema::monthly_goals::guild_id.eq(BigDecimal::from(u64::from(guild_id))))
+            .filter(schema::monthly_goals::id.eq(id))
+            .filter(schema::monthly_goals::disabled.is_null())
+            .get_result(&mut conn)
+            .await
+            .optional()?)
+    }
+
+    /// Replaces the header and body of an active goal of the guild. Returns false if the goal has been cleared in the
+    /// meantime, or belongs to another guild.
+    pub async fn update_text(connection_maker: &impl Connector, guild_id: GuildId, id: i64, updater: BigDecimal, header: &str, body: &str) -> DbResult<bool> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        let header = header.to_owned();
+        let body = body.to_owned();
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            let in_guild: bool = diesel::select(diesel::dsl::exists(
+                schema::monthly_goals::table
+                    .filter(schema::monthly_goals::id.eq(id))
+                    .filter(schema::monthly_goals::guild_id.eq(BigDecimal::from(u64::from(guild_id))))
+            ))
+                .get_result(conn)
+                .await?;
+            if !in_guild {
+                return Ok(false);
+            }
+            Ok(Self::update_revised(conn, id, updater, header, body, None).await?.is_some())
+        }.scope_boxed()).await?)
+    }
+
+    /// Puts back the header and body a goal had as of a revision, and its progress unless `progress` is false. Returns
+    /// `None` if the goal has been cleared in the meantime.
+    pub async fn restore(connection_maker: &impl Connector, revision: &GoalRevision, updater: BigDecimal, progress: bool) -> DbResult<Option<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        let id = revision.goal_id;
+        let header = revision.header.clone();
+        let body = revision.body.clone();
+        let progress = progress.then_some(revision.progress);
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            Self::update_revised(conn, id, updater, header, body, progress).await
+        }.scope_boxed()).await?)
+    }
+
+    /// Updates an active goal and records the revision, within the caller's transaction.
+    async fn update_revised(conn: &mut AsyncPgConnection, id: i64, updater: BigDecimal, header: String, body: String, progress: Option<i16>) -> diesel::QueryResult<Option<Self>> {
+        let old: Option<Self> = schema::monthly_goals::table
+            .filter(schema::monthly_goals::id.eq(id))
+            .filter(schema::monthly_goals::disabled.is_null())
+            .for_update()
+            .get_result(conn)
+            .await
+            .optional()?;
+        let Some(old) = old else {
+            return Ok(None);
+        };
+
+        let goal: Self = diesel::update(schema::monthly_goals::table.filter(schema::monthly_goals::id.eq(id)))
+            .set((
+                schema::monthly_goals::updater.eq(&updater),
+                schema::monthly_goals::header.eq(header),
+                schema::monthly_goals::body.eq(body),
+                schema::monthly_goals::progress.eq(progress.unwrap_or(old.progress)),
+            ))
+            .get_result(conn)
+            .await?;
+        goal_revision::record(conn, updater, Some(&old), &goal).await?;
+        Ok(Some(goal))
+    }
+
+    /// Returns how many goals were cleared.
+    pub async fn clear_active(connection_maker: &impl Connector, guild_id: GuildId) -> DbResult<usize> {
         let mut conn = connection_maker.async_connect().await?;
 
-        diesel::update(schema::monthly_goals::table)
+        Ok(diesel::update(schema::monthly_goals::table)
             .set(schema::monthly_goals::disabled.eq(diesel::dsl::now))
             .filter(schema::monthly_goals::guild_id.eq(BigDecimal::from(u64::from(guild_id))))
             .filter(schema::monthly_goals::disabled.is_null())
             .execute(&mut conn)
-            .await?;
-
-        Ok(())
+            .await?)
     }
 
-    pub async fn clear_active_by_shortname(connection_maker: &impl Connector, guild_id: GuildId, shortname: &str) -> DbResult<()> {
+    /// Returns how many goals were cleared, 0 if there's no active goal by that name.
+    pub async fn clear_active_by_shortname(connection_maker: &impl Connector, guild_id: GuildId, shortname: &str) -> DbResult<usize> {
         let mut conn = connection_maker.async_connect().await?;
 
-        diesel::update(schema::monthly_goals::table)
+        Ok(diesel::update(schema::monthly_goals::table)
             .set(schema::monthly_goals::disabled.eq(diesel::dsl::now))
             .filter(schema::monthly_goals::guild_id.eq(BigDecimal::from(u64::from(guild_id))))
             .filter(schema::monthly_goals::disabled.is_null())
             .filter(schema::monthly_goals::shortname.eq(shortname))
             .execute(&mut conn)
-            .await?;
-
-        Ok(())
+            .await?)
     }
 
-    pub async fn clear_active_by_tag(connection_maker: &impl Connector, guild_id: GuildId, tag: &str) -> DbResult<()> {
+    /// Returns how many goals were cleared, 0 if the branch has no active goals.
+    pub async fn clear_active_by_tag(connection_maker: &impl Connector, guild_id: GuildId, tag: &str) -> DbResult<usize> {
         let mut conn = connection_maker.async_connect().await?;
 
-        diesel::update(schema::monthly_goals::table)
+        Ok(diesel::update(schema::monthly_goals::table)
             .set(schema::monthly_goals::disabled.eq(diesel::dsl::now))
             .filter(schema::monthly_goals::guild_id.eq(BigDecimal::from(u64::from(guild_id))))
             .filter(schema::monthly_goals::disabled.is_null())
             .filter(schema::monthly_goals::tag.eq(tag))
             .execute(&mut conn)
-            .await?;
-
-        Ok(())
+            .await?)
     }
 
     pub async fn load_primary_summary(connection_maker: &impl Connector, guild_id: GuildId) -> DbResult<HashMap<String, (i64, i64)>> {
@@ -184,4 +280,111 @@ impl MonthlyGoal {
             ))
             .get_results(&mut conn).await?)
     }
+
+    /// Cleared goals, most recently cleared first.
+    pub async fn load_archived(connection_maker: &impl Connector, guild_id: GuildId, limit: i64) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::monthly_goals::table
+            .filter(schema::monthly_goals::guild_id.eq(BigDecimal::from(u64::from(guild_id))))
+            .filter(schema::monthly_goals::disabled.is_not_null())
+            .order_by(schema::monthly_goals::disabled.desc())
+            .limit(limit)
+            .get_results(&mut conn).await?)
+    }
+
+    /// Goals that were active at some point during `range`, whether or not they've been cleared since.
+    pub async fn load_active_during(connection_maker: &impl Connector, guild_id: GuildId, range: UtcRange) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::monthly_goals::table
+            .filter(schema::monthly_goals::guild_id.eq(BigDecimal::from(u64::from(guild_id))))
+            .filter(schema::monthly_goals::created.lt(range.end))
+            .filter(schema::monthly_goals::disabled.is_null().or(schema::monthly_goals::disabled.ge(range.start)))
+            .order_by((
+                schema::monthly_goals::tag,
+                schema::monthly_goals::shortname,
+                schema::monthly_goals::created,
+            ))
+            .get_results(&mut conn).await?)
+    }
+
+    /// Every guild that has ever had a goal.
+    pub async fn load_guild_ids(connection_maker: &impl Connector) -> DbResult<Vec<BigDecimal>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::monthly_goals::table
+            .select(schema::monthly_goals::guild_id)
+            .distinct()
+            .get_results(&mut conn).await?)
+    }
+
+    /// Leaves the goal without an owner, so nobody gets DMs about it.
+    pub async fn clear_owner(connection_maker: &impl Connector, goal_id: i64) -> DbResult<()> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        diesel::update(schema::monthly_goals::table.filter(schema::monthly_goals::id.eq(goal_id)))
+            .set(schema::monthly_goals::owner_id.eq(None::<DiscordUserId>))
+            .execute(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Replaces the goal's extra milestones.
+    pub async fn set_milestones(connection_maker: &impl Connector, goal_id: i64, percents: &[i16]) -> DbResult<()> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        let rows: Vec<_> = percents.iter()
+            .map(|percent| (schema::goal_milestones::goal_id.eq(goal_id), schema::goal_milestones::percent.eq(*percent)))
+            .collect();
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            diesel::delete(schema::goal_milestones::table.filter(schema::goal_milestones::goal_id.eq(goal_id)))
+                .execute(conn)
+                .await?;
+            diesel::insert_into(schema::goal_milestones::table)
+                .values(rows)
+                .execute(conn)
+                .await?;
+            Ok(())
+        }.scope_boxed()).await?)
+    }
+
+    pub async fn load_milestones(connection_maker: &impl Connector, goal_id: i64) -> DbResult<Vec<i16>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::goal_milestones::table
+            .filter(schema::goal_milestones::goal_id.eq(goal_id))
+            .select(schema::goal_milestones::percent)
+            .order_by(schema::goal_milestones::percent)
+            .get_results(&mut conn)
+            .await?)
+    }
+
+    /// Active goals in every guild that are due within `within` of `now`, soonest first.
+    pub async fn load_upcoming_deadlines(connection_maker: &impl Connector, now: DateTime<Utc>, within: Duration) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::monthly_goals::table
+            .filter(schema::monthly_goals::disabled.is_null())
+            .filter(schema::monthly_goals::deadline.gt(now))
+            .filter(schema::monthly_goals::deadline.le(now + within))
+            .order_by(schema::monthly_goals::deadline)
+            .get_results(&mut conn)
+            .await?)
+    }
+
+    /// Records that the first `sent` reminders have gone out. Returns false if they already had, so a reminder is only
+    /// ever posted once.
+    pub async fn mark_reminded(connection_maker: &impl Connector, goal_id: i64, sent: i16) -> DbResult<bool> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        let updated = diesel::update(schema::monthly_goals::table)
+            .set(schema::monthly_goals::reminders_sent.eq(sent))
+            .filter(schema::monthly_goals::id.eq(goal_id))
+            .filter(schema::monthly_goals::reminders_sent.lt(sent))
+            .execute(&mut conn)
+            .await?;
+
+        Ok(updated > 0)
+    }
 }
@@ -0,0 +1,167 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, prelude::{Identifiable, Insertable, Queryable}};
+use diesel_async::{AsyncConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
+
+use crate::{db::{AdjustmentError, DiscordGuildId, DiscordUserId, NewTrackerCountChange, tracker::apply_change}, schema};
+
+use azel::db::{Connector, DbResult};
+
+#[derive(Debug, Clone)]
+#[derive(Insertable)]
+#[diesel(table_name = schema::profit_runs)]
+pub struct NewProfitRun<'a> {
+    pub guild_id: DiscordGuildId,
+    pub owner: DiscordUserId,
+    pub recorded_by: DiscordUserId,
+    pub name: &'a str,
+    pub gross: BigDecimal,
+    pub costs: BigDecimal,
+}
+
+/// A crewed hauling, salvage or mining run whose profit is split between the crew.
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::profit_runs)]
+pub struct ProfitRun {
+    pub id: i64,
+    pub created: DateTime<Utc>,
+    pub guild_id: DiscordGuildId,
+    /// Funded the run and collected the revenue, so owes the crew their shares.
+    pub owner: DiscordUserId,
+    pub recorded_by: DiscordUserId,
+    pub name: String,
+    pub gross: BigDecimal,
+    pub costs: BigDecimal,
+}
+
+/// A crew member's cut of a run, before it's written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrewShare {
+    pub user_id: DiscordUserId,
+    pub percent: BigDecimal,
+    pub amount: BigDecimal,
+}
+
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::profit_run_shares)]
+pub struct ProfitRunShare {
+    pub id: i64,
+    pub run_id: i64,
+    pub user_id: DiscordUserId,
+    pub percent: BigDecimal,
+    pub amount: BigDecimal,
+    /// `None` while the owner still owes the payout.
+    pub paid: Option<DateTime<Utc>>,
+}
+
+impl ProfitRun {
+    pub fn net(&self) -> BigDecimal {
+        &self.gross - &self.costs
+    }
+
+    /// Writes the run and its crew, and applies `credits` to the crew's stats, all or nothing. Shares owed by the
+    /// owner to themselves start out paid. Failing to write the run or crew is reported as a failed change.
+    pub async fn create(
+        connection_maker: &impl Connector,
+        new: NewProfitRun<'_>,
+        crew: Vec<CrewShare>,
+        credits: Vec<NewTrackerCountChange>,
+    ) -> Result<Self, AdjustmentError> {
+        let mut conn = connection_maker.async_connect().await
+            .map_err(AdjustmentError::Connect)?;
+
+        conn.transaction::<_, AdjustmentError, _>(|conn| async move {
+            let run: Self = diesel::insert_into(schema::profit_runs::table)
+                .values(&new)
+                .get_result(conn)
+                .await
+                .map_err(AdjustmentError::Change)?;
+
+            diesel::insert_into(schema::profit_run_shares::table)
+                .values(crew.iter().map(|share| (
+                    schema::profit_run_shares::run_id.eq(run.id),
+                    schema::profit_run_shares::user_id.eq(share.user_id),
+                    schema::profit_run_shares::percent.eq(&share.percent),
+                    schema::profit_run_shares::amount.eq(&share.amount),
+                    schema::profit_run_shares::paid.eq((share.user_id == run.owner).then(Utc::now)),
+                )).collect::<Vec<_>>())
+                .execute(conn)
+                .await
+                .map_err(AdjustmentError::Change)?;
+
+            for credit in &credits {
+                apply_change(conn, credit).await?;
+            }
+
+            Ok(run)
+        }.scope_boxed()).await
+    }
+
+    pub async fn load_with_shares(connection_maker: &impl Connector, guild_id: DiscordGuildId, run_id: i64) -> DbResult<Option<(Self, Vec<ProfitRunShare>)>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        let Some(run) = schema::profit_runs::table
+            .filter(schema::profit_runs::guild_id.eq(guild_id))
+            .filter(schema::profit_runs::id.eq(run_id))
+            .get_result::<Self>(&mut conn)
+            .await
+            .optional()? else {
+            return Ok(None);
+        };
+        let shares = schema::profit_run_shares::table
+            .filter(schema::profit_run_shares::run_id.eq(run.id))
+            .order_by((schema::profit_run_shares::amount.desc(), schema::profit_run_shares::user_id))
+            .get_results(&mut conn)
+            .await?;
+
+        Ok(Some((run, shares)))
+    }
+
+    /// Unpaid shares in the guild, oldest run first, narrowed to those owed by `owner` and/or to `user_id`.
+    pub async fn load_outstanding(
+        connection_maker: &impl Connector,
+        guild_id: DiscordGuildId,
+        owner: Option<DiscordUserId>,
+        user_id: Option<DiscordUserId>,
+    ) -> DbResult<Vec<(Self, ProfitRunShare)>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        let mut query = schema::profit_runs::table
+            .inner_join(schema::profit_run_shares::table)
+            .filter(schema::profit_runs::guild_id.eq(guild_id))
+            .filter(schema::profit_run_shares::paid.is_null())
+            .into_boxed();
+        if let Some(owner) = owner {
+            query = query.filter(schema::profit_runs::owner.eq(owner));
+        }
+        if let Some(user_id) = user_id {
+            query = query.filter(schema::profit_run_shares::user_id.eq(user_id));
+        }
+
+        Ok(query
+            .order_by((schema::profit_runs::created, schema::profit_run_shares::user_id))
+            .get_results(&mut conn)
+            .await?)
+    }
+
+    /// Returns the number of shares marked, which is 0 if it was already paid or doesn't exist in the guild.
+    pub async fn mark_paid(connection_maker: &impl Connector, guild_id: DiscordGuildId, run_id: i64, user_id: DiscordUserId) -> DbResult<usize> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        let run = schema::profit_runs::table
+            .filter(schema::profit_runs::id.eq(run_id))
+            .filter(schema::profit_runs::guild_id.eq(guild_id))
+            .select(schema::profit_runs::id);
+        Ok(diesel::update(
+            schema::profit_run_shares::table
+                .filter(schema::profit_run_shares::run_id.eq_any(run))
+                .filter(schema::profit_run_shares::user_id.eq(user_id))
+                .filter(schema::profit_run_shares::paid.is_null())
+        )
+            .set(schema::profit_run_shares::paid.eq(Some(Utc::now())))
+            .execute(&mut conn)
+            .await?)
+    }
+}
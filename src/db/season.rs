@@ -0,0 +1,151 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, NaiveDate, Utc};
+use diesel::{ExpressionMethods, QueryDsl, prelude::{Identifiable, Insertable, Queryable}};
+use diesel_async::{AsyncConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
+
+use crate::{calendar::Period, db::{DiscordGuildId, DiscordUserId, TrackerStat}, schema};
+
+use azel::db::{Connector, DbResult};
+
+#[derive(Debug, Clone)]
+#[derive(Insertable)]
+#[diesel(table_name = schema::seasons)]
+pub struct NewSeason<'a> {
+    pub guild_id: DiscordGuildId,
+    pub name: &'a str,
+    pub starts: NaiveDate,
+    pub ends: NaiveDate,
+}
+
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::seasons)]
+pub struct Season {
+    pub id: i64,
+    pub created: DateTime<Utc>,
+    pub guild_id: DiscordGuildId,
+    pub name: String,
+    pub starts: NaiveDate,
+    pub ends: NaiveDate,
+    /// Set once the season's final standings have been written to the hall of fame.
+    pub finalized: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+#[derive(Insertable)]
+#[diesel(table_name = schema::season_results)]
+pub struct NewSeasonResult {
+    pub season_id: i64,
+    pub stat: TrackerStat,
+    pub rank: i32,
+    pub user_id: DiscordUserId,
+    pub total: BigDecimal,
+}
+
+#[derive(Debug, Clone)]
+#[derive(Queryable, Identifiable)]
+#[diesel(table_name = schema::season_results)]
+pub struct SeasonResult {
+    pub id: i64,
+    pub season_id: i64,
+    pub stat: TrackerStat,
+    pub rank: i32,
+    pub user_id: DiscordUserId,
+    pub total: BigDecimal,
+}
+
+impl Season {
+    pub fn period(&self) -> Period {
+        Period::Dates(self.starts, self.ends)
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.starts <= date && date <= self.ends
+    }
+
+    pub fn overlaps(&self, starts: NaiveDate, ends: NaiveDate) -> bool {
+        self.starts <= ends && starts <= self.ends
+    }
+
+    pub async fn create(connection_maker: &impl Connector, new: NewSeason<'_>) -> DbResult<Self> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(diesel::insert_into(schema::seasons::table)
+            .values(new)
+            .get_result(&mut conn)
+            .await?)
+    }
+
+    /// Only removes seasons that haven't been finalized, so the hall of fame can't lose entries by accident.
+    pub async fn remove(connection_maker: &impl Connector, guild_id: DiscordGuildId, name: &str) -> DbResult<usize> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(diesel::delete(
+            schema::seasons::table
+                .filter(schema::seasons::guild_id.eq(guild_id))
+                .filter(schema::seasons::name.eq(name))
+                .filter(schema::seasons::finalized.is_null())
+        )
+            .execute(&mut conn)
+            .await?)
+    }
+
+    /// Most recent first.
+    pub async fn load_all(connection_maker: &impl Connector, guild_id: DiscordGuildId) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::seasons::table
+            .filter(schema::seasons::guild_id.eq(guild_id))
+            .order_by(schema::seasons::starts.desc())
+            .get_results(&mut conn)
+            .await?)
+    }
+
+    /// Seasons in any guild that haven't been finalized and ended on or before `date`. The guild's timezone still
+    /// needs to be checked to see whether the season is really over.
+    pub async fn load_unfinalized_ending_by(connection_maker: &impl Connector, date: NaiveDate) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::seasons::table
+            .filter(schema::seasons::finalized.is_null())
+            .filter(schema::seasons::ends.le(date))
+            .get_results(&mut conn)
+            .await?)
+    }
+
+    /// Freezes the final standings. Returns false without writing anything if the season was already finalized.
+    pub async fn finalize(connection_maker: &impl Connector, season_id: i64, results: Vec<NewSeasonResult>) -> DbResult<bool> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            let updated = diesel::update(
+                schema::seasons::table
+                    .filter(schema::seasons::id.eq(season_id))
+                    .filter(schema::seasons::finalized.is_null())
+            )
+                .set(schema::seasons::finalized.eq(Some(Utc::now())))
+                .execute(conn)
+                .await?;
+            if updated == 0 {
+                return Ok(false);
+            }
+
+            diesel::insert_into(schema::season_results::table)
+                .values(results)
+                .execute(conn)
+                .await?;
+
+            Ok(true)
+        }.scope_boxed()).await?)
+    }
+
+    pub async fn load_results(connection_maker: &impl Connector, season_id: i64) -> DbResult<Vec<SeasonResult>> {
+        let mut conn = connection_maker.async_connect().await?;
+
+        Ok(schema::season_results::table
+            .filter(schema::season_results::season_id.eq(season_id))
+            .order_by((schema::season_results::stat, schema::season_results::rank, schema::season_results::user_id))
+            .get_results(&mut conn)
+            .await?)
+    }
+}
@@ -1,11 +1,11 @@
 use std::ops::Add;
 
 use bigdecimal::BigDecimal;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use diesel::{ConnectionError, ExpressionMethods, OptionalExtension, QueryDsl, prelude::{Identifiable, Insertable, Queryable}};
-use diesel_async::RunQueryDsl;
+use diesel_async::{AsyncConnection, AsyncPgConnection, RunQueryDsl, scoped_futures::ScopedFutureExt};
 
-use crate::{db::{DiscordGuildId, DiscordUserId}, schema};
+use crate::{calendar::UtcRange, db::{DiscordGuildId, DiscordUserId}, schema};
 
 use azel::db::{Connector, DbResult};
 
@@ -17,6 +17,8 @@ mod tracker_stat {
     use diesel_pg_type_utils::impl_sql_convert;
     use strum::{EnumIter, EnumString, IntoStaticStr};
 
+    use crate::locale::Locale;
+
     #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
     #[derive(IntoStaticStr, EnumString, EnumIter)]
     #[derive(AsExpression, FromSqlRow)]
@@ -70,6 +72,10 @@ mod tracker_stat {
             }
         }
 
+        pub fn display_name_in(&self, locale: Locale) -> &'static str {
+            locale.translate(self.as_command_opt_display_name())
+        }
+
         pub fn denominator(&self) -> BigDecimal {
             match self {
                 Self::NavyVictory => 4,
@@ -78,81 +84,82 @@ mod tracker_stat {
         }
 
         pub fn format_count(&self, db_value: BigDecimal) -> String {
+            self.format_count_in(Locale::English, db_value)
+        }
+
+        pub fn format_count_in(&self, locale: Locale, db_value: BigDecimal) -> String {
             let display_value = self.display_value(db_value);
             let singular = display_value.is_one_quickcheck().unwrap_or(false);
-            let counter_text = match self {
-                Self::PersonnelSaved => "personnel saved",
+            let msgid = match self {
+                Self::PersonnelSaved => "{} personnel saved",
                 Self::EventParticipation => if singular {
-                    "event"
+                    "{} event"
                 } else {
-                    "events"
+                    "{} events"
                 },
                 Self::IndustryAuec => if singular {
-                    "credit"
+                    "{} credit"
                 } else {
-                    "credits"
+                    "{} credits"
                 },
                 Self::GroundKill => if singular {
-                    "kill"
+                    "{} kill"
                 } else {
-                    "kills"
+                    "{} kills"
                 },
                 Self::NavyVictory => if singular {
-                    "victory"
+                    "{} victory"
                 } else {
-                    "victories"
+                    "{} victories"
                 },
                 Self::NavyTackleAssist => if singular {
-                    "tackle assist"
+                    "{} tackle assist"
                 } else {
-                    "tackle assists"
+                    "{} tackle assists"
                 },
             };
 
-            if display_value.is_integer() {
-                format!("{} {}", display_value, counter_text)
-            } else {
-                format!("{:.2} {}", display_value, counter_text)
-            }
+            locale.format(msgid, &[&format_number(&display_value)])
         }
 
         pub fn format_count_as_past_participle(&self, db_value: BigDecimal) -> String {
+            self.format_count_as_past_participle_in(Locale::English, db_value)
+        }
+
+        /// Reads as what someone has done, e.g. "earned 3 victories".
+        pub fn format_count_as_past_participle_in(&self, locale: Locale, db_value: BigDecimal) -> String {
             let display_value = self.display_value(db_value);
             let singular = display_value.is_one_quickcheck().unwrap_or(false);
-            let (past_participle, counter_text) = match self {
-                Self::PersonnelSaved => ("saved", "personnel"),
+            let msgid = match self {
+                Self::PersonnelSaved => "saved {} personnel",
                 Self::EventParticipation => if singular {
-                    ("participated in ", "event")
+                    "participated in {} event"
                 } else {
-                    ("participated in ", "events")
+                    "participated in {} events"
                 },
                 Self::IndustryAuec => if singular {
-                    ("contributed ", "credit")
+                    "contributed {} credit"
                 } else {
-                    ("contributed ", "credits")
+                    "contributed {} credits"
                 },
                 Self::GroundKill => if singular {
-                    ("", "confirmed kill")
+                    "{} confirmed kill"
                 } else {
-                    ("", "confirmed kills")
+                    "{} confirmed kills"
                 },
                 Self::NavyVictory => if singular {
-                    ("earned ", "victory")
+                    "earned {} victory"
                 } else {
-                    ("earned ", "victories")
+                    "earned {} victories"
                 },
                 Self::NavyTackleAssist => if singular {
-                    ("earned ", "tackle assist")
+                    "earned {} tackle assist"
                 } else {
-                    ("earned ", "tackle assists")
+                    "earned {} tackle assists"
                 },
             };
 
-            if display_value.is_integer() {
-                format!("{}{} {}", past_participle, display_value, counter_text)
-            } else {
-                format!("{}{:.2} {}", past_participle, display_value, counter_text)
-            }
+            locale.format(msgid, &[&format_number(&display_value)])
         }
 
         pub fn display_value(&self, db_value: BigDecimal) -> BigDecimal {
@@ -164,6 +171,14 @@ mod tracker_stat {
         }
     }
 
+    fn format_number(display_value: &BigDecimal) -> String {
+        if display_value.is_integer() {
+            display_value.to_string()
+        } else {
+            format!("{:.2}", display_value)
+        }
+    }
+
     impl_sql_convert!(
         <Pg>
         Text > String > TrackerStat
@@ -209,6 +224,20 @@ mod tracker_stat {
             assert_eq!("industry_personnel_saved", <&'static str>::from(super::TrackerStat::PersonnelSaved));
             assert_eq!(Ok(super::TrackerStat::PersonnelSaved), super::TrackerStat::from_str("industry_personnel_saved"));
         }
+
+        #[test]
+        fn test_format_count() {
+            use bigdecimal::BigDecimal;
+
+            use crate::locale::Locale;
+
+            use super::TrackerStat;
+
+            assert_eq!(TrackerStat::PersonnelSaved.format_count_as_past_participle(BigDecimal::from(5)), "saved 5 personnel");
+            assert_eq!(TrackerStat::NavyVictory.format_count(BigDecimal::from(2)), "0.50 victories");
+            assert_eq!(TrackerStat::NavyVictory.format_count_in(Locale::German, BigDecimal::from(4)), "1 Sieg");
+            assert_eq!(TrackerStat::GroundKill.format_count_as_past_participle_in(Locale::Spanish, BigDecimal::from(3)), "conseguido 3 bajas confirmadas");
+        }
     }
 }
 pub use tracker_stat::TrackerStat;
@@ -244,6 +273,8 @@ pub struct TrackerCount {
     pub guild_id: DiscordGuildId,
     pub user_id: DiscordUserId,
     pub total: BigDecimal,
+    /// Set while the member is away from the guild. Archived rows are kept out of scoreboards and ranks.
+    pub archived: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 diesel::define_sql_function! {
@@ -251,11 +282,101 @@ diesel::define_sql_function! {
     fn max2(a: diesel::sql_types::Numeric, b: diesel::sql_types::Numeric) -> diesel::sql_types::Numeric;
 }
 
+/// One stat moving from one account to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatTransfer {
+    pub stat: TrackerStat,
+    pub moved: BigDecimal,
+    /// What the receiving account had before and will have after.
+    pub before: BigDecimal,
+    pub after: BigDecimal,
+}
+
+/// Works out what moving `from`'s rows onto `to`'s would do. Totals are summed when both accounts have the stat.
+pub fn plan_transfer(from: &[TrackerCount], to: &[TrackerCount], stats: Option<&[TrackerStat]>) -> Vec<StatTransfer> {
+    from.iter()
+        .filter(|record| stats.is_none_or(|stats| stats.contains(&record.stat)))
+        .map(|record| {
+            let before = to.iter()
+                .find(|existing| existing.stat == record.stat)
+                .map(|existing| existing.total.clone())
+                .unwrap_or_default();
+            StatTransfer {
+                stat: record.stat,
+                moved: record.total.clone(),
+                after: &before + &record.total,
+                before,
+            }
+        })
+        .collect()
+}
+
 #[derive(Debug, PartialEq)]
 pub enum AdjustmentError {
     Connect(ConnectionError),
     Change(diesel::result::Error),
     Count(diesel::result::Error),
+    /// Beginning or committing a transaction around several changes failed.
+    Transaction(diesel::result::Error),
+}
+
+impl From<diesel::result::Error> for AdjustmentError {
+    fn from(e: diesel::result::Error) -> Self {
+        Self::Transaction(e)
+    }
+}
+
+/// When the member left the guild, if they're away. New stat rows take this as their `archived`, so the column stays
+/// the one place that says whose stats are kept off scoreboards.
+#[diesel::dsl::auto_type]
+fn departed_at(guild_id: DiscordGuildId, user_id: DiscordUserId) -> _ {
+    schema::guild_member_departures::table
+        .filter(schema::guild_member_departures::guild_id.eq(guild_id))
+        .filter(schema::guild_member_departures::user_id.eq(user_id))
+        .select(schema::guild_member_departures::departed)
+        .single_value()
+}
+
+/// Records the change and applies it to the running total, which never drops below zero. Returns the new total.
+pub(super) async fn apply_change(conn: &mut AsyncPgConnection, change: &NewTrackerCountChange) -> Result<BigDecimal, AdjustmentError> {
+    Ok(apply_change_returning_id(conn, change).await?.1)
+}
+
+/// Like [`apply_change`], but also returns the id of the recorded change so it can be linked to.
+pub(super) async fn apply_change_returning_id(conn: &mut AsyncPgConnection, change: &NewTrackerCountChange) -> Result<(i64, BigDecimal), AdjustmentError> {
+    let change_id = diesel::insert_into(schema::tracker_count_changes::table)
+        .values(change)
+        .returning(schema::tracker_count_changes::id)
+        .get_result(conn)
+        .await
+        .map_err(AdjustmentError::Change)?;
+    let total = diesel::insert_into(schema::tracker_counts::table)
+        .values((
+            schema::tracker_counts::stat.eq(change.stat),
+            schema::tracker_counts::user_id.eq(change.target),
+            schema::tracker_counts::guild_id.eq(change.guild_id),
+            schema::tracker_counts::updated.eq(diesel::dsl::now),
+            schema::tracker_counts::total.eq(max2(
+                BigDecimal::from(0),
+                &change.total,
+            )),
+            // Anything recorded for a member who's away is archived along with the rest of their stats.
+            schema::tracker_counts::archived.eq(departed_at(change.guild_id, change.target)),
+        ))
+        .on_conflict((schema::tracker_counts::stat, schema::tracker_counts::guild_id, schema::tracker_counts::user_id))
+        .do_update()
+        .set((
+            schema::tracker_counts::updated.eq(diesel::dsl::now),
+            schema::tracker_counts::total.eq(max2(
+                BigDecimal::from(0),
+                schema::tracker_counts::total.add(&change.total)
+            )),
+        ))
+        .returning(schema::tracker_counts::total)
+        .get_result(conn)
+        .await
+        .map_err(AdjustmentError::Count)?;
+    Ok((change_id, total))
 }
 
 impl TrackerCount {
@@ -274,60 +395,161 @@ impl TrackerCount {
     pub async fn adjust_count(connection_maker: &impl Connector, change: NewTrackerCountChange) -> Result<BigDecimal, AdjustmentError> {
         let mut conn = connection_maker.async_connect().await
             .map_err(AdjustmentError::Connect)?;
-        diesel::insert_into(schema::tracker_count_changes::table)
-            .values(&change)
-            .execute(&mut conn)
-            .await
-            .map_err(AdjustmentError::Change)?;
-        diesel::insert_into(schema::tracker_counts::table)
-            .values((
-                schema::tracker_counts::stat.eq(change.stat),
-                schema::tracker_counts::user_id.eq(change.target),
-                schema::tracker_counts::guild_id.eq(change.guild_id),
-                schema::tracker_counts::updated.eq(diesel::dsl::now),
-                schema::tracker_counts::total.eq(max2(
-                    BigDecimal::from(0),
-                    &change.total,
-                )),
-            ))
-            .on_conflict((schema::tracker_counts::stat, schema::tracker_counts::guild_id, schema::tracker_counts::user_id))
-            .do_update()
-            .set((
-                schema::tracker_counts::updated.eq(diesel::dsl::now),
-                schema::tracker_counts::total.eq(max2(
-                    BigDecimal::from(0),
-                    schema::tracker_counts::total.add(&change.total)
-                )),
-            ))
-            .returning(schema::tracker_counts::total)
-            .get_result(&mut conn)
-            .await
-            .map_err(AdjustmentError::Count)
+        apply_change(&mut conn, &change).await
     }
 
-    pub async fn delete(connection_maker: &impl Connector, deleter: DiscordUserId, ids: &[TrackerCountId]) -> Result<usize, AdjustmentError> {
-        let mut conn = connection_maker.async_connect().await.map_err(AdjustmentError::Connect)?;
-        let data = diesel::delete(
-            schema::tracker_counts::table
-                .filter(schema::tracker_counts::id.eq_any(ids))
-        ).get_results::<Self>(&mut conn).await.map_err(AdjustmentError::Change)?;
-        let deleted_record_count = data.len();
-
-        // write changes back to db
-        diesel::insert_into(schema::tracker_count_changes::table)
-            .values(data.into_iter().map(|TrackerCount { stat, user_id, guild_id, total, .. }| NewTrackerCountChange {
-                stat,
-                updater: deleter,
-                target: user_id,
-                total: -total,
-                guild_id,
-                user_note: None,
-            }).collect::<Vec<_>>())
-            .execute(&mut conn)
-            .await
-            .map_err(AdjustmentError::Count)?;
+    /// Applies every change or none of them. Returns the new totals in the same order as `changes`.
+    pub async fn adjust_counts(connection_maker: &impl Connector, changes: Vec<NewTrackerCountChange>) -> Result<Vec<BigDecimal>, AdjustmentError> {
+        let mut conn = connection_maker.async_connect().await
+            .map_err(AdjustmentError::Connect)?;
+        conn.transaction::<_, AdjustmentError, _>(|conn| async move {
+            let mut totals = Vec::with_capacity(changes.len());
+            for change in &changes {
+                totals.push(apply_change(conn, change).await?);
+            }
+            Ok(totals)
+        }.scope_boxed()).await
+    }
+
+    /// Permanently removes rows that have been archived since before `cutoff`, along with their history. Returns the
+    /// number of rows removed.
+    pub async fn purge_archived(connection_maker: &impl Connector, guild_id: DiscordGuildId, cutoff: DateTime<Utc>) -> DbResult<usize> {
+        let mut conn = connection_maker.async_connect().await?;
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            let purged: Vec<(TrackerStat, DiscordUserId)> = diesel::delete(
+                schema::tracker_counts::table
+                    .filter(schema::tracker_counts::guild_id.eq(guild_id))
+                    .filter(schema::tracker_counts::archived.lt(cutoff))
+            )
+                .returning((schema::tracker_counts::stat, schema::tracker_counts::user_id))
+                .get_results(conn)
+                .await?;
+
+            for (stat, user_id) in purged.iter() {
+                diesel::delete(
+                    schema::tracker_count_changes::table
+                        .filter(schema::tracker_count_changes::stat.eq(stat))
+                        .filter(schema::tracker_count_changes::guild_id.eq(guild_id))
+                        .filter(schema::tracker_count_changes::target.eq(user_id))
+                )
+                    .execute(conn)
+                    .await?;
+            }
+
+            // Nothing is left to restore for anyone who left before the cutoff.
+            diesel::delete(
+                schema::guild_member_departures::table
+                    .filter(schema::guild_member_departures::guild_id.eq(guild_id))
+                    .filter(schema::guild_member_departures::departed.lt(cutoff))
+            )
+                .execute(conn)
+                .await?;
+
+            Ok(purged.len())
+        }.scope_boxed()).await?)
+    }
 
-        Ok(deleted_record_count)
+    /// Every stat the user has in the guild, archived or not.
+    pub async fn load_all_for_user(connection_maker: &impl Connector, guild_id: DiscordGuildId, user_id: DiscordUserId) -> DbResult<Vec<Self>> {
+        let mut conn = connection_maker.async_connect().await?;
+        Ok(schema::tracker_counts::table
+            .filter(schema::tracker_counts::guild_id.eq(guild_id))
+            .filter(schema::tracker_counts::user_id.eq(user_id))
+            .order_by(schema::tracker_counts::stat)
+            .get_results(&mut conn)
+            .await?)
+    }
+
+    /// Moves `from`'s stats onto `to`, optionally only some of them. Each moved stat gets a pair of ledger entries,
+    /// one taking it away from `from` and one giving it to `to`, both carrying `note`. They're marked as a transfer so
+    /// the moved totals don't count as activity on the day of the transfer.
+    pub async fn transfer(
+        connection_maker: &impl Connector,
+        guild_id: DiscordGuildId,
+        updater: DiscordUserId,
+        from: DiscordUserId,
+        to: DiscordUserId,
+        stats: Option<&[TrackerStat]>,
+        note: &str,
+    ) -> DbResult<Vec<StatTransfer>> {
+        let mut conn = connection_maker.async_connect().await?;
+        Ok(conn.transaction::<_, diesel::result::Error, _>(|conn| async move {
+            let from_records: Vec<Self> = schema::tracker_counts::table
+                .filter(schema::tracker_counts::guild_id.eq(guild_id))
+                .filter(schema::tracker_counts::user_id.eq(from))
+                .order_by(schema::tracker_counts::stat)
+                .for_update()
+                .get_results(conn)
+                .await?;
+            let to_records: Vec<Self> = schema::tracker_counts::table
+                .filter(schema::tracker_counts::guild_id.eq(guild_id))
+                .filter(schema::tracker_counts::user_id.eq(to))
+                .for_update()
+                .get_results(conn)
+                .await?;
+            let plan = plan_transfer(&from_records, &to_records, stats);
+
+            for transfer in plan.iter() {
+                diesel::insert_into(schema::tracker_count_changes::table)
+                    .values(&[
+                        (NewTrackerCountChange {
+                            stat: transfer.stat,
+                            guild_id,
+                            updater,
+                            target: from,
+                            total: -transfer.moved.clone(),
+                            user_note: Some(note.to_owned()),
+                        }, schema::tracker_count_changes::transfer.eq(true)),
+                        (NewTrackerCountChange {
+                            stat: transfer.stat,
+                            guild_id,
+                            updater,
+                            target: to,
+                            total: transfer.moved.clone(),
+                            user_note: Some(note.to_owned()),
+                        }, schema::tracker_count_changes::transfer.eq(true)),
+                    ])
+                    .execute(conn)
+                    .await?;
+                diesel::delete(
+                    schema::tracker_counts::table
+                        .filter(schema::tracker_counts::stat.eq(transfer.stat))
+                        .filter(schema::tracker_counts::guild_id.eq(guild_id))
+                        .filter(schema::tracker_counts::user_id.eq(from))
+                )
+                    .execute(conn)
+                    .await?;
+                diesel::insert_into(schema::tracker_counts::table)
+                    .values((
+                        schema::tracker_counts::stat.eq(transfer.stat),
+                        schema::tracker_counts::user_id.eq(to),
+                        schema::tracker_counts::guild_id.eq(guild_id),
+                        schema::tracker_counts::updated.eq(diesel::dsl::now),
+                        schema::tracker_counts::total.eq(&transfer.after),
+                        schema::tracker_counts::archived.eq(departed_at(guild_id, to)),
+                    ))
+                    .on_conflict((schema::tracker_counts::stat, schema::tracker_counts::guild_id, schema::tracker_counts::user_id))
+                    .do_update()
+                    .set((
+                        schema::tracker_counts::updated.eq(diesel::dsl::now),
+                        schema::tracker_counts::total.eq(&transfer.after),
+                    ))
+                    .execute(conn)
+                    .await?;
+            }
+
+            Ok(plan)
+        }.scope_boxed()).await?)
+    }
+
+    /// Every guild that has ever recorded a stat.
+    pub async fn load_guild_ids(connection_maker: &impl Connector) -> DbResult<Vec<DiscordGuildId>> {
+        let mut conn = connection_maker.async_connect().await?;
+        Ok(schema::tracker_counts::table
+            .select(schema::tracker_counts::guild_id)
+            .distinct()
+            .get_results(&mut conn)
+            .await?)
     }
 
     pub async fn count_rows(connection_maker: &impl Connector, stat: TrackerStat, guild_id: DiscordGuildId) -> DbResult<i64> {
@@ -335,6 +557,7 @@ impl TrackerCount {
         Ok(schema::tracker_counts::table
             .filter(schema::tracker_counts::stat.eq(stat))
             .filter(schema::tracker_counts::guild_id.eq(guild_id))
+            .filter(schema::tracker_counts::archived.is_null())
             .count()
             .get_result(&mut conn)
             .await?)
@@ -348,6 +571,7 @@ impl TrackerCount {
         Ok(schema::tracker_counts::table
             .filter(schema::tracker_counts::stat.eq(stat))
             .filter(schema::tracker_counts::guild_id.eq(guild_id))
+            .filter(schema::tracker_counts::archived.is_null())
             .filter(schema::tracker_counts::updated.lt(usage))
             .filter(schema::tracker_counts::total.gt(total))
             .select(diesel::dsl::count(schema::tracker_counts::id))
@@ -360,6 +584,7 @@ impl TrackerCount {
         Ok(schema::tracker_counts::table
             .filter(schema::tracker_counts::stat.eq(stat))
             .filter(schema::tracker_counts::guild_id.eq(guild_id))
+            .filter(schema::tracker_counts::archived.is_null())
             .order_by((schema::tracker_counts::total.desc(), schema::tracker_counts::updated))
             .offset(start)
             .limit(lim)
@@ -367,11 +592,109 @@ impl TrackerCount {
             .await?)
     }
 
+    /// Running total per user, highest first, leaving out archived stats.
+    pub async fn load_totals(connection_maker: &impl Connector, stat: TrackerStat, guild_id: DiscordGuildId) -> DbResult<Vec<(DiscordUserId, BigDecimal)>> {
+        let mut conn = connection_maker.async_connect().await?;
+        Ok(schema::tracker_counts::table
+            .filter(schema::tracker_counts::stat.eq(stat))
+            .filter(schema::tracker_counts::guild_id.eq(guild_id))
+            .filter(schema::tracker_counts::archived.is_null())
+            .order_by((schema::tracker_counts::total.desc(), schema::tracker_counts::user_id))
+            .select((schema::tracker_counts::user_id, schema::tracker_counts::total))
+            .get_results(&mut conn)
+            .await?)
+    }
+
+    /// Net change per user over `range`, highest first, leaving out archived stats and transfers. Unlike the running
+    /// totals, these aren't clamped at zero.
+    pub async fn load_period_totals(connection_maker: &impl Connector, stat: TrackerStat, guild_id: DiscordGuildId, range: UtcRange) -> DbResult<Vec<(DiscordUserId, BigDecimal)>> {
+        let mut conn = connection_maker.async_connect().await?;
+        let mut totals: Vec<(DiscordUserId, BigDecimal)> = schema::tracker_count_changes::table
+            .filter(schema::tracker_count_changes::stat.eq(stat))
+            .filter(schema::tracker_count_changes::guild_id.eq(guild_id))
+            .filter(schema::tracker_count_changes::created.ge(range.start))
+            .filter(schema::tracker_count_changes::created.lt(range.end))
+            .filter(schema::tracker_count_changes::transfer.eq(false))
+            .filter(diesel::dsl::not(schema::tracker_count_changes::target.eq_any(
+                schema::tracker_counts::table
+                    .filter(schema::tracker_counts::stat.eq(stat))
+                    .filter(schema::tracker_counts::guild_id.eq(guild_id))
+                    .filter(schema::tracker_counts::archived.is_not_null())
+                    .select(schema::tracker_counts::user_id)
+            )))
+            .group_by(schema::tracker_count_changes::target)
+            .select((
+                schema::tracker_count_changes::target,
+                diesel::dsl::sum(schema::tracker_count_changes::total),
+            ))
+            .get_results::<(DiscordUserId, Option<BigDecimal>)>(&mut conn)
+            .await?
+            .into_iter()
+            .map(|(user_id, total)| (user_id, total.unwrap_or_default()))
+            .collect();
+        totals.sort_by(|(a_user, a_total), (b_user, b_total)| b_total.cmp(a_total).then(a_user.cmp(b_user)));
+        Ok(totals)
+    }
+
+    /// Every change to the stat as `(target, created, total)`, oldest first, leaving out archived stats and transfers.
+    pub async fn load_changes(connection_maker: &impl Connector, stat: TrackerStat, guild_id: DiscordGuildId) -> DbResult<Vec<(DiscordUserId, DateTime<Utc>, BigDecimal)>> {
+        let mut conn = connection_maker.async_connect().await?;
+        Ok(schema::tracker_count_changes::table
+            .filter(schema::tracker_count_changes::stat.eq(stat))
+            .filter(schema::tracker_count_changes::guild_id.eq(guild_id))
+            .filter(schema::tracker_count_changes::transfer.eq(false))
+            .filter(diesel::dsl::not(schema::tracker_count_changes::target.eq_any(
+                schema::tracker_counts::table
+                    .filter(schema::tracker_counts::stat.eq(stat))
+                    .filter(schema::tracker_counts::guild_id.eq(guild_id))
+                    .filter(schema::tracker_counts::archived.is_not_null())
+                    .select(schema::tracker_counts::user_id)
+            )))
+            .order_by(schema::tracker_count_changes::created)
+            .select((
+                schema::tracker_count_changes::target,
+                schema::tracker_count_changes::created,
+                schema::tracker_count_changes::total,
+            ))
+            .get_results(&mut conn)
+            .await?)
+    }
+
+    /// What's been recorded for `target` since `since`, only counting additions and not transfers. Limited to what
+    /// `updater` recorded if given.
+    pub async fn sum_recorded_since(
+        connection_maker: &impl Connector,
+        stat: TrackerStat,
+        guild_id: DiscordGuildId,
+        target: DiscordUserId,
+        updater: Option<DiscordUserId>,
+        since: DateTime<Utc>,
+    ) -> DbResult<BigDecimal> {
+        let mut conn = connection_maker.async_connect().await?;
+        let mut query = schema::tracker_count_changes::table
+            .filter(schema::tracker_count_changes::stat.eq(stat))
+            .filter(schema::tracker_count_changes::guild_id.eq(guild_id))
+            .filter(schema::tracker_count_changes::target.eq(target))
+            .filter(schema::tracker_count_changes::created.ge(since))
+            .filter(schema::tracker_count_changes::total.gt(BigDecimal::from(0)))
+            .filter(schema::tracker_count_changes::transfer.eq(false))
+            .select(diesel::dsl::sum(schema::tracker_count_changes::total))
+            .into_boxed();
+        if let Some(updater) = updater {
+            query = query.filter(schema::tracker_count_changes::updater.eq(updater));
+        }
+        Ok(query
+            .get_result::<Option<BigDecimal>>(&mut conn)
+            .await?
+            .unwrap_or_default())
+    }
+
     pub async fn load_desc(connection_maker: &impl Connector, stat: TrackerStat, guild_id: DiscordGuildId, start: i64, lim: i64) -> DbResult<Vec<Self>> {
         let mut conn = connection_maker.async_connect().await?;
         Ok(schema::tracker_counts::table
             .filter(schema::tracker_counts::stat.eq(stat))
             .filter(schema::tracker_counts::guild_id.eq(guild_id))
+            .filter(schema::tracker_counts::archived.is_null())
             .order_by((schema::tracker_counts::total, schema::tracker_counts::updated.desc()))
             .offset(start)
             .limit(lim)
@@ -379,3 +702,53 @@ impl TrackerCount {
             .await?)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use bigdecimal::BigDecimal;
+    use chrono::Utc;
+    use serenity::all::{GuildId, UserId};
+
+    use super::{StatTransfer, TrackerCount, TrackerCountId, TrackerStat, plan_transfer};
+
+    fn record(stat: TrackerStat, user_id: u64, total: i64) -> TrackerCount {
+        TrackerCount {
+            id: TrackerCountId::assume_valid(0),
+            created: Utc::now(),
+            updated: Utc::now(),
+            stat,
+            guild_id: GuildId::new(1).into(),
+            user_id: UserId::new(user_id).into(),
+            total: total.into(),
+            archived: None,
+        }
+    }
+
+    #[test]
+    fn test_plan_transfer_sums_on_conflict() {
+        let from = [record(TrackerStat::GroundKill, 1, 5), record(TrackerStat::NavyVictory, 1, 6)];
+        let to = [record(TrackerStat::GroundKill, 2, 3)];
+        assert_eq!(plan_transfer(&from, &to, None), vec![
+            StatTransfer {
+                stat: TrackerStat::GroundKill,
+                moved: BigDecimal::from(5),
+                before: BigDecimal::from(3),
+                after: BigDecimal::from(8),
+            },
+            StatTransfer {
+                stat: TrackerStat::NavyVictory,
+                moved: BigDecimal::from(6),
+                before: BigDecimal::from(0),
+                after: BigDecimal::from(6),
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_plan_transfer_selected_stats() {
+        let from = [record(TrackerStat::GroundKill, 1, 5), record(TrackerStat::NavyVictory, 1, 6)];
+        let plan = plan_transfer(&from, &[], Some(&[TrackerStat::NavyVictory]));
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].stat, TrackerStat::NavyVictory);
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::{Arc, atomic::{AtomicBool, Ordering}};
+
+use serenity::all::{Context, EventHandler, Guild, GuildId, Interaction, Member, Ready, User};
+use tracing as trc;
+
+use azel::{DatabaseConfiguration, DiscordHandler, cmd::CommandTreeTop};
+
+use crate::{cmd::{self, RequestKind}, db, locale, scheduler};
+
+/// azel's command handler, except commands are registered with their translations.
+pub struct Commands(pub DiscordHandler<RequestKind>);
+
+/// Commands as Discord gets them, with names and descriptions in every language we have a catalog for.
+fn localized_commands(descriptions: Vec<CommandTreeTop<RequestKind>>) -> Vec<serde_json::Value> {
+    descriptions.into_iter().map(|description| {
+        let mut command = serde_json::to_value(description.into_discord_command()).expect("commands serialize");
+        locale::localize_command(&mut command);
+        command
+    }).collect()
+}
+
+#[serenity::async_trait]
+impl EventHandler for Commands {
+    async fn ready(&self, ctx: Context, ready: Ready) {
+        trc::info!("CMD-SETUP");
+        let (global, local): (Vec<_>, Vec<_>) = self.0.command_descriptions.iter().cloned().partition(|top| top.is_global());
+        let local = localized_commands(local);
+        trc::info!("CMD-HOME-GUILD {:?}", self.0.home_guild_id);
+        for guild in ready.guilds {
+            trc::info!("CMD-SETUP-GUILD {:?}", guild.id);
+            ctx.http.create_guild_commands(guild.id, &local).await.expect("commands should have updated appropriately");
+        }
+        ctx.http.create_global_commands(&localized_commands(global)).await.expect("global commands updated appropriately");
+        trc::info!("CMD-SETUP-GLOBAL");
+        trc::info!("CMD-SETUP-CMPL");
+    }
+
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        self.0.interaction_create(ctx, interaction).await
+    }
+}
+
+/// Handles gateway events that azel doesn't dispatch to commands.
+pub struct Handler {
+    db_cfg: Arc<DatabaseConfiguration>,
+    /// `ready` fires again on every reconnect, the scheduler must only be started once.
+    scheduler_started: AtomicBool,
+}
+
+impl Handler {
+    pub fn new(db_cfg: DatabaseConfiguration) -> Self {
+        Self {
+            db_cfg: Arc::new(db_cfg),
+            scheduler_started: AtomicBool::new(false),
+        }
+    }
+}
+
+#[serenity::async_trait]
+impl EventHandler for Handler {
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        let db_cfg = self.db_cfg.as_ref();
+        match interaction {
+            Interaction::Autocomplete(cmd) => {
+                if let Err(e) = cmd::autocomplete::respond(&ctx, &db_cfg, &cmd).await {
+                    trc::error!("Failed to respond to autocomplete for {:?} due to {e:?}.", cmd.data.name);
+                }
+            },
+            Interaction::Component(component) => cmd::components::respond(&ctx, &db_cfg, &component).await,
+            Interaction::Modal(modal) => cmd::components::respond_modal(&ctx, &db_cfg, &modal).await,
+            _ => {},
+        }
+    }
+
+    async fn ready(&self, ctx: Context, _ready: Ready) {
+        if !self.scheduler_started.swap(true, Ordering::SeqCst) {
+            tokio::spawn(scheduler::run(Arc::clone(&self.db_cfg), Arc::clone(&ctx.http)));
+        }
+    }
+
+    async fn guild_create(&self, ctx: Context, guild: Guild, _is_new: Option<bool>) {
+        // Catch up on anyone who left or rejoined while we weren't connected.
+        let db_cfg = self.db_cfg.as_ref();
+        if let Err(e) = cmd::lib::members::reconcile(&ctx, &db_cfg, guild.id).await {
+            trc::error!("Failed to reconcile members of {:?} due to {e:?}.", guild.id);
+        }
+    }
+
+    async fn guild_member_addition(&self, _ctx: Context, new_member: Member) {
+        let db_cfg = self.db_cfg.as_ref();
+        match db::GuildMemberDeparture::clear(&db_cfg, new_member.guild_id.into(), new_member.user.id.into()).await {
+            Ok(restored) => trc::info!("{:?} joined {:?}, restored {restored} stats.", new_member.user.id, new_member.guild_id),
+            Err(e) => trc::error!("Failed to clear departure of {:?} from {:?} due to {e:?}.", new_member.user.id, new_member.guild_id),
+        }
+    }
+
+    async fn guild_member_removal(&self, _ctx: Context, guild_id: GuildId, user: User, _member_data_if_available: Option<Member>) {
+        let db_cfg = self.db_cfg.as_ref();
+        match db::GuildMemberDeparture::record(&db_cfg, guild_id.into(), user.id.into()).await {
+            Ok(archived) => trc::info!("{:?} left {:?}, archived {archived} stats.", user.id, guild_id),
+            Err(e) => trc::error!("Failed to record departure of {:?} from {:?} due to {e:?}.", user.id, guild_id),
+        }
+    }
+}
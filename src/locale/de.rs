@@ -0,0 +1,473 @@
+//! German.
+
+/// Top-level commands, and the entries in a member's context menu.
+pub const COMMAND_NAMES: &[(&str, &str)] = &[
+    ("event", "veranstaltung"),
+    ("industry", "industrie"),
+    ("navy", "marine"),
+    ("monthly_goal", "monatsziel"),
+    ("branch", "zweig"),
+    ("settings", "einstellungen"),
+    ("members", "mitglieder"),
+    ("stats", "statistiken"),
+    ("season", "saison"),
+    ("group", "gruppe"),
+    ("merit", "verdienst"),
+    ("Record One Naval Victory", "Einen Marinesieg eintragen"),
+    ("Check Naval Victories", "Marinesiege prüfen"),
+];
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Stats.
+    ("Personnel Saved", "Gerettetes Personal"),
+    ("Event Participation", "Veranstaltungsteilnahme"),
+    ("Industry Profit", "Industriegewinn"),
+    ("Ground Kill", "Bodenabschuss"),
+    ("Navy Victory", "Marinesieg"),
+    ("Navy Tackle Assist", "Marine-Tackle-Assist"),
+    ("{} personnel saved", "{} Personal gerettet"),
+    ("{} event", "{} Veranstaltung"),
+    ("{} events", "{} Veranstaltungen"),
+    ("{} credit", "{} Credit"),
+    ("{} credits", "{} Credits"),
+    ("{} kill", "{} Abschuss"),
+    ("{} kills", "{} Abschüsse"),
+    ("{} victory", "{} Sieg"),
+    ("{} victories", "{} Siege"),
+    ("{} tackle assist", "{} Tackle-Assist"),
+    ("{} tackle assists", "{} Tackle-Assists"),
+    ("saved {} personnel", "{} Personal gerettet"),
+    ("participated in {} event", "an {} Veranstaltung teilgenommen"),
+    ("participated in {} events", "an {} Veranstaltungen teilgenommen"),
+    ("contributed {} credit", "{} Credit beigetragen"),
+    ("contributed {} credits", "{} Credits beigetragen"),
+    ("{} confirmed kill", "{} bestätigter Abschuss"),
+    ("{} confirmed kills", "{} bestätigte Abschüsse"),
+    ("earned {} victory", "{} Sieg errungen"),
+    ("earned {} victories", "{} Siege errungen"),
+    ("earned {} tackle assist", "{} Tackle-Assist erzielt"),
+    ("earned {} tackle assists", "{} Tackle-Assists erzielt"),
+
+    // Replies.
+    ("Added {} to {} (total {}).", "{} für {} hinzugefügt (insgesamt {})."),
+    ("Removed {} from {} (total {}).", "{} von {} entfernt (insgesamt {})."),
+    ("@here {} has {}!", "@here {} hat {}!"),
+    ("We have {} recorded for {}.", "Wir haben {} für {} eingetragen."),
+    ("We have {} recorded for {} {}.", "Wir haben {} für {} {} eingetragen."),
+    ("\nStreak: {} (longest {}).\nAttendance: {} {}.", "\nSerie: {} (längste {}).\nAnwesenheit: {} {}."),
+    ("over the last {} weeks", "in den letzten {} Wochen"),
+    ("1 week", "1 Woche"),
+    ("{} weeks", "{} Wochen"),
+    ("no event days", "keine Veranstaltungstage"),
+    ("{} of {} event days ({}%)", "{} von {} Veranstaltungstagen ({} %)"),
+    ("no events", "keine Veranstaltungen"),
+    ("{} of {} events ({}%)", "{} von {} Veranstaltungen ({} %)"),
+    ("this week", "diese Woche"),
+    ("last week", "letzte Woche"),
+    ("this month", "diesen Monat"),
+    ("last month", "letzten Monat"),
+    ("on {}", "am {}"),
+    ("from {} to {}", "vom {} bis {}"),
+    ("season {}", "Saison {}"),
+    ("Scoreboard:", "Bestenliste:"),
+    ("**Scoreboard:**\n", "**Bestenliste:**\n"),
+    ("**Scoreboard ({}):**\n", "**Bestenliste ({}):**\n"),
+    ("Nobody has a streak going yet!", "Noch hat niemand eine Serie!"),
+    ("**Current streaks:**\n", "**Aktuelle Serien:**\n"),
+    ("**Longest streaks:**\n", "**Längste Serien:**\n"),
+    ("\t{}) {}: {} (longest {})\n", "\t{}) {}: {} (längste {})\n"),
+    ("all time", "gesamte Zeit"),
+    ("of all time", "insgesamt"),
+    ("**Group scoreboard for {} ({}, by total):**\n", "**Gruppen-Bestenliste für {} ({}, nach Summe):**\n"),
+    ("**Group scoreboard for {} ({}, by average per member):**\n", "**Gruppen-Bestenliste für {} ({}, nach Schnitt pro Mitglied):**\n"),
+    ("\t{}) {} {}: {} ({} per member across {} members)\n", "\t{}) {} {}: {} ({} pro Mitglied bei {} Mitgliedern)\n"),
+    ("No groups have been set up! Use `/group add` to rank a role's members together.", "Es sind noch keine Gruppen eingerichtet! Mit `/group add` werden die Mitglieder einer Rolle gemeinsam gewertet."),
+    ("{} has no matching stats to transfer.", "{} hat keine passenden Statistiken zum Übertragen."),
+    ("**Transferred from {} to {}:**\n", "**Von {} auf {} übertragen:**\n"),
+    ("**Preview of transfer from {} to {}:**\n", "**Vorschau der Übertragung von {} auf {}:**\n"),
+    ("Nothing has been changed yet. Run the command again with `confirm` set to apply it.", "Noch wurde nichts geändert. Führe den Befehl erneut mit `confirm` aus, um ihn anzuwenden."),
+    ("No stat limits have been set up! Use `/stats limit_set` to add some.", "Es sind noch keine Statistik-Limits eingerichtet! Mit `/stats limit_set` kannst du welche hinzufügen."),
+    ("At once", "Auf einmal"),
+    ("Per member per day", "Pro Mitglied pro Tag"),
+    ("For yourself per day", "Für dich selbst pro Tag"),
+    ("flagged over {}", "gemeldet über {}"),
+    ("rejected over {}", "abgelehnt über {}"),
+    ("no limit", "kein Limit"),
+    ("Limits for {} are now:\n{}", "Limits für {} sind jetzt:\n{}"),
+    ("{} no longer has any limits.", "{} hat keine Limits mehr."),
+    ("{} merit", "{} Verdienst"),
+    ("{} has no merit {}.", "{} hat keinen Verdienst {}."),
+    ("{} has {} {}, rank {} of {}.\n", "{} hat {} {}, Rang {} von {}.\n"),
+    ("**Merit scoreboard ({}):**\n", "**Verdienst-Bestenliste ({}):**\n"),
+    ("{} no longer counts towards merit.", "{} zählt nicht mehr zum Verdienst."),
+    ("{} now earns {} merit per {}.", "{} bringt jetzt {} Verdienst pro {}."),
+    ("{}: {} merit per {}", "{}: {} Verdienst pro {}"),
+    ("No payouts are outstanding.", "Es stehen keine Auszahlungen aus."),
+    ("**Outstanding payouts:**\n", "**Ausstehende Auszahlungen:**\n"),
+    ("- #{} `{}`: {} owes {} {}\n", "- #{} `{}`: {} schuldet {} {}\n"),
+    ("{} owes {} in total.\n", "{} schuldet insgesamt {}.\n"),
+    ("**Profit run #{} `{}`** ({}), funded by {}\nGross {}, costs {}, net {}.\n", "**Tour #{} `{}`** ({}), finanziert von {}\nBrutto {}, Kosten {}, netto {}.\n"),
+    ("own share", "eigener Anteil"),
+    ("paid {}", "bezahlt am {}"),
+    ("**unpaid**", "**unbezahlt**"),
+    ("- {} keeps {}\n", "- {} behält {}\n"),
+    ("Marked {}'s share of {} from run #{} `{}` as paid.", "Der Anteil von {} über {} aus Tour #{} `{}` ist als bezahlt markiert."),
+    ("Recorded profit run #{} `{}`: {} net, funded by {}.\n", "Tour #{} `{}` eingetragen: {} netto, finanziert von {}.\n"),
+    (", owed by the owner", ", geschuldet vom Besitzer"),
+    ("Use `/industry run paid run:{}` once a share has been paid out.", "Nutze `/industry run paid run:{}`, sobald ein Anteil ausgezahlt wurde."),
+    ("**Hall of fame: {}** ({} to {})\n", "**Ruhmeshalle: {}** ({} bis {})\n"),
+    ("Shared {} between {} crew:\n", "{} auf {} Crewmitglieder aufgeteilt:\n"),
+    ("- {}: {} (weight {}, total {})\n", "- {}: {} (Gewicht {}, insgesamt {})\n"),
+    ("No goals have been set up!", "Es sind noch keine Ziele eingerichtet!"),
+    ("Goals for {}", "Ziele für {}"),
+    ("Goal Progress: Main", "Zielfortschritt: Haupt"),
+    ("Goal Progress: {}", "Zielfortschritt: {}"),
+    ("Due <t:{}:R>\n", "Fällig <t:{}:R>\n"),
+    ("Owner: {}\n", "Verantwortlich: {}\n"),
+    ("Pledged ({}): {}\n", "Zugesagt ({}): {}\n"),
+    // Commands.
+    ("Ping!", "Ping!"),
+    ("Record a participant for an event", "Teilnahme an einer Veranstaltung eintragen"),
+    ("Remove a participant from an event", "Teilnahme an einer Veranstaltung entfernen"),
+    ("Check how many events a participant has been part of", "Prüfen, an wie vielen Veranstaltungen jemand teilgenommen hat"),
+    ("Rank members by how many weeks in a row they've taken part in events", "Mitglieder danach ordnen, wie viele Wochen am Stück sie teilgenommen haben"),
+    ("Schedule an event and post a card members can RSVP on", "Eine Veranstaltung planen und eine Karte zum Zu- und Absagen posten"),
+    ("Confirm who attended an event and credit their participation", "Bestätigen, wer teilgenommen hat, und die Teilnahme gutschreiben"),
+    ("Records rocks", "Gesteine eintragen"),
+    ("Record profits", "Gewinne eintragen"),
+    ("Delete profits", "Gewinne löschen"),
+    ("Boast about your profits", "Mit deinen Gewinnen prahlen"),
+    ("Checks someone's (or your own) profits", "Die Gewinne von jemandem (oder deine eigenen) prüfen"),
+    ("Creates the scoreboard of profits across Auric.", "Erstellt die Bestenliste der Gewinne in ganz Auric."),
+    ("Removes old unknown users from the scoreboard", "Entfernt alte, unbekannte Nutzer aus der Bestenliste"),
+    ("Record a crewed run, split its profit and credit everyone's share", "Eine Crew-Tour eintragen, den Gewinn aufteilen und allen ihren Anteil gutschreiben"),
+    ("Show a run's profit split and which shares have been paid", "Die Gewinnaufteilung einer Tour zeigen und welche Anteile bezahlt sind"),
+    ("List crew shares that haven't been paid out yet", "Crew-Anteile auflisten, die noch nicht ausgezahlt wurden"),
+    ("Mark a crew member's share of a run as paid", "Den Anteil eines Crewmitglieds an einer Tour als bezahlt markieren"),
+    ("Record saved personnel", "Gerettetes Personal eintragen"),
+    ("Delete saved personnel", "Gerettetes Personal löschen"),
+    ("Boast about your saved personnel", "Mit deinem geretteten Personal prahlen"),
+    ("Checks someone's (or your own) saved personnel count", "Das gerettete Personal von jemandem (oder dein eigenes) prüfen"),
+    ("Creates the scoreboard of saved personnel across Auric", "Erstellt die Bestenliste des geretteten Personals in ganz Auric"),
+    ("Record one victory for this user.", "Einen Sieg für dieses Mitglied eintragen."),
+    ("Checks one victory for user.", "Die Siege dieses Mitglieds prüfen."),
+    ("Records a certain number of naval victories for a user.", "Trägt eine bestimmte Anzahl Marinesiege für jemanden ein."),
+    ("Removes a certain number of naval victories for a user. Only goes down to 0!", "Entfernt eine bestimmte Anzahl Marinesiege für jemanden. Geht nur bis 0!"),
+    ("Boast about your naval victories.", "Mit deinen Marinesiegen prahlen."),
+    ("Checks the number of naval victories for a specific user (or yourself).", "Prüft die Marinesiege von jemandem (oder deine eigenen)."),
+    ("Creates the scoreboard of naval victories across Auric.", "Erstellt die Bestenliste der Marinesiege in ganz Auric."),
+    ("Split victories between a crew, optionally weighted by role", "Siege auf eine Crew aufteilen, wahlweise nach Rolle gewichtet"),
+    ("Records a certain number of naval tackle assists for a user.", "Trägt eine bestimmte Anzahl Tackle-Assists für jemanden ein."),
+    ("Removes a certain number of naval tackle assists for a user. Only goes down to 0!", "Entfernt eine bestimmte Anzahl Tackle-Assists für jemanden. Geht nur bis 0!"),
+    ("Boast about your naval tackle assists.", "Mit deinen Tackle-Assists prahlen."),
+    ("Checks the number of naval tackle assists for a specific user (or yourself).", "Prüft die Tackle-Assists von jemandem (oder deine eigenen)."),
+    ("Creates the scoreboard of naval tackle assists across Auric.", "Erstellt die Bestenliste der Tackle-Assists in ganz Auric."),
+    ("Records a certain number of kills for a user.", "Trägt eine bestimmte Anzahl Abschüsse für jemanden ein."),
+    ("Deletes a certain number of kills for a user.", "Löscht eine bestimmte Anzahl Abschüsse für jemanden."),
+    ("Boast about your legion kills.", "Mit deinen Legionsabschüssen prahlen."),
+    ("Checks the number of legion kills for a specific user (or yourself).", "Prüft die Legionsabschüsse von jemandem (oder deine eigenen)."),
+    ("Creates the scoreboard of legion kills across Auric.", "Erstellt die Bestenliste der Legionsabschüsse in ganz Auric."),
+    ("Check the monthly goal for the org or a branch", "Das Monatsziel der Org oder eines Zweigs prüfen"),
+    ("Clear all monthly goals", "Alle Monatsziele zurücksetzen"),
+    ("Edit a goal's header and body in a form, with a preview before saving", "Titel und Text eines Ziels in einem Formular bearbeiten, mit Vorschau"),
+    ("Post a goal board to a channel that updates itself whenever goals change", "Eine Zieltafel in einen Kanal posten, die sich bei Änderungen selbst aktualisiert"),
+    ("Stop updating a goal board and delete its message", "Eine Zieltafel nicht mehr aktualisieren und ihre Nachricht löschen"),
+    ("List out goals including shortnames", "Ziele mit ihren Kurznamen auflisten"),
+    ("Post a goal here with a button members can use to pledge their help", "Ein Ziel hier posten, mit einem Knopf, über den Mitglieder Hilfe zusagen können"),
+    ("Show how a goal's text and progress changed over time", "Zeigen, wie sich Text und Fortschritt eines Ziels verändert haben"),
+    ("Put a goal back the way it was as of an earlier revision", "Ein Ziel auf den Stand einer früheren Version zurücksetzen"),
+    ("Add an item to a goal's checklist, which then sets the goal's progress", "Einen Punkt zur Checkliste eines Ziels hinzufügen, die dann den Fortschritt bestimmt"),
+    ("Tick off an item of a goal's checklist", "Einen Punkt auf der Checkliste eines Ziels abhaken"),
+    ("Mark a completed checklist item as not done after all", "Einen abgehakten Punkt wieder als offen markieren"),
+    ("Create or update a branch of the org", "Einen Zweig der Org anlegen oder ändern"),
+    ("Remove a branch of the org", "Einen Zweig der Org entfernen"),
+    ("List the branches of the org", "Die Zweige der Org auflisten"),
+    ("Set the timezone used for weeks and months", "Die Zeitzone für Wochen und Monate festlegen"),
+    ("Show this server's settings", "Die Einstellungen dieses Servers zeigen"),
+    ("Set where records over a soft limit get flagged", "Festlegen, wo Einträge über einem weichen Limit gemeldet werden"),
+    ("Set how progress bars are drawn, e.g. without colour codes for mobile", "Festlegen, wie Fortschrittsbalken aussehen, z. B. ohne Farbcodes fürs Handy"),
+    ("List members who left and whose stats are archived", "Ausgetretene Mitglieder mit archivierten Statistiken auflisten"),
+    ("Permanently delete stats of members who left a while ago", "Statistiken von vor einer Weile ausgetretenen Mitgliedern endgültig löschen"),
+    ("Move stats from one account to another, e.g. after a member switches accounts", "Statistiken auf ein anderes Konto übertragen, z. B. nach einem Kontowechsel"),
+    ("Limit how much of a stat can be recorded, replacing its current limits", "Begrenzen, wie viel eingetragen werden kann, ersetzt die bisherigen Limits"),
+    ("Remove all limits on a stat", "Alle Limits einer Statistik entfernen"),
+    ("List the limits on recording stats", "Die Limits für das Eintragen von Statistiken auflisten"),
+    ("Start a season, scoreboards count from zero while it runs", "Eine Saison starten, Bestenlisten zählen währenddessen ab null"),
+    ("List this server's seasons", "Die Saisons dieses Servers auflisten"),
+    ("Remove a season that hasn't finished yet", "Eine noch nicht beendete Saison entfernen"),
+    ("Show a finished season's hall of fame", "Die Ruhmeshalle einer beendeten Saison zeigen"),
+    ("Rank everyone with a role together, e.g. a wing or squad", "Alle mit einer Rolle gemeinsam werten, z. B. einen Flügel oder Trupp"),
+    ("Stop ranking a role as a group", "Eine Rolle nicht mehr als Gruppe werten"),
+    ("List the roles ranked as groups", "Die als Gruppen gewerteten Rollen auflisten"),
+    ("Rank groups by the combined stats of their members", "Gruppen nach den gemeinsamen Statistiken ihrer Mitglieder ordnen"),
+    ("Set how much a stat counts towards merit", "Festlegen, wie viel eine Statistik zum Verdienst beiträgt"),
+    ("Stop a stat from counting towards merit", "Eine Statistik nicht mehr zum Verdienst zählen"),
+    ("List how much each stat counts towards merit", "Auflisten, wie viel jede Statistik zum Verdienst beiträgt"),
+    ("Rank members by merit", "Mitglieder nach Verdienst ordnen"),
+    ("Check a member's merit and what it's made of", "Den Verdienst eines Mitglieds prüfen und woraus er sich zusammensetzt"),
+    ("Event commands", "Befehle für Veranstaltungen"),
+    ("Commands for tracking event participation", "Befehle zum Erfassen der Veranstaltungsteilnahme"),
+    ("Industry commands", "Befehle für die Industrie"),
+    ("Commands for mining data stashing", "Befehle zum Speichern von Bergbaudaten"),
+    ("Commands for managing profit records", "Befehle zum Verwalten von Gewinnen"),
+    ("Commands for crewed profit runs and their payouts", "Befehle für Crew-Touren und ihre Auszahlungen"),
+    ("Navy commands", "Befehle für die Marine"),
+    ("Commands for managing victory counts", "Befehle zum Verwalten von Siegen"),
+    ("Commands for managing tackle assist counts", "Befehle zum Verwalten von Tackle-Assists"),
+    ("Legion commands", "Befehle für die Legion"),
+    ("Commands for managing kill counts", "Befehle zum Verwalten von Abschüssen"),
+    ("Commands for managing monthly goals", "Befehle zum Verwalten von Monatszielen"),
+    ("Commands for managing a goal's checklist", "Befehle zum Verwalten der Checkliste eines Ziels"),
+    ("Commands for managing records around goal progress", "Befehle zum Verwalten von Einträgen zum Zielfortschritt"),
+    ("Commands for managing the branches of the org", "Befehle zum Verwalten der Zweige der Org"),
+    ("Commands for managing server-wide settings", "Befehle zum Verwalten serverweiter Einstellungen"),
+    ("Commands for managing stats of members who left", "Befehle für die Statistiken ausgetretener Mitglieder"),
+    ("Officer commands for managing members' stats", "Offiziersbefehle zum Verwalten der Statistiken von Mitgliedern"),
+    ("Commands for seasons and their hall of fame", "Befehle für Saisons und ihre Ruhmeshalle"),
+    ("Commands for ranking wings, squads and other role groups", "Befehle zum Werten von Flügeln, Trupps und anderen Rollengruppen"),
+    ("Commands for weighted merit scores built from tracked stats", "Befehle für gewichtete Verdienstpunkte aus erfassten Statistiken"),
+
+    // Options.
+    ("Person being recorded for. Leaving this out means that you're recording your own participation.", "Für wen eingetragen wird. Ohne Angabe trägst du deine eigene Teilnahme ein."),
+    ("Number of events being recorded, defaults to 1", "Anzahl der eingetragenen Veranstaltungen, standardmäßig 1"),
+    ("Notes. This is not accessible via commands.", "Notizen. Diese sind über Befehle nicht abrufbar."),
+    ("notes", "Notizen"),
+    ("Person being checked. Leaving this out means that you're checking your own participation.", "Wer geprüft wird. Ohne Angabe prüfst du deine eigene Teilnahme."),
+    ("Only count records from this period, e.g. `this_month` or `2026-03-01..2026-03-15`.", "Nur Einträge aus diesem Zeitraum zählen, z. B. `this_month` oder `2026-03-01..2026-03-15`."),
+    ("Rank by the streak members have going now, or their longest ever. Defaults to current.", "Nach der aktuellen oder der längsten Serie ordnen. Standardmäßig die aktuelle."),
+    ("How many members to show, defaults to 10", "Wie viele Mitglieder gezeigt werden, standardmäßig 10"),
+    ("What the event is called", "Wie die Veranstaltung heißt"),
+    ("When it starts, as YYYY-MM-DD HH:MM in the server's timezone", "Wann sie beginnt, als JJJJ-MM-TT HH:MM in der Zeitzone des Servers"),
+    ("What to bring, where to meet, and so on", "Was mitzubringen ist, wo man sich trifft und so weiter"),
+    ("The event to run the roll call for", "Die Veranstaltung, für die der Appell gemacht wird"),
+    ("Someone who attended without RSVPing", "Jemand, der ohne Zusage teilgenommen hat"),
+    ("Number of alpha UEC. Defaults to 1000.", "Anzahl alpha UEC. Standardmäßig 1000."),
+    ("Person being recorded for. Leaving this out means that you're recording your own profits.", "Für wen eingetragen wird. Ohne Angabe trägst du deine eigenen Gewinne ein."),
+    ("Number of alpha UEC.", "Anzahl alpha UEC."),
+    ("Person to get victories for. Defaults to self. Quieter than boasting.", "Wessen Siege abgefragt werden. Standardmäßig deine. Leiser als Prahlen."),
+    ("Maximum entries to return. Max of 20. Defaults to 10.", "Höchstzahl an Einträgen. Maximal 20. Standardmäßig 10."),
+    ("What to orient the scoreboard on.", "Woran sich die Bestenliste ausrichtet."),
+    ("Should only be provided if \"at\" is set to \"someone\".", "Nur angeben, wenn \"at\" auf \"someone\" steht."),
+    ("The integer rank to start the scoreboard at. Mutually exclusive with \"someone\"", "Der Rang, bei dem die Bestenliste beginnt. Nicht zusammen mit \"someone\""),
+    ("Only count this period, e.g. `this_month`. Defaults to the running season, or `all_time`.", "Nur diesen Zeitraum zählen, z. B. `this_month`. Standardmäßig die laufende Saison."),
+    ("What the run was, e.g. `Hull-C to ARC-L1`", "Was die Tour war, z. B. `Hull-C nach ARC-L1`"),
+    ("Total revenue of the run in aUEC", "Gesamteinnahmen der Tour in aUEC"),
+    ("Costs of the run in aUEC, taken off before splitting. Defaults to 0.", "Kosten der Tour in aUEC, vor dem Aufteilen abgezogen. Standardmäßig 0."),
+    ("Who funded the run and owes the crew. Defaults to you.", "Wer die Tour finanziert hat und der Crew etwas schuldet. Standardmäßig du."),
+    ("Crew member getting a share", "Crewmitglied, das einen Anteil bekommt"),
+    ("Percentage of the net profit going to crew1, e.g. 25", "Prozent des Nettogewinns für crew1, z. B. 25"),
+    ("Percentage of the net profit going to crew2, e.g. 25", "Prozent des Nettogewinns für crew2, z. B. 25"),
+    ("Percentage of the net profit going to crew3, e.g. 25", "Prozent des Nettogewinns für crew3, z. B. 25"),
+    ("Percentage of the net profit going to crew4, e.g. 25", "Prozent des Nettogewinns für crew4, z. B. 25"),
+    ("Percentage of the net profit going to crew5, e.g. 25", "Prozent des Nettogewinns für crew5, z. B. 25"),
+    ("Percentage of the net profit going to crew6, e.g. 25", "Prozent des Nettogewinns für crew6, z. B. 25"),
+    ("Percentage of the net profit going to crew7, e.g. 25", "Prozent des Nettogewinns für crew7, z. B. 25"),
+    ("Percentage of the net profit going to crew8, e.g. 25", "Prozent des Nettogewinns für crew8, z. B. 25"),
+    ("Number of the run", "Nummer der Tour"),
+    ("Only show shares owed by this person", "Nur Anteile zeigen, die diese Person schuldet"),
+    ("Only show shares owed to this person", "Nur Anteile zeigen, die dieser Person zustehen"),
+    ("Crew member who was paid. Defaults to you.", "Crewmitglied, das bezahlt wurde. Standardmäßig du."),
+    ("Number of victories. Only accepts values in intervals of 0.25. Defaults to 1.", "Anzahl Siege. Nur in Schritten von 0,25. Standardmäßig 1."),
+    ("Person being recorded for. Leaving this out means that you're recording your own victories.", "Für wen eingetragen wird. Ohne Angabe trägst du deine eigenen Siege ein."),
+    ("Crew member sharing in the victories", "Crewmitglied, das an den Siegen beteiligt ist"),
+    ("Number of victories to share. Only accepts values in intervals of 0.25. Defaults to 1.", "Anzahl der aufzuteilenden Siege. Nur in Schritten von 0,25. Standardmäßig 1."),
+    ("Relative share of crew1, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Relativer Anteil von crew1, z. B. 2 für Pilot und 1 für Schütze. Standardmäßig 1."),
+    ("Relative share of crew2, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Relativer Anteil von crew2, z. B. 2 für Pilot und 1 für Schütze. Standardmäßig 1."),
+    ("Relative share of crew3, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Relativer Anteil von crew3, z. B. 2 für Pilot und 1 für Schütze. Standardmäßig 1."),
+    ("Relative share of crew4, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Relativer Anteil von crew4, z. B. 2 für Pilot und 1 für Schütze. Standardmäßig 1."),
+    ("Relative share of crew5, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Relativer Anteil von crew5, z. B. 2 für Pilot und 1 für Schütze. Standardmäßig 1."),
+    ("Relative share of crew6, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Relativer Anteil von crew6, z. B. 2 für Pilot und 1 für Schütze. Standardmäßig 1."),
+    ("Relative share of crew7, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Relativer Anteil von crew7, z. B. 2 für Pilot und 1 für Schütze. Standardmäßig 1."),
+    ("Relative share of crew8, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Relativer Anteil von crew8, z. B. 2 für Pilot und 1 für Schütze. Standardmäßig 1."),
+    ("Number of tackle assists. Defaults to 1.", "Anzahl Tackle-Assists. Standardmäßig 1."),
+    ("Person being recorded for. Leaving this out means that you're recording your own tackle assists.", "Für wen eingetragen wird. Ohne Angabe trägst du deine eigenen Tackle-Assists ein."),
+    ("Person to get tackle assists for. Defaults to self. Quieter than boasting.", "Wessen Tackle-Assists abgefragt werden. Standardmäßig deine. Leiser als Prahlen."),
+    ("Number of kills. Only accepts values in intervals of 0.25. Defaults to 1.", "Anzahl Abschüsse. Nur in Schritten von 0,25. Standardmäßig 1."),
+    ("Person being recorded for. Leaving this out means that you're recording your own kills.", "Für wen eingetragen wird. Ohne Angabe trägst du deine eigenen Abschüsse ein."),
+    ("Person to get kills for. Defaults to self. Quieter than boasting.", "Wessen Abschüsse abgefragt werden. Standardmäßig deine. Leiser als Prahlen."),
+    ("Which branch to check the goal for, or the org (defaults to main)", "Für welchen Zweig das Ziel geprüft wird, oder die Org (standardmäßig main)"),
+    ("Whether to show additional detail (defaults to false)", "Ob weitere Details gezeigt werden (standardmäßig nein)"),
+    ("Whether to show additional detail (defaults to false), only applies if checking Main goals", "Ob weitere Details gezeigt werden (standardmäßig nein), nur für die Ziele von Main"),
+    ("whether to show progress bars", "ob Fortschrittsbalken gezeigt werden"),
+    ("How to draw progress bars, defaults to the server's setting", "Wie Fortschrittsbalken aussehen, standardmäßig wie in den Servereinstellungen"),
+    ("Name for the goal. If active doesn't exist, will create", "Name des Ziels. Gibt es kein aktives, wird es angelegt"),
+    ("Progress of the goal, up to 100", "Fortschritt des Ziels, bis 100"),
+    ("Header of the message, max 256 chars", "Titel der Nachricht, maximal 256 Zeichen"),
+    ("Body of the message, max 4096 chars. Use `monthly_goal edit` for anything long.", "Text der Nachricht, maximal 4096 Zeichen. Für Längeres `monatsziel edit` nutzen."),
+    ("Due date as YYYY-MM-DD or YYYY-MM-DD HH:MM in the server's timezone, or `none`", "Fällig am, als JJJJ-MM-TT oder JJJJ-MM-TT HH:MM in der Serverzeitzone, oder `none`"),
+    ("Extra percentages to announce besides 25/50/75/100, e.g. `10,90`, or `none`", "Weitere anzukündigende Prozente neben 25/50/75/100, z. B. `10,90`, oder `none`"),
+    ("Officer responsible for the goal, who gets a DM on pledges and progress", "Zuständiger Offizier, der bei Zusagen und Fortschritt eine DM bekommt"),
+    ("Leave the goal without an owner", "Das Ziel ohne Zuständigen lassen"),
+    ("Which branch to set the goal for, or the org (defaults to main)", "Für welchen Zweig das Ziel gilt, oder die Org (standardmäßig main)"),
+    ("Shortname to clear", "Kurzname des zurückzusetzenden Ziels"),
+    ("Branch to clear", "Zurückzusetzender Zweig"),
+    ("Shortname of the goal to edit", "Kurzname des zu bearbeitenden Ziels"),
+    ("Where to post the board, defaults to this channel", "Wo die Tafel gepostet wird, standardmäßig in diesem Kanal"),
+    ("Which branch the board is for, or the org (defaults to main)", "Für welchen Zweig die Tafel ist, oder die Org (standardmäßig main)"),
+    ("Whether to show each goal (defaults to false)", "Ob jedes Ziel gezeigt wird (standardmäßig nein)"),
+    ("Whether to show each branch (defaults to false), only applies to the Main board", "Ob jeder Zweig gezeigt wird (standardmäßig nein), nur für die Tafel von Main"),
+    ("Which branch's board to remove (defaults to main)", "Die Tafel welches Zweigs entfernt wird (standardmäßig main)"),
+    ("Tag of the goal. will get all if not provided", "Tag des Ziels. Ohne Angabe werden alle geholt"),
+    ("Shortname of the goal to share", "Kurzname des zu teilenden Ziels"),
+    ("Shortname of the goal", "Kurzname des Ziels"),
+    ("How many revisions to show, defaults to 5", "Wie viele Versionen gezeigt werden, standardmäßig 5"),
+    ("Number of the revision, as shown by `monthly_goal history`", "Nummer der Version, wie von `monatsziel history` gezeigt"),
+    ("What needs doing, max 256 chars", "Was zu tun ist, maximal 256 Zeichen"),
+    ("Number of the item, as shown by `monthly_goal check`", "Nummer des Punkts, wie von `monatsziel check` gezeigt"),
+    ("Relevant tracked stat for command", "Betroffene erfasste Statistik"),
+    ("Total to add. Defaults to 1.", "Hinzuzufügende Menge. Standardmäßig 1."),
+    ("Total to remove. Defaults to 1.", "Zu entfernende Menge. Standardmäßig 1."),
+    ("Tag goals use for this branch, e.g. `navy`. Updates the branch if it already exists.", "Tag der Ziele dieses Zweigs, z. B. `navy`. Ändert den Zweig, falls es ihn schon gibt."),
+    ("Name shown for the branch. Defaults to the tag.", "Angezeigter Name des Zweigs. Standardmäßig der Tag."),
+    ("ANSI color (0 - 255) for progress bars. Defaults to white.", "ANSI-Farbe (0 - 255) für Fortschrittsbalken. Standardmäßig weiß."),
+    ("Emoji shown next to the branch name.", "Emoji neben dem Namen des Zweigs."),
+    ("Remove the branch's emoji.", "Das Emoji des Zweigs entfernen."),
+    ("Branches with lower values are listed first. Defaults to 0.", "Zweige mit niedrigeren Werten stehen weiter oben. Standardmäßig 0."),
+    ("Where goal reminders and milestones for this branch are posted.", "Wo Erinnerungen und Meilensteine für diesen Zweig gepostet werden."),
+    ("Tag of the branch to remove", "Tag des zu entfernenden Zweigs"),
+    ("IANA timezone name, e.g. `America/New_York` or `Australia/Sydney`.", "IANA-Zeitzonenname, z. B. `Europe/Berlin` oder `Europe/Vienna`."),
+    ("Channel for flagged records. Leave out to stop flagging.", "Kanal für gemeldete Einträge. Weglassen, um nicht mehr zu melden."),
+    ("How to draw progress bars unless a command asks otherwise.", "Wie Fortschrittsbalken aussehen, sofern ein Befehl nichts anderes verlangt."),
+    ("Only delete stats archived at least this many days ago. Defaults to 90.", "Nur Statistiken löschen, die mindestens so viele Tage archiviert sind. Standardmäßig 90."),
+    ("Account the stats are moved away from", "Konto, von dem die Statistiken übertragen werden"),
+    ("Account receiving the stats. Totals are added to anything it already has.", "Empfangendes Konto. Die Summen kommen zu seinen bisherigen hinzu."),
+    ("Only move this stat. Defaults to all stats.", "Nur diese Statistik übertragen. Standardmäßig alle."),
+    ("Apply the transfer. Without this, only a preview is shown.", "Die Übertragung durchführen. Ohne dies wird nur eine Vorschau gezeigt."),
+    ("Stat to limit", "Zu begrenzende Statistik"),
+    ("Flag records bigger than this", "Einträge melden, die größer sind"),
+    ("Reject records bigger than this", "Einträge ablehnen, die größer sind"),
+    ("Flag once a member has been given more than this in a day", "Melden, sobald jemand an einem Tag mehr als das bekommen hat"),
+    ("Reject once a member would be given more than this in a day", "Ablehnen, sobald jemand an einem Tag mehr als das bekäme"),
+    ("Flag once a member has recorded more than this for themselves in a day", "Melden, sobald jemand an einem Tag mehr als das für sich selbst eingetragen hat"),
+    ("Reject once a member would record more than this for themselves in a day. 0 forbids it.", "Ablehnen, sobald jemand an einem Tag mehr als das für sich eintrüge. 0 verbietet es."),
+    ("Stat to remove the limits of", "Statistik, deren Limits entfernt werden"),
+    ("Name of the season, e.g. `Summer 2026`", "Name der Saison, z. B. `Sommer 2026`"),
+    ("First day of the season, e.g. `2026-07-01`", "Erster Tag der Saison, z. B. `2026-07-01`"),
+    ("Last day of the season, e.g. `2026-09-30`", "Letzter Tag der Saison, z. B. `2026-09-30`"),
+    ("Season to remove", "Zu entfernende Saison"),
+    ("Season to show. Defaults to the most recently finished one.", "Zu zeigende Saison. Standardmäßig die zuletzt beendete."),
+    ("Only show this stat", "Nur diese Statistik zeigen"),
+    ("Role whose members make up the group", "Rolle, deren Mitglieder die Gruppe bilden"),
+    ("Name shown on scoreboards. Defaults to the role's name.", "Name in Bestenlisten. Standardmäßig der Name der Rolle."),
+    ("Role to stop ranking as a group", "Rolle, die nicht mehr als Gruppe gewertet wird"),
+    ("Stat to rank groups by", "Statistik, nach der Gruppen geordnet werden"),
+    ("Rank by the group's total, or its average per member. Defaults to total.", "Nach der Summe der Gruppe oder ihrem Schnitt pro Mitglied ordnen. Standardmäßig Summe."),
+    ("Stat to weigh", "Zu gewichtende Statistik"),
+    ("Merit earned for every `per` of the stat", "Verdienst für jedes `per` der Statistik"),
+    ("How much of the stat earns `points` merit, e.g. 100000 aUEC. Defaults to 1.", "Wie viel der Statistik `points` Verdienst bringt, z. B. 100000 aUEC. Standardmäßig 1."),
+    ("Stat to stop counting", "Statistik, die nicht mehr zählt"),
+    ("Only count this period, e.g. `this_month`. Defaults to all time.", "Nur diesen Zeitraum zählen, z. B. `this_month`. Standardmäßig alles."),
+    ("Member to check. Defaults to you.", "Zu prüfendes Mitglied. Standardmäßig du."),
+
+    // Errors.
+    ("At least one crew member needs a weight above zero.", "Mindestens ein Crewmitglied braucht ein Gewicht über null."),
+    ("Command must be run from within a guild.", "Der Befehl muss auf einem Server ausgeführt werden."),
+    ("Command must be run from within a server.", "Der Befehl muss auf einem Server ausgeführt werden."),
+    ("Events only work within a server.", "Veranstaltungen gibt es nur auf einem Server."),
+    ("Goals only work within a server.", "Ziele gibt es nur auf einem Server."),
+    ("No merit weights have been set up! Use `/merit set_weight` to add some.", "Es sind keine Verdienstgewichte eingerichtet! Mit `/verdienst set_weight` kannst du welche anlegen."),
+    ("No season has finished yet.", "Es ist noch keine Saison beendet."),
+    ("Nobody has RSVP'd yet. Add attendees with the `extra` options.", "Noch hat niemand zugesagt. Teilnehmer mit den `extra`-Optionen hinzufügen."),
+    ("Only officers can publish goal boards.", "Nur Offiziere können Zieltafeln veröffentlichen."),
+    ("Only officers can remove goal boards.", "Nur Offiziere können Zieltafeln entfernen."),
+    ("Only officers can purge the stats of members who left.", "Nur Offiziere können die Statistiken von Mitgliedern löschen, die gegangen sind."),
+    ("Only officers can change branches.", "Nur Offiziere können Zweige ändern."),
+    ("Only officers can change the server's timezone.", "Nur Offiziere können die Zeitzone des Servers ändern."),
+    ("Only officers can change seasons.", "Nur Offiziere können Saisons ändern."),
+    ("Only officers can change groups.", "Nur Offiziere können Gruppen ändern."),
+    ("Only officers can record profit runs.", "Nur Offiziere können Touren erfassen."),
+    ("Only officers can change merit weights.", "Nur Offiziere können Verdienstgewichte ändern."),
+    ("Only officers can change the officer channel.", "Nur Offiziere können den Offizierskanal ändern."),
+    ("Pick an `owner` or `clear_owner`, not both.", "Wähle `owner` oder `clear_owner`, nicht beides."),
+    ("Only officers can change the server's bar style.", "Nur Offiziere können den Balkenstil des Servers ändern."),
+    ("Pick an `emoji` or `clear_emoji`, not both.", "Wähle `emoji` oder `clear_emoji`, nicht beides."),
+    ("Only the host or an officer can run the roll call.", "Nur der Gastgeber oder ein Offizier kann den Appell machen."),
+    ("Only the run's owner or the crew member being paid can mark a share as paid.", "Nur wer die Tour finanziert hat oder das bezahlte Crewmitglied kann einen Anteil als bezahlt markieren."),
+    ("Pick an event from the list.", "Wähle eine Veranstaltung aus der Liste."),
+    ("Roll call for this event has already been done.", "Der Appell für diese Veranstaltung wurde schon gemacht."),
+    ("Roll call for this event is done, RSVPs are closed.", "Der Appell für diese Veranstaltung ist erledigt, Zusagen sind geschlossen."),
+    ("Stats can't be transferred to the same account.", "Statistiken können nicht auf dasselbe Konto übertragen werden."),
+    ("The crew's shares add up to more than 100%.", "Die Anteile der Crew ergeben zusammen mehr als 100 %."),
+    ("The deadline has to be in the future.", "Die Frist muss in der Zukunft liegen."),
+    ("The item needs a description.", "Der Punkt braucht eine Beschreibung."),
+    ("The run cost more than it made, so there's no profit to split.", "Die Tour hat mehr gekostet als eingebracht, es gibt keinen Gewinn aufzuteilen."),
+    ("The season has to start before it ends.", "Die Saison muss beginnen, bevor sie endet."),
+    ("There has to be something to share.", "Es muss etwas zum Aufteilen geben."),
+    ("This command must be used in a server.", "Dieser Befehl muss auf einem Server benutzt werden."),
+    ("This event doesn't exist anymore.", "Diese Veranstaltung gibt es nicht mehr."),
+    ("This goal has been cleared in the meantime.", "Dieses Ziel wurde inzwischen zurückgesetzt."),
+    ("This goal has been cleared.", "Dieses Ziel wurde zurückgesetzt."),
+    ("You can only show 50 users per command.", "Pro Befehl können nur 50 Nutzer gezeigt werden."),
+    ("You haven't pledged to help with this goal.", "Du hast deine Hilfe bei diesem Ziel nicht zugesagt."),
+    ("You've already pledged to help with this goal.", "Du hast deine Hilfe bei diesem Ziel schon zugesagt."),
+    ("`color` must be an ANSI color between 0 and 255.", "`color` muss eine ANSI-Farbe zwischen 0 und 255 sein."),
+    ("`per` has to be at least 0.01.", "`per` muss mindestens 0.01 sein."),
+    ("`points` can't be negative.", "`points` darf nicht negativ sein."),
+    ("`rank` is missing but `rank` provided for `at`.", "`rank` fehlt, obwohl `at` auf `rank` steht."),
+    ("`someone` is missing but `someone` provided for `at`.", "`someone` fehlt, obwohl `at` auf `someone` steht."),
+    ("`sort_order` is too large.", "`sort_order` ist zu groß."),
+    ("<t:{}:F> has already passed.", "<t:{}:F> ist schon vorbei."),
+    ("A goal can have at most {} milestones.", "Ein Ziel kann höchstens {} Meilensteine haben."),
+    ("Can't record {} for {}: {}.", "{} für {} kann nicht eingetragen werden: {}."),
+    ("Couldn't post in {}, check that I can send messages there.", "Konnte nicht in {} posten, prüfe, ob ich dort Nachrichten senden darf."),
+    ("Couldn't read `{}` as a deadline, use YYYY-MM-DD or YYYY-MM-DD HH:MM.", "`{}` ist keine gültige Frist, nutze YYYY-MM-DD oder YYYY-MM-DD HH:MM."),
+    ("Event descriptions can be at most {} characters.", "Beschreibungen von Veranstaltungen dürfen höchstens {} Zeichen lang sein."),
+    ("Event names have to be between 1 and {} characters.", "Namen von Veranstaltungen müssen zwischen 1 und {} Zeichen lang sein."),
+    ("Items can be at most {} characters.", "Punkte dürfen höchstens {} Zeichen lang sein."),
+    ("Milestones must be percentages between 1 and 100, not `{}`.", "Meilensteine müssen Prozentwerte zwischen 1 und 100 sein, nicht `{}`."),
+    ("Negative value for `total` in `{} delete`. Were you looking for `{} record`?", "Negativer Wert für `total` in `{} delete`. Meintest du `{} record`?"),
+    ("Negative value for `total` in `{} record`. Were you looking for `{} delete`?", "Negativer Wert für `total` in `{} record`. Meintest du `{} delete`?"),
+    ("No active goal is called `{}`.", "Kein aktives Ziel heißt `{}`."),
+    ("No branch with tag `{}` exists.", "Es gibt keinen Zweig mit dem Kürzel `{}`."),
+    ("No season called `{}` exists.", "Es gibt keine Saison namens `{}`."),
+    ("No unfinished season called `{}` exists. Seasons that already made it into the hall of fame can't be removed.", "Es gibt keine laufende Saison namens `{}`. Saisons, die schon in der Ruhmeshalle stehen, können nicht entfernt werden."),
+    ("Roll call for **{}** has already been done.", "Der Appell für **{}** wurde schon gemacht."),
+    ("Roll call opens once **{}** starts <t:{}:R>.", "Der Appell ist möglich, sobald **{}** beginnt, <t:{}:R>."),
+    ("Run names must be between 1 and {} characters.", "Namen von Touren müssen zwischen 1 und {} Zeichen lang sein."),
+    ("Season names must be between 1 and {} characters.", "Namen von Saisons müssen zwischen 1 und {} Zeichen lang sein."),
+    ("That overlaps with `{}` ({} to {}). Seasons can't overlap.", "Das überschneidet sich mit `{}` ({} bis {}). Saisons dürfen sich nicht überschneiden."),
+    ("The `{}` role isn't a group.", "Die Rolle `{}` ist keine Gruppe."),
+    ("The body of `{}` is longer than the editor can hold ({} characters), use `monthly_goal set` instead.", "Der Text von `{}` ist länger, als der Editor fassen kann ({} Zeichen), nutze stattdessen `monthly_goal set`."),
+    ("The progress of `{}` comes from its checklist, use `monthly_goal item complete` instead.", "Der Fortschritt von `{}` kommt aus seiner Checkliste, nutze stattdessen `monthly_goal item complete`."),
+    ("The header can be at most {} characters, this one is {}.", "Die Überschrift darf höchstens {} Zeichen lang sein, diese hat {}."),
+    ("The body can be at most {} characters, this one is {}.", "Der Text darf höchstens {} Zeichen lang sein, dieser hat {}."),
+    ("There's already a season called `{}`.", "Es gibt schon eine Saison namens `{}`."),
+    ("There's no goal board for branch `{}`.", "Es gibt keine Zieltafel für den Zweig `{}`."),
+    ("There's no item {}.", "Es gibt keinen Punkt {}."),
+    ("There's no profit run #{}.", "Es gibt keine Tour #{}."),
+    ("Unknown branch `{}`. Use `/branch list` to see the branches for this server.", "Unbekannter Zweig `{}`. Mit `/branch list` siehst du die Zweige dieses Servers."),
+    ("`crew{}` needs a `share{}`.", "`crew{}` braucht ein `share{}`."),
+    ("`display_name` must be between 1 and {} characters.", "`display_name` muss zwischen 1 und {} Zeichen lang sein."),
+    ("`emoji` must be at most {} characters.", "`emoji` darf höchstens {} Zeichen lang sein."),
+    ("`name` must be between 1 and {} characters.", "`name` muss zwischen 1 und {} Zeichen lang sein."),
+    ("`share{}` was given without a `crew{}`.", "`share{}` wurde ohne `crew{}` angegeben."),
+    ("`tag` must be lowercase, without spaces, and at most {} characters.", "`tag` muss kleingeschrieben, ohne Leerzeichen und höchstens {} Zeichen lang sein."),
+    ("`{}_soft` has to be at most `{}_hard`, or it would never be flagged.", "`{}_soft` darf höchstens `{}_hard` sein, sonst würde nie etwas gemeldet."),
+    ("`{}` can't be negative.", "`{}` darf nicht negativ sein."),
+    ("`{}` has no item {}.", "`{}` hat keinen Punkt {}."),
+    ("`{}` has no revision #{}, see `monthly_goal history` for the ones it has.", "`{}` hat keine Version #{}, die vorhandenen zeigt `monthly_goal history`."),
+    ("`{}` hasn't finished yet. Its results are added to the hall of fame once it ends on {}.", "`{}` ist noch nicht beendet. Die Ergebnisse kommen in die Ruhmeshalle, sobald sie am {} endet."),
+    ("`{}` isn't a date like `2026-07-01`.", "`{}` ist kein Datum wie `2026-07-01`."),
+    ("`{}` isn't a time I understand. Use `YYYY-MM-DD HH:MM` in the server's timezone (`{}`).", "`{}` ist keine Zeit, die ich verstehe. Nutze `YYYY-MM-DD HH:MM` in der Zeitzone des Servers (`{}`)."),
+    ("`{}` isn't a timezone I know. Use an IANA name like `America/New_York` or `Australia/Sydney`.", "`{}` ist keine Zeitzone, die ich kenne. Nutze einen IANA-Namen wie `America/New_York` oder `Australia/Sydney`."),
+    ("`{}` must be a percentage above 0 and at most 100.", "`{}` muss ein Prozentwert über 0 und höchstens 100 sein."),
+    ("over the limit of {} at once", "über dem Limit von {} auf einmal"),
+    ("over the soft limit of {} at once", "über dem weichen Limit von {} auf einmal"),
+    ("over the limit of {} per member per day ({} already today)", "über dem Limit von {} pro Mitglied und Tag (heute schon {})"),
+    ("over the soft limit of {} per member per day ({} already today)", "über dem weichen Limit von {} pro Mitglied und Tag (heute schon {})"),
+    ("over the limit of {} recorded for yourself per day ({} already today)", "über dem Limit von {} pro Tag für dich selbst (heute schon {})"),
+    ("over the soft limit of {} recorded for yourself per day ({} already today)", "über dem weichen Limit von {} pro Tag für dich selbst (heute schon {})"),
+    ("{} can only be shared in steps of {}.", "{} kann nur in Schritten von {} aufgeteilt werden."),
+    ("{} doesn't count towards merit.", "{} zählt nicht zum Verdienst."),
+    ("{} doesn't have any limits.", "{} hat keine Limits."),
+    ("{} is listed more than once.", "{} ist mehr als einmal angegeben."),
+    ("{} wasn't crew on run #{}.", "{} war nicht in der Crew von Tour #{}."),
+    ("{}'s share of run #{} was already paid.", "Der Anteil von {} an Tour #{} wurde schon bezahlt."),
+    ("Something broke! Please contact a mod for help.", "Etwas ist kaputtgegangen! Bitte wende dich an einen Mod."),
+
+    // API.
+    ("Create a new token for the read-only HTTP API, replacing any old one", "Ein neues Token für die lesende HTTP-API erstellen, ersetzt das alte"),
+    ("Revoke the token instead, turning off API access for this server.", "Stattdessen das Token widerrufen und den API-Zugriff für diesen Server abschalten."),
+    ("Only officers can manage the API token.", "Nur Offiziere können das API-Token verwalten."),
+    ("This server doesn't have an API token.", "Dieser Server hat kein API-Token."),
+];
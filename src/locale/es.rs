@@ -0,0 +1,474 @@
+//! Spanish, for both of Discord's Spanish locales.
+
+/// Top-level commands, and the entries in a member's context menu.
+pub const COMMAND_NAMES: &[(&str, &str)] = &[
+    ("event", "evento"),
+    ("industry", "industria"),
+    ("navy", "armada"),
+    ("legion", "legión"),
+    ("monthly_goal", "objetivo_mensual"),
+    ("branch", "rama"),
+    ("settings", "ajustes"),
+    ("members", "miembros"),
+    ("stats", "estadísticas"),
+    ("season", "temporada"),
+    ("group", "grupo"),
+    ("merit", "mérito"),
+    ("Record One Naval Victory", "Registrar una victoria naval"),
+    ("Check Naval Victories", "Consultar victorias navales"),
+];
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Stats.
+    ("Personnel Saved", "Personal rescatado"),
+    ("Event Participation", "Participación en eventos"),
+    ("Industry Profit", "Beneficio industrial"),
+    ("Ground Kill", "Baja terrestre"),
+    ("Navy Victory", "Victoria naval"),
+    ("Navy Tackle Assist", "Asistencia de placaje naval"),
+    ("{} personnel saved", "{} personal rescatado"),
+    ("{} event", "{} evento"),
+    ("{} events", "{} eventos"),
+    ("{} credit", "{} crédito"),
+    ("{} credits", "{} créditos"),
+    ("{} kill", "{} baja"),
+    ("{} kills", "{} bajas"),
+    ("{} victory", "{} victoria"),
+    ("{} victories", "{} victorias"),
+    ("{} tackle assist", "{} asistencia de placaje"),
+    ("{} tackle assists", "{} asistencias de placaje"),
+    ("saved {} personnel", "rescatado a {} personas"),
+    ("participated in {} event", "participado en {} evento"),
+    ("participated in {} events", "participado en {} eventos"),
+    ("contributed {} credit", "aportado {} crédito"),
+    ("contributed {} credits", "aportado {} créditos"),
+    ("{} confirmed kill", "conseguido {} baja confirmada"),
+    ("{} confirmed kills", "conseguido {} bajas confirmadas"),
+    ("earned {} victory", "conseguido {} victoria"),
+    ("earned {} victories", "conseguido {} victorias"),
+    ("earned {} tackle assist", "conseguido {} asistencia de placaje"),
+    ("earned {} tackle assists", "conseguido {} asistencias de placaje"),
+
+    // Replies.
+    ("Added {} to {} (total {}).", "Añadido: {} a {} (total {})."),
+    ("Removed {} from {} (total {}).", "Quitado: {} de {} (total {})."),
+    ("@here {} has {}!", "@here ¡{} ha {}!"),
+    ("We have {} recorded for {}.", "Tenemos {} en el registro de {}."),
+    ("We have {} recorded for {} {}.", "Tenemos {} en el registro de {} {}."),
+    ("\nStreak: {} (longest {}).\nAttendance: {} {}.", "\nRacha: {} (la más larga {}).\nAsistencia: {} {}."),
+    ("over the last {} weeks", "en las últimas {} semanas"),
+    ("1 week", "1 semana"),
+    ("{} weeks", "{} semanas"),
+    ("no event days", "ningún día de evento"),
+    ("{} of {} event days ({}%)", "{} de {} días de evento ({} %)"),
+    ("no events", "ningún evento"),
+    ("{} of {} events ({}%)", "{} de {} eventos ({} %)"),
+    ("this week", "esta semana"),
+    ("last week", "la semana pasada"),
+    ("this month", "este mes"),
+    ("last month", "el mes pasado"),
+    ("on {}", "el {}"),
+    ("from {} to {}", "del {} al {}"),
+    ("season {}", "temporada {}"),
+    ("Scoreboard:", "Clasificación:"),
+    ("**Scoreboard:**\n", "**Clasificación:**\n"),
+    ("**Scoreboard ({}):**\n", "**Clasificación ({}):**\n"),
+    ("Nobody has a streak going yet!", "¡Nadie tiene una racha todavía!"),
+    ("**Current streaks:**\n", "**Rachas actuales:**\n"),
+    ("**Longest streaks:**\n", "**Rachas más largas:**\n"),
+    ("\t{}) {}: {} (longest {})\n", "\t{}) {}: {} (la más larga {})\n"),
+    ("all time", "todo el tiempo"),
+    ("of all time", "en total"),
+    ("**Group scoreboard for {} ({}, by total):**\n", "**Clasificación de grupos de {} ({}, por total):**\n"),
+    ("**Group scoreboard for {} ({}, by average per member):**\n", "**Clasificación de grupos de {} ({}, por media por miembro):**\n"),
+    ("\t{}) {} {}: {} ({} per member across {} members)\n", "\t{}) {} {}: {} ({} por miembro entre {} miembros)\n"),
+    ("No groups have been set up! Use `/group add` to rank a role's members together.", "¡No hay grupos configurados! Usa `/group add` para clasificar juntos a los miembros de un rol."),
+    ("{} has no matching stats to transfer.", "{} no tiene estadísticas que coincidan para transferir."),
+    ("**Transferred from {} to {}:**\n", "**Transferido de {} a {}:**\n"),
+    ("**Preview of transfer from {} to {}:**\n", "**Vista previa de la transferencia de {} a {}:**\n"),
+    ("Nothing has been changed yet. Run the command again with `confirm` set to apply it.", "Aún no se ha cambiado nada. Vuelve a ejecutar el comando con `confirm` para aplicarlo."),
+    ("No stat limits have been set up! Use `/stats limit_set` to add some.", "¡No hay límites de estadísticas configurados! Usa `/stats limit_set` para añadir alguno."),
+    ("At once", "De una vez"),
+    ("Per member per day", "Por miembro por día"),
+    ("For yourself per day", "Para ti mismo por día"),
+    ("flagged over {}", "señalado por encima de {}"),
+    ("rejected over {}", "rechazado por encima de {}"),
+    ("no limit", "sin límite"),
+    ("Limits for {} are now:\n{}", "Los límites de {} son ahora:\n{}"),
+    ("{} no longer has any limits.", "{} ya no tiene límites."),
+    ("{} merit", "{} de mérito"),
+    ("{} has no merit {}.", "{} no tiene mérito {}."),
+    ("{} has {} {}, rank {} of {}.\n", "{} tiene {} {}, puesto {} de {}.\n"),
+    ("**Merit scoreboard ({}):**\n", "**Clasificación de mérito ({}):**\n"),
+    ("{} no longer counts towards merit.", "{} ya no cuenta para el mérito."),
+    ("{} now earns {} merit per {}.", "{} ahora otorga {} de mérito por {}."),
+    ("{}: {} merit per {}", "{}: {} de mérito por {}"),
+    ("No payouts are outstanding.", "No hay pagos pendientes."),
+    ("**Outstanding payouts:**\n", "**Pagos pendientes:**\n"),
+    ("- #{} `{}`: {} owes {} {}\n", "- #{} `{}`: {} debe a {} {}\n"),
+    ("{} owes {} in total.\n", "{} debe {} en total.\n"),
+    ("**Profit run #{} `{}`** ({}), funded by {}\nGross {}, costs {}, net {}.\n", "**Ruta #{} `{}`** ({}), financiada por {}\nBruto {}, costes {}, neto {}.\n"),
+    ("own share", "parte propia"),
+    ("paid {}", "pagada el {}"),
+    ("**unpaid**", "**sin pagar**"),
+    ("- {} keeps {}\n", "- {} se queda {}\n"),
+    ("Marked {}'s share of {} from run #{} `{}` as paid.", "La parte de {} de {} de la ruta #{} `{}` se marcó como pagada."),
+    ("Recorded profit run #{} `{}`: {} net, funded by {}.\n", "Ruta #{} `{}` registrada: {} netos, financiada por {}.\n"),
+    (", owed by the owner", ", que debe el propietario"),
+    ("Use `/industry run paid run:{}` once a share has been paid out.", "Usa `/industry run paid run:{}` cuando se haya pagado una parte."),
+    ("**Hall of fame: {}** ({} to {})\n", "**Salón de la fama: {}** ({} a {})\n"),
+    ("Shared {} between {} crew:\n", "Se repartieron {} entre {} tripulantes:\n"),
+    ("- {}: {} (weight {}, total {})\n", "- {}: {} (peso {}, total {})\n"),
+    ("No goals have been set up!", "¡No hay objetivos configurados!"),
+    ("Goals for {}", "Objetivos de {}"),
+    ("Goal Progress: Main", "Progreso de objetivos: Principal"),
+    ("Goal Progress: {}", "Progreso de objetivos: {}"),
+    ("Due <t:{}:R>\n", "Vence <t:{}:R>\n"),
+    ("Owner: {}\n", "Responsable: {}\n"),
+    ("Pledged ({}): {}\n", "Comprometidos ({}): {}\n"),
+    // Commands.
+    ("Ping!", "¡Ping!"),
+    ("Record a participant for an event", "Registrar la participación en un evento"),
+    ("Remove a participant from an event", "Quitar la participación en un evento"),
+    ("Check how many events a participant has been part of", "Consultar en cuántos eventos ha participado alguien"),
+    ("Rank members by how many weeks in a row they've taken part in events", "Clasificar a los miembros por semanas seguidas participando en eventos"),
+    ("Schedule an event and post a card members can RSVP on", "Programar un evento y publicar una tarjeta para confirmar asistencia"),
+    ("Confirm who attended an event and credit their participation", "Confirmar quién asistió a un evento y acreditar su participación"),
+    ("Records rocks", "Registra rocas"),
+    ("Record profits", "Registrar beneficios"),
+    ("Delete profits", "Borrar beneficios"),
+    ("Boast about your profits", "Presumir de tus beneficios"),
+    ("Checks someone's (or your own) profits", "Consulta los beneficios de alguien (o los tuyos)"),
+    ("Creates the scoreboard of profits across Auric.", "Crea la clasificación de beneficios de todo Auric."),
+    ("Removes old unknown users from the scoreboard", "Quita de la clasificación a usuarios antiguos desconocidos"),
+    ("Record a crewed run, split its profit and credit everyone's share", "Registrar una ruta con tripulación, repartir el beneficio y acreditar cada parte"),
+    ("Show a run's profit split and which shares have been paid", "Mostrar el reparto de una ruta y qué partes se han pagado"),
+    ("List crew shares that haven't been paid out yet", "Listar las partes de la tripulación aún sin pagar"),
+    ("Mark a crew member's share of a run as paid", "Marcar como pagada la parte de un tripulante en una ruta"),
+    ("Record saved personnel", "Registrar personal rescatado"),
+    ("Delete saved personnel", "Borrar personal rescatado"),
+    ("Boast about your saved personnel", "Presumir del personal que has rescatado"),
+    ("Checks someone's (or your own) saved personnel count", "Consulta el personal rescatado por alguien (o por ti)"),
+    ("Creates the scoreboard of saved personnel across Auric", "Crea la clasificación de personal rescatado de todo Auric"),
+    ("Record one victory for this user.", "Registrar una victoria para este usuario."),
+    ("Checks one victory for user.", "Consulta las victorias de este usuario."),
+    ("Records a certain number of naval victories for a user.", "Registra un número de victorias navales para un usuario."),
+    ("Removes a certain number of naval victories for a user. Only goes down to 0!", "Quita un número de victorias navales a un usuario. ¡Solo baja hasta 0!"),
+    ("Boast about your naval victories.", "Presumir de tus victorias navales."),
+    ("Checks the number of naval victories for a specific user (or yourself).", "Consulta las victorias navales de un usuario (o las tuyas)."),
+    ("Creates the scoreboard of naval victories across Auric.", "Crea la clasificación de victorias navales de todo Auric."),
+    ("Split victories between a crew, optionally weighted by role", "Repartir victorias entre una tripulación, opcionalmente ponderadas por rol"),
+    ("Records a certain number of naval tackle assists for a user.", "Registra un número de asistencias de placaje para un usuario."),
+    ("Removes a certain number of naval tackle assists for a user. Only goes down to 0!", "Quita un número de asistencias de placaje a un usuario. ¡Solo baja hasta 0!"),
+    ("Boast about your naval tackle assists.", "Presumir de tus asistencias de placaje."),
+    ("Checks the number of naval tackle assists for a specific user (or yourself).", "Consulta las asistencias de placaje de un usuario (o las tuyas)."),
+    ("Creates the scoreboard of naval tackle assists across Auric.", "Crea la clasificación de asistencias de placaje de todo Auric."),
+    ("Records a certain number of kills for a user.", "Registra un número de bajas para un usuario."),
+    ("Deletes a certain number of kills for a user.", "Borra un número de bajas de un usuario."),
+    ("Boast about your legion kills.", "Presumir de tus bajas en la legión."),
+    ("Checks the number of legion kills for a specific user (or yourself).", "Consulta las bajas en la legión de un usuario (o las tuyas)."),
+    ("Creates the scoreboard of legion kills across Auric.", "Crea la clasificación de bajas de la legión de todo Auric."),
+    ("Check the monthly goal for the org or a branch", "Consultar el objetivo mensual de la org o de una rama"),
+    ("Clear all monthly goals", "Borrar todos los objetivos mensuales"),
+    ("Edit a goal's header and body in a form, with a preview before saving", "Editar el título y el texto de un objetivo en un formulario, con vista previa"),
+    ("Post a goal board to a channel that updates itself whenever goals change", "Publicar un tablero de objetivos que se actualiza solo cuando cambian"),
+    ("Stop updating a goal board and delete its message", "Dejar de actualizar un tablero de objetivos y borrar su mensaje"),
+    ("List out goals including shortnames", "Listar los objetivos con sus nombres cortos"),
+    ("Post a goal here with a button members can use to pledge their help", "Publicar aquí un objetivo con un botón para que los miembros ofrezcan su ayuda"),
+    ("Show how a goal's text and progress changed over time", "Mostrar cómo han cambiado el texto y el progreso de un objetivo"),
+    ("Put a goal back the way it was as of an earlier revision", "Devolver un objetivo al estado de una revisión anterior"),
+    ("Add an item to a goal's checklist, which then sets the goal's progress", "Añadir un punto a la lista de un objetivo, que pasa a marcar su progreso"),
+    ("Tick off an item of a goal's checklist", "Marcar como hecho un punto de la lista de un objetivo"),
+    ("Mark a completed checklist item as not done after all", "Volver a marcar como pendiente un punto completado"),
+    ("Create or update a branch of the org", "Crear o actualizar una rama de la org"),
+    ("Remove a branch of the org", "Quitar una rama de la org"),
+    ("List the branches of the org", "Listar las ramas de la org"),
+    ("Set the timezone used for weeks and months", "Fijar la zona horaria de semanas y meses"),
+    ("Show this server's settings", "Mostrar los ajustes de este servidor"),
+    ("Set where records over a soft limit get flagged", "Fijar dónde se avisa de los registros que superan un límite suave"),
+    ("Set how progress bars are drawn, e.g. without colour codes for mobile", "Fijar cómo se dibujan las barras de progreso, p. ej. sin colores para móvil"),
+    ("List members who left and whose stats are archived", "Listar a quienes se fueron y tienen estadísticas archivadas"),
+    ("Permanently delete stats of members who left a while ago", "Borrar para siempre las estadísticas de quienes se fueron hace tiempo"),
+    ("Move stats from one account to another, e.g. after a member switches accounts", "Pasar estadísticas de una cuenta a otra, p. ej. tras un cambio de cuenta"),
+    ("Limit how much of a stat can be recorded, replacing its current limits", "Limitar cuánto se puede registrar de una estadística, sustituyendo sus límites"),
+    ("Remove all limits on a stat", "Quitar todos los límites de una estadística"),
+    ("List the limits on recording stats", "Listar los límites para registrar estadísticas"),
+    ("Start a season, scoreboards count from zero while it runs", "Empezar una temporada, las clasificaciones cuentan desde cero mientras dure"),
+    ("List this server's seasons", "Listar las temporadas de este servidor"),
+    ("Remove a season that hasn't finished yet", "Quitar una temporada que aún no ha terminado"),
+    ("Show a finished season's hall of fame", "Mostrar el salón de la fama de una temporada terminada"),
+    ("Rank everyone with a role together, e.g. a wing or squad", "Clasificar juntos a todos los que tienen un rol, p. ej. un ala o escuadrón"),
+    ("Stop ranking a role as a group", "Dejar de clasificar un rol como grupo"),
+    ("List the roles ranked as groups", "Listar los roles clasificados como grupos"),
+    ("Rank groups by the combined stats of their members", "Clasificar grupos por las estadísticas conjuntas de sus miembros"),
+    ("Set how much a stat counts towards merit", "Fijar cuánto cuenta una estadística para el mérito"),
+    ("Stop a stat from counting towards merit", "Hacer que una estadística deje de contar para el mérito"),
+    ("List how much each stat counts towards merit", "Listar cuánto cuenta cada estadística para el mérito"),
+    ("Rank members by merit", "Clasificar a los miembros por mérito"),
+    ("Check a member's merit and what it's made of", "Consultar el mérito de un miembro y de qué se compone"),
+    ("Event commands", "Comandos de eventos"),
+    ("Commands for tracking event participation", "Comandos para registrar la participación en eventos"),
+    ("Industry commands", "Comandos de industria"),
+    ("Commands for mining data stashing", "Comandos para guardar datos de minería"),
+    ("Commands for managing profit records", "Comandos para gestionar beneficios"),
+    ("Commands for crewed profit runs and their payouts", "Comandos para rutas con tripulación y sus pagos"),
+    ("Navy commands", "Comandos de la armada"),
+    ("Commands for managing victory counts", "Comandos para gestionar victorias"),
+    ("Commands for managing tackle assist counts", "Comandos para gestionar asistencias de placaje"),
+    ("Legion commands", "Comandos de la legión"),
+    ("Commands for managing kill counts", "Comandos para gestionar bajas"),
+    ("Commands for managing monthly goals", "Comandos para gestionar los objetivos mensuales"),
+    ("Commands for managing a goal's checklist", "Comandos para gestionar la lista de un objetivo"),
+    ("Commands for managing records around goal progress", "Comandos para gestionar registros del progreso de objetivos"),
+    ("Commands for managing the branches of the org", "Comandos para gestionar las ramas de la org"),
+    ("Commands for managing server-wide settings", "Comandos para gestionar los ajustes del servidor"),
+    ("Commands for managing stats of members who left", "Comandos para las estadísticas de quienes se fueron"),
+    ("Officer commands for managing members' stats", "Comandos de oficiales para gestionar estadísticas de miembros"),
+    ("Commands for seasons and their hall of fame", "Comandos de temporadas y su salón de la fama"),
+    ("Commands for ranking wings, squads and other role groups", "Comandos para clasificar alas, escuadrones y otros grupos de roles"),
+    ("Commands for weighted merit scores built from tracked stats", "Comandos para puntos de mérito ponderados a partir de estadísticas"),
+
+    // Options.
+    ("Person being recorded for. Leaving this out means that you're recording your own participation.", "Para quién se registra. Si lo omites, registras tu propia participación."),
+    ("Number of events being recorded, defaults to 1", "Número de eventos que se registran, 1 por defecto"),
+    ("Notes. This is not accessible via commands.", "Notas. No se pueden consultar con comandos."),
+    ("notes", "notas"),
+    ("Person being checked. Leaving this out means that you're checking your own participation.", "A quién se consulta. Si lo omites, consultas tu propia participación."),
+    ("Only count records from this period, e.g. `this_month` or `2026-03-01..2026-03-15`.", "Contar solo este periodo, p. ej. `this_month` o `2026-03-01..2026-03-15`."),
+    ("Rank by the streak members have going now, or their longest ever. Defaults to current.", "Clasificar por la racha actual o la más larga. Por defecto, la actual."),
+    ("How many members to show, defaults to 10", "Cuántos miembros mostrar, 10 por defecto"),
+    ("What the event is called", "Cómo se llama el evento"),
+    ("When it starts, as YYYY-MM-DD HH:MM in the server's timezone", "Cuándo empieza, como AAAA-MM-DD HH:MM en la zona horaria del servidor"),
+    ("What to bring, where to meet, and so on", "Qué traer, dónde quedar, etc."),
+    ("The event to run the roll call for", "El evento del que pasar lista"),
+    ("Someone who attended without RSVPing", "Alguien que asistió sin confirmar"),
+    ("Number of alpha UEC. Defaults to 1000.", "Cantidad de alpha UEC. 1000 por defecto."),
+    ("Person being recorded for. Leaving this out means that you're recording your own profits.", "Para quién se registra. Si lo omites, registras tus propios beneficios."),
+    ("Number of alpha UEC.", "Cantidad de alpha UEC."),
+    ("Person to get victories for. Defaults to self. Quieter than boasting.", "De quién consultar las victorias. Tú por defecto. Más discreto que presumir."),
+    ("Maximum entries to return. Max of 20. Defaults to 10.", "Máximo de entradas. Como mucho 20. 10 por defecto."),
+    ("What to orient the scoreboard on.", "En qué centrar la clasificación."),
+    ("Should only be provided if \"at\" is set to \"someone\".", "Solo si \"at\" es \"someone\"."),
+    ("The integer rank to start the scoreboard at. Mutually exclusive with \"someone\"", "Puesto en el que empieza la clasificación. Incompatible con \"someone\""),
+    ("Only count this period, e.g. `this_month`. Defaults to the running season, or `all_time`.", "Contar solo este periodo, p. ej. `this_month`. Por defecto, la temporada en curso."),
+    ("What the run was, e.g. `Hull-C to ARC-L1`", "Qué ruta fue, p. ej. `Hull-C a ARC-L1`"),
+    ("Total revenue of the run in aUEC", "Ingresos totales de la ruta en aUEC"),
+    ("Costs of the run in aUEC, taken off before splitting. Defaults to 0.", "Costes de la ruta en aUEC, descontados antes del reparto. 0 por defecto."),
+    ("Who funded the run and owes the crew. Defaults to you.", "Quién financió la ruta y debe a la tripulación. Tú por defecto."),
+    ("Crew member getting a share", "Tripulante que recibe una parte"),
+    ("Percentage of the net profit going to crew1, e.g. 25", "Porcentaje del beneficio neto para crew1, p. ej. 25"),
+    ("Percentage of the net profit going to crew2, e.g. 25", "Porcentaje del beneficio neto para crew2, p. ej. 25"),
+    ("Percentage of the net profit going to crew3, e.g. 25", "Porcentaje del beneficio neto para crew3, p. ej. 25"),
+    ("Percentage of the net profit going to crew4, e.g. 25", "Porcentaje del beneficio neto para crew4, p. ej. 25"),
+    ("Percentage of the net profit going to crew5, e.g. 25", "Porcentaje del beneficio neto para crew5, p. ej. 25"),
+    ("Percentage of the net profit going to crew6, e.g. 25", "Porcentaje del beneficio neto para crew6, p. ej. 25"),
+    ("Percentage of the net profit going to crew7, e.g. 25", "Porcentaje del beneficio neto para crew7, p. ej. 25"),
+    ("Percentage of the net profit going to crew8, e.g. 25", "Porcentaje del beneficio neto para crew8, p. ej. 25"),
+    ("Number of the run", "Número de la ruta"),
+    ("Only show shares owed by this person", "Mostrar solo lo que debe esta persona"),
+    ("Only show shares owed to this person", "Mostrar solo lo que se le debe a esta persona"),
+    ("Crew member who was paid. Defaults to you.", "Tripulante al que se pagó. Tú por defecto."),
+    ("Number of victories. Only accepts values in intervals of 0.25. Defaults to 1.", "Número de victorias. Solo en pasos de 0,25. 1 por defecto."),
+    ("Person being recorded for. Leaving this out means that you're recording your own victories.", "Para quién se registra. Si lo omites, registras tus propias victorias."),
+    ("Crew member sharing in the victories", "Tripulante que participa en las victorias"),
+    ("Number of victories to share. Only accepts values in intervals of 0.25. Defaults to 1.", "Victorias a repartir. Solo en pasos de 0,25. 1 por defecto."),
+    ("Relative share of crew1, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Parte relativa de crew1, p. ej. 2 para piloto y 1 para artillero. 1 por defecto."),
+    ("Relative share of crew2, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Parte relativa de crew2, p. ej. 2 para piloto y 1 para artillero. 1 por defecto."),
+    ("Relative share of crew3, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Parte relativa de crew3, p. ej. 2 para piloto y 1 para artillero. 1 por defecto."),
+    ("Relative share of crew4, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Parte relativa de crew4, p. ej. 2 para piloto y 1 para artillero. 1 por defecto."),
+    ("Relative share of crew5, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Parte relativa de crew5, p. ej. 2 para piloto y 1 para artillero. 1 por defecto."),
+    ("Relative share of crew6, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Parte relativa de crew6, p. ej. 2 para piloto y 1 para artillero. 1 por defecto."),
+    ("Relative share of crew7, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Parte relativa de crew7, p. ej. 2 para piloto y 1 para artillero. 1 por defecto."),
+    ("Relative share of crew8, e.g. 2 for a pilot and 1 for a gunner. Defaults to 1.", "Parte relativa de crew8, p. ej. 2 para piloto y 1 para artillero. 1 por defecto."),
+    ("Number of tackle assists. Defaults to 1.", "Número de asistencias de placaje. 1 por defecto."),
+    ("Person being recorded for. Leaving this out means that you're recording your own tackle assists.", "Para quién se registra. Si lo omites, registras tus propias asistencias de placaje."),
+    ("Person to get tackle assists for. Defaults to self. Quieter than boasting.", "De quién consultar las asistencias. Tú por defecto. Más discreto que presumir."),
+    ("Number of kills. Only accepts values in intervals of 0.25. Defaults to 1.", "Número de bajas. Solo en pasos de 0,25. 1 por defecto."),
+    ("Person being recorded for. Leaving this out means that you're recording your own kills.", "Para quién se registra. Si lo omites, registras tus propias bajas."),
+    ("Person to get kills for. Defaults to self. Quieter than boasting.", "De quién consultar las bajas. Tú por defecto. Más discreto que presumir."),
+    ("Which branch to check the goal for, or the org (defaults to main)", "De qué rama consultar el objetivo, o la org (main por defecto)"),
+    ("Whether to show additional detail (defaults to false)", "Si mostrar más detalles (no por defecto)"),
+    ("Whether to show additional detail (defaults to false), only applies if checking Main goals", "Si mostrar más detalles (no por defecto), solo para los objetivos de Main"),
+    ("whether to show progress bars", "si mostrar las barras de progreso"),
+    ("How to draw progress bars, defaults to the server's setting", "Cómo dibujar las barras de progreso, según el ajuste del servidor por defecto"),
+    ("Name for the goal. If active doesn't exist, will create", "Nombre del objetivo. Si no hay uno activo, se crea"),
+    ("Progress of the goal, up to 100", "Progreso del objetivo, hasta 100"),
+    ("Header of the message, max 256 chars", "Título del mensaje, máximo 256 caracteres"),
+    ("Body of the message, max 4096 chars. Use `monthly_goal edit` for anything long.", "Texto del mensaje, máx. 4096 caracteres. Para textos largos, `objetivo_mensual edit`."),
+    ("Due date as YYYY-MM-DD or YYYY-MM-DD HH:MM in the server's timezone, or `none`", "Fecha límite como AAAA-MM-DD o AAAA-MM-DD HH:MM en hora del servidor, o `none`"),
+    ("Extra percentages to announce besides 25/50/75/100, e.g. `10,90`, or `none`", "Porcentajes a anunciar además de 25/50/75/100, p. ej. `10,90`, o `none`"),
+    ("Officer responsible for the goal, who gets a DM on pledges and progress", "Oficial responsable, que recibe un MD con ofertas de ayuda y avances"),
+    ("Leave the goal without an owner", "Dejar el objetivo sin responsable"),
+    ("Which branch to set the goal for, or the org (defaults to main)", "Para qué rama es el objetivo, o la org (main por defecto)"),
+    ("Shortname to clear", "Nombre corto del objetivo a borrar"),
+    ("Branch to clear", "Rama a borrar"),
+    ("Shortname of the goal to edit", "Nombre corto del objetivo a editar"),
+    ("Where to post the board, defaults to this channel", "Dónde publicar el tablero, este canal por defecto"),
+    ("Which branch the board is for, or the org (defaults to main)", "Para qué rama es el tablero, o la org (main por defecto)"),
+    ("Whether to show each goal (defaults to false)", "Si mostrar cada objetivo (no por defecto)"),
+    ("Whether to show each branch (defaults to false), only applies to the Main board", "Si mostrar cada rama (no por defecto), solo para el tablero de Main"),
+    ("Which branch's board to remove (defaults to main)", "De qué rama quitar el tablero (main por defecto)"),
+    ("Tag of the goal. will get all if not provided", "Etiqueta del objetivo. Si se omite, se muestran todos"),
+    ("Shortname of the goal to share", "Nombre corto del objetivo a compartir"),
+    ("Shortname of the goal", "Nombre corto del objetivo"),
+    ("How many revisions to show, defaults to 5", "Cuántas revisiones mostrar, 5 por defecto"),
+    ("Number of the revision, as shown by `monthly_goal history`", "Número de la revisión, según `objetivo_mensual history`"),
+    ("What needs doing, max 256 chars", "Qué hay que hacer, máximo 256 caracteres"),
+    ("Number of the item, as shown by `monthly_goal check`", "Número del punto, según `objetivo_mensual check`"),
+    ("Relevant tracked stat for command", "Estadística a la que se refiere el comando"),
+    ("Total to add. Defaults to 1.", "Cantidad a sumar. 1 por defecto."),
+    ("Total to remove. Defaults to 1.", "Cantidad a quitar. 1 por defecto."),
+    ("Tag goals use for this branch, e.g. `navy`. Updates the branch if it already exists.", "Etiqueta de los objetivos de esta rama, p. ej. `navy`. Si ya existe, se actualiza."),
+    ("Name shown for the branch. Defaults to the tag.", "Nombre que se muestra para la rama. La etiqueta por defecto."),
+    ("ANSI color (0 - 255) for progress bars. Defaults to white.", "Color ANSI (0 - 255) de las barras de progreso. Blanco por defecto."),
+    ("Emoji shown next to the branch name.", "Emoji junto al nombre de la rama."),
+    ("Remove the branch's emoji.", "Quitar el emoji de la rama."),
+    ("Branches with lower values are listed first. Defaults to 0.", "Las ramas con valores más bajos salen primero. 0 por defecto."),
+    ("Where goal reminders and milestones for this branch are posted.", "Dónde se publican los recordatorios e hitos de esta rama."),
+    ("Tag of the branch to remove", "Etiqueta de la rama a quitar"),
+    ("IANA timezone name, e.g. `America/New_York` or `Australia/Sydney`.", "Zona horaria IANA, p. ej. `Europe/Madrid` o `America/Mexico_City`."),
+    ("Channel for flagged records. Leave out to stop flagging.", "Canal para los registros señalados. Omítelo para dejar de señalar."),
+    ("How to draw progress bars unless a command asks otherwise.", "Cómo dibujar las barras de progreso si un comando no pide otra cosa."),
+    ("Only delete stats archived at least this many days ago. Defaults to 90.", "Borrar solo estadísticas archivadas hace al menos estos días. 90 por defecto."),
+    ("Account the stats are moved away from", "Cuenta de la que salen las estadísticas"),
+    ("Account receiving the stats. Totals are added to anything it already has.", "Cuenta que recibe las estadísticas. Los totales se suman a los que ya tenga."),
+    ("Only move this stat. Defaults to all stats.", "Pasar solo esta estadística. Todas por defecto."),
+    ("Apply the transfer. Without this, only a preview is shown.", "Hacer el traspaso. Sin esto, solo se muestra una vista previa."),
+    ("Stat to limit", "Estadística a limitar"),
+    ("Flag records bigger than this", "Señalar registros mayores que esto"),
+    ("Reject records bigger than this", "Rechazar registros mayores que esto"),
+    ("Flag once a member has been given more than this in a day", "Señalar cuando alguien haya recibido más que esto en un día"),
+    ("Reject once a member would be given more than this in a day", "Rechazar cuando alguien fuera a recibir más que esto en un día"),
+    ("Flag once a member has recorded more than this for themselves in a day", "Señalar cuando alguien se haya registrado más que esto en un día"),
+    ("Reject once a member would record more than this for themselves in a day. 0 forbids it.", "Rechazar cuando alguien fuera a registrarse más que esto en un día. 0 lo prohíbe."),
+    ("Stat to remove the limits of", "Estadística cuyos límites se quitan"),
+    ("Name of the season, e.g. `Summer 2026`", "Nombre de la temporada, p. ej. `Verano 2026`"),
+    ("First day of the season, e.g. `2026-07-01`", "Primer día de la temporada, p. ej. `2026-07-01`"),
+    ("Last day of the season, e.g. `2026-09-30`", "Último día de la temporada, p. ej. `2026-09-30`"),
+    ("Season to remove", "Temporada a quitar"),
+    ("Season to show. Defaults to the most recently finished one.", "Temporada a mostrar. Por defecto, la última terminada."),
+    ("Only show this stat", "Mostrar solo esta estadística"),
+    ("Role whose members make up the group", "Rol cuyos miembros forman el grupo"),
+    ("Name shown on scoreboards. Defaults to the role's name.", "Nombre en las clasificaciones. Por defecto, el del rol."),
+    ("Role to stop ranking as a group", "Rol que deja de clasificarse como grupo"),
+    ("Stat to rank groups by", "Estadística por la que clasificar los grupos"),
+    ("Rank by the group's total, or its average per member. Defaults to total.", "Clasificar por el total del grupo o su media por miembro. Total por defecto."),
+    ("Stat to weigh", "Estadística a ponderar"),
+    ("Merit earned for every `per` of the stat", "Mérito por cada `per` de la estadística"),
+    ("How much of the stat earns `points` merit, e.g. 100000 aUEC. Defaults to 1.", "Cuánto de la estadística da `points` de mérito, p. ej. 100000 aUEC. 1 por defecto."),
+    ("Stat to stop counting", "Estadística que deja de contar"),
+    ("Only count this period, e.g. `this_month`. Defaults to all time.", "Contar solo este periodo, p. ej. `this_month`. Por defecto, todo."),
+    ("Member to check. Defaults to you.", "Miembro a consultar. Tú por defecto."),
+
+    // Errors.
+    ("At least one crew member needs a weight above zero.", "Al menos un tripulante necesita un peso mayor que cero."),
+    ("Command must be run from within a guild.", "El comando debe usarse dentro de un servidor."),
+    ("Command must be run from within a server.", "El comando debe usarse dentro de un servidor."),
+    ("Events only work within a server.", "Los eventos solo funcionan dentro de un servidor."),
+    ("Goals only work within a server.", "Los objetivos solo funcionan dentro de un servidor."),
+    ("No merit weights have been set up! Use `/merit set_weight` to add some.", "¡No hay pesos de mérito! Usa `/mérito set_weight` para añadir alguno."),
+    ("No season has finished yet.", "Todavía no ha terminado ninguna temporada."),
+    ("Nobody has RSVP'd yet. Add attendees with the `extra` options.", "Nadie ha confirmado todavía. Añade asistentes con las opciones `extra`."),
+    ("Only officers can publish goal boards.", "Solo los oficiales pueden publicar tableros de objetivos."),
+    ("Only officers can remove goal boards.", "Solo los oficiales pueden quitar tableros de objetivos."),
+    ("Only officers can purge the stats of members who left.", "Solo los oficiales pueden borrar las estadísticas de miembros que se fueron."),
+    ("Only officers can change branches.", "Solo los oficiales pueden cambiar las ramas."),
+    ("Only officers can change the server's timezone.", "Solo los oficiales pueden cambiar la zona horaria del servidor."),
+    ("Only officers can change seasons.", "Solo los oficiales pueden cambiar las temporadas."),
+    ("Only officers can change groups.", "Solo los oficiales pueden cambiar los grupos."),
+    ("Only officers can record profit runs.", "Solo los oficiales pueden registrar rutas."),
+    ("Only officers can change merit weights.", "Solo los oficiales pueden cambiar los pesos de mérito."),
+    ("Only officers can change the officer channel.", "Solo los oficiales pueden cambiar el canal de oficiales."),
+    ("Pick an `owner` or `clear_owner`, not both.", "Elige `owner` o `clear_owner`, no ambos."),
+    ("Only officers can change the server's bar style.", "Solo los oficiales pueden cambiar el estilo de barra del servidor."),
+    ("Pick an `emoji` or `clear_emoji`, not both.", "Elige `emoji` o `clear_emoji`, no ambos."),
+    ("Only the host or an officer can run the roll call.", "Solo el anfitrión o un oficial puede pasar lista."),
+    ("Only the run's owner or the crew member being paid can mark a share as paid.", "Solo quien financió la ruta o el tripulante que cobra puede marcar una parte como pagada."),
+    ("Pick an event from the list.", "Elige un evento de la lista."),
+    ("Roll call for this event has already been done.", "Ya se ha pasado lista en este evento."),
+    ("Roll call for this event is done, RSVPs are closed.", "Ya se ha pasado lista en este evento, las confirmaciones están cerradas."),
+    ("Stats can't be transferred to the same account.", "No se pueden pasar estadísticas a la misma cuenta."),
+    ("The crew's shares add up to more than 100%.", "Las partes de la tripulación suman más del 100 %."),
+    ("The deadline has to be in the future.", "La fecha límite tiene que estar en el futuro."),
+    ("The item needs a description.", "El punto necesita una descripción."),
+    ("The run cost more than it made, so there's no profit to split.", "La ruta costó más de lo que ganó, así que no hay beneficio que repartir."),
+    ("The season has to start before it ends.", "La temporada tiene que empezar antes de terminar."),
+    ("There has to be something to share.", "Tiene que haber algo que repartir."),
+    ("This command must be used in a server.", "Este comando debe usarse en un servidor."),
+    ("This event doesn't exist anymore.", "Este evento ya no existe."),
+    ("This goal has been cleared in the meantime.", "Este objetivo se ha borrado mientras tanto."),
+    ("This goal has been cleared.", "Este objetivo se ha borrado."),
+    ("You can only show 50 users per command.", "Solo se pueden mostrar 50 usuarios por comando."),
+    ("You haven't pledged to help with this goal.", "No has ofrecido tu ayuda con este objetivo."),
+    ("You've already pledged to help with this goal.", "Ya has ofrecido tu ayuda con este objetivo."),
+    ("`color` must be an ANSI color between 0 and 255.", "`color` debe ser un color ANSI entre 0 y 255."),
+    ("`per` has to be at least 0.01.", "`per` tiene que ser al menos 0.01."),
+    ("`points` can't be negative.", "`points` no puede ser negativo."),
+    ("`rank` is missing but `rank` provided for `at`.", "Falta `rank`, pero `at` es `rank`."),
+    ("`someone` is missing but `someone` provided for `at`.", "Falta `someone`, pero `at` es `someone`."),
+    ("`sort_order` is too large.", "`sort_order` es demasiado grande."),
+    ("<t:{}:F> has already passed.", "<t:{}:F> ya ha pasado."),
+    ("A goal can have at most {} milestones.", "Un objetivo puede tener como máximo {} hitos."),
+    ("Can't record {} for {}: {}.", "No se pueden registrar {} para {}: {}."),
+    ("Couldn't post in {}, check that I can send messages there.", "No he podido publicar en {}, comprueba que puedo enviar mensajes allí."),
+    ("Couldn't read `{}` as a deadline, use YYYY-MM-DD or YYYY-MM-DD HH:MM.", "No entiendo `{}` como fecha límite, usa YYYY-MM-DD o YYYY-MM-DD HH:MM."),
+    ("Event descriptions can be at most {} characters.", "Las descripciones de eventos pueden tener como máximo {} caracteres."),
+    ("Event names have to be between 1 and {} characters.", "Los nombres de eventos deben tener entre 1 y {} caracteres."),
+    ("Items can be at most {} characters.", "Los puntos pueden tener como máximo {} caracteres."),
+    ("Milestones must be percentages between 1 and 100, not `{}`.", "Los hitos deben ser porcentajes entre 1 y 100, no `{}`."),
+    ("Negative value for `total` in `{} delete`. Were you looking for `{} record`?", "Valor negativo para `total` en `{} delete`. ¿Buscabas `{} record`?"),
+    ("Negative value for `total` in `{} record`. Were you looking for `{} delete`?", "Valor negativo para `total` en `{} record`. ¿Buscabas `{} delete`?"),
+    ("No active goal is called `{}`.", "Ningún objetivo activo se llama `{}`."),
+    ("No branch with tag `{}` exists.", "No existe ninguna rama con la etiqueta `{}`."),
+    ("No season called `{}` exists.", "No existe ninguna temporada llamada `{}`."),
+    ("No unfinished season called `{}` exists. Seasons that already made it into the hall of fame can't be removed.", "No existe ninguna temporada sin terminar llamada `{}`. Las temporadas que ya están en el salón de la fama no se pueden quitar."),
+    ("Roll call for **{}** has already been done.", "Ya se ha pasado lista en **{}**."),
+    ("Roll call opens once **{}** starts <t:{}:R>.", "Se podrá pasar lista cuando empiece **{}**, <t:{}:R>."),
+    ("Run names must be between 1 and {} characters.", "Los nombres de rutas deben tener entre 1 y {} caracteres."),
+    ("Season names must be between 1 and {} characters.", "Los nombres de temporadas deben tener entre 1 y {} caracteres."),
+    ("That overlaps with `{}` ({} to {}). Seasons can't overlap.", "Se solapa con `{}` (del {} al {}). Las temporadas no pueden solaparse."),
+    ("The `{}` role isn't a group.", "El rol `{}` no es un grupo."),
+    ("The body of `{}` is longer than the editor can hold ({} characters), use `monthly_goal set` instead.", "El texto de `{}` es más largo de lo que cabe en el editor ({} caracteres), usa `monthly_goal set` en su lugar."),
+    ("The progress of `{}` comes from its checklist, use `monthly_goal item complete` instead.", "El progreso de `{}` sale de su lista, usa `monthly_goal item complete` en su lugar."),
+    ("The header can be at most {} characters, this one is {}.", "El título puede tener como máximo {} caracteres, este tiene {}."),
+    ("The body can be at most {} characters, this one is {}.", "El texto puede tener como máximo {} caracteres, este tiene {}."),
+    ("There's already a season called `{}`.", "Ya hay una temporada llamada `{}`."),
+    ("There's no goal board for branch `{}`.", "No hay tablero de objetivos para la rama `{}`."),
+    ("There's no item {}.", "No hay ningún punto {}."),
+    ("There's no profit run #{}.", "No hay ninguna ruta #{}."),
+    ("Unknown branch `{}`. Use `/branch list` to see the branches for this server.", "Rama desconocida `{}`. Usa `/branch list` para ver las ramas de este servidor."),
+    ("`crew{}` needs a `share{}`.", "`crew{}` necesita un `share{}`."),
+    ("`display_name` must be between 1 and {} characters.", "`display_name` debe tener entre 1 y {} caracteres."),
+    ("`emoji` must be at most {} characters.", "`emoji` puede tener como máximo {} caracteres."),
+    ("`name` must be between 1 and {} characters.", "`name` debe tener entre 1 y {} caracteres."),
+    ("`share{}` was given without a `crew{}`.", "Se ha dado `share{}` sin `crew{}`."),
+    ("`tag` must be lowercase, without spaces, and at most {} characters.", "`tag` debe ir en minúsculas, sin espacios y tener como máximo {} caracteres."),
+    ("`{}_soft` has to be at most `{}_hard`, or it would never be flagged.", "`{}_soft` tiene que ser como mucho `{}_hard`, si no nunca se avisaría."),
+    ("`{}` can't be negative.", "`{}` no puede ser negativo."),
+    ("`{}` has no item {}.", "`{}` no tiene ningún punto {}."),
+    ("`{}` has no revision #{}, see `monthly_goal history` for the ones it has.", "`{}` no tiene la revisión #{}, consulta las que tiene con `monthly_goal history`."),
+    ("`{}` hasn't finished yet. Its results are added to the hall of fame once it ends on {}.", "`{}` no ha terminado todavía. Sus resultados pasan al salón de la fama cuando termine el {}."),
+    ("`{}` isn't a date like `2026-07-01`.", "`{}` no es una fecha como `2026-07-01`."),
+    ("`{}` isn't a time I understand. Use `YYYY-MM-DD HH:MM` in the server's timezone (`{}`).", "No entiendo `{}` como hora. Usa `YYYY-MM-DD HH:MM` en la zona horaria del servidor (`{}`)."),
+    ("`{}` isn't a timezone I know. Use an IANA name like `America/New_York` or `Australia/Sydney`.", "No conozco la zona horaria `{}`. Usa un nombre IANA como `America/New_York` o `Australia/Sydney`."),
+    ("`{}` must be a percentage above 0 and at most 100.", "`{}` debe ser un porcentaje mayor que 0 y como mucho 100."),
+    ("over the limit of {} at once", "por encima del límite de {} de una vez"),
+    ("over the soft limit of {} at once", "por encima del límite suave de {} de una vez"),
+    ("over the limit of {} per member per day ({} already today)", "por encima del límite de {} por miembro y día (hoy ya {})"),
+    ("over the soft limit of {} per member per day ({} already today)", "por encima del límite suave de {} por miembro y día (hoy ya {})"),
+    ("over the limit of {} recorded for yourself per day ({} already today)", "por encima del límite de {} al día para ti mismo (hoy ya {})"),
+    ("over the soft limit of {} recorded for yourself per day ({} already today)", "por encima del límite suave de {} al día para ti mismo (hoy ya {})"),
+    ("{} can only be shared in steps of {}.", "{} solo se puede repartir en pasos de {}."),
+    ("{} doesn't count towards merit.", "{} no cuenta para el mérito."),
+    ("{} doesn't have any limits.", "{} no tiene límites."),
+    ("{} is listed more than once.", "{} aparece más de una vez."),
+    ("{} wasn't crew on run #{}.", "{} no era de la tripulación de la ruta #{}."),
+    ("{}'s share of run #{} was already paid.", "La parte de {} de la ruta #{} ya estaba pagada."),
+    ("Something broke! Please contact a mod for help.", "¡Algo se ha roto! Pide ayuda a un moderador."),
+
+    // API.
+    ("Create a new token for the read-only HTTP API, replacing any old one", "Crear un token nuevo para la API HTTP de solo lectura, sustituyendo al anterior"),
+    ("Revoke the token instead, turning off API access for this server.", "Revocar el token en su lugar, desactivando el acceso a la API de este servidor."),
+    ("Only officers can manage the API token.", "Solo los oficiales pueden gestionar el token de la API."),
+    ("This server doesn't have an API token.", "Este servidor no tiene token de API."),
+];
@@ -0,0 +1,261 @@
+//! What members see, in their own language. Catalogs are keyed by the English text, so English needs no catalog of its
+//! own and anything missing from one stays in English.
+
+mod de;
+mod es;
+
+use std::{collections::HashMap, fmt::Display, sync::LazyLock};
+
+use tracing as trc;
+
+use serde_json::{Map, Value};
+use serenity::all::CommandInteraction;
+
+use azel::cmd::RequestError;
+
+static GERMAN: LazyLock<Catalog> = LazyLock::new(|| Catalog::new(de::MESSAGES, de::COMMAND_NAMES));
+static SPANISH: LazyLock<Catalog> = LazyLock::new(|| Catalog::new(es::MESSAGES, es::COMMAND_NAMES));
+
+const APOLOGY: &str = "Something broke! Please contact a mod for help.";
+
+struct Catalog {
+    messages: HashMap<&'static str, &'static str>,
+    command_names: HashMap<&'static str, &'static str>,
+}
+
+impl Catalog {
+    fn new(messages: &'static [(&'static str, &'static str)], command_names: &'static [(&'static str, &'static str)]) -> Self {
+        Self {
+            messages: messages.iter().copied().collect(),
+            command_names: command_names.iter().copied().collect(),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    #[default]
+    English,
+    German,
+    Spanish,
+}
+
+impl Locale {
+    /// Every language with a catalog.
+    const TRANSLATED: [Locale; 2] = [Locale::German, Locale::Spanish];
+
+    /// The closest language to a Discord locale, e.g. "de" or "es-419".
+    pub fn from_discord(locale: &str) -> Self {
+        match locale.split('-').next().unwrap_or_default() {
+            "de" => Self::German,
+            "es" => Self::Spanish,
+            _ => Self::English,
+        }
+    }
+
+    /// The language of whoever ran the command.
+    pub fn of(cmd: &CommandInteraction) -> Self {
+        Self::from_discord(cmd.locale.as_str())
+    }
+
+    /// Discord's locales for the language, Spanish has one for Spain and one for Latin America.
+    fn discord_locales(self) -> &'static [&'static str] {
+        match self {
+            Self::English => &[],
+            Self::German => &["de"],
+            Self::Spanish => &["es-ES", "es-419"],
+        }
+    }
+
+    fn catalog(self) -> Option<&'static Catalog> {
+        match self {
+            Self::English => None,
+            Self::German => Some(&GERMAN),
+            Self::Spanish => Some(&SPANISH),
+        }
+    }
+
+    fn lookup(self, msgid: &str) -> Option<&'static str> {
+        self.catalog().and_then(|catalog| catalog.messages.get(msgid).copied())
+    }
+
+    pub fn translate(self, msgid: &str) -> &str {
+        self.lookup(msgid).unwrap_or(msgid)
+    }
+
+    /// Translates `msgid`, then fills in each `{}` with the next of `args`.
+    pub fn format(self, msgid: &str, args: &[&dyn Display]) -> String {
+        fill(self.translate(msgid), args)
+    }
+
+    /// What to show for a failed request. Internal errors are never shown as they are.
+    pub fn error_message(self, error: &RequestError) -> String {
+        match error {
+            RequestError::User(msg) => self.translate(msg.as_ref()).to_owned(),
+            RequestError::Internal(_) => self.translate(APOLOGY).to_owned(),
+        }
+    }
+
+    /// The error as azel should report it. azel shows internal errors as an English apology, so for other languages
+    /// they're logged here and passed on as a translated user error instead.
+    pub fn localize_error(self, error: RequestError) -> RequestError {
+        match error {
+            _ if self == Self::English => error,
+            RequestError::User(msg) => RequestError::User(self.translate(msg.as_ref()).to_owned().into()),
+            RequestError::Internal(reason) => {
+                trc::error!("REQ-ERR-INTERNAL reason={}", reason);
+                RequestError::User(self.translate(APOLOGY).to_owned().into())
+            },
+        }
+    }
+}
+
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut args = args.iter();
+    let mut pieces = template.split("{}");
+    let mut filled = pieces.next().unwrap_or_default().to_owned();
+    for piece in pieces {
+        if let Some(arg) = args.next() {
+            filled.push_str(arg.to_string().as_str());
+        }
+        filled.push_str(piece);
+    }
+    filled
+}
+
+/// Adds translations to a command as it's sent to Discord. Only top-level commands get translated names, everything
+/// below keeps its English name so instructions like `check` or `at` read the same to everyone.
+pub fn localize_command(command: &mut Value) {
+    localize_entry(command, true);
+}
+
+fn localize_entry(entry: &mut Value, top_level: bool) {
+    let Some(entry) = entry.as_object_mut() else {
+        return;
+    };
+    let name = entry.get("name").and_then(Value::as_str).unwrap_or_default();
+    let description = entry.get("description").and_then(Value::as_str).unwrap_or_default();
+    let mut names = Map::new();
+    let mut descriptions = Map::new();
+    for locale in Locale::TRANSLATED {
+        let Some(catalog) = locale.catalog() else {
+            continue;
+        };
+        let name = catalog.command_names.get(name).filter(|_| top_level);
+        let description = catalog.messages.get(description);
+        for discord_locale in locale.discord_locales() {
+            if let Some(name) = name {
+                names.insert(discord_locale.to_string(), Value::from(*name));
+            }
+            if let Some(description) = description {
+                descriptions.insert(discord_locale.to_string(), Value::from(*description));
+            }
+        }
+    }
+    if !names.is_empty() {
+        entry.insert("name_localizations".to_owned(), Value::Object(names));
+    }
+    if !descriptions.is_empty() {
+        entry.insert("description_localizations".to_owned(), Value::Object(descriptions));
+    }
+    if let Some(Value::Array(options)) = entry.get_mut("options") {
+        for option in options {
+            localize_entry(option, false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use azel::cmd::RequestError;
+    use serde_json::Value;
+
+    use crate::cmd::generate_command_descriptions;
+
+    use super::{Locale, fill, localize_command};
+
+    #[test]
+    fn test_from_discord() {
+        assert_eq!(Locale::from_discord("de"), Locale::German);
+        assert_eq!(Locale::from_discord("es-ES"), Locale::Spanish);
+        assert_eq!(Locale::from_discord("es-419"), Locale::Spanish);
+        assert_eq!(Locale::from_discord("en-GB"), Locale::English);
+        assert_eq!(Locale::from_discord("fr"), Locale::English);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(fill("Added {} to {}.", &[&5, &"Ann"]), "Added 5 to Ann.");
+        assert_eq!(Locale::German.format("{} victories", &[&3]), "3 Siege");
+        assert_eq!(Locale::English.format("{} victories", &[&3]), "3 victories");
+        assert_eq!(Locale::Spanish.translate("Not in any catalog"), "Not in any catalog");
+    }
+
+    #[test]
+    fn test_error_message() {
+        let internal = RequestError::Internal("Count update failed".into());
+        assert_eq!(Locale::German.error_message(&internal), Locale::German.translate("Something broke! Please contact a mod for help."));
+        assert_ne!(Locale::German.error_message(&internal), Locale::English.error_message(&internal));
+        let user = RequestError::User("Pick an event from the list.".into());
+        assert_ne!(Locale::Spanish.error_message(&user), Locale::English.error_message(&user));
+
+        match Locale::German.localize_error(RequestError::Internal("Count update failed".into())) {
+            RequestError::User(msg) => assert_eq!(msg, Locale::German.error_message(&internal)),
+            e => panic!("{e:?}"),
+        }
+        assert!(matches!(Locale::English.localize_error(internal), RequestError::Internal(_)));
+    }
+
+    /// Every name and description in the command tree, with whether it's a top-level chat input command.
+    fn collect_entries(entry: &Value, top_level: bool, names: &mut Vec<(String, bool)>, descriptions: &mut Vec<String>) {
+        if top_level {
+            let chat_input = entry.get("type").and_then(Value::as_u64).is_none_or(|kind| kind == 1);
+            names.push((entry["name"].as_str().unwrap_or_default().to_owned(), chat_input));
+        }
+        if let Some(description) = entry.get("description").and_then(Value::as_str).filter(|description| !description.is_empty()) {
+            descriptions.push(description.to_owned());
+        }
+        for option in entry.get("options").and_then(Value::as_array).into_iter().flatten() {
+            collect_entries(option, false, names, descriptions);
+        }
+    }
+
+    /// Every description Discord gets has a translation that fits, and names follow Discord's rules.
+    #[test]
+    fn test_catalogs_cover_commands() {
+        let mut names = vec![];
+        let mut descriptions = vec![];
+        let mut commands = vec![];
+        for top in generate_command_descriptions() {
+            let command = serde_json::to_value(top.into_discord_command()).expect("commands serialize");
+            collect_entries(&command, true, &mut names, &mut descriptions);
+            commands.push(command);
+        }
+
+        for locale in Locale::TRANSLATED {
+            let catalog = locale.catalog().expect("translated locales have catalogs");
+            for description in &descriptions {
+                let translated = catalog.messages.get(description.as_str());
+                assert!(translated.is_some(), "{locale:?} is missing {description:?}");
+                assert!(translated.is_some_and(|translated| translated.chars().count() <= 100), "{locale:?} {description:?}");
+            }
+            for (name, chat_input) in &names {
+                if let Some(translated) = catalog.command_names.get(name.as_str()) {
+                    assert!(translated.chars().count() <= 32, "{locale:?} {name:?}");
+                    if *chat_input {
+                        assert!(translated.chars().all(|c| c == '_' || c == '-' || c.is_lowercase() || c.is_numeric()), "{locale:?} {name:?}");
+                    }
+                }
+            }
+            for (msgid, translated) in &catalog.messages {
+                assert_eq!(msgid.matches("{}").count(), translated.matches("{}").count(), "{locale:?} {msgid:?}");
+            }
+        }
+
+        for command in commands.iter_mut() {
+            localize_command(command);
+            let described = command.get("description").and_then(Value::as_str).is_some_and(|description| !description.is_empty());
+            assert!(!described || command["description_localizations"].get("es-419").is_some(), "{command}");
+        }
+    }
+}
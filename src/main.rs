@@ -1,22 +1,101 @@
 #![feature(never_type, option_into_flat_iter, iter_intersperse)]
 
-use std::future;
+use std::sync::Arc;
+
+use serenity::all::{ApplicationId, Client, GatewayIntents, GuildId};
 
 mod schema;
 mod db;
 
+mod calendar;
+mod locale;
+
+mod api;
 mod cmd;
+mod event;
+mod report;
+mod scheduler;
+
+/// azel keeps its configuration to itself, so pull the sections we need back out of the same file.
+fn load_configuration_section<T: serde::de::DeserializeOwned>(key: &str) -> Result<T, config::ConfigError> {
+    let cfg_path = std::env::args().nth(1).ok_or_else(|| config::ConfigError::NotFound("configuration path".to_owned()))?;
+    config::Config::builder()
+        .add_source(config::File::with_name(cfg_path.as_str()))
+        .build()?
+        .get(key)
+}
+
+/// The parts of the discord section needed to build the client. azel's `build_client` registers commands in English
+/// only, so we build it ourselves.
+#[derive(serde::Deserialize)]
+struct DiscordConfiguration {
+    token: String,
+    application: u64,
+}
+
+/// The database section, for our own event handler.
+fn load_database_configuration() -> Result<azel::DatabaseConfiguration, config::ConfigError> {
+    load_configuration_section("database")
+}
+
+/// The HTTP API is off unless there's an `[api]` section turning it on.
+fn load_api_configuration() -> Result<api::ApiConfiguration, config::ConfigError> {
+    match load_configuration_section("api") {
+        Err(config::ConfigError::NotFound(_)) => Ok(api::ApiConfiguration::default()),
+        result => result,
+    }
+}
+
+/// `auric-regia <config> report <directory> [period]` writes the monthly report instead of starting the bot, see
+/// `src/report/mod.rs`.
+async fn run_report(args: &[String]) {
+    tracing_subscriber::fmt::init();
+    let options = match report::Options::parse(args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(2);
+        },
+    };
+    let db_cfg = &load_database_configuration().expect("database configuration complete");
+    let discord_cfg = load_configuration_section("discord").unwrap_or_default();
+    match report::run(&db_cfg, discord_cfg, options).await {
+        Ok(index) => println!("{}", index.display()),
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        },
+    }
+}
 
 #[tokio::main]
 async fn main() {
-    let cfg = azel::setup_default_log_and_load_configuration().expect("configuration complete");
-    let mut client = azel::build_client(
-        cfg,
-        |_| future::ready(vec![]),
-        |_guild_id, _c| {
-            future::ready(cmd::generate_command_descriptions())
-        },
-        |b| b,
-    ).await.expect("build complete");
-    client.0.start().await.expect("launch complete");
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(2).is_some_and(|arg| arg == "report") {
+        return run_report(&args[3..]).await;
+    }
+
+    // Sets up logging and checks the whole configuration is there.
+    azel::setup_default_log_and_load_configuration().expect("configuration complete");
+    let discord_cfg: DiscordConfiguration = load_configuration_section("discord").expect("discord configuration complete");
+    let home_guild_id: u64 = load_configuration_section("home_guild.id").expect("home guild configuration complete");
+    let db_cfg = load_database_configuration().expect("database configuration complete");
+    let api_cfg = load_api_configuration().expect("api configuration complete");
+    if api_cfg.enabled {
+        let api_db_cfg = azel::DatabaseConfiguration { url: db_cfg.url.clone() };
+        tokio::spawn(api::serve(api_cfg, Arc::new(api_db_cfg)));
+    }
+    let commands = azel::DiscordHandler {
+        home_guild_id: GuildId::new(home_guild_id),
+        db_cfg: azel::DatabaseConfiguration { url: db_cfg.url.clone() },
+        command_descriptions: cmd::generate_command_descriptions(),
+    };
+    // Member joins and departures, plus listing members, need the privileged members intent.
+    let intents = GatewayIntents::non_privileged() | GatewayIntents::GUILD_MEMBERS;
+    let mut client = Client::builder(discord_cfg.token.as_str(), intents)
+        .application_id(ApplicationId::new(discord_cfg.application))
+        .event_handler(event::Commands(commands))
+        .event_handler(event::Handler::new(db_cfg))
+        .await.expect("build complete");
+    client.start().await.expect("launch complete");
 }
@@ -0,0 +1,191 @@
+//! Pages are self-contained, with the styles inline and no scripts, so they can be published or archived as they are.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use super::{GuildReport, page_file_name};
+
+const STYLE: &str = "\
+body { font-family: sans-serif; max-width: 60rem; margin: 2rem auto; padding: 0 1rem; color: #222; }
+h1 { margin-bottom: 0; }
+.meta { color: #666; margin-top: 0.25rem; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 1.5rem; }
+th, td { text-align: left; padding: 0.3rem 0.5rem; border-bottom: 1px solid #ddd; }
+td.number { text-align: right; }
+.bar { background: #eee; width: 10rem; height: 0.8rem; }
+.bar > div { background: #2a7; height: 100%; }
+.cleared { color: #666; }
+";
+
+/// Makes text safe to put in an element or a quoted attribute.
+pub fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{STYLE}</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        escape(title),
+    )
+}
+
+fn describe_dates((first, last): (NaiveDate, NaiveDate)) -> String {
+    if first == last {
+        first.to_string()
+    } else {
+        format!("{first} to {last}")
+    }
+}
+
+pub fn render_guild(report: &GuildReport) -> String {
+    let title = format!("{} report, {}", report.guild_name, describe_dates(report.dates));
+    let mut body = String::new();
+    let _ = writeln!(body, "<h1>{}</h1>", escape(report.guild_name.as_str()));
+    let _ = writeln!(
+        body,
+        "<p class=\"meta\">{}, generated {}</p>",
+        describe_dates(report.dates),
+        report.generated.format("%Y-%m-%d %H:%M UTC"),
+    );
+
+    body.push_str("<h2>Totals</h2>\n<table>\n<tr><th>Stat</th><th>Total</th><th>Contributors</th><th>Newcomers</th></tr>\n");
+    for stat in report.stats.iter() {
+        let _ = writeln!(
+            body,
+            "<tr><td>{}</td><td class=\"number\">{}</td><td class=\"number\">{}</td><td class=\"number\">{}</td></tr>",
+            escape(stat.stat.as_command_opt_display_name()),
+            escape(stat.stat.format_count(stat.total.clone()).as_str()),
+            stat.contributors,
+            stat.newcomers.len(),
+        );
+    }
+    body.push_str("</table>\n");
+
+    body.push_str("<h2>Goals</h2>\n");
+    if report.branches.is_empty() {
+        body.push_str("<p>No goals were active.</p>\n");
+    }
+    for branch in report.branches.iter() {
+        let _ = writeln!(body, "<h3>{}</h3>", escape(branch.name.as_str()));
+        body.push_str("<table>\n<tr><th>Goal</th><th colspan=\"2\">Progress</th><th>Status</th></tr>\n");
+        for goal in branch.goals.iter() {
+            let percent = goal.progress.clamp(0, 100);
+            let status = match goal.cleared {
+                Some(cleared) => format!("Cleared {}", cleared.format("%Y-%m-%d")),
+                None => "Active".to_owned(),
+            };
+            let _ = writeln!(
+                body,
+                "<tr{}><td>{} (<code>{}</code>)</td><td><div class=\"bar\"><div style=\"width: {percent}%\"></div></div></td><td class=\"number\">{}%</td><td>{status}</td></tr>",
+                if goal.cleared.is_some() { " class=\"cleared\"" } else { "" },
+                escape(goal.header.as_str()),
+                escape(goal.shortname.as_str()),
+                goal.progress,
+            );
+        }
+        body.push_str("</table>\n");
+    }
+
+    body.push_str("<h2>Top contributors</h2>\n");
+    for stat in report.stats.iter() {
+        let _ = writeln!(body, "<h3>{}</h3>", escape(stat.stat.as_command_opt_display_name()));
+        if stat.top.is_empty() {
+            body.push_str("<p>Nothing recorded.</p>\n");
+        } else {
+            body.push_str("<table>\n<tr><th>#</th><th>Member</th><th>Recorded</th></tr>\n");
+            for (rank, (name, total)) in stat.top.iter().enumerate() {
+                let _ = writeln!(
+                    body,
+                    "<tr><td>{}</td><td>{}</td><td class=\"number\">{}</td></tr>",
+                    rank + 1,
+                    escape(name.as_str()),
+                    escape(stat.stat.format_count(total.clone()).as_str()),
+                );
+            }
+            body.push_str("</table>\n");
+        }
+        if !stat.newcomers.is_empty() {
+            let names: String = stat.newcomers.iter().map(|name| escape(name.as_str())).intersperse(", ".to_owned()).collect();
+            let _ = writeln!(body, "<p>New on the leaderboard: {names}</p>");
+        }
+    }
+
+    page(title.as_str(), body.as_str())
+}
+
+pub fn render_index(dates: (NaiveDate, NaiveDate), generated: DateTime<Utc>, reports: &[GuildReport]) -> String {
+    let title = format!("Reports, {}", describe_dates(dates));
+    let mut body = String::new();
+    let _ = writeln!(body, "<h1>{}</h1>", escape(title.as_str()));
+    let _ = writeln!(body, "<p class=\"meta\">Generated {}</p>", generated.format("%Y-%m-%d %H:%M UTC"));
+    body.push_str("<ul>\n");
+    for report in reports {
+        let _ = writeln!(
+            body,
+            "<li><a href=\"{}\">{}</a></li>",
+            escape(page_file_name(report.guild_id).as_str()),
+            escape(report.guild_name.as_str()),
+        );
+    }
+    body.push_str("</ul>\n");
+    page(title.as_str(), body.as_str())
+}
+
+#[cfg(test)]
+mod test {
+    use serenity::all::GuildId;
+
+    use super::*;
+    use crate::{db::TrackerStat, report::{BranchGoals, GoalSummary, StatSummary}};
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("Plain text"), "Plain text");
+        assert_eq!(escape("<b>\"Tom\" & 'Jerry'</b>"), "&lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;");
+    }
+
+    #[test]
+    fn test_render_guild_escapes_names() {
+        let date = NaiveDate::from_ymd_opt(2026, 9, 1).unwrap();
+        let report = GuildReport {
+            guild_id: GuildId::new(1),
+            guild_name: "<script>".to_owned(),
+            dates: (date, NaiveDate::from_ymd_opt(2026, 9, 30).unwrap()),
+            generated: date.and_hms_opt(0, 0, 0).unwrap().and_utc(),
+            branches: vec![BranchGoals {
+                name: "Navy".to_owned(),
+                goals: vec![GoalSummary {
+                    header: "Win & hold".to_owned(),
+                    shortname: "hold".to_owned(),
+                    progress: 40,
+                    cleared: None,
+                }],
+            }],
+            stats: vec![StatSummary {
+                stat: TrackerStat::GroundKill,
+                total: 3.into(),
+                contributors: 1,
+                top: vec![("<img>".to_owned(), 3.into())],
+                newcomers: vec!["<img>".to_owned()],
+            }],
+        };
+        let html = render_guild(&report);
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<img>"));
+        assert!(html.contains("Win &amp; hold"));
+        assert!(html.contains("2026-09-01 to 2026-09-30"));
+        assert!(html.contains("3 kills"));
+    }
+}
@@ -0,0 +1,412 @@
+//! Monthly report for leadership to archive, run with the bot's configuration as
+//! `auric-regia <config> report <directory> [period]`. The period defaults to last month and takes the same forms as
+//! the scoreboard's, e.g. `2026-09`. Each guild gets a self-contained HTML page in a subdirectory named after the
+//! period, next to an `index.html` linking them. Names are looked up on Discord when the configuration has a bot
+//! token, otherwise members show up by id.
+
+mod html;
+
+use std::{collections::{HashMap, HashSet}, fmt, io, path::PathBuf, str::FromStr};
+
+use bigdecimal::{BigDecimal, ToPrimitive, Zero};
+use chrono::{DateTime, NaiveDate, Utc};
+use serenity::{all::GuildId, http::Http};
+use strum::IntoEnumIterator;
+use tracing as trc;
+
+use azel::db::{Connector, DbError};
+
+use crate::{calendar::{Period, UtcRange}, cmd::lib::members, db::{DiscordUserId, GoalRevision, GuildBranch, GuildSettings, MonthlyGoal, TrackerCount, TrackerStat}};
+
+const USAGE: &str = "Usage: auric-regia <config> report <directory> [period]";
+
+/// How many members each stat lists as its top contributors.
+const TOP_CONTRIBUTORS: usize = 5;
+
+#[derive(Debug)]
+pub enum ReportError {
+    Usage(String),
+    Db(DbError),
+    Io(io::Error),
+}
+
+impl fmt::Display for ReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Usage(message) => write!(f, "{message}"),
+            Self::Db(e) => write!(f, "Database error: {e:?}"),
+            Self::Io(e) => write!(f, "Failed to write the report: {e}"),
+        }
+    }
+}
+
+impl From<DbError> for ReportError {
+    fn from(e: DbError) -> Self {
+        Self::Db(e)
+    }
+}
+
+impl From<io::Error> for ReportError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// Just the bot token out of the `[discord]` section, for looking up names.
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct DiscordConfiguration {
+    #[serde(default)]
+    pub token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub directory: PathBuf,
+    pub period: Period,
+}
+
+impl Options {
+    /// From the arguments following `report`.
+    pub fn parse(args: &[String]) -> Result<Self, ReportError> {
+        let (directory, period) = match args {
+            [directory] => (directory, Period::LastMonth),
+            [directory, period] => (directory, Period::from_str(period).map_err(|e| ReportError::Usage(e.to_string()))?),
+            _ => return Err(ReportError::Usage(USAGE.to_owned())),
+        };
+        Ok(Self {
+            directory: directory.into(),
+            period,
+        })
+    }
+}
+
+/// Everything on one guild's page.
+#[derive(Debug)]
+struct GuildReport {
+    guild_id: GuildId,
+    guild_name: String,
+    /// First and last local day covered.
+    dates: (NaiveDate, NaiveDate),
+    generated: DateTime<Utc>,
+    branches: Vec<BranchGoals>,
+    stats: Vec<StatSummary>,
+}
+
+#[derive(Debug)]
+struct BranchGoals {
+    name: String,
+    goals: Vec<GoalSummary>,
+}
+
+#[derive(Debug)]
+struct GoalSummary {
+    header: String,
+    shortname: String,
+    /// As it stood at the end of the period.
+    progress: i16,
+    /// Set if the goal was cleared before the period ended.
+    cleared: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug)]
+struct StatSummary {
+    stat: TrackerStat,
+    total: BigDecimal,
+    contributors: usize,
+    top: Vec<(String, BigDecimal)>,
+    newcomers: Vec<String>,
+}
+
+/// Writes a page for every guild with stats or goals in the period, and returns where the index went.
+pub async fn run(db_cfg: &impl Connector, discord_cfg: DiscordConfiguration, options: Options) -> Result<PathBuf, ReportError> {
+    let now = Utc::now();
+    let http = (!discord_cfg.token.is_empty()).then(|| Http::new(discord_cfg.token.as_str()));
+    if http.is_none() {
+        trc::warn!("No Discord token configured, the report will show ids instead of names.");
+    }
+
+    let mut reports = vec![];
+    for guild_id in load_guild_ids(db_cfg).await? {
+        match build_guild_report(db_cfg, http.as_ref(), guild_id, options.period, now).await? {
+            Some(report) => reports.push(report),
+            None => trc::info!("Nothing happened in {guild_id} during the period, skipping its report."),
+        }
+    }
+
+    // Each page covers its guild's local dates, the directory is named after all of them.
+    let (start, end) = options.period.local_dates(now.date_naive());
+    let dates = covered_dates(&reports).unwrap_or((start, end.pred_opt().unwrap_or(end)));
+    let directory = options.directory.join(format!("{}_{}", dates.0, dates.1));
+    std::fs::create_dir_all(&directory)?;
+    for report in reports.iter() {
+        let path = directory.join(page_file_name(report.guild_id));
+        std::fs::write(&path, html::render_guild(report))?;
+        trc::info!("Wrote the report for {} to {}.", report.guild_id, path.display());
+    }
+
+    let index = directory.join("index.html");
+    std::fs::write(&index, html::render_index(dates, now, &reports))?;
+    Ok(index)
+}
+
+/// From the earliest first day to the latest last day of the reports, which only differ when guilds' timezones put
+/// them on different dates.
+fn covered_dates(reports: &[GuildReport]) -> Option<(NaiveDate, NaiveDate)> {
+    let start = reports.iter().map(|report| report.dates.0).min()?;
+    let end = reports.iter().map(|report| report.dates.1).max()?;
+    Some((start, end))
+}
+
+fn page_file_name(guild_id: GuildId) -> String {
+    format!("{guild_id}.html")
+}
+
+/// Every guild that's recorded a stat or set a goal.
+async fn load_guild_ids(db_cfg: &impl Connector) -> Result<Vec<GuildId>, ReportError> {
+    let mut guild_ids: Vec<GuildId> = TrackerCount::load_guild_ids(db_cfg).await?
+        .into_iter()
+        .map(GuildId::from)
+        .chain(MonthlyGoal::load_guild_ids(db_cfg).await?
+            .into_iter()
+            .filter_map(|guild_id| guild_id.to_u64().filter(|id| *id != 0).map(GuildId::new)))
+        .collect();
+    guild_ids.sort();
+    guild_ids.dedup();
+    Ok(guild_ids)
+}
+
+/// `None` if the guild had no goals and recorded nothing during the period.
+async fn build_guild_report(db_cfg: &impl Connector, http: Option<&Http>, guild_id: GuildId, period: Period, now: DateTime<Utc>) -> Result<Option<GuildReport>, ReportError> {
+    let tz = GuildSettings::load_timezone(db_cfg, guild_id.into()).await?;
+    let (start, end) = period.local_dates(now.with_timezone(&tz).date_naive());
+    let range = period.to_utc_range(tz, now);
+
+    let mut goals = vec![];
+    for goal in MonthlyGoal::load_active_during(db_cfg, guild_id, range).await? {
+        let revisions = GoalRevision::load_for(db_cfg, goal.id).await?;
+        goals.push((goal.tag.clone(), GoalSummary {
+            progress: progress_at(&goal, &revisions, range.end),
+            cleared: goal.disabled.filter(|cleared| *cleared < range.end),
+            header: goal.header,
+            shortname: goal.shortname,
+        }));
+    }
+    let branches = GuildBranch::load_all(db_cfg, guild_id.into()).await?;
+    let branches = group_by_branch(&branches, goals);
+
+    let mut stat_totals = vec![];
+    let mut stat_newcomers = vec![];
+    for stat in TrackerStat::iter() {
+        let totals: Vec<_> = TrackerCount::load_period_totals(db_cfg, stat, guild_id.into(), range).await?
+            .into_iter()
+            .filter(|(_, total)| !total.is_zero())
+            .collect();
+        let newcomers = newcomers(&TrackerCount::load_changes(db_cfg, stat, guild_id.into()).await?, range);
+        stat_totals.push((stat, totals));
+        stat_newcomers.push(newcomers);
+    }
+    if branches.is_empty() && stat_totals.iter().all(|(_, totals)| totals.is_empty()) {
+        return Ok(None);
+    }
+
+    let (guild_name, names) = match http {
+        Some(http) => fetch_names(http, guild_id).await,
+        None => (None, HashMap::new()),
+    };
+    let name_of = |user_id: DiscordUserId| {
+        names.get(&user_id)
+            .cloned()
+            .unwrap_or_else(|| format!("User {}", user_id.inner()))
+    };
+
+    let stats = stat_totals.into_iter()
+        .zip(stat_newcomers)
+        .map(|((stat, totals), newcomers)| StatSummary {
+            stat,
+            total: totals.iter().map(|(_, total)| total).sum(),
+            contributors: totals.len(),
+            top: totals.iter().take(TOP_CONTRIBUTORS).map(|(user_id, total)| (name_of(*user_id), total.clone())).collect(),
+            newcomers: newcomers.into_iter().map(name_of).collect(),
+        })
+        .collect();
+
+    Ok(Some(GuildReport {
+        guild_id,
+        guild_name: guild_name.unwrap_or_else(|| format!("Server {guild_id}")),
+        dates: (start, end.pred_opt().unwrap_or(end)),
+        generated: now,
+        branches,
+        stats,
+    }))
+}
+
+/// The guild's name and its current members' display names, or nothing if Discord can't be reached. A report with
+/// ids beats no report, so failures are only logged.
+async fn fetch_names(http: &Http, guild_id: GuildId) -> (Option<String>, HashMap<DiscordUserId, String>) {
+    let guild_name = match http.get_guild(guild_id).await {
+        Ok(guild) => Some(guild.name),
+        Err(e) => {
+            trc::warn!("Failed to fetch guild {guild_id} due to {e:?}.");
+            None
+        },
+    };
+    let names = match members::fetch_members(http, guild_id).await {
+        Ok(members) => members.into_iter()
+            .map(|member| (DiscordUserId::from(member.user.id), member.display_name().to_owned()))
+            .collect(),
+        Err(e) => {
+            trc::warn!("Failed to fetch members of {guild_id} due to {e:?}.");
+            HashMap::new()
+        },
+    };
+    (guild_name, names)
+}
+
+/// Goals under their branches in the guild's order. Goals whose branch has since been removed go last, under their
+/// tag.
+fn group_by_branch(branches: &[GuildBranch], goals: Vec<(String, GoalSummary)>) -> Vec<BranchGoals> {
+    let mut grouped: Vec<(String, BranchGoals)> = branches.iter()
+        .map(|branch| (branch.tag.clone(), BranchGoals {
+            name: branch.display_name.clone(),
+            goals: vec![],
+        }))
+        .collect();
+    for (tag, goal) in goals {
+        match grouped.iter_mut().find(|(branch_tag, _)| *branch_tag == tag) {
+            Some((_, branch)) => branch.goals.push(goal),
+            None => grouped.push((tag.clone(), BranchGoals {
+                name: tag,
+                goals: vec![goal],
+            })),
+        }
+    }
+    grouped.into_iter()
+        .map(|(_, branch)| branch)
+        .filter(|branch| !branch.goals.is_empty())
+        .collect()
+}
+
+/// The goal's progress as of `end`, from its revisions (oldest first) where it has any.
+fn progress_at(goal: &MonthlyGoal, revisions: &[GoalRevision], end: DateTime<Utc>) -> i16 {
+    if let Some(revision) = revisions.iter().rev().find(|revision| revision.created < end) {
+        return revision.progress;
+    }
+    // Every change came later, so the first one knows what it started from.
+    revisions.first()
+        .and_then(|revision| revision.old_progress)
+        .unwrap_or(goal.progress)
+}
+
+/// Members whose first addition to the stat falls within `range`, in the order they got on the board. `changes` is
+/// oldest first, as `TrackerCount::load_changes` gives them.
+fn newcomers(changes: &[(DiscordUserId, DateTime<Utc>, BigDecimal)], range: UtcRange) -> Vec<DiscordUserId> {
+    let mut seen = HashSet::new();
+    changes.iter()
+        .filter(|(_, _, total)| *total > BigDecimal::zero())
+        .filter(|(user_id, _, _)| seen.insert(*user_id))
+        .filter(|(_, created, _)| range.contains(*created))
+        .map(|(user_id, _, _)| *user_id)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeZone;
+    use serenity::all::UserId;
+
+    use super::*;
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 9, day, 12, 0, 0).unwrap()
+    }
+
+    fn user(id: u64) -> DiscordUserId {
+        UserId::new(id).into()
+    }
+
+    fn goal(progress: i16) -> MonthlyGoal {
+        MonthlyGoal {
+            id: 1,
+            created: at(1),
+            updater: 0.into(),
+            tag: "navy".to_owned(),
+            header: "Header".to_owned(),
+            body: "Body".to_owned(),
+            progress,
+            shortname: "goal".to_owned(),
+            disabled: None,
+            guild_id: 1.into(),
+            deadline: None,
+            reminders_sent: 0,
+            owner_id: None,
+        }
+    }
+
+    fn revision(day: u32, old_progress: Option<i16>, progress: i16) -> GoalRevision {
+        GoalRevision {
+            id: day.into(),
+            goal_id: 1,
+            created: at(day),
+            updater: 0.into(),
+            old_header: None,
+            old_body: None,
+            old_progress,
+            header: "Header".to_owned(),
+            body: "Body".to_owned(),
+            progress,
+        }
+    }
+
+    #[test]
+    fn test_options_parse() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(Options::parse(&args(&["out"])).unwrap(), Options { directory: "out".into(), period: Period::LastMonth });
+        assert_eq!(
+            Options::parse(&args(&["out", "2026-09"])).unwrap().period,
+            Period::Dates(NaiveDate::from_ymd_opt(2026, 9, 1).unwrap(), NaiveDate::from_ymd_opt(2026, 9, 30).unwrap()),
+        );
+        assert!(matches!(Options::parse(&args(&[])), Err(ReportError::Usage(_))));
+        assert!(matches!(Options::parse(&args(&["out", "soon"])), Err(ReportError::Usage(_))));
+    }
+
+    #[test]
+    fn test_covered_dates() {
+        let date = |month, day| NaiveDate::from_ymd_opt(2026, month, day).unwrap();
+        let report = |dates| GuildReport {
+            guild_id: GuildId::new(1),
+            guild_name: "Server".to_owned(),
+            dates,
+            generated: at(1),
+            branches: vec![],
+            stats: vec![],
+        };
+        assert_eq!(covered_dates(&[]), None);
+        // A guild far enough east is already a day ahead.
+        let reports = [report((date(9, 1), date(9, 30))), report((date(9, 2), date(10, 1)))];
+        assert_eq!(covered_dates(&reports), Some((date(9, 1), date(10, 1))));
+    }
+
+    #[test]
+    fn test_progress_at() {
+        let revisions = [revision(5, None, 10), revision(10, Some(10), 40), revision(20, Some(40), 90)];
+        assert_eq!(progress_at(&goal(90), &revisions, at(15)), 40);
+        assert_eq!(progress_at(&goal(90), &revisions, at(25)), 90);
+        // Edited only after the period ended.
+        assert_eq!(progress_at(&goal(90), &revisions[1..], at(8)), 10);
+        assert_eq!(progress_at(&goal(30), &[], at(8)), 30);
+    }
+
+    #[test]
+    fn test_newcomers() {
+        let range = UtcRange { start: at(10), end: at(20) };
+        let changes = [
+            (user(1), at(2), BigDecimal::from(3)),
+            (user(2), at(5), BigDecimal::from(-1)),
+            (user(3), at(12), BigDecimal::from(1)),
+            (user(1), at(13), BigDecimal::from(2)),
+            (user(2), at(14), BigDecimal::from(5)),
+            (user(4), at(22), BigDecimal::from(1)),
+        ];
+        assert_eq!(newcomers(&changes, range), vec![user(3), user(2)]);
+    }
+}
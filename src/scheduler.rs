@@ -0,0 +1,36 @@
+//! Periodic background work that isn't triggered by a command or gateway event.
+
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use serenity::all::Http;
+use tracing as trc;
+
+use azel::DatabaseConfiguration;
+
+use crate::cmd::{monthly_goal, season};
+
+const TICK: Duration = Duration::from_secs(5 * 60);
+
+/// Runs forever, so spawn it.
+pub async fn run(db_cfg: Arc<DatabaseConfiguration>, http: Arc<Http>) {
+    let mut interval = tokio::time::interval(TICK);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        tick(&db_cfg, &http).await;
+    }
+}
+
+async fn tick(db_cfg: &DatabaseConfiguration, http: &Http) {
+    match season::finalize_ended(&db_cfg, Utc::now()).await {
+        Ok(0) => {},
+        Ok(finalized) => trc::info!("Finalized {finalized} seasons."),
+        Err(e) => trc::error!("Failed to finalize ended seasons due to {e:?}."),
+    }
+    match monthly_goal::deadlines::send_reminders(http, &db_cfg, Utc::now()).await {
+        Ok(0) => {},
+        Ok(sent) => trc::info!("Sent {sent} goal reminders."),
+        Err(e) => trc::error!("Failed to send goal reminders due to {e:?}."),
+    }
+}
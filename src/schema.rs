@@ -1,5 +1,188 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    event_attendees (event_id, user_id) {
+        event_id -> Int8,
+        user_id -> Numeric,
+        change_id -> Int8,
+    }
+}
+
+diesel::table! {
+    event_rsvps (event_id, user_id) {
+        event_id -> Int8,
+        user_id -> Numeric,
+        updated -> Timestamptz,
+        #[max_length = 16]
+        response -> Varchar,
+    }
+}
+
+diesel::table! {
+    events (id) {
+        id -> Int8,
+        created -> Timestamptz,
+        guild_id -> Numeric,
+        host -> Numeric,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 1000]
+        description -> Nullable<Varchar>,
+        starts -> Timestamptz,
+        channel_id -> Numeric,
+        message_id -> Nullable<Numeric>,
+        roll_called -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    goal_boards (guild_id, branch) {
+        guild_id -> Numeric,
+        #[max_length = 100]
+        branch -> Varchar,
+        created -> Timestamptz,
+        channel_id -> Numeric,
+        message_id -> Numeric,
+        show_details -> Bool,
+        show_branches -> Bool,
+    }
+}
+
+diesel::table! {
+    goal_items (id) {
+        id -> Int8,
+        goal_id -> Int8,
+        position -> Int4,
+        created -> Timestamptz,
+        #[max_length = 256]
+        description -> Varchar,
+        completed -> Nullable<Timestamptz>,
+        completed_by -> Nullable<Numeric>,
+    }
+}
+
+diesel::table! {
+    goal_milestones (goal_id, percent) {
+        goal_id -> Int8,
+        percent -> Int2,
+    }
+}
+
+diesel::table! {
+    goal_pledges (goal_id, user_id) {
+        goal_id -> Int8,
+        user_id -> Numeric,
+        created -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    goal_revisions (id) {
+        id -> Int8,
+        goal_id -> Int8,
+        created -> Timestamptz,
+        updater -> Numeric,
+        #[max_length = 256]
+        old_header -> Nullable<Varchar>,
+        #[max_length = 4096]
+        old_body -> Nullable<Varchar>,
+        old_progress -> Nullable<Int2>,
+        #[max_length = 256]
+        header -> Varchar,
+        #[max_length = 4096]
+        body -> Varchar,
+        progress -> Int2,
+    }
+}
+
+diesel::table! {
+    guild_api_tokens (guild_id) {
+        guild_id -> Numeric,
+        created -> Timestamptz,
+        created_by -> Numeric,
+        #[max_length = 64]
+        token_hash -> Varchar,
+    }
+}
+
+diesel::table! {
+    guild_branches (id) {
+        id -> Int8,
+        created -> Timestamptz,
+        updated -> Timestamptz,
+        guild_id -> Numeric,
+        #[max_length = 100]
+        tag -> Varchar,
+        #[max_length = 100]
+        display_name -> Varchar,
+        ansi_color -> Int2,
+        #[max_length = 100]
+        emoji -> Nullable<Varchar>,
+        sort_order -> Int4,
+        channel_id -> Nullable<Numeric>,
+    }
+}
+
+diesel::table! {
+    guild_member_departures (guild_id, user_id) {
+        guild_id -> Numeric,
+        user_id -> Numeric,
+        departed -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    guild_merit_weights (guild_id, stat) {
+        guild_id -> Numeric,
+        #[max_length = 100]
+        stat -> Varchar,
+        created -> Timestamptz,
+        updated -> Timestamptz,
+        points -> Numeric,
+        per -> Numeric,
+    }
+}
+
+diesel::table! {
+    guild_role_groups (id) {
+        id -> Int8,
+        created -> Timestamptz,
+        guild_id -> Numeric,
+        role_id -> Numeric,
+        #[max_length = 100]
+        name -> Varchar,
+    }
+}
+
+diesel::table! {
+    guild_settings (guild_id) {
+        guild_id -> Numeric,
+        created -> Timestamptz,
+        updated -> Timestamptz,
+        #[max_length = 64]
+        timezone -> Varchar,
+        officer_channel -> Nullable<Numeric>,
+        #[max_length = 20]
+        bar_style -> Varchar,
+    }
+}
+
+diesel::table! {
+    guild_stat_limits (guild_id, stat) {
+        guild_id -> Numeric,
+        #[max_length = 100]
+        stat -> Varchar,
+        created -> Timestamptz,
+        updated -> Timestamptz,
+        max_change_soft -> Nullable<Numeric>,
+        max_change_hard -> Nullable<Numeric>,
+        daily_cap_soft -> Nullable<Numeric>,
+        daily_cap_hard -> Nullable<Numeric>,
+        self_daily_soft -> Nullable<Numeric>,
+        self_daily_hard -> Nullable<Numeric>,
+    }
+}
+
 diesel::table! {
     monthly_goals (id) {
         id -> Int8,
@@ -16,6 +199,59 @@ diesel::table! {
         shortname -> Varchar,
         disabled -> Nullable<Timestamptz>,
         guild_id -> Numeric,
+        deadline -> Nullable<Timestamptz>,
+        reminders_sent -> Int2,
+        owner_id -> Nullable<Numeric>,
+    }
+}
+
+diesel::table! {
+    profit_run_shares (id) {
+        id -> Int8,
+        run_id -> Int8,
+        user_id -> Numeric,
+        percent -> Numeric,
+        amount -> Numeric,
+        paid -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    profit_runs (id) {
+        id -> Int8,
+        created -> Timestamptz,
+        guild_id -> Numeric,
+        owner -> Numeric,
+        recorded_by -> Numeric,
+        #[max_length = 100]
+        name -> Varchar,
+        gross -> Numeric,
+        costs -> Numeric,
+    }
+}
+
+diesel::table! {
+    season_results (id) {
+        id -> Int8,
+        season_id -> Int8,
+        #[max_length = 100]
+        stat -> Varchar,
+        rank -> Int4,
+        user_id -> Numeric,
+        total -> Numeric,
+    }
+}
+
+diesel::table! {
+    seasons (id) {
+        id -> Int8,
+        created -> Timestamptz,
+        guild_id -> Numeric,
+        #[max_length = 100]
+        name -> Varchar,
+        starts -> Date,
+        ends -> Date,
+        finalized -> Nullable<Timestamptz>,
     }
 }
 
@@ -31,6 +267,7 @@ diesel::table! {
         total -> Numeric,
         #[max_length = 10000]
         user_note -> Nullable<Varchar>,
+        transfer -> Bool,
     }
 }
 
@@ -44,11 +281,41 @@ diesel::table! {
         guild_id -> Numeric,
         user_id -> Numeric,
         total -> Numeric,
+        archived -> Nullable<Timestamptz>,
     }
 }
 
+diesel::joinable!(event_attendees -> events (event_id));
+diesel::joinable!(event_attendees -> tracker_count_changes (change_id));
+diesel::joinable!(event_rsvps -> events (event_id));
+diesel::joinable!(goal_items -> monthly_goals (goal_id));
+diesel::joinable!(goal_milestones -> monthly_goals (goal_id));
+diesel::joinable!(goal_pledges -> monthly_goals (goal_id));
+diesel::joinable!(goal_revisions -> monthly_goals (goal_id));
+diesel::joinable!(profit_run_shares -> profit_runs (run_id));
+diesel::joinable!(season_results -> seasons (season_id));
+
 diesel::allow_tables_to_appear_in_same_query!(
+    event_attendees,
+    event_rsvps,
+    events,
+    goal_boards,
+    goal_items,
+    goal_milestones,
+    goal_pledges,
+    goal_revisions,
+    guild_api_tokens,
+    guild_branches,
+    guild_member_departures,
+    guild_merit_weights,
+    guild_role_groups,
+    guild_settings,
+    guild_stat_limits,
     monthly_goals,
+    profit_run_shares,
+    profit_runs,
+    season_results,
+    seasons,
     tracker_count_changes,
     tracker_counts,
 );